@@ -13,7 +13,7 @@ mod helper;
 use boa_engine::{
     builtins::promise::PromiseState,
     context::ContextBuilder,
-    job::{FutureJob, JobQueue, NativeJob},
+    job::{FutureJob, JobQueue, NativeJob, PendingJobs},
     module::{Module, SimpleModuleLoader},
     optimizer::OptimizerOptions,
     script::Script,
@@ -38,6 +38,10 @@ use std::{
     path::{Path, PathBuf},
     println,
     rc::Rc,
+    sync::Arc,
+    task::{self, Wake, Waker},
+    thread::Thread,
+    time::{Duration, Instant},
 };
 
 #[cfg(all(
@@ -137,6 +141,12 @@ struct Opt {
     /// Root path from where the module resolver will try to load the modules.
     #[arg(long, short = 'r', default_value_os_t = PathBuf::from("."), requires = "mod")]
     root: PathBuf,
+
+    /// Load the locale data used by `Intl` from an ICU4X data blob, instead of using the
+    /// bundled data.
+    #[cfg(feature = "intl")]
+    #[arg(long, value_name = "FILE", value_hint = ValueHint::FilePath)]
+    icu_data: Option<PathBuf>,
 }
 
 impl Opt {
@@ -338,11 +348,20 @@ fn main() -> Result<()> {
 
     let queue = Rc::new(Jobs::default());
     let loader = Rc::new(SimpleModuleLoader::new(&args.root).map_err(|e| eyre!(e.to_string()))?);
-    let mut context = ContextBuilder::new()
+    let builder = ContextBuilder::new()
         .job_queue(queue)
-        .module_loader(loader.clone())
-        .build()
-        .map_err(|e| eyre!(e.to_string()))?;
+        .module_loader(loader.clone());
+
+    #[cfg(feature = "intl")]
+    let builder = if let Some(path) = &args.icu_data {
+        builder
+            .icu_blob_file(path)
+            .wrap_err_with(|| eyre!("could not load the ICU data from `{}`", path.display()))?
+    } else {
+        builder
+    };
+
+    let mut context = builder.build().map_err(|e| eyre!(e.to_string()))?;
 
     // Strict mode
     context.strict(args.strict);
@@ -453,18 +472,34 @@ fn add_runtime(context: &mut Context) {
 }
 
 #[derive(Default)]
-struct Jobs(RefCell<VecDeque<NativeJob>>);
+struct Jobs {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    pending: PendingJobs,
+}
 
 impl JobQueue for Jobs {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = task::Context::from_waker(&waker);
         loop {
-            let jobs = std::mem::take(&mut *self.0.borrow_mut());
+            let jobs = std::mem::take(&mut *self.jobs.borrow_mut());
             if jobs.is_empty() {
-                return;
+                let mut ready = self.pending.poll_futures(&mut cx);
+                let now = Instant::now();
+                while let Some(job) = self.pending.pop_expired(now) {
+                    ready.push(job);
+                }
+                match self.pending.next_deadline() {
+                    _ if !ready.is_empty() => self.jobs.borrow_mut().extend(ready),
+                    Some(deadline) => std::thread::park_timeout(deadline - now),
+                    None if self.pending.has_futures() => std::thread::park(),
+                    None => return,
+                }
+                continue;
             }
             for job in jobs {
                 if let Err(e) = job.call(context) {
@@ -475,7 +510,21 @@ impl JobQueue for Jobs {
     }
 
     fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
-        let job = pollster::block_on(future);
-        self.0.borrow_mut().push_back(job);
+        self.pending.enqueue_future(future);
+    }
+
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, _: &mut Context) {
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.pending.enqueue_timeout(job, deadline);
+        }
+    }
+}
+
+/// Wakes up the thread running the jobs of [`Jobs`] when a future can make progress.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
     }
 }
@@ -9,7 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-exports
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
 
-use super::{ModuleSpecifier, VarDeclaration};
+use super::{ImportAttribute, ModuleRequest, ModuleSpecifier, VarDeclaration};
 use crate::{
     expression::Identifier,
     function::{
@@ -88,6 +88,8 @@ pub enum ExportDeclaration {
         kind: ReExportKind,
         /// Reexported module specifier.
         specifier: ModuleSpecifier,
+        /// Import attributes of the `with` clause.
+        attributes: Box<[ImportAttribute]>,
     },
     /// List of exports.
     List(Box<[ExportSpecifier]>),
@@ -115,9 +117,17 @@ impl VisitWith for ExportDeclaration {
         V: Visitor<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 visitor.visit_module_specifier(specifier)?;
-                visitor.visit_re_export_kind(kind)
+                visitor.visit_re_export_kind(kind)?;
+                for attribute in &**attributes {
+                    visitor.visit_import_attribute(attribute)?;
+                }
+                ControlFlow::Continue(())
             }
             Self::List(list) => {
                 for item in &**list {
@@ -145,9 +155,17 @@ impl VisitWith for ExportDeclaration {
         V: VisitorMut<'a>,
     {
         match self {
-            Self::ReExport { specifier, kind } => {
+            Self::ReExport {
+                specifier,
+                kind,
+                attributes,
+            } => {
                 visitor.visit_module_specifier_mut(specifier)?;
-                visitor.visit_re_export_kind_mut(kind)
+                visitor.visit_re_export_kind_mut(kind)?;
+                for attribute in &mut **attributes {
+                    visitor.visit_import_attribute_mut(attribute)?;
+                }
+                ControlFlow::Continue(())
             }
             Self::List(list) => {
                 for item in &mut **list {
@@ -254,14 +272,14 @@ pub enum ReExportImportName {
 /// [`ExportEntry`][spec] record.
 ///
 /// [spec]: https://tc39.es/ecma262/#table-exportentry-records
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum ExportEntry {
     /// An ordinary export entry
     Ordinary(LocalExportEntry),
     /// A star reexport entry.
     StarReExport {
         /// The module from where this reexport will import.
-        module_request: Sym,
+        module_request: ModuleRequest,
     },
     /// A reexport entry with an export name.
     ReExport(IndirectExportEntry),
@@ -310,9 +328,9 @@ impl LocalExportEntry {
 }
 
 /// A reexported export entry.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct IndirectExportEntry {
-    module_request: Sym,
+    module_request: ModuleRequest,
     import_name: ReExportImportName,
     export_name: Sym,
 }
@@ -321,7 +339,7 @@ impl IndirectExportEntry {
     /// Creates a new `IndirectExportEntry`.
     #[must_use]
     pub const fn new(
-        module_request: Sym,
+        module_request: ModuleRequest,
         import_name: ReExportImportName,
         export_name: Sym,
     ) -> Self {
@@ -334,8 +352,8 @@ impl IndirectExportEntry {
 
     /// Gets the module from where this entry reexports.
     #[must_use]
-    pub const fn module_request(&self) -> Sym {
-        self.module_request
+    pub const fn module_request(&self) -> &ModuleRequest {
+        &self.module_request
     }
 
     /// Gets the import name of the reexport.
@@ -17,6 +17,7 @@
 use crate::expression::Expression;
 use crate::function::PrivateName;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::Position;
 use boa_interner::{Interner, Sym, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -129,10 +130,11 @@ impl VisitWith for PropertyAccess {
 /// A simple property access, where the target object is an [`Expression`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct SimplePropertyAccess {
     target: Box<Expression>,
     field: PropertyAccessField,
+    position: Option<Position>,
 }
 
 impl SimplePropertyAccess {
@@ -150,6 +152,16 @@ impl SimplePropertyAccess {
         &self.field
     }
 
+    /// Gets the position of the property access in the source code.
+    ///
+    /// This is the position of the `.` or `[` punctuator of the access. Returns `None` if the
+    /// access was not created by the parser.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+
     /// Creates a `PropertyAccess` AST Expression.
     pub fn new<F>(target: Expression, field: F) -> Self
     where
@@ -158,8 +170,28 @@ impl SimplePropertyAccess {
         Self {
             target: target.into(),
             field: field.into(),
+            position: None,
         }
     }
+
+    /// Creates a `PropertyAccess` AST Expression with the position of the access in the source
+    /// code.
+    pub fn with_position<F>(target: Expression, field: F, position: Position) -> Self
+    where
+        F: Into<PropertyAccessField>,
+    {
+        Self {
+            target: target.into(),
+            field: field.into(),
+            position: Some(position),
+        }
+    }
+}
+
+impl PartialEq for SimplePropertyAccess {
+    fn eq(&self, other: &Self) -> bool {
+        self.target == other.target && self.field == other.field
+    }
 }
 
 impl ToInternedString for SimplePropertyAccess {
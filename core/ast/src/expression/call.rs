@@ -1,5 +1,5 @@
-use crate::join_nodes;
 use crate::visitor::{VisitWith, Visitor, VisitorMut};
+use crate::{join_nodes, Position};
 use boa_interner::{Interner, ToInternedString};
 use core::ops::ControlFlow;
 
@@ -21,10 +21,11 @@ use super::Expression;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Functions#Calling_functions
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Call {
     function: Box<Expression>,
     args: Box<[Expression]>,
+    position: Option<Position>,
 }
 
 impl Call {
@@ -35,6 +36,22 @@ impl Call {
         Self {
             function: function.into(),
             args,
+            position: None,
+        }
+    }
+
+    /// Creates a new `Call` AST Expression with the position of the call in the source code.
+    #[inline]
+    #[must_use]
+    pub fn with_position(
+        function: Expression,
+        args: Box<[Expression]>,
+        position: Position,
+    ) -> Self {
+        Self {
+            function: function.into(),
+            args,
+            position: Some(position),
         }
     }
 
@@ -51,6 +68,24 @@ impl Call {
     pub const fn args(&self) -> &[Expression] {
         &self.args
     }
+
+    /// Gets the position of the call in the source code.
+    ///
+    /// This is the position of the opening parenthesis of the arguments, or the position of the
+    /// `new` keyword for `new` expressions. Returns `None` if the call was not created by the
+    /// parser.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+// The position is only used for diagnostics, so it doesn't take part in equality.
+impl PartialEq for Call {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function && self.args == other.args
+    }
 }
 
 impl ToInternedString for Call {
@@ -105,9 +140,10 @@ impl VisitWith for Call {
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct SuperCall {
     args: Box<[Expression]>,
+    position: Option<Position>,
 }
 
 impl SuperCall {
@@ -116,7 +152,22 @@ impl SuperCall {
     where
         A: Into<Box<[Expression]>>,
     {
-        Self { args: args.into() }
+        Self {
+            args: args.into(),
+            position: None,
+        }
+    }
+
+    /// Creates a new `SuperCall` AST node with the position of the `super` keyword in the source
+    /// code.
+    pub fn with_position<A>(args: A, position: Position) -> Self
+    where
+        A: Into<Box<[Expression]>>,
+    {
+        Self {
+            args: args.into(),
+            position: Some(position),
+        }
     }
 
     /// Retrieves the arguments of the super call.
@@ -124,6 +175,18 @@ impl SuperCall {
     pub const fn arguments(&self) -> &[Expression] {
         &self.args
     }
+
+    /// Gets the position of the `super` keyword of the call in the source code.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl PartialEq for SuperCall {
+    fn eq(&self, other: &Self) -> bool {
+        self.args == other.args
+    }
 }
 
 impl ToInternedString for SuperCall {
@@ -177,6 +240,7 @@ impl VisitWith for SuperCall {
 #[derive(Clone, Debug, PartialEq)]
 pub struct ImportCall {
     arg: Box<Expression>,
+    options: Option<Box<Expression>>,
 }
 
 impl ImportCall {
@@ -187,20 +251,47 @@ impl ImportCall {
     {
         Self {
             arg: Box::new(arg.into()),
+            options: None,
+        }
+    }
+
+    /// Creates a new `ImportCall` AST node with an options argument.
+    pub fn with_options<A, O>(arg: A, options: O) -> Self
+    where
+        A: Into<Expression>,
+        O: Into<Expression>,
+    {
+        Self {
+            arg: Box::new(arg.into()),
+            options: Some(Box::new(options.into())),
         }
     }
 
-    /// Retrieves the single argument of the import call.
+    /// Retrieves the specifier argument of the import call.
     #[must_use]
     pub const fn argument(&self) -> &Expression {
         &self.arg
     }
+
+    /// Retrieves the optional options argument of the import call.
+    #[must_use]
+    pub fn options(&self) -> Option<&Expression> {
+        self.options.as_deref()
+    }
 }
 
 impl ToInternedString for ImportCall {
     #[inline]
     fn to_interned_string(&self, interner: &Interner) -> String {
-        format!("import({})", self.arg.to_interned_string(interner))
+        if let Some(options) = &self.options {
+            format!(
+                "import({}, {})",
+                self.arg.to_interned_string(interner),
+                options.to_interned_string(interner)
+            )
+        } else {
+            format!("import({})", self.arg.to_interned_string(interner))
+        }
     }
 }
 
@@ -216,13 +307,21 @@ impl VisitWith for ImportCall {
     where
         V: Visitor<'a>,
     {
-        visitor.visit_expression(&self.arg)
+        visitor.visit_expression(&self.arg)?;
+        if let Some(options) = &self.options {
+            visitor.visit_expression(options)?;
+        }
+        ControlFlow::Continue(())
     }
 
     fn visit_with_mut<'a, V>(&'a mut self, visitor: &mut V) -> ControlFlow<V::BreakTy>
     where
         V: VisitorMut<'a>,
     {
-        visitor.visit_expression_mut(&mut self.arg)
+        visitor.visit_expression_mut(&mut self.arg)?;
+        if let Some(options) = &mut self.options {
+            visitor.visit_expression_mut(options)?;
+        }
+        ControlFlow::Continue(())
     }
 }
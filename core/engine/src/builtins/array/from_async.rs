@@ -0,0 +1,530 @@
+//! Boa's implementation of the `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )` method.
+//!
+//! The spec defines `Array.fromAsync` as an abstract closure started with `AsyncFunctionStart`,
+//! which means that every `Await` inside of it suspends the operation until the awaited promise
+//! settles. Since native functions cannot suspend, the closure is implemented as a state machine
+//! that schedules its own continuation on the awaited promise.
+//!
+//! More information:
+//!  - [ECMAScript proposal][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+
+use crate::{
+    builtins::{
+        iterable::{AsyncFromSyncIterator, IteratorRecord, IteratorResult},
+        promise::PromiseCapability,
+        Promise,
+    },
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    value::JsVariant,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsValue,
+};
+
+use super::Array;
+
+/// The source of the values collected by `Array.fromAsync`.
+#[derive(Debug, Trace, Finalize)]
+enum Source {
+    /// An async iterator, or a sync iterator wrapped by `CreateAsyncFromSyncIterator`.
+    Iterator(IteratorRecord),
+
+    /// An array-like object of length `len`.
+    ArrayLike { array_like: JsObject, len: u64 },
+}
+
+/// The point of the `Array.fromAsync` closure that resumes after an `Await`.
+#[derive(Debug, Trace, Finalize)]
+#[boa_gc(unsafe_no_drop)]
+enum Resume {
+    /// Resumes with the awaited result of calling `next` on the iterator.
+    NextResult,
+
+    /// Resumes with the awaited element of the array-like object.
+    Value,
+
+    /// Resumes with the awaited result of calling `mapfn`.
+    MappedValue,
+
+    /// Resumes after awaiting the result of `AsyncIteratorClose`, rethrowing the original error.
+    Close(JsError),
+}
+
+/// An action that the `Array.fromAsync` closure must take after running one of its steps.
+enum Step {
+    /// Suspend the closure until `value` settles.
+    Await(JsValue, Resume),
+
+    /// Complete the closure, fulfilling the returned promise with the created array.
+    Return(JsObject),
+}
+
+/// The suspended state of an `Array.fromAsync` call.
+#[derive(Debug, Trace, Finalize)]
+struct FromAsyncState {
+    promise_capability: PromiseCapability,
+    array: JsObject,
+    mapfn: Option<JsObject>,
+    this_arg: JsValue,
+    source: Source,
+    k: GcRefCell<u64>,
+}
+
+impl Array {
+    /// `Array.fromAsync ( asyncItems [ , mapfn [ , thisArg ] ] )`
+    ///
+    /// The `Array.fromAsync()` static method creates a new, shallow-copied `Array` instance from
+    /// an async iterable, iterable, or array-like object, returning a promise that fulfills with
+    /// the new array.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-from-async/#sec-array.fromAsync
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fromAsync
+    #[allow(clippy::unnecessary_wraps)]
+    pub(crate) fn from_async(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail per the spec");
+        let promise = promise_capability.promise().clone();
+
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters that captures C,
+        //    mapfn, and thisArg and performs the following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        match Self::from_async_start(this, args, promise_capability.clone(), context) {
+            Ok((state, step)) => Self::from_async_continue(&state, Ok(step), context),
+            Err(err) => {
+                let err = err.to_opaque(context);
+                promise_capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("cannot fail per the spec");
+            }
+        }
+
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// Runs the steps of the `fromAsyncClosure` up until its first `Await`.
+    fn from_async_start(
+        this: &JsValue,
+        args: &[JsValue],
+        promise_capability: PromiseCapability,
+        context: &mut Context,
+    ) -> JsResult<(Gc<FromAsyncState>, Step)> {
+        let async_items = args.get_or_undefined(0);
+        let mapfn = args.get_or_undefined(1);
+        let this_arg = args.get_or_undefined(2).clone();
+
+        // a. If mapfn is undefined, let mapping be false.
+        // b. Else,
+        //     i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        //     ii. Let mapping be true.
+        let mapfn = match mapfn.variant() {
+            JsVariant::Undefined => None,
+            JsVariant::Object(o) if o.is_callable() => Some(o.clone()),
+            _ => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!("`{}` is not callable", mapfn.type_of()))
+                    .into())
+            }
+        };
+
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let async_method = async_items.get_method(JsSymbol::async_iterator(), context)?;
+
+        // d. If usingAsyncIterator is undefined, then
+        //     i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+        let sync_method = if async_method.is_none() {
+            async_items.get_method(JsSymbol::iterator(), context)?
+        } else {
+            None
+        };
+
+        // e. Let iteratorRecord be undefined.
+        // f. If usingAsyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        //     i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+        let iterator_record = match (async_method, sync_method) {
+            (Some(method), _) => Some(async_items.get_iterator_from_method(&method, context)?),
+            (None, Some(method)) => {
+                let sync_iterator_record =
+                    async_items.get_iterator_from_method(&method, context)?;
+                Some(AsyncFromSyncIterator::create(sync_iterator_record, context))
+            }
+            (None, None) => None,
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        if let Some(iterator_record) = iterator_record {
+            // i. If IsConstructor(C) is true, then
+            //     1. Let A be ? Construct(C).
+            // ii. Else,
+            //     1. Let A be ! ArrayCreate(0).
+            let array = match this.as_constructor() {
+                Some(constructor) => constructor.construct(&[], None, context)?,
+                _ => Self::array_create(0, None, context)
+                    .expect("creating an empty array cannot fail"),
+            };
+
+            // iii. Let k be 0.
+            let state = Gc::new(FromAsyncState {
+                promise_capability,
+                array,
+                mapfn,
+                this_arg,
+                source: Source::Iterator(iterator_record),
+                k: GcRefCell::new(0),
+            });
+
+            // iv. Repeat,
+            let step = state.next_iterator_step(context)?;
+            return Ok((state, step));
+        }
+
+        // i. Else,
+        //     i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so assume it is an array-like object.
+        //     ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = async_items
+            .to_object(context)
+            .expect("should not fail according to spec");
+
+        //     iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like.length_of_array_like(context)?;
+
+        //     iv. If IsConstructor(C) is true, then
+        //         1. Let A be ? Construct(C, « 𝔽(len) »).
+        //     v. Else,
+        //         1. Let A be ? ArrayCreate(len).
+        let array = match this.as_constructor() {
+            Some(constructor) => constructor.construct(&[len.into()], None, context)?,
+            _ => Self::array_create(len, None, context)?,
+        };
+
+        //     vi. Let k be 0.
+        let state = Gc::new(FromAsyncState {
+            promise_capability,
+            array,
+            mapfn,
+            this_arg,
+            source: Source::ArrayLike { array_like, len },
+            k: GcRefCell::new(0),
+        });
+
+        //     vii. Repeat, while k < len,
+        let step = state.next_array_like_step(context)?;
+        Ok((state, step))
+    }
+
+    /// Handles the result of a step of the `fromAsyncClosure`, either settling the returned
+    /// promise or awaiting the next value.
+    fn from_async_continue(
+        state: &Gc<FromAsyncState>,
+        step: JsResult<Step>,
+        context: &mut Context,
+    ) {
+        let capability = &state.promise_capability;
+        let (value, resume) = match step {
+            Ok(Step::Await(value, resume)) => (value, resume),
+            Ok(Step::Return(array)) => {
+                capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[array.into()], context)
+                    .expect("cannot fail per the spec");
+                return;
+            }
+            Err(err) => {
+                let err = err.to_opaque(context);
+                capability
+                    .reject()
+                    .call(&JsValue::undefined(), &[err], context)
+                    .expect("cannot fail per the spec");
+                return;
+            }
+        };
+
+        // Await ( value )
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise = match Promise::promise_resolve(
+            &context.intrinsics().constructors().promise().constructor(),
+            value,
+            context,
+        ) {
+            Ok(promise) => promise,
+            Err(err) => {
+                let step = state.resume(resume, Err(err), context);
+                return Self::from_async_continue(state, step, context);
+            }
+        };
+
+        let resume = Gc::new(GcRefCell::new(Some(resume)));
+
+        // 3. Let fulfilledClosure be a new Abstract Closure with parameters (v) that captures
+        //    asyncContext and performs the following steps when called:
+        // 4. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 1, "", « »).
+        let on_fulfilled = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, (state, resume), context| {
+                    let resume = resume.borrow_mut().take().expect("must only resume once");
+                    let step = state.resume(resume, Ok(args.get_or_undefined(0).clone()), context);
+                    Self::from_async_continue(state, step, context);
+                    Ok(JsValue::undefined())
+                },
+                (state.clone(), resume.clone()),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        // 5. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures
+        //    asyncContext and performs the following steps when called:
+        // 6. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+        let on_rejected = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, (state, resume), context| {
+                    let resume = resume.borrow_mut().take().expect("must only resume once");
+                    let err = JsError::from_opaque(args.get_or_undefined(0).clone());
+                    let step = state.resume(resume, Err(err), context);
+                    Self::from_async_continue(state, step, context);
+                    Ok(JsValue::undefined())
+                },
+                (state.clone(), resume),
+            ),
+        )
+        .name(js_string!())
+        .length(1)
+        .build();
+
+        // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        Promise::perform_promise_then(
+            &promise,
+            Some(on_fulfilled),
+            Some(on_rejected),
+            None,
+            context,
+        );
+    }
+}
+
+impl FromAsyncState {
+    /// Resumes the `fromAsyncClosure` at `resume` with the completion of the awaited value.
+    fn resume(
+        &self,
+        resume: Resume,
+        result: JsResult<JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Step> {
+        match resume {
+            Resume::NextResult => {
+                // iv. 4. Set nextResult to ? Await(nextResult).
+                let next_result = result?;
+
+                // iv. 5. If nextResult is not an Object, throw a TypeError exception.
+                let next_result = IteratorResult::from_value(next_result)?;
+
+                // iv. 6. Let done be ? IteratorComplete(nextResult).
+                // iv. 7. If done is true,
+                if next_result.complete(context)? {
+                    // a. Perform ? Set(A, "length", 𝔽(k), true).
+                    let k = *self.k.borrow();
+                    self.array.set(StaticJsStrings::LENGTH, k, true, context)?;
+
+                    // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+                    return Ok(Step::Return(self.array.clone()));
+                }
+
+                // iv. 8. Let nextValue be ? IteratorValue(nextResult).
+                let next_value = next_result.value(context)?;
+
+                // iv. 9. If mapping is true, then
+                if let Some(mapfn) = &self.mapfn {
+                    // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                    let k = *self.k.borrow();
+                    match mapfn.call(&self.this_arg, &[next_value, k.into()], context) {
+                        // c. Set mappedValue to Await(mappedValue).
+                        Ok(mapped_value) => Ok(Step::Await(mapped_value, Resume::MappedValue)),
+                        // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                        Err(err) => self.close_iterator(err, context),
+                    }
+                } else {
+                    // iv. 10. Else, let mappedValue be nextValue.
+                    self.define_from_iterator(next_value, context)
+                }
+            }
+            Resume::MappedValue => match &self.source {
+                Source::Iterator(_) => match result {
+                    Ok(mapped_value) => self.define_from_iterator(mapped_value, context),
+                    // iv. 9. d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                    Err(err) => self.close_iterator(err, context),
+                },
+                // vii. 4. b. Set mappedValue to ? Await(mappedValue).
+                Source::ArrayLike { .. } => self.define_from_array_like(result?, context),
+            },
+            Resume::Value => {
+                // vii. 3. Set kValue to ? Await(kValue).
+                let k_value = result?;
+
+                // vii. 4. If mapping is true, then
+                if let Some(mapfn) = &self.mapfn {
+                    // a. Let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+                    let k = *self.k.borrow();
+                    let mapped_value = mapfn.call(&self.this_arg, &[k_value, k.into()], context)?;
+
+                    // b. Set mappedValue to ? Await(mappedValue).
+                    Ok(Step::Await(mapped_value, Resume::MappedValue))
+                } else {
+                    // vii. 5. Else, let mappedValue be kValue.
+                    self.define_from_array_like(k_value, context)
+                }
+            }
+            // `AsyncIteratorClose ( iteratorRecord, completion )`
+            // 5. If completion is a throw completion, return ? completion.
+            Resume::Close(err) => Err(err),
+        }
+    }
+
+    /// Runs the steps of the iterator loop up until the `Await` of the next result.
+    fn next_iterator_step(&self, context: &mut Context) -> JsResult<Step> {
+        let Source::Iterator(iterator_record) = &self.source else {
+            unreachable!("must only be called when iterating an iterator");
+        };
+
+        // 1. If k ≥ 2^53 - 1, then
+        if *self.k.borrow() >= 9_007_199_254_740_991 {
+            // a. Let error be ThrowCompletion(a newly created TypeError object).
+            let error = JsNativeError::typ()
+                .with_message("Invalid array length")
+                .into();
+
+            // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+            return self.close_iterator(error, context);
+        }
+
+        // 2. Let Pk be ! ToString(𝔽(k)).
+        // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        let next_result = iterator_record.next_method().call(
+            &iterator_record.iterator().clone().into(),
+            &[],
+            context,
+        )?;
+
+        // 4. Set nextResult to ? Await(nextResult).
+        Ok(Step::Await(next_result, Resume::NextResult))
+    }
+
+    /// Stores the mapped value of the iterator loop, then continues to the next iteration.
+    fn define_from_iterator(&self, mapped_value: JsValue, context: &mut Context) -> JsResult<Step> {
+        let k = *self.k.borrow();
+
+        // 11. Let defineStatus be Completion(CreateDataPropertyOrThrow(A, Pk, mappedValue)).
+        // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        if let Err(err) = self
+            .array
+            .create_data_property_or_throw(k, mapped_value, context)
+        {
+            return self.close_iterator(err, context);
+        }
+
+        // 13. Set k to k + 1.
+        *self.k.borrow_mut() = k + 1;
+
+        self.next_iterator_step(context)
+    }
+
+    /// Runs the steps of the array-like loop up until the `Await` of the next element.
+    fn next_array_like_step(&self, context: &mut Context) -> JsResult<Step> {
+        let Source::ArrayLike { array_like, len } = &self.source else {
+            unreachable!("must only be called when iterating an array-like object");
+        };
+
+        let k = *self.k.borrow();
+
+        // vii. Repeat, while k < len,
+        if k < *len {
+            // 1. Let Pk be ! ToString(𝔽(k)).
+            // 2. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = array_like.get(k, context)?;
+
+            // 3. Set kValue to ? Await(kValue).
+            return Ok(Step::Await(k_value, Resume::Value));
+        }
+
+        // viii. Perform ? Set(A, "length", 𝔽(len), true).
+        self.array
+            .set(StaticJsStrings::LENGTH, *len, true, context)?;
+
+        // ix. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        Ok(Step::Return(self.array.clone()))
+    }
+
+    /// Stores the mapped value of the array-like loop, then continues to the next iteration.
+    fn define_from_array_like(
+        &self,
+        mapped_value: JsValue,
+        context: &mut Context,
+    ) -> JsResult<Step> {
+        let k = *self.k.borrow();
+
+        // 6. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+        self.array
+            .create_data_property_or_throw(k, mapped_value, context)?;
+
+        // 7. Set k to k + 1.
+        *self.k.borrow_mut() = k + 1;
+
+        self.next_array_like_step(context)
+    }
+
+    /// `AsyncIteratorClose ( iteratorRecord, completion )`, for a throw `completion`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close_iterator(&self, error: JsError, context: &mut Context) -> JsResult<Step> {
+        let Source::Iterator(iterator_record) = &self.source else {
+            unreachable!("must only be called when iterating an iterator");
+        };
+
+        // 2. Let iterator be iteratorRecord.[[Iterator]].
+        let iterator = iterator_record.iterator();
+
+        // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+        // 4. If innerResult is a normal completion, then
+        //     a. Let return be innerResult.[[Value]].
+        //     b. If return is undefined, return ? completion.
+        //     c. Set innerResult to Completion(Call(return, iterator)).
+        //     d. If innerResult is a normal completion, set innerResult to Completion(Await(innerResult.[[Value]])).
+        // 5. If completion is a throw completion, return ? completion.
+        match iterator.get_method(js_string!("return"), context) {
+            Ok(Some(r#return)) => match r#return.call(&iterator.clone().into(), &[], context) {
+                Ok(inner_result) => Ok(Step::Await(inner_result, Resume::Close(error))),
+                Err(_) => Err(error),
+            },
+            Ok(None) | Err(_) => Err(error),
+        }
+    }
+}
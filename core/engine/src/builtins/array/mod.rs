@@ -38,6 +38,7 @@ use std::cmp::{min, Ordering};
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 
 mod array_iterator;
+mod from_async;
 use crate::value::JsVariant;
 pub(crate) use array_iterator::ArrayIterator;
 
@@ -109,6 +110,7 @@ impl IntrinsicObject for Array {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             // Static Methods
             .static_method(Self::from, js_string!("from"), 1)
+            .static_method(Self::from_async, js_string!("fromAsync"), 1)
             .static_method(Self::is_array, js_string!("isArray"), 1)
             .static_method(Self::of, js_string!("of"), 0)
             .static_accessor(
@@ -192,7 +194,7 @@ impl BuiltInObject for Array {
 
 impl BuiltInConstructor for Array {
     const P: usize = 41;
-    const SP: usize = 5;
+    const SP: usize = 6;
 
     const LENGTH: usize = 1;
 
@@ -2182,6 +2184,11 @@ impl Array {
         // 4. Let R be the empty String.
         let mut r = Vec::new();
 
+        let locales_and_options = [
+            args.get_or_undefined(0).clone(),
+            args.get_or_undefined(1).clone(),
+        ];
+
         // 5. Let k be 0.
         // 6. Repeat, while k < len,
         for k in 0..len {
@@ -2198,7 +2205,7 @@ impl Array {
             if !next.is_null_or_undefined() {
                 // i. Let S be ? ToString(? Invoke(nextElement, "toLocaleString", « locales, options »)).
                 let s = next
-                    .invoke(js_string!("toLocaleString"), args, context)?
+                    .invoke(js_string!("toLocaleString"), &locales_and_options, context)?
                     .to_string(context)?;
 
                 // ii. Set R to the string-concatenation of R and S.
@@ -46,6 +46,69 @@ fn of() {
     ]);
 }
 
+#[test]
+fn from_async() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            async function* gen() {
+                yield 1;
+                yield Promise.resolve(2);
+                yield 3;
+            }
+            var results = {};
+            Array.fromAsync(gen()).then((a) => { results.asyncIterable = a; });
+            Array.fromAsync([Promise.resolve(1), 2, Promise.resolve(3)])
+                .then((a) => { results.iterable = a; });
+            Array.fromAsync({ length: 2, 0: Promise.resolve("a"), 1: "b" })
+                .then((a) => { results.arrayLike = a; });
+            Array.fromAsync(gen(), async (x, i) => x * 10 + i)
+                .then((a) => { results.mapped = a; });
+            Array.fromAsync.call(Object, [1, 2])
+                .then((a) => { results.constructed = a; });
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("arrayEquals(results.asyncIterable, [1, 2, 3])"),
+        TestAction::assert("arrayEquals(results.iterable, [1, 2, 3])"),
+        TestAction::assert(r#"arrayEquals(results.arrayLike, ["a", "b"])"#),
+        TestAction::assert("arrayEquals(results.mapped, [10, 21, 32])"),
+        TestAction::assert("!Array.isArray(results.constructed)"),
+        TestAction::assert_eq("results.constructed.length", 2),
+        TestAction::assert_eq("results.constructed[1]", 2),
+    ]);
+}
+
+#[test]
+fn from_async_errors() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = false;
+            var iterable = {
+                [Symbol.asyncIterator]() {
+                    return {
+                        next() { return Promise.resolve({ value: 1, done: false }); },
+                        return() { closed = true; return {}; },
+                    };
+                },
+            };
+            var errors = {};
+            Array.fromAsync(iterable, () => { throw new Error("map"); })
+                .catch((e) => { errors.map = e; });
+            Array.fromAsync([1], 5).catch((e) => { errors.notCallable = e; });
+            Array.fromAsync([Promise.reject(new Error("rejected"))])
+                .catch((e) => { errors.rejected = e; });
+            var sync = false;
+            Array.fromAsync({ get length() { sync = true; return 0; } });
+        "#}),
+        TestAction::assert("sync"),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert("closed"),
+        TestAction::assert_eq("errors.map.message", js_str!("map")),
+        TestAction::assert("errors.notCallable instanceof TypeError"),
+        TestAction::assert_eq("errors.rejected.message", js_str!("rejected")),
+    ]);
+}
+
 #[test]
 fn concat() {
     run_test_actions([
@@ -91,6 +154,41 @@ fn to_string() {
     ]);
 }
 
+#[test]
+fn to_locale_string() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var calls = [];
+            var element = {
+                toLocaleString() {
+                    calls.push(Array.from(arguments));
+                    return "x";
+                }
+            };
+            var options = { style: "percent" };
+        "#}),
+        TestAction::assert_eq(
+            "[element, null, element].toLocaleString('de', options, 'ignored')",
+            js_str!("x, , x"),
+        ),
+        TestAction::assert_eq("calls.length", 2),
+        TestAction::assert(
+            "calls.every(args => args.length === 2 && args[0] === 'de' && args[1] === options)",
+        ),
+        TestAction::assert("[element].toLocaleString() === 'x'"),
+        TestAction::assert("calls[2].length === 2 && calls[2].every(arg => arg === undefined)"),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn to_locale_string_intl() {
+    run_test_actions([TestAction::assert_eq(
+        "[1234.5, 0.25].toLocaleString('de', { maximumFractionDigits: 0 })",
+        js_str!("1.235, 0"),
+    )]);
+}
+
 #[test]
 fn every() {
     // taken from https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/every
@@ -97,6 +97,7 @@ fn sort() {
                 Uint16Array,
                 Int32Array,
                 Uint32Array,
+                Float16Array,
                 Float32Array,
                 Float64Array,
             ];
@@ -115,6 +116,7 @@ fn sort() {
         TestAction::assert("cmp(descending[5], [1, 2, 3, 4])"),
         TestAction::assert("cmp(descending[6], [1, 2, 3, 4])"),
         TestAction::assert("cmp(descending[7], [1, 2, 3, 4])"),
+        TestAction::assert("cmp(descending[8], [1, 2, 3, 4])"),
         // Mixed
         TestAction::assert("cmp(mixed[0], [1, 2, 3, 4])"),
         TestAction::assert("cmp(mixed[1], [1, 2, 3, 4])"),
@@ -124,6 +126,7 @@ fn sort() {
         TestAction::assert("cmp(mixed[5], [1, 2, 3, 4])"),
         TestAction::assert("cmp(mixed[6], [1, 2, 3, 4])"),
         TestAction::assert("cmp(mixed[7], [1, 2, 3, 4])"),
+        TestAction::assert("cmp(mixed[8], [1, 2, 3, 4])"),
         // Repeating
         TestAction::assert("cmp(repeating[0], [0, 1, 1, 2, 3, 3, 4])"),
         TestAction::assert("cmp(repeating[1], [0, 1, 1, 2, 3, 3, 4])"),
@@ -133,6 +136,7 @@ fn sort() {
         TestAction::assert("cmp(repeating[5], [0, 1, 1, 2, 3, 3, 4])"),
         TestAction::assert("cmp(repeating[6], [0, 1, 1, 2, 3, 3, 4])"),
         TestAction::assert("cmp(repeating[7], [0, 1, 1, 2, 3, 3, 4])"),
+        TestAction::assert("cmp(repeating[8], [0, 1, 1, 2, 3, 3, 4])"),
     ]);
 }
 
@@ -146,7 +150,7 @@ fn sort_negative_zero() {
                 return a.length === b.length && a.every((v, i) => v === b[i]);
             }
 
-            var TypedArrayCtor = [Float32Array, Float64Array];
+            var TypedArrayCtor = [Float16Array, Float32Array, Float64Array];
             var negativeZero = TypedArrayCtor.map((ctor) => new ctor([1, 0, -0, 2]).sort());
             var infinities = TypedArrayCtor.map((ctor) => new ctor([3, 4, Infinity, -Infinity, 1, 2]).sort());
         "#,
@@ -155,5 +159,33 @@ fn sort_negative_zero() {
         TestAction::assert("cmp(negativeZero[1], [-0, 0, 1, 2])"),
         TestAction::assert("cmp(infinities[0], [-Infinity, 1, 2, 3, 4, Infinity])"),
         TestAction::assert("cmp(infinities[1], [-Infinity, 1, 2, 3, 4, Infinity])"),
+        TestAction::assert("cmp(negativeZero[2], [-0, 0, 1, 2])"),
+        TestAction::assert("cmp(infinities[2], [-Infinity, 1, 2, 3, 4, Infinity])"),
+    ]);
+}
+
+#[test]
+fn float16() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            var array = new Float16Array([1.1, 65520, 2 ** -25, 1 + 2 ** -11 + 2 ** -52, NaN]);
+            var view = new DataView(new ArrayBuffer(4));
+            view.setFloat16(0, 1.5);
+            view.setFloat16(2, -2, true);
+        "#,
+        ),
+        TestAction::assert_eq("Float16Array.BYTES_PER_ELEMENT", 2),
+        TestAction::assert_eq("array.byteLength", 10),
+        TestAction::assert_eq("array[0]", 1.099_609_375),
+        TestAction::assert_eq("array[1]", f64::INFINITY),
+        TestAction::assert_eq("array[2]", 0.0),
+        TestAction::assert_eq("array[3]", 1.000_976_562_5),
+        TestAction::assert_eq("array[4]", f64::NAN),
+        TestAction::assert_eq("view.getUint16(0)", 0x3E00),
+        TestAction::assert_eq("view.getUint16(2, true)", 0xC000),
+        TestAction::assert_eq("view.getFloat16(0)", 1.5),
+        TestAction::assert_eq("view.getFloat16(0, true)", 62.0 * 2f64.powi(-24)),
+        TestAction::assert_eq("view.getFloat16(2, true)", -2.0),
     ]);
 }
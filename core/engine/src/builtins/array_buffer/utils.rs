@@ -2,6 +2,7 @@
 
 use std::{ptr, slice::SliceIndex, sync::atomic::Ordering};
 
+use half::f16;
 use portable_atomic::AtomicU8;
 
 use crate::{
@@ -129,6 +130,7 @@ impl SliceRef<'_> {
                 TypedArrayKind::Uint32 => read_elem::<u32>(buffer, order).into(),
                 TypedArrayKind::BigInt64 => read_elem::<i64>(buffer, order).into(),
                 TypedArrayKind::BigUint64 => read_elem::<u64>(buffer, order).into(),
+                TypedArrayKind::Float16 => read_elem::<f16>(buffer, order).into(),
                 TypedArrayKind::Float32 => read_elem::<f32>(buffer, order).into(),
                 TypedArrayKind::Float64 => read_elem::<f64>(buffer, order).into(),
             }
@@ -322,6 +324,7 @@ impl SliceRefMut<'_> {
                 TypedArrayElement::Uint32(e) => write_elem(buffer, e, order),
                 TypedArrayElement::BigInt64(e) => write_elem(buffer, e, order),
                 TypedArrayElement::BigUint64(e) => write_elem(buffer, e, order),
+                TypedArrayElement::Float16(e) => write_elem(buffer, e, order),
                 TypedArrayElement::Float32(e) => write_elem(buffer, e, order),
                 TypedArrayElement::Float64(e) => write_elem(buffer, e, order),
             }
@@ -133,25 +133,44 @@
 // In a future point in time, "Thread 1" will be notified, which will proceed with the
 // exact same steps as "Thread 2", emptying the wait queue and finishing the execution of our
 // program.
+//
+// `Atomics.waitAsync` shares the same wait queues, but its waiters cannot block the thread that
+// created them. Instead, an async `FutexWaiter` is allocated on the heap and owned by an
+// `AsyncWaiter`, which is a `Future` that gets polled by the job queue of the context. Notifying
+// an async waiter sets `waiting` to false and wakes the `Waker` registered by the last poll of
+// its future, which then resolves the promise returned by `Atomics.waitAsync`. Timeouts are handled
+// by a timeout job that removes the waiter from its queue if it is still waiting, then wakes the
+// future as if the waiter was notified.
 
 #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::undocumented_unsafe_blocks)]
 #![allow(clippy::expl_impl_clone_on_copy)]
 #![allow(unstable_name_collisions)]
 
-use std::{cell::UnsafeCell, sync::atomic::Ordering};
+use std::{
+    cell::{Cell, UnsafeCell},
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    sync::{atomic::Ordering, MutexGuard},
+    task::{self, Poll},
+};
 
 use crate::{
     builtins::{
         array_buffer::{utils::SliceRef, SharedArrayBuffer},
         typed_array::Element,
     },
+    js_string,
     sys::time::{Duration, Instant},
-    JsNativeError, JsResult,
+    JsNativeError, JsResult, JsString,
 };
 
 mod sync {
-    use std::sync::{Condvar, Mutex, MutexGuard};
+    use std::{
+        sync::{Condvar, Mutex, MutexGuard},
+        task::Waker,
+    };
 
     use intrusive_collections::{intrusive_adapter, LinkedList, LinkedListLink, UnsafeRef};
 
@@ -161,14 +180,28 @@ mod sync {
     };
 
     /// A waiter of a memory address.
+    ///
+    /// Synchronous waiters are woken up using `cond_var`, while asynchronous waiters are woken up
+    /// using the `waker` of the last poll of their future.
     #[derive(Debug, Default)]
     pub(crate) struct FutexWaiter {
         pub(super) link: LinkedListLink,
         pub(super) cond_var: Condvar,
+        pub(super) waker: Option<Waker>,
         pub(super) waiting: bool,
         addr: usize,
     }
 
+    impl FutexWaiter {
+        /// Wakes up the agent that is waiting on this waiter.
+        pub(super) fn wake(&mut self) {
+            self.cond_var.notify_one();
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
     intrusive_adapter!(FutexWaiterAdapter = UnsafeRef<FutexWaiter>: FutexWaiter { link: LinkedListLink });
 
     /// List of memory addresses and its corresponding list of waiters for that address.
@@ -214,11 +247,11 @@ mod sync {
                     return i;
                 };
 
-                elem.cond_var.notify_one();
-
                 // SAFETY: all elements of the waiters list are guaranteed to be valid.
                 unsafe {
-                    (*UnsafeRef::into_raw(elem)).waiting = false;
+                    let elem = UnsafeRef::into_raw(elem);
+                    (*elem).waiting = false;
+                    (*elem).wake();
                 }
             }
 
@@ -281,44 +314,80 @@ pub(super) enum AtomicsWaitResult {
     Ok,
 }
 
-/// Adds this agent to the wait queue for the address pointed to by `buffer[offset..]`.
+impl AtomicsWaitResult {
+    /// Gets the string returned to ECMAScript code for this result.
+    pub(super) fn to_js_string(self) -> JsString {
+        match self {
+            Self::NotEqual => js_string!("not-equal"),
+            Self::TimedOut => js_string!("timed-out"),
+            Self::Ok => js_string!("ok"),
+        }
+    }
+}
+
+/// Enters the critical section of the waiter list for the address pointed to by `buffer[offset..]`,
+/// returning the list and the address if the value stored at the address is equal to `check`.
 ///
 /// # Safety
 ///
 /// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
 /// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
-// our implementation guarantees that `SharedArrayBuffer` is always aligned to `u64` at minimum.
-pub(super) unsafe fn wait<E: Element + PartialEq>(
+unsafe fn enter_critical_section<E: Element + PartialEq>(
     buffer: &SharedArrayBuffer,
     buf_len: usize,
     offset: usize,
     check: E,
-    timeout: Option<Duration>,
-) -> JsResult<AtomicsWaitResult> {
-    // 10. Let block be buffer.[[ArrayBufferData]].
-    // 11. Let WL be GetWaiterList(block, indexedPosition).
-    // 12. Perform EnterCriticalSection(WL).
-    let mut waiters = sync::FutexWaiters::get()?;
-
-    let time_info = timeout.map(|timeout| (Instant::now(), timeout));
+) -> JsResult<Option<(MutexGuard<'static, sync::FutexWaiters>, usize)>> {
+    // 11. Let block be buffer.[[ArrayBufferData]].
+    // 14. Let WL be GetWaiterList(block, byteIndexInBuffer).
+    // 17. Perform EnterCriticalSection(WL).
+    let waiters = sync::FutexWaiters::get()?;
 
     let buffer = &buffer.bytes_with_len(buf_len)[offset..];
 
-    // 13. Let elementType be TypedArrayElementType(typedArray).
-    // 14. Let w be GetValueFromBuffer(buffer, indexedPosition, elementType, true, SeqCst).
+    // 18. Let elementType be TypedArrayElementType(typedArray).
+    // 19. Let w be GetValueFromBuffer(buffer, byteIndexInBuffer, elementType, true, SeqCst).
 
     // SAFETY: The safety of this operation is guaranteed by the caller.
     let value = unsafe { E::read(SliceRef::AtomicSlice(buffer)).load(Ordering::SeqCst) };
 
-    // 15. If v ≠ w, then
+    // 20. If v ≠ w, then
     //     a. Perform LeaveCriticalSection(WL).
-    //     b. Return "not-equal".
     if check != value {
-        return Ok(AtomicsWaitResult::NotEqual);
+        return Ok(None);
     }
 
-    // 16. Let W be AgentSignifier().
-    // 17. Perform AddWaiter(WL, W).
+    Ok(Some((waiters, sptr::Strict::addr(buffer.as_ptr()))))
+}
+
+/// Adds this agent to the wait queue for the address pointed to by `buffer[offset..]`.
+///
+/// # Safety
+///
+/// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
+/// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
+// our implementation guarantees that `SharedArrayBuffer` is always aligned to `u64` at minimum.
+pub(super) unsafe fn wait<E: Element + PartialEq>(
+    buffer: &SharedArrayBuffer,
+    buf_len: usize,
+    offset: usize,
+    check: E,
+    timeout: Option<Duration>,
+) -> JsResult<AtomicsWaitResult> {
+    // SAFETY: The safety of this operation is guaranteed by the caller.
+    let Some((mut waiters, addr)) =
+        (unsafe { enter_critical_section(buffer, buf_len, offset, check)? })
+    else {
+        //     b. If mode is sync, return "not-equal".
+        return Ok(AtomicsWaitResult::NotEqual);
+    };
+
+    let time_info = timeout.map(|timeout| (Instant::now(), timeout));
+
+    // 22. Let thisAgent be AgentSignifier().
+    // 27. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: thisAgent, [[PromiseCapability]]: promiseCapability,
+    //     [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+    // 28. Perform AddWaiter(WL, waiterRecord).
 
     // ensure we can have aliased pointers to the waiter in a sound way.
     let waiter = UnsafeCell::new(sync::FutexWaiter::default());
@@ -326,12 +395,13 @@ pub(super) unsafe fn wait<E: Element + PartialEq>(
 
     // SAFETY: waiter is valid and we call `remove_node` below.
     unsafe {
-        waiters.add_waiter(waiter_ptr, sptr::Strict::addr(buffer.as_ptr()));
+        waiters.add_waiter(waiter_ptr, addr);
     }
 
-    // 18. Let notified be SuspendAgent(WL, W, t).
+    // 29. If mode is sync, then
+    //     a. Perform SuspendThisAgent(WL, waiterRecord).
 
-    // `SuspendAgent(WL, W, t)`
+    // `SuspendThisAgent(WL, waiterRecord)`
     // https://tc39.es/ecma262/#sec-suspendthisagent
 
     let result = loop {
@@ -374,25 +444,181 @@ pub(super) unsafe fn wait<E: Element + PartialEq>(
 
     // SAFETY: waiter is valid and contained in its waiter list if `waiting == true`.
     unsafe {
-        // 20. Else,
-        //     a. Perform RemoveWaiter(WL, W).
+        // If the agent timed out, it must remove itself from the waiter list.
         if (*waiter_ptr).waiting {
             waiters.remove_waiter(waiter_ptr);
         } else {
-            // 19. If notified is true, then
-            //     a. Assert: W is not on the list of waiters in WL.
+            // Otherwise, the notifier must have removed it.
             debug_assert!(!(*waiter_ptr).link.is_linked());
         }
     }
 
-    // 21. Perform LeaveCriticalSection(WL).
+    // 31. Perform LeaveCriticalSection(WL).
     drop(waiters);
 
-    // 22. If notified is true, return "ok".
-    // 23. Return "timed-out".
+    // 32. If mode is sync, return waiterRecord.[[Result]].
     Ok(result)
 }
 
+/// The result of adding an asynchronous waiter using [`wait_async`].
+#[derive(Debug)]
+#[allow(variant_size_differences)] // `AsyncWaiter` is a single pointer.
+pub(super) enum AsyncWaitResult {
+    /// The wait completed without adding a waiter to the wait queue.
+    Sync(AtomicsWaitResult),
+    /// The waiter was added to the wait queue.
+    Async(AsyncWaiter),
+}
+
+/// Adds an asynchronous waiter to the wait queue for the address pointed to by `buffer[offset..]`.
+///
+/// A timeout of zero completes the wait immediately if the value matches `check`. Any other
+/// timeout must be handled by the caller using [`AsyncWaiter::time_out`].
+///
+/// # Safety
+///
+/// - `addr` must be a multiple of `std::mem::size_of::<E>()`.
+/// - `buffer` must contain at least `std::mem::size_of::<E>()` bytes to read starting from `usize`.
+pub(super) unsafe fn wait_async<E: Element + PartialEq>(
+    buffer: &SharedArrayBuffer,
+    buf_len: usize,
+    offset: usize,
+    check: E,
+    timeout: Option<Duration>,
+) -> JsResult<AsyncWaitResult> {
+    // SAFETY: The safety of this operation is guaranteed by the caller.
+    let Some((mut waiters, addr)) =
+        (unsafe { enter_critical_section(buffer, buf_len, offset, check)? })
+    else {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::NotEqual));
+    };
+
+    // 21. If t = 0 and mode is async, then
+    //     a. Perform LeaveCriticalSection(WL).
+    if timeout == Some(Duration::ZERO) {
+        return Ok(AsyncWaitResult::Sync(AtomicsWaitResult::TimedOut));
+    }
+
+    // 27. Let waiterRecord be a new Waiter Record { [[AgentSignifier]]: thisAgent, [[PromiseCapability]]: promiseCapability,
+    //     [[TimeoutTime]]: timeoutTime, [[Result]]: "ok" }.
+    let waiter = AsyncWaiter {
+        inner: Rc::new(AsyncWaiterInner {
+            node: UnsafeCell::default(),
+            timed_out: Cell::new(false),
+        }),
+    };
+
+    // 28. Perform AddWaiter(WL, waiterRecord).
+    // SAFETY: The node is allocated inside an `Rc`, so its address is stable, and it is removed
+    // from its waiter list before being dropped.
+    unsafe {
+        waiters.add_waiter(waiter.inner.node.get(), addr);
+    }
+
+    // 31. Perform LeaveCriticalSection(WL).
+    drop(waiters);
+
+    Ok(AsyncWaitResult::Async(waiter))
+}
+
+/// A waiter added by `Atomics.waitAsync`.
+///
+/// Polling this future completes it when the waiter is either notified or timed out.
+#[derive(Debug, Clone)]
+pub(super) struct AsyncWaiter {
+    inner: Rc<AsyncWaiterInner>,
+}
+
+#[derive(Debug)]
+struct AsyncWaiterInner {
+    node: UnsafeCell<sync::FutexWaiter>,
+    timed_out: Cell<bool>,
+}
+
+impl Drop for AsyncWaiterInner {
+    fn drop(&mut self) {
+        // If the mutex is poisoned, the waiter lists cannot be read from any thread, meaning
+        // the node can be dropped without removing it from its list.
+        let Ok(mut waiters) = sync::FutexWaiters::get() else {
+            return;
+        };
+
+        let node = self.node.get();
+
+        // SAFETY: node is valid and contained in its waiter list if `waiting == true`.
+        unsafe {
+            if (*node).waiting {
+                waiters.remove_waiter(node);
+            }
+        }
+    }
+}
+
+impl AsyncWaiter {
+    /// Removes this waiter from its waiter list if it hasn't been notified yet, completing it
+    /// with a `"timed-out"` result.
+    ///
+    /// Equivalent to the job enqueued by [`EnqueueAtomicsWaitAsyncTimeoutJob`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enqueueatomicswaitasynctimeoutjob
+    pub(super) fn time_out(&self) -> JsResult<()> {
+        // a. Perform EnterCriticalSection(WL).
+        let mut waiters = sync::FutexWaiters::get()?;
+
+        let node = self.inner.node.get();
+
+        // SAFETY: node is valid and contained in its waiter list if `waiting == true`.
+        unsafe {
+            // b. If WL.[[Waiters]] contains waiterRecord, then
+            if (*node).waiting {
+                // iii. Set waiterRecord.[[Result]] to "timed-out".
+                self.inner.timed_out.set(true);
+
+                // iv. Perform RemoveWaiter(WL, waiterRecord).
+                waiters.remove_waiter(node);
+                (*node).waiting = false;
+
+                // v. Perform NotifyWaiter(WL, waiterRecord).
+                (*node).wake();
+            }
+        }
+
+        // c. Perform LeaveCriticalSection(WL).
+        // d. Return unused.
+        Ok(())
+    }
+}
+
+impl Future for AsyncWaiter {
+    type Output = JsResult<AtomicsWaitResult>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let waiters = match sync::FutexWaiters::get() {
+            Ok(waiters) => waiters,
+            Err(err) => return Poll::Ready(Err(err)),
+        };
+
+        let node = self.inner.node.get();
+
+        // SAFETY: node is valid, and the critical section guarantees that no other agent
+        // is accessing it.
+        unsafe {
+            if (*node).waiting {
+                (*node).waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+        }
+
+        drop(waiters);
+
+        if self.inner.timed_out.get() {
+            Poll::Ready(Ok(AtomicsWaitResult::TimedOut))
+        } else {
+            Poll::Ready(Ok(AtomicsWaitResult::Ok))
+        }
+    }
+}
+
 /// Notifies at most `count` agents waiting on the memory address pointed to by `buffer[offset..]`.
 pub(super) fn notify(buffer: &SharedArrayBuffer, offset: usize, count: u64) -> JsResult<u64> {
     let addr = sptr::Strict::addr(buffer.as_ptr()) + offset;
@@ -12,13 +12,24 @@
 
 mod futex;
 
+#[cfg(test)]
+mod tests;
+
 use std::sync::atomic::Ordering;
 
 use crate::{
-    builtins::BuiltInObject, context::intrinsics::Intrinsics, js_string, object::JsObject,
-    property::Attribute, realm::Realm, string::StaticJsStrings, symbol::JsSymbol,
-    sys::time::Duration, value::IntegerOrInfinity, Context, JsArgs, JsNativeError, JsResult,
-    JsString, JsValue,
+    builtins::BuiltInObject,
+    context::intrinsics::Intrinsics,
+    job::NativeJob,
+    js_string,
+    object::{builtins::JsPromise, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    sys::time::Duration,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsNativeError, JsResult, JsString, JsValue,
 };
 
 use boa_profiler::Profiler;
@@ -53,6 +64,7 @@ impl IntrinsicObject for Atomics {
             .static_method(Atomics::store, js_string!("store"), 3)
             .static_method(Atomics::sub, js_string!("sub"), 3)
             .static_method(Atomics::wait, js_string!("wait"), 4)
+            .static_method(Atomics::wait_async, js_string!("waitAsync"), 4)
             .static_method(Atomics::notify, js_string!("notify"), 3)
             .static_method(Atomics::bit_xor, js_string!("xor"), 3);
 
@@ -137,6 +149,7 @@ macro_rules! atomic_op {
                         .$name(num, Ordering::SeqCst)
                         .into(),
                     TypedArrayElement::Uint8Clamped(_)
+                    | TypedArrayElement::Float16(_)
                     | TypedArrayElement::Float32(_)
                     | TypedArrayElement::Float64(_) => unreachable!(
                         "must have been filtered out by the call to `validate_integer_typed_array`"
@@ -333,6 +346,7 @@ impl Atomics {
                     .compare_exchange(exp, rep, Ordering::SeqCst)
                     .into(),
                 TypedArrayKind::Uint8Clamped
+                | TypedArrayKind::Float16
                 | TypedArrayKind::Float32
                 | TypedArrayKind::Float64 => unreachable!(
                     "must have been filtered out by the call to `validate_integer_typed_array`"
@@ -390,88 +404,17 @@ impl Atomics {
     /// [`Atomics.wait ( typedArray, index, value, timeout )`][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-atomics.wait
-    // TODO: rewrite this to support Atomics.waitAsync
     fn wait(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
-        let array = args.get_or_undefined(0);
-        let index = args.get_or_undefined(1);
-        let value = args.get_or_undefined(2);
-        let timeout = args.get_or_undefined(3);
-
-        // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
-        let (ta, buf_len) = validate_integer_typed_array(array, true)?;
-
-        // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
-        // 2. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
-        let buffer = match ta.borrow().data.viewed_array_buffer() {
-            BufferObject::SharedBuffer(buf) => buf.clone(),
-            BufferObject::Buffer(_) => {
-                return Err(JsNativeError::typ()
-                    .with_message("cannot use `ArrayBuffer` for an atomic wait")
-                    .into())
-            }
-        };
-
-        // 3. Let indexedPosition be ? ValidateAtomicAccess(typedArray, index).
-        let access = validate_atomic_access(&ta, buf_len, index, context)?;
-
-        // spec expects the evaluation of this first, then the timeout.
-        let value = if access.kind == TypedArrayKind::BigInt64 {
-            // 4. If typedArray.[[TypedArrayName]] is "BigInt64Array", let v be ? ToBigInt64(value).
-            value.to_big_int64(context)?
-        } else {
-            // 5. Otherwise, let v be ? ToInt32(value).
-            i64::from(value.to_i32(context)?)
-        };
-
-        // moving above since we need to make a generic call next.
-
-        // 6. Let q be ? ToNumber(timeout).
-        // 7. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
-        let mut timeout = timeout.to_number(context)?;
-        // convert to nanoseconds to discard any excessively big timeouts.
-        timeout = timeout.clamp(0.0, f64::INFINITY) * 1000.0 * 1000.0;
-        let timeout = if timeout.is_nan() || timeout.is_infinite() || timeout > u64::MAX as f64 {
-            None
-        } else {
-            Some(Duration::from_nanos(timeout as u64))
-        };
-
-        // 8. Let B be AgentCanSuspend().
-        // 9. If B is false, throw a TypeError exception.
-        if !context.can_block() {
-            return Err(JsNativeError::typ()
-                .with_message("agent cannot be suspended")
-                .into());
-        }
-
-        // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
-        let result = unsafe {
-            if access.kind == TypedArrayKind::BigInt64 {
-                futex::wait(
-                    &buffer.borrow().data,
-                    buf_len,
-                    access.byte_offset,
-                    value,
-                    timeout,
-                )?
-            } else {
-                // value must fit into `i32` since it came from an `i32` above.
-                futex::wait(
-                    &buffer.borrow().data,
-                    buf_len,
-                    access.byte_offset,
-                    value as i32,
-                    timeout,
-                )?
-            }
-        };
+        // 1. Return ? DoWait(sync, typedArray, index, value, timeout).
+        do_wait(WaitMode::Sync, args, context)
+    }
 
-        Ok(match result {
-            futex::AtomicsWaitResult::NotEqual => js_string!("not-equal"),
-            futex::AtomicsWaitResult::TimedOut => js_string!("timed-out"),
-            futex::AtomicsWaitResult::Ok => js_string!("ok"),
-        }
-        .into())
+    /// [`Atomics.waitAsync ( typedArray, index, value, timeout )`][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-atomics.waitasync
+    fn wait_async(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? DoWait(async, typedArray, index, value, timeout).
+        do_wait(WaitMode::Async, args, context)
     }
 
     /// [`Atomics.notify ( typedArray, index, count )`][spec]
@@ -561,6 +504,142 @@ impl Atomics {
     }
 }
 
+/// The mode of a [`do_wait`] operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WaitMode {
+    /// Blocks the current agent until it is notified or timed out.
+    Sync,
+    /// Returns a promise that is resolved when the waiter is notified or timed out.
+    Async,
+}
+
+/// [`DoWait ( mode, typedArray, index, value, timeout )`][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-dowait
+fn do_wait(mode: WaitMode, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let array = args.get_or_undefined(0);
+    let index = args.get_or_undefined(1);
+    let value = args.get_or_undefined(2);
+    let timeout = args.get_or_undefined(3);
+
+    // 1. Let taRecord be ? ValidateIntegerTypedArray(typedArray, true).
+    let (ta, buf_len) = validate_integer_typed_array(array, true)?;
+
+    // 2. Let buffer be taRecord.[[Object]].[[ViewedArrayBuffer]].
+    // 3. If IsSharedArrayBuffer(buffer) is false, throw a TypeError exception.
+    let buffer = match ta.borrow().data.viewed_array_buffer() {
+        BufferObject::SharedBuffer(buf) => buf.clone(),
+        BufferObject::Buffer(_) => {
+            return Err(JsNativeError::typ()
+                .with_message("cannot use `ArrayBuffer` for an atomic wait")
+                .into())
+        }
+    };
+
+    // 4. Let i be ? ValidateAtomicAccess(taRecord, index).
+    let access = validate_atomic_access(&ta, buf_len, index, context)?;
+
+    // spec expects the evaluation of this first, then the timeout.
+    let value = if access.kind == TypedArrayKind::BigInt64 {
+        // 5. Let arrayTypeName be typedArray.[[TypedArrayName]].
+        // 6. If arrayTypeName is "BigInt64Array", let v be ? ToBigInt64(value).
+        value.to_big_int64(context)?
+    } else {
+        // 7. Else, let v be ? ToInt32(value).
+        i64::from(value.to_i32(context)?)
+    };
+
+    // 8. Let q be ? ToNumber(timeout).
+    // 9. If q is either NaN or +∞𝔽, let t be +∞; else if q is -∞𝔽, let t be 0; else let t be max(ℝ(q), 0).
+    let mut timeout = timeout.to_number(context)?;
+    // convert to nanoseconds to discard any excessively big timeouts.
+    timeout = timeout.clamp(0.0, f64::INFINITY) * 1000.0 * 1000.0;
+    let timeout = if timeout.is_nan() || timeout.is_infinite() || timeout > u64::MAX as f64 {
+        None
+    } else {
+        Some(Duration::from_nanos(timeout as u64))
+    };
+
+    // 10. If mode is sync and AgentCanSuspend() is false, throw a TypeError exception.
+    if mode == WaitMode::Sync && !context.can_block() {
+        return Err(JsNativeError::typ()
+            .with_message("agent cannot be suspended")
+            .into());
+    }
+
+    let buffer = &buffer.borrow().data;
+
+    if mode == WaitMode::Sync {
+        // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
+        let result = unsafe {
+            if access.kind == TypedArrayKind::BigInt64 {
+                futex::wait(buffer, buf_len, access.byte_offset, value, timeout)?
+            } else {
+                // value must fit into `i32` since it came from an `i32` above.
+                futex::wait(buffer, buf_len, access.byte_offset, value as i32, timeout)?
+            }
+        };
+
+        return Ok(result.to_js_string().into());
+    }
+
+    // SAFETY: the validity of `addr` is verified by our call to `validate_atomic_access`.
+    let result = unsafe {
+        if access.kind == TypedArrayKind::BigInt64 {
+            futex::wait_async(buffer, buf_len, access.byte_offset, value, timeout)?
+        } else {
+            // value must fit into `i32` since it came from an `i32` above.
+            futex::wait_async(buffer, buf_len, access.byte_offset, value as i32, timeout)?
+        }
+    };
+
+    let (is_async, value) = match result {
+        futex::AsyncWaitResult::Sync(result) => (false, JsValue::from(result.to_js_string())),
+        futex::AsyncWaitResult::Async(waiter) => {
+            // 30. Else if timeoutTime is finite, then
+            //     a. Perform EnqueueAtomicsWaitAsyncTimeoutJob(WL, waiterRecord).
+            if let Some(timeout) = timeout {
+                let waiter = waiter.clone();
+                let job = NativeJob::new(move |_| {
+                    waiter.time_out()?;
+                    Ok(JsValue::undefined())
+                });
+                context
+                    .job_queue()
+                    .enqueue_timeout_job(job, timeout, context);
+            }
+
+            // 16. Else,
+            //     a. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+            let promise = JsPromise::from_future(
+                async move { waiter.await.map(|result| result.to_js_string().into()) },
+                context,
+            );
+
+            (true, promise.into())
+        }
+    };
+
+    // 16. Else,
+    //     b. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    let result_object = JsObject::with_object_proto(context.intrinsics());
+
+    // Steps 20 and 21 set "async" to false and "value" to the result if the wait completed
+    // without adding a waiter.
+    // 33. Perform ! CreateDataPropertyOrThrow(resultObject, "async", true).
+    result_object
+        .create_data_property_or_throw(js_string!("async"), is_async, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 34. Perform ! CreateDataPropertyOrThrow(resultObject, "value", promiseCapability.[[Promise]]).
+    result_object
+        .create_data_property_or_throw(js_string!("value"), value, context)
+        .expect("this CreateDataPropertyOrThrow call must not fail");
+
+    // 35. Return resultObject.
+    Ok(result_object.into())
+}
+
 /// [`ValidateIntegerTypedArray ( typedArray, waitable )`][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#sec-validateintegertypedarray
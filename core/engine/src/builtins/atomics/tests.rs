@@ -0,0 +1,79 @@
+use crate::{js_string, run_test_actions, Context, JsNativeErrorKind, TestAction};
+use indoc::indoc;
+
+#[test]
+fn wait_async_sync_results() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new Int32Array(new SharedArrayBuffer(16));
+            var notEqual = Atomics.waitAsync(ia, 0, 1);
+            var timedOut = Atomics.waitAsync(ia, 0, 0, 0);
+        "#}),
+        TestAction::assert("!notEqual.async"),
+        TestAction::assert_eq("notEqual.value", js_string!("not-equal")),
+        TestAction::assert("!timedOut.async"),
+        TestAction::assert_eq("timedOut.value", js_string!("timed-out")),
+        TestAction::assert("Object.getPrototypeOf(timedOut) === Object.prototype"),
+    ]);
+}
+
+#[test]
+fn wait_async_notify() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new Int32Array(new SharedArrayBuffer(16));
+            var results = [];
+            var first = Atomics.waitAsync(ia, 1, 0);
+            var second = Atomics.waitAsync(ia, 1, 0, 100);
+            first.value.then((v) => results.push("first " + v));
+            second.value.then((v) => results.push("second " + v));
+            var notified = Atomics.notify(ia, 1, 1);
+        "#}),
+        TestAction::assert("first.async && first.value instanceof Promise"),
+        TestAction::assert("second.async"),
+        TestAction::assert_eq("notified", 1),
+        TestAction::assert_eq("Atomics.notify(ia, 1)", 1),
+        TestAction::assert_eq("Atomics.notify(ia, 1)", 0),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq("results.join()", js_string!("first ok,second ok")),
+    ]);
+}
+
+#[test]
+fn wait_async_timeout() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var ia = new BigInt64Array(new SharedArrayBuffer(16));
+            var results = [];
+            Atomics.waitAsync(ia, 0, 0n, 20).value.then((v) => results.push("long " + v));
+            Atomics.waitAsync(ia, 0, 0n, 1).value.then((v) => results.push("short " + v));
+        "#}),
+        TestAction::inspect_context(Context::run_jobs),
+        TestAction::assert_eq(
+            "results.join()",
+            js_string!("short timed-out,long timed-out"),
+        ),
+        TestAction::assert_eq("Atomics.notify(ia, 0)", 0),
+    ]);
+}
+
+#[test]
+fn wait_async_errors() {
+    run_test_actions([
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int32Array(4), 0, 0)",
+            JsNativeErrorKind::Type,
+            "cannot use `ArrayBuffer` for an atomic wait",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int16Array(new SharedArrayBuffer(8)), 0, 0)",
+            JsNativeErrorKind::Type,
+            "can only atomically wait using Int32 or BigInt64 arrays",
+        ),
+        TestAction::assert_native_error(
+            "Atomics.waitAsync(new Int32Array(new SharedArrayBuffer(8)), 2, 0)",
+            JsNativeErrorKind::Range,
+            "index for typed array outside of bounds",
+        ),
+    ]);
+}
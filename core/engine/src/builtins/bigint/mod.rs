@@ -43,6 +43,7 @@ impl IntrinsicObject for BigInt {
 
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::to_locale_string, js_string!("toLocaleString"), 0)
             .method(Self::value_of, js_string!("valueOf"), 0)
             .static_method(Self::as_int_n, js_string!("asIntN"), 2)
             .static_method(Self::as_uint_n, js_string!("asUintN"), 2)
@@ -65,7 +66,7 @@ impl BuiltInObject for BigInt {
 
 impl BuiltInConstructor for BigInt {
     const LENGTH: usize = 1;
-    const P: usize = 3;
+    const P: usize = 4;
     const SP: usize = 2;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -211,6 +212,52 @@ impl BigInt {
         Ok(JsValue::new(js_string!(x.to_string_radix(radix_mv as u32))))
     }
 
+    /// `BigInt.prototype.toLocaleString( [locales [, options]] )`
+    ///
+    /// The `toLocaleString()` method returns a string with a language-sensitive representation of this `BigInt`.
+    ///
+    /// Without the `intl` feature, this returns the same string as `BigInt.prototype.toString()`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-bigint.prototype.tolocalestring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt/toLocaleString
+    #[allow(clippy::wrong_self_convention)]
+    pub(crate) fn to_locale_string(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisBigIntValue(this value).
+        let x = Self::this_bigint_value(this)?;
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::{IntlMathematicalValue, NumberFormat};
+            use fixed_decimal::FixedDecimal;
+
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+            let number_format =
+                NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 3. Return FormatNumeric(numberFormat, ℝ(x)).
+            let mut x = FixedDecimal::try_from(x.to_string().as_bytes())
+                .map(IntlMathematicalValue::Finite)
+                .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+            Ok(js_string!(number_format.format(&mut x).to_string()).into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+
+            Ok(js_string!(x.to_string()).into())
+        }
+    }
+
     /// `BigInt.prototype.valueOf()`
     ///
     /// The `valueOf()` method returns the wrapped primitive value of a Number object.
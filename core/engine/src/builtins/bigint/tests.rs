@@ -156,6 +156,27 @@ fn to_string() {
     ]);
 }
 
+#[cfg(not(feature = "intl"))]
+#[test]
+fn to_locale_string() {
+    run_test_actions([
+        TestAction::assert_eq("123456789n.toLocaleString()", js_str!("123456789")),
+        TestAction::assert_eq("(-5n).toLocaleString()", js_str!("-5")),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn to_locale_string_intl() {
+    run_test_actions([
+        TestAction::assert_eq("123456789n.toLocaleString('en-US')", js_str!("123,456,789")),
+        TestAction::assert_eq(
+            "[1234n, 5678].toLocaleString('en-US')",
+            js_str!("1,234, 5,678"),
+        ),
+    ]);
+}
+
 #[test]
 fn to_string_invalid_radix() {
     run_test_actions([
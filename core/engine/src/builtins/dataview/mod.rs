@@ -24,6 +24,7 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 use bytemuck::{bytes_of, bytes_of_mut};
+use half::f16;
 
 use super::{
     array_buffer::{
@@ -130,6 +131,7 @@ impl IntrinsicObject for DataView {
             )
             .method(Self::get_big_int64, js_string!("getBigInt64"), 1)
             .method(Self::get_big_uint64, js_string!("getBigUint64"), 1)
+            .method(Self::get_float16, js_string!("getFloat16"), 1)
             .method(Self::get_float32, js_string!("getFloat32"), 1)
             .method(Self::get_float64, js_string!("getFloat64"), 1)
             .method(Self::get_int8, js_string!("getInt8"), 1)
@@ -140,6 +142,7 @@ impl IntrinsicObject for DataView {
             .method(Self::get_uint32, js_string!("getUint32"), 1)
             .method(Self::set_big_int64, js_string!("setBigInt64"), 2)
             .method(Self::set_big_uint64, js_string!("setBigUint64"), 2)
+            .method(Self::set_float16, js_string!("setFloat16"), 2)
             .method(Self::set_float32, js_string!("setFloat32"), 2)
             .method(Self::set_float64, js_string!("setFloat64"), 2)
             .method(Self::set_int8, js_string!("setInt8"), 2)
@@ -552,6 +555,30 @@ impl DataView {
         Self::get_view_value::<u64>(this, byte_offset, is_little_endian, context)
     }
 
+    /// `DataView.prototype.getFloat16 ( byteOffset [ , littleEndian ] )`
+    ///
+    /// The `getFloat16()` method gets a 16-bit half precision float at the specified byte offset
+    /// from the start of the `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.getfloat16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/getFloat16
+    pub(crate) fn get_float16(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let byte_offset = args.get_or_undefined(0);
+        let is_little_endian = args.get_or_undefined(1);
+        // 1. Let v be the this value.
+        // 2. If littleEndian is not present, set littleEndian to false.
+        // 3. Return ? GetViewValue(v, byteOffset, littleEndian, Float16).
+        Self::get_view_value::<f16>(this, byte_offset, is_little_endian, context)
+    }
+
     /// `DataView.prototype.getBigUint64 ( byteOffset [ , littleEndian ] )`
     ///
     /// The `getFloat32()` method gets a signed 32-bit float (float) at the specified byte offset
@@ -875,6 +902,31 @@ impl DataView {
         Self::set_view_value::<u64>(this, byte_offset, is_little_endian, value, context)
     }
 
+    /// `DataView.prototype.setFloat16 ( byteOffset, value [ , littleEndian ] )`
+    ///
+    /// The `setFloat16()` method stores a 16-bit half precision float value at the specified byte
+    /// offset from the start of the `DataView`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-dataview.prototype.setfloat16
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView/setFloat16
+    pub(crate) fn set_float16(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let byte_offset = args.get_or_undefined(0);
+        let value = args.get_or_undefined(1);
+        let is_little_endian = args.get_or_undefined(2);
+        // 1. Let v be the this value.
+        // 2. If littleEndian is not present, set littleEndian to false.
+        // 3. Return ? SetViewValue(v, byteOffset, littleEndian, Float16, value).
+        Self::set_view_value::<f16>(this, byte_offset, is_little_endian, value, context)
+    }
+
     /// `DataView.prototype.setFloat32 ( byteOffset, value [ , littleEndian ] )`
     ///
     /// The `setFloat32()` method stores a signed 32-bit float (float) value at the specified byte
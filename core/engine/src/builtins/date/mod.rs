@@ -30,12 +30,15 @@ use crate::{
     string::StaticJsStrings,
     symbol::JsSymbol,
     value::{JsValue, PreferredType},
-    Context, JsArgs, JsData, JsError, JsResult, JsString,
+    Context, JsArgs, JsData, JsResult, JsString,
 };
 use boa_gc::{Finalize, Trace};
 use boa_macros::js_str;
 use boa_profiler::Profiler;
 
+#[cfg(not(feature = "intl"))]
+use crate::builtins::date::utils::{locale_date_string, locale_time_string};
+
 pub(crate) mod utils;
 
 #[cfg(test)]
@@ -1585,7 +1588,7 @@ impl Date {
         func.call(this, &[], context)
     }
 
-    /// [`Date.prototype.toLocaleDateString()`][spec].
+    /// [`Date.prototype.toLocaleDateString ( [ locales [ , options ] ] )`][spec].
     ///
     /// The `toLocaleDateString()` method returns the date portion of the given Date instance according
     /// to language-specific conventions.
@@ -1593,55 +1596,161 @@ impl Date {
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaledatestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaledatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleDateString
     pub(crate) fn to_locale_date_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented"
-        ))))
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this
+            .as_object()
+            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+            .0;
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(js_string!("Invalid Date").into());
+        }
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, date, date).
+            // 4. Return ! FormatDateTime(dateFormat, x).
+            format_date_time_locale(
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                &DateTimeReqs::Date,
+                &DateTimeReqs::Date,
+                x,
+                context,
+            )
+            .map(JsValue::from)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+
+            // Without `Intl`, dates are formatted following the conventions of the `en-US` locale.
+            let t = local_time(x, context.host_hooks());
+            Ok(JsValue::from(locale_date_string(t)))
+        }
     }
 
-    /// [`Date.prototype.toLocaleString()`][spec].
+    /// [`Date.prototype.toLocaleString ( [ locales [ , options ] ] )`][spec].
     ///
     /// The `toLocaleString()` method returns a string representing the specified Date object.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleString
     pub(crate) fn to_locale_string(
-        _this: &JsValue,
-        _: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this
+            .as_object()
+            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+            .0;
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(js_string!("Invalid Date").into());
+        }
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 3. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, any, all).
+            // 4. Return ! FormatDateTime(dateFormat, x).
+            format_date_time_locale(
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                &DateTimeReqs::AnyAll,
+                &DateTimeReqs::AnyAll,
+                x,
+                context,
+            )
+            .map(JsValue::from)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+
+            // Without `Intl`, dates are formatted following the conventions of the `en-US` locale.
+            let t = local_time(x, context.host_hooks());
+            Ok(JsValue::from(js_string!(
+                &locale_date_string(t),
+                js_str!(", "),
+                &locale_time_string(t)
+            )))
+        }
     }
 
-    /// [`Date.prototype.toLocaleTimeString()`][spec].
+    /// [`Date.prototype.toLocaleTimeString ( [ locales [ , options ] ] )`][spec].
     ///
-    /// The `toLocaleTimeString()` method returns the time portion of a Date object in human readable
-    /// form in American English.
+    /// The `toLocaleTimeString()` method returns the time portion of a Date object according to
+    /// language-specific conventions.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring
+    /// [spec]: https://tc39.es/ecma402/#sup-date.prototype.tolocaletimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toLocaleTimeString
     pub(crate) fn to_locale_time_string(
-        _this: &JsValue,
-        _args: &[JsValue],
-        _context: &mut Context,
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
-        Err(JsError::from_opaque(JsValue::new(js_string!(
-            "Function Unimplemented]"
-        ))))
+        // 1. Let x be ? thisTimeValue(this value).
+        let x = this
+            .as_object()
+            .and_then(|obj| obj.downcast_ref::<Date>().as_deref().copied())
+            .ok_or_else(|| JsNativeError::typ().with_message("'this' is not a Date"))?
+            .0;
+
+        // 2. If x is NaN, return "Invalid Date".
+        if x.is_nan() {
+            return Ok(js_string!("Invalid Date").into());
+        }
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::date_time_format::{format_date_time_locale, DateTimeReqs};
+
+            // 3. Let timeFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, time, time).
+            // 4. Return ! FormatDateTime(timeFormat, x).
+            format_date_time_locale(
+                args.get_or_undefined(0),
+                args.get_or_undefined(1),
+                &DateTimeReqs::Time,
+                &DateTimeReqs::Time,
+                x,
+                context,
+            )
+            .map(JsValue::from)
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = args;
+
+            // Without `Intl`, dates are formatted following the conventions of the `en-US` locale.
+            let t = local_time(x, context.host_hooks());
+            Ok(JsValue::from(locale_time_string(t)))
+        }
     }
 
     /// [`Date.prototype.toString()`][spec].
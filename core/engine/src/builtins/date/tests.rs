@@ -839,6 +839,57 @@ fn date_proto_to_time_string() {
     )]);
 }
 
+#[cfg(not(feature = "intl"))]
+#[test]
+fn date_proto_to_locale_string() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Date(2020, 6, 8, 21, 16, 5, 779).toLocaleString()",
+            js_str!("7/8/2020, 9:16:05 PM"),
+        ),
+        TestAction::assert_eq(
+            "new Date(2020, 6, 8, 0, 16, 5).toLocaleDateString()",
+            js_str!("7/8/2020"),
+        ),
+        TestAction::assert_eq(
+            "new Date(2020, 6, 8, 0, 16, 5).toLocaleTimeString()",
+            js_str!("12:16:05 AM"),
+        ),
+        TestAction::assert_eq("new Date(NaN).toLocaleString()", js_str!("Invalid Date")),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn date_proto_to_locale_string_intl() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Date(0).toLocaleString('en-US', { timeZone: 'UTC' })",
+            js_str!("1/1/1970, 12:00:00\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "new Date(0).toLocaleDateString('en-US', { timeZone: 'UTC' })",
+            js_str!("1/1/1970"),
+        ),
+        TestAction::assert_eq(
+            "new Date(0).toLocaleTimeString('en-US', { timeZone: 'UTC' })",
+            js_str!("12:00:00\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "new Date(0).toLocaleDateString('de', { timeZone: 'UTC', dateStyle: 'long' })",
+            js_str!("1. Januar 1970"),
+        ),
+        TestAction::assert_eq(
+            "new Date(0).toLocaleTimeString('en-US', { timeZone: 'UTC', hour: 'numeric' })",
+            js_str!("12\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "new Date(NaN).toLocaleString('en-US')",
+            js_str!("Invalid Date"),
+        ),
+    ]);
+}
+
 #[test]
 fn date_proto_to_utc_string() {
     run_test_actions([TestAction::assert_eq(
@@ -0,0 +1,521 @@
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        promise::{Promise, PromiseCapability},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{add_disposable_resource, suppress_error, DisposeCapability, DisposeHint, DisposeStep};
+
+/// Boa's implementation of the `AsyncDisposableStack` builtin object.
+///
+/// An `AsyncDisposableStack` aggregates multiple async disposable resources, disposing all of them
+/// in reverse order of addition, awaiting each disposal, when the stack itself is disposed.
+///
+/// More Information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct AsyncDisposableStack {
+    /// `[[AsyncDisposableState]]`, `true` if the stack was already disposed.
+    disposed: bool,
+
+    /// `[[DisposeCapability]]`
+    capability: DisposeCapability,
+}
+
+/// The state of an in progress `AsyncDisposableStack.prototype.disposeAsync` call.
+#[derive(Trace, Finalize)]
+struct DisposeAsyncState {
+    /// The capability whose resources are being disposed.
+    capability: GcRefCell<DisposeCapability>,
+
+    /// The current completion of `DisposeResources`, which is `None` if it is a normal completion.
+    completion: GcRefCell<Option<JsError>>,
+
+    /// The capability of the promise returned by `disposeAsync`.
+    promise_capability: PromiseCapability,
+}
+
+impl IntrinsicObject for AsyncDisposableStack {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose_async = BuiltInBuilder::callable(realm, Self::dispose_async)
+            .name(js_string!("disposeAsync"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("disposeAsync"),
+                dispose_async.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::async_dispose(),
+                dispose_async,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+}
+
+impl BuiltInObject for AsyncDisposableStack {
+    const NAME: JsString = StaticJsStrings::ASYNC_DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for AsyncDisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::async_disposable_stack;
+
+    /// Constructor [`AsyncDisposableStack ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("AsyncDisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::async_disposable_stack,
+            context,
+        )?;
+
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return asyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability: DisposeCapability::new(),
+            },
+        )
+        .into())
+    }
+}
+
+impl AsyncDisposableStack {
+    /// Gets the `this` value as an `AsyncDisposableStack`, throwing a `TypeError` if it is not
+    /// one.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack, [[AsyncDisposableState]]).
+        this.as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "AsyncDisposableStack.prototype.{method}: `this` is not an AsyncDisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn require_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, throw a ReferenceError exception.
+        if stack.borrow().data.disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "AsyncDisposableStack.prototype.{method}: the stack was already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// `get AsyncDisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let disposed = stack.borrow().data.disposed;
+        Ok(disposed.into())
+    }
+
+    /// `AsyncDisposableStack.prototype.disposeAsync ( )`
+    ///
+    /// Disposes all resources of the stack in reverse order of addition, returning a promise that
+    /// settles after all of them have been disposed.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync
+    #[allow(clippy::unnecessary_wraps)]
+    fn dispose_async(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("cannot fail per the spec");
+
+        // 3. If asyncDisposableStack does not have an [[AsyncDisposableState]] internal slot, then
+        let Some(stack) = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+        else {
+            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            let error = JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.disposeAsync: `this` is not an AsyncDisposableStack",
+                )
+                .to_opaque(context);
+            promise_capability
+                .reject()
+                .call(&JsValue::undefined(), &[error.into()], context)
+                .expect("cannot fail per the spec");
+
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(promise_capability.promise().clone().into());
+        };
+
+        let capability = {
+            let mut stack = stack.borrow_mut();
+
+            // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed, then
+            if stack.data.disposed {
+                drop(stack);
+
+                // a. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                promise_capability
+                    .resolve()
+                    .call(&JsValue::undefined(), &[JsValue::undefined()], context)
+                    .expect("cannot fail per the spec");
+
+                // b. Return promiseCapability.[[Promise]].
+                return Ok(promise_capability.promise().clone().into());
+            }
+
+            // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+            stack.data.disposed = true;
+            stack.data.capability.take()
+        };
+
+        let promise = promise_capability.promise().clone();
+
+        // 6. Let result be Completion(DisposeResources(asyncDisposableStack.[[DisposeCapability]], NormalCompletion(undefined))).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result »).
+        let state = Gc::new(DisposeAsyncState {
+            capability: GcRefCell::new(capability),
+            completion: GcRefCell::new(None),
+            promise_capability,
+        });
+        Self::continue_dispose(&state, context);
+
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(promise.into())
+    }
+
+    /// Runs the steps of `DisposeResources` until it needs to await a value, scheduling its
+    /// continuation on the resolution of the awaited value.
+    fn continue_dispose(state: &Gc<DisposeAsyncState>, context: &mut Context) {
+        loop {
+            let step = state.capability.borrow_mut().next_step();
+            let result = match step {
+                DisposeStep::Await => JsValue::undefined(),
+                DisposeStep::Dispose(resource) => match resource.dispose(context) {
+                    Ok(result) if resource.hint() == DisposeHint::Async => result,
+                    Ok(_) => continue,
+                    Err(error) => {
+                        state.suppress(error);
+                        continue;
+                    }
+                },
+                DisposeStep::Done => {
+                    let completion = state.completion.borrow_mut().take();
+                    let capability = &state.promise_capability;
+                    match completion {
+                        Some(error) => {
+                            let error = error.to_opaque(context);
+                            capability
+                                .reject()
+                                .call(&JsValue::undefined(), &[error], context)
+                        }
+                        None => capability.resolve().call(
+                            &JsValue::undefined(),
+                            &[JsValue::undefined()],
+                            context,
+                        ),
+                    }
+                    .expect("cannot fail per the spec");
+                    return;
+                }
+            };
+
+            // Await(result)
+            let promise = match Promise::promise_resolve(
+                &context.intrinsics().constructors().promise().constructor(),
+                result,
+                context,
+            ) {
+                Ok(promise) => promise,
+                Err(error) => {
+                    state.suppress(error);
+                    continue;
+                }
+            };
+
+            let on_fulfilled = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, state, context| {
+                        Self::continue_dispose(state, context);
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, state, context| {
+                        state.suppress(JsError::from_opaque(args.get_or_undefined(0).clone()));
+                        Self::continue_dispose(state, context);
+                        Ok(JsValue::undefined())
+                    },
+                    state.clone(),
+                ),
+            )
+            .name(js_string!())
+            .length(1)
+            .build();
+
+            Promise::perform_promise_then(
+                &promise,
+                Some(on_fulfilled),
+                Some(on_rejected),
+                None,
+                context,
+            );
+            return;
+        }
+    }
+
+    /// `AsyncDisposableStack.prototype.use ( value )`
+    ///
+    /// Adds an async disposable resource to the stack, returning the resource.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "use")?;
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], value, async-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = add_disposable_resource(value, DisposeHint::Async, None, context)? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )`
+    ///
+    /// Adds a non-disposable value to the stack, calling `onDisposeAsync` with the value when the
+    /// stack is disposed.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "adopt")?;
+        Self::require_pending(&stack, "adopt")?;
+
+        let value = args.get_or_undefined(0);
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "AsyncDisposableStack.prototype.adopt: onDisposeAsync is not callable",
+                )
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDisposeAsync and performs the following steps when called:
+        //     a. Return ? Call(onDisposeAsync, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (value, on_dispose), context| {
+                    on_dispose.call(&JsValue::undefined(), &[value.clone()], context)
+                },
+                (value.clone(), on_dispose),
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, F).
+        if let Some(resource) = add_disposable_resource(
+            &JsValue::undefined(),
+            DisposeHint::Async,
+            Some(&f.into()),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `AsyncDisposableStack.prototype.defer ( onDisposeAsync )`
+    ///
+    /// Adds a callback to the stack, which is called and awaited when the stack is disposed.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "defer")?;
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0);
+        if !on_dispose.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "AsyncDisposableStack.prototype.defer: onDisposeAsync is not callable",
+                )
+                .into());
+        }
+
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]], undefined, async-dispose, onDisposeAsync).
+        if let Some(resource) = add_disposable_resource(
+            &JsValue::undefined(),
+            DisposeHint::Async,
+            Some(on_dispose),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `AsyncDisposableStack.prototype.move ( )`
+    ///
+    /// Moves all resources of the stack into a new `AsyncDisposableStack`, marking this stack as
+    /// disposed without disposing its resources.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "move")?;
+        Self::require_pending(&stack, "move")?;
+
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            stack.data.disposed = true;
+            stack.data.capability.take()
+        };
+
+        // 4. Let newAsyncDisposableStack be ? OrdinaryCreateFromConstructor(%AsyncDisposableStack%, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 9. Return newAsyncDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .async_disposable_stack()
+                .prototype(),
+            Self {
+                disposed: false,
+                capability,
+            },
+        )
+        .into())
+    }
+}
+
+impl DisposeAsyncState {
+    /// Combines an error thrown while disposing a resource with the current completion.
+    fn suppress(&self, error: JsError) {
+        let mut completion = self.completion.borrow_mut();
+        let current = completion.take().map_or(Ok(()), Err);
+        *completion = Some(suppress_error(error, current));
+    }
+}
@@ -0,0 +1,356 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{add_disposable_resource, DisposeCapability, DisposeHint};
+
+/// Boa's implementation of the `DisposableStack` builtin object.
+///
+/// A `DisposableStack` aggregates multiple disposable resources, disposing all of them in reverse
+/// order of addition when the stack itself is disposed.
+///
+/// More Information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct DisposableStack {
+    /// `[[DisposableState]]`, `true` if the stack was already disposed.
+    disposed: bool,
+
+    /// `[[DisposeCapability]]`
+    capability: DisposeCapability,
+}
+
+impl IntrinsicObject for DisposableStack {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_disposed = BuiltInBuilder::callable(realm, Self::get_disposed)
+            .name(js_string!("get disposed"))
+            .build();
+
+        let dispose = BuiltInBuilder::callable(realm, Self::dispose)
+            .name(js_string!("dispose"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .accessor(
+                js_string!("disposed"),
+                Some(get_disposed),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::adopt, js_string!("adopt"), 2)
+            .method(Self::defer, js_string!("defer"), 1)
+            .property(
+                js_string!("dispose"),
+                dispose.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .method(Self::r#move, js_string!("move"), 0)
+            .method(Self::r#use, js_string!("use"), 1)
+            .property(
+                JsSymbol::dispose(),
+                dispose,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+}
+
+impl BuiltInObject for DisposableStack {
+    const NAME: JsString = StaticJsStrings::DISPOSABLE_STACK;
+}
+
+impl BuiltInConstructor for DisposableStack {
+    const LENGTH: usize = 0;
+    const P: usize = 8;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::disposable_stack;
+
+    /// Constructor [`DisposableStack ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::disposable_stack,
+            context,
+        )?;
+
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 5. Return disposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                disposed: false,
+                capability: DisposeCapability::new(),
+            },
+        )
+        .into())
+    }
+}
+
+impl DisposableStack {
+    /// Gets the `[[DisposableState]]` and `[[DisposeCapability]]` slots of the `this` value,
+    /// throwing a `TypeError` if it is not a `DisposableStack`.
+    fn this_stack(this: &JsValue, method: &str) -> JsResult<JsObject<Self>> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        this.as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "DisposableStack.prototype.{method}: `this` is not a DisposableStack"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Throws a `ReferenceError` if the stack was already disposed.
+    fn require_pending(stack: &JsObject<Self>, method: &str) -> JsResult<()> {
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+        if stack.borrow().data.disposed {
+            return Err(JsNativeError::reference()
+                .with_message(format!(
+                    "DisposableStack.prototype.{method}: the stack was already disposed"
+                ))
+                .into());
+        }
+        Ok(())
+    }
+
+    /// `get DisposableStack.prototype.disposed`
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed
+    fn get_disposed(this: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "disposed")?;
+
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        let disposed = stack.borrow().data.disposed;
+        Ok(disposed.into())
+    }
+
+    /// `DisposableStack.prototype.dispose ( )`
+    ///
+    /// Disposes all resources of the stack in reverse order of addition.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose
+    fn dispose(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "dispose")?;
+
+        let mut capability = {
+            let mut stack = stack.borrow_mut();
+
+            // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+            if stack.data.disposed {
+                return Ok(JsValue::undefined());
+            }
+
+            // 4. Set disposableStack.[[DisposableState]] to disposed.
+            stack.data.disposed = true;
+            stack.data.capability.take()
+        };
+
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        capability.dispose_resources(Ok(()), context)?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.use ( value )`
+    ///
+    /// Adds a disposable resource to the stack, returning the resource.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use
+    fn r#use(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "use")?;
+        Self::require_pending(&stack, "use")?;
+
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+        let value = args.get_or_undefined(0);
+        if let Some(resource) = add_disposable_resource(value, DisposeHint::Sync, None, context)? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 5. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.adopt ( value, onDispose )`
+    ///
+    /// Adds a non-disposable value to the stack, calling `onDispose` with the value when the
+    /// stack is disposed.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt
+    fn adopt(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "adopt")?;
+        Self::require_pending(&stack, "adopt")?;
+
+        let value = args.get_or_undefined(0);
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args
+            .get_or_undefined(1)
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("DisposableStack.prototype.adopt: onDispose is not callable")
+            })?;
+
+        // 5. Let closure be a new Abstract Closure with no parameters that captures value and onDispose and performs the following steps when called:
+        //     a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        let f = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |_, _, (value, on_dispose), context| {
+                    on_dispose.call(&JsValue::undefined(), &[value.clone()], context)
+                },
+                (value.clone(), on_dispose),
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+        if let Some(resource) = add_disposable_resource(
+            &JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(&f.into()),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 8. Return value.
+        Ok(value.clone())
+    }
+
+    /// `DisposableStack.prototype.defer ( onDispose )`
+    ///
+    /// Adds a callback to the stack, which is called when the stack is disposed.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer
+    fn defer(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "defer")?;
+        Self::require_pending(&stack, "defer")?;
+
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let on_dispose = args.get_or_undefined(0);
+        if !on_dispose.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("DisposableStack.prototype.defer: onDispose is not callable")
+                .into());
+        }
+
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+        if let Some(resource) = add_disposable_resource(
+            &JsValue::undefined(),
+            DisposeHint::Sync,
+            Some(on_dispose),
+            context,
+        )? {
+            stack.borrow_mut().data.capability.push(resource);
+        }
+
+        // 6. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// `DisposableStack.prototype.move ( )`
+    ///
+    /// Moves all resources of the stack into a new `DisposableStack`, marking this stack as
+    /// disposed without disposing its resources.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move
+    fn r#move(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let stack = Self::this_stack(this, "move")?;
+        Self::require_pending(&stack, "move")?;
+
+        // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        let capability = {
+            let mut stack = stack.borrow_mut();
+            stack.data.disposed = true;
+            stack.data.capability.take()
+        };
+
+        // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        // 9. Return newDisposableStack.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .constructors()
+                .disposable_stack()
+                .prototype(),
+            Self {
+                disposed: false,
+                capability,
+            },
+        )
+        .into())
+    }
+}
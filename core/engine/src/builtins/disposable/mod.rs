@@ -0,0 +1,404 @@
+//! Boa's implementation of the abstract operations of the explicit resource management proposal,
+//! along with the `DisposableStack` and `AsyncDisposableStack` builtin objects.
+//!
+//! More information:
+//!  - [Explicit Resource Management proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-explicit-resource-management/
+
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::promise::{if_abrupt_reject_promise, PromiseCapability},
+    js_string,
+    native_function::NativeFunction,
+    object::{FunctionObjectBuilder, JsObject},
+    symbol::JsSymbol,
+    Context, JsError, JsNativeError, JsResult, JsValue,
+};
+
+// TODO: remove `cfg` when the explicit resource management proposal gets to stage 4.
+#[cfg(feature = "experimental")]
+mod async_disposable_stack;
+#[cfg(feature = "experimental")]
+mod disposable_stack;
+
+#[cfg(all(test, feature = "experimental"))]
+mod tests;
+
+#[cfg(feature = "experimental")]
+pub(crate) use async_disposable_stack::AsyncDisposableStack;
+#[cfg(feature = "experimental")]
+pub(crate) use disposable_stack::DisposableStack;
+
+/// The hint used to select the disposal method of a resource.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    /// `sync-dispose`, which uses the `@@dispose` method of a resource.
+    Sync,
+
+    /// `async-dispose`, which uses the `@@asyncDispose` method of a resource, falling back to
+    /// `@@dispose`.
+    Async,
+}
+
+/// The `DisposableResource` record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct DisposableResource {
+    /// `[[ResourceValue]]`
+    value: JsValue,
+
+    /// `[[Hint]]`
+    #[unsafe_ignore_trace]
+    hint: DisposeHint,
+
+    /// `[[DisposeMethod]]`
+    method: Option<JsObject>,
+}
+
+impl DisposableResource {
+    /// Abstract operation [`CreateDisposableResource ( V, hint [ , method ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource
+    pub(crate) fn new(
+        value: &JsValue,
+        hint: DisposeHint,
+        method: Option<&JsValue>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let Some(method) = method else {
+            // 1. If method is not present, then
+            // a. If V is either null or undefined, then
+            if value.is_null_or_undefined() {
+                // i. Set V to undefined.
+                // ii. Set method to undefined.
+                return Ok(Self {
+                    value: JsValue::undefined(),
+                    hint,
+                    method: None,
+                });
+            }
+
+            // b. Else,
+            // i. If V is not an Object, throw a TypeError exception.
+            if !value.is_object() {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "cannot use a value of type `{}` as a disposable resource",
+                        value.type_of()
+                    ))
+                    .into());
+            }
+
+            // ii. Set method to ? GetDisposeMethod(V, hint).
+            // iii. If method is undefined, throw a TypeError exception.
+            let method = get_dispose_method(value, hint, context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(match hint {
+                    DisposeHint::Sync => "resource does not have a Symbol.dispose method",
+                    DisposeHint::Async => {
+                        "resource does not have a Symbol.asyncDispose or Symbol.dispose method"
+                    }
+                })
+            })?;
+
+            return Ok(Self {
+                value: value.clone(),
+                hint,
+                method: Some(method),
+            });
+        };
+
+        // 2. Else,
+        // a. If IsCallable(method) is false, throw a TypeError exception.
+        let method = method
+            .as_callable()
+            .cloned()
+            .ok_or_else(|| JsNativeError::typ().with_message("dispose method is not callable"))?;
+
+        // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+        Ok(Self {
+            value: value.clone(),
+            hint,
+            method: Some(method),
+        })
+    }
+
+    /// Returns the hint of this resource.
+    pub(crate) const fn hint(&self) -> DisposeHint {
+        self.hint
+    }
+
+    /// Calls the dispose method of this resource, returning the value that has to be awaited if
+    /// the resource has an `async-dispose` hint.
+    ///
+    /// This implements the first steps of the abstract operation [`Dispose ( V, hint, method )`][spec];
+    /// the caller is responsible for awaiting the result.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-dispose
+    pub(crate) fn dispose(&self, context: &mut Context) -> JsResult<JsValue> {
+        // 1. If method is undefined, let result be undefined.
+        let Some(method) = &self.method else {
+            return Ok(JsValue::undefined());
+        };
+
+        // 2. Else, let result be ? Call(method, V).
+        method.call(&self.value, &[], context)
+    }
+}
+
+/// Abstract operation [`GetDisposeMethod ( V, hint )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod
+pub(crate) fn get_dispose_method(
+    value: &JsValue,
+    hint: DisposeHint,
+    context: &mut Context,
+) -> JsResult<Option<JsObject>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::Async {
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        if let Some(method) = value.get_method(JsSymbol::async_dispose(), context)? {
+            return Ok(Some(method));
+        }
+
+        // b. If method is undefined, then
+        // i. Set method to ? GetMethod(V, @@dispose).
+        let Some(method) = value.get_method(JsSymbol::dispose(), context)? else {
+            return Ok(None);
+        };
+
+        // ii. If method is not undefined, then
+        // 1. Let closure be a new Abstract Closure with no parameters that captures method and
+        //    performs the following steps when called:
+        // 2. Return CreateBuiltinFunction(closure, 0, "", « »).
+        let closure = FunctionObjectBuilder::new(
+            context.realm(),
+            NativeFunction::from_copy_closure_with_captures(
+                |this, _, method, context| {
+                    // a. Let O be the this value.
+                    // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+                    let promise_capability = PromiseCapability::new(
+                        &context.intrinsics().constructors().promise().constructor(),
+                        context,
+                    )
+                    .expect("cannot fail per the spec");
+
+                    // c. Let result be Completion(Call(method, O)).
+                    let result = method.call(this, &[], context);
+
+                    // d. IfAbruptRejectPromise(result, promiseCapability).
+                    if_abrupt_reject_promise!(result, promise_capability, context);
+
+                    // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+                    promise_capability.resolve().call(
+                        &JsValue::undefined(),
+                        &[JsValue::undefined()],
+                        context,
+                    )?;
+
+                    // f. Return promiseCapability.[[Promise]].
+                    Ok(promise_capability.promise().clone().into())
+                },
+                method,
+            ),
+        )
+        .name(js_string!())
+        .length(0)
+        .build();
+
+        return Ok(Some(closure.into()));
+    }
+
+    // 2. Else,
+    // a. Let method be ? GetMethod(V, @@dispose).
+    // 3. Return method.
+    value.get_method(JsSymbol::dispose(), context)
+}
+
+/// Abstract operation [`AddDisposableResource ( disposeCapability, V, hint [ , method ] )`][spec].
+///
+/// This only creates the resource, returning `None` if nothing has to be added; appending it to
+/// the capability is left to the caller with [`DisposeCapability::push`], since creating the
+/// resource can call into user code.
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource
+pub(crate) fn add_disposable_resource(
+    value: &JsValue,
+    hint: DisposeHint,
+    method: Option<&JsValue>,
+    context: &mut Context,
+) -> JsResult<Option<DisposableResource>> {
+    // 1. If method is not present, then
+    //     a. If V is either null or undefined and hint is sync-dispose, return unused.
+    if method.is_none() && value.is_null_or_undefined() && hint == DisposeHint::Sync {
+        return Ok(None);
+    }
+
+    //     b. NOTE: When V is either null or undefined and hint is async-dispose, we record that
+    //        the resource was evaluated to ensure we will still perform an Await when resources
+    //        are later disposed.
+    //     c. Let resource be ? CreateDisposableResource(V, hint).
+    // 2. Else,
+    //     a. Assert: V is undefined.
+    //     b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+    // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+    DisposableResource::new(value, hint, method, context).map(Some)
+}
+
+/// The next step that has to be performed while disposing the resources of a
+/// [`DisposeCapability`].
+#[derive(Debug)]
+pub(crate) enum DisposeStep {
+    /// Perform `! Await(undefined)`.
+    Await,
+
+    /// Dispose the resource, awaiting the result if it has an `async-dispose` hint.
+    Dispose(DisposableResource),
+
+    /// All resources have been disposed.
+    Done,
+}
+
+/// The `DisposeCapability` record.
+///
+/// More information:
+///  - [Explicit Resource Management proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposecapability-records
+#[derive(Debug, Clone, Default, Trace, Finalize)]
+pub(crate) struct DisposeCapability {
+    /// `[[DisposableResourceStack]]`
+    stack: Vec<DisposableResource>,
+
+    /// `needsAwait` flag of `DisposeResources`.
+    needs_await: bool,
+
+    /// `hasAwaited` flag of `DisposeResources`.
+    has_awaited: bool,
+}
+
+impl DisposeCapability {
+    /// Abstract operation [`NewDisposeCapability ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-newdisposecapability
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a resource created by [`add_disposable_resource`] to the stack of resources.
+    pub(crate) fn push(&mut self, resource: DisposableResource) {
+        self.stack.push(resource);
+    }
+
+    /// Moves all the resources of this capability into a new capability, leaving this one empty.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn take(&mut self) -> Self {
+        std::mem::take(self)
+    }
+
+    /// Returns the next step of the abstract operation [`DisposeResources ( disposeCapability, completion )`][spec].
+    ///
+    /// Resources are disposed in reverse order of addition, so this pops resources from the stack
+    /// until it is empty.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn next_step(&mut self) -> DisposeStep {
+        // 2. For each element resource of disposeCapability.[[DisposableResourceStack]], in reverse list order, do
+        while let Some(resource) = self.stack.last() {
+            // b. If hint is sync-dispose and needsAwait is true and hasAwaited is false, then
+            if resource.hint == DisposeHint::Sync && self.needs_await && !self.has_awaited {
+                // i. Perform ! Await(undefined).
+                // ii. Set needsAwait to false.
+                self.needs_await = false;
+                return DisposeStep::Await;
+            }
+
+            let resource = self.stack.pop().expect("stack cannot be empty");
+
+            // c. If method is not undefined, then
+            if resource.method.is_some() {
+                // ii. If result is a normal completion and hint is async-dispose, then
+                //     2. Set hasAwaited to true.
+                if resource.hint == DisposeHint::Async {
+                    self.has_awaited = true;
+                }
+                return DisposeStep::Dispose(resource);
+            }
+
+            // d. Else,
+            //     i. Assert: hint is async-dispose.
+            debug_assert_eq!(resource.hint, DisposeHint::Async);
+
+            //     ii. Set needsAwait to true.
+            //     iii. NOTE: This can only indicate a case where either null or undefined was the
+            //          initialized value of an await using declaration.
+            self.needs_await = true;
+        }
+
+        // 3. If needsAwait is true and hasAwaited is false, then
+        if self.needs_await && !self.has_awaited {
+            // a. Perform ! Await(undefined).
+            self.needs_await = false;
+            return DisposeStep::Await;
+        }
+
+        // 5. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+        DisposeStep::Done
+    }
+
+    /// Abstract operation [`DisposeResources ( disposeCapability, completion )`][spec], for
+    /// capabilities that only contain resources with a `sync-dispose` hint.
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    #[cfg(feature = "experimental")]
+    pub(crate) fn dispose_resources(
+        &mut self,
+        mut completion: JsResult<()>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        loop {
+            match self.next_step() {
+                DisposeStep::Await => {
+                    unreachable!("synchronous dispose capabilities cannot contain async resources")
+                }
+                DisposeStep::Dispose(resource) => {
+                    // i. Let result be Completion(Call(method, value)).
+                    // iii. If result is a throw completion, then
+                    if let Err(error) = resource.dispose(context) {
+                        completion = Err(suppress_error(error, completion));
+                    }
+                }
+                // 6. Return ? completion.
+                DisposeStep::Done => return completion,
+            }
+        }
+    }
+}
+
+/// Combines the error thrown while disposing a resource with the current completion.
+///
+/// This implements step 2.c.iii of [`DisposeResources ( disposeCapability, completion )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+pub(crate) fn suppress_error(error: JsError, completion: JsResult<()>) -> JsError {
+    match completion {
+        // 1. If completion is a throw completion, then
+        Err(suppressed) => {
+            // a. Set result to result.[[Value]].
+            // b. Let suppressed be completion.[[Value]].
+            // c. Let error be a newly created SuppressedError object.
+            // d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+            // e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+            // f. Set completion to ThrowCompletion(error).
+            JsNativeError::suppressed(error, suppressed).into()
+        }
+        // 2. Else,
+        //     a. Set completion to result.
+        Ok(()) => error,
+    }
+}
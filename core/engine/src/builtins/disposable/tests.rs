@@ -0,0 +1,201 @@
+use crate::{run_test_actions, JsNativeErrorKind, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+const RESOURCE: &str = indoc! {r#"
+    var log = [];
+    function resource(name) {
+        return {
+            [Symbol.dispose]() { log.push(name); },
+        };
+    }
+    function throwing(name) {
+        return {
+            [Symbol.dispose]() { throw new Error(name); },
+        };
+    }
+"#};
+
+#[test]
+fn using_declaration() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            {
+                using a = resource("a"), b = resource("b");
+                using c = null;
+                log.push("body");
+            }
+        "#}),
+        TestAction::assert(r#"arrayEquals(log, ["body", "b", "a"])"#),
+        TestAction::run(indoc! {r#"
+            log = [];
+            function f() {
+                using a = resource("a");
+                return log.length;
+            }
+        "#}),
+        TestAction::assert_eq("f()", 0),
+        TestAction::assert(r#"arrayEquals(log, ["a"])"#),
+        TestAction::run(indoc! {r#"
+            log = [];
+            for (using x of [resource("x"), resource("y")]) {
+                log.push("iteration");
+                if (log.length > 2) break;
+            }
+        "#}),
+        TestAction::assert(r#"arrayEquals(log, ["iteration", "x", "iteration", "y"])"#),
+        TestAction::assert_native_error(
+            "{ using x = 1; }",
+            JsNativeErrorKind::Type,
+            "cannot use a value of type `number` as a disposable resource",
+        ),
+        TestAction::assert_native_error(
+            "{ using x = {}; }",
+            JsNativeErrorKind::Type,
+            "resource does not have a Symbol.dispose method",
+        ),
+    ]);
+}
+
+#[test]
+fn using_declaration_errors() {
+    run_test_actions([
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            var error;
+            try {
+                using a = throwing("a"), b = resource("b");
+                throw new Error("body");
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_str!("a")),
+        TestAction::assert_eq("error.suppressed.message", js_str!("body")),
+        TestAction::run(indoc! {r#"
+            try {
+                using a = throwing("a"), b = throwing("b");
+            } catch (e) {
+                error = e;
+            }
+        "#}),
+        TestAction::assert("error instanceof SuppressedError"),
+        TestAction::assert_eq("error.error.message", js_str!("a")),
+        TestAction::assert_eq("error.suppressed.message", js_str!("b")),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function* g() {
+                    using a = resource("generator");
+                    yield 1;
+                }
+                log = [];
+                const it = g();
+                it.next();
+                it.return();
+                log[0]
+            "#},
+            js_str!("generator"),
+        ),
+    ]);
+}
+
+#[test]
+fn suppressed_error() {
+    run_test_actions([
+        TestAction::run("var e = new SuppressedError(1, 2, 'message');"),
+        TestAction::assert_eq("e.error", 1),
+        TestAction::assert_eq("e.suppressed", 2),
+        TestAction::assert_eq("e.message", js_str!("message")),
+        TestAction::assert_eq("e.name", js_str!("SuppressedError")),
+        TestAction::assert("e instanceof Error"),
+        TestAction::assert("!Object.prototype.hasOwnProperty.call(SuppressedError(), 'message')"),
+    ]);
+}
+
+#[test]
+fn disposable_stack() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(RESOURCE),
+        TestAction::run(indoc! {r#"
+            var stack = new DisposableStack();
+            stack.use(resource("use"));
+            stack.adopt("adopt", (value) => log.push(value));
+            stack.defer(() => log.push("defer"));
+        "#}),
+        TestAction::assert("!stack.disposed"),
+        TestAction::run("stack.dispose();"),
+        TestAction::assert("stack.disposed"),
+        TestAction::assert(r#"arrayEquals(log, ["defer", "adopt", "use"])"#),
+        TestAction::assert_native_error(
+            "stack.use(resource('again'))",
+            JsNativeErrorKind::Reference,
+            "DisposableStack.prototype.use: the stack was already disposed",
+        ),
+        TestAction::run(indoc! {r#"
+            log = [];
+            var first = new DisposableStack();
+            first.defer(() => log.push("moved"));
+            var second = first.move();
+        "#}),
+        TestAction::assert("first.disposed && !second.disposed"),
+        TestAction::run("first.dispose();"),
+        TestAction::assert_eq("log.length", 0),
+        TestAction::run(indoc! {r#"
+            {
+                using s = second;
+            }
+        "#}),
+        TestAction::assert(r#"arrayEquals(log, ["moved"])"#),
+        TestAction::assert(
+            "DisposableStack.prototype[Symbol.dispose] === DisposableStack.prototype.dispose",
+        ),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(new DisposableStack())",
+            js_str!("[object DisposableStack]"),
+        ),
+    ]);
+}
+
+#[test]
+fn async_disposable_stack() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            var log = [];
+            var stack = new AsyncDisposableStack();
+            stack.use({
+                [Symbol.dispose]() { log.push("sync"); },
+            });
+            stack.use({
+                async [Symbol.asyncDispose]() { log.push("async"); },
+            });
+            stack.defer(async () => { throw new Error("defer"); });
+            var error;
+            stack.disposeAsync().catch((e) => { error = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert(r#"arrayEquals(log, ["async", "sync"])"#),
+        TestAction::assert_eq("error.message", js_str!("defer")),
+        TestAction::assert("stack.disposed"),
+        TestAction::run(indoc! {r#"
+            log = [];
+            async function f() {
+                await using a = {
+                    async [Symbol.asyncDispose]() {
+                        await null;
+                        log.push("a");
+                    },
+                };
+                await using b = null;
+                log.push("body");
+            }
+            f().then(() => log.push("done"));
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert(r#"arrayEquals(log, ["body", "a", "done"])"#),
+    ]);
+}
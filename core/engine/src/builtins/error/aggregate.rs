@@ -93,6 +93,7 @@ impl BuiltInConstructor for AggregateError {
             prototype,
             Error::Aggregate,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(1);
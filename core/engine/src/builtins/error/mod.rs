@@ -13,10 +13,10 @@
 use crate::{
     builtins::BuiltInObject,
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    error::JsNativeError,
+    error::{JsNativeError, StackFrame},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
-    property::Attribute,
+    property::{Attribute, PropertyDescriptor},
     realm::Realm,
     string::StaticJsStrings,
     Context, JsArgs, JsData, JsResult, JsString, JsValue,
@@ -29,6 +29,8 @@ pub(crate) mod aggregate;
 pub(crate) mod eval;
 pub(crate) mod range;
 pub(crate) mod reference;
+#[cfg(feature = "experimental")]
+pub(crate) mod suppressed;
 pub(crate) mod syntax;
 pub(crate) mod r#type;
 pub(crate) mod uri;
@@ -41,6 +43,8 @@ pub(crate) use self::eval::EvalError;
 pub(crate) use self::r#type::TypeError;
 pub(crate) use self::range::RangeError;
 pub(crate) use self::reference::ReferenceError;
+#[cfg(feature = "experimental")]
+pub(crate) use self::suppressed::SuppressedError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
@@ -118,6 +122,14 @@ pub enum Error {
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
     Syntax,
 
+    /// The `SuppressedError` type.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    Suppressed,
+
     /// The `URIError` type.
     ///
     /// More information:
@@ -131,10 +143,24 @@ impl IntrinsicObject for Error {
     fn init(realm: &Realm) {
         let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
+        let get_stack = BuiltInBuilder::callable(realm, Self::get_stack)
+            .name(js_string!("get stack"))
+            .build();
+        let set_stack = BuiltInBuilder::callable(realm, Self::set_stack)
+            .name(js_string!("set stack"))
+            .length(1)
+            .build();
+
         let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
         let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .property(js_string!("name"), Self::NAME, attribute)
             .property(js_string!("message"), js_string!(), attribute)
+            .accessor(
+                js_string!("stack"),
+                Some(get_stack),
+                Some(set_stack),
+                Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .method(Self::to_string, js_string!("toString"), 0);
 
         #[cfg(feature = "experimental")]
@@ -154,7 +180,7 @@ impl BuiltInObject for Error {
 
 impl BuiltInConstructor for Error {
     const LENGTH: usize = 1;
-    const P: usize = 2;
+    const P: usize = 3;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -186,6 +212,7 @@ impl BuiltInConstructor for Error {
             prototype,
             Error::Error,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -281,6 +308,110 @@ impl Error {
         Ok(js_string!(&name, js_str!(": "), &msg).into())
     }
 
+    /// Records the stack trace of the current execution as the stack trace of the error object `o`.
+    pub(crate) fn capture_stack_trace(o: &JsObject, context: &mut Context) {
+        let stack_trace = context.vm.stack_trace().into_boxed_slice();
+        Self::set_stack_trace(o, stack_trace, context);
+    }
+
+    /// Records `stack_trace` as the stack trace of the error object `o`.
+    pub(crate) fn set_stack_trace(
+        o: &JsObject,
+        stack_trace: Box<[StackFrame]>,
+        context: &mut Context,
+    ) {
+        context.error_stack_traces.insert(o.inner(), stack_trace);
+    }
+
+    /// Gets the stack trace recorded for the error object `o` when it was created.
+    ///
+    /// This is empty if `o` is not an error object created by this context.
+    pub(crate) fn stack_trace(o: &JsObject, context: &Context) -> Box<[StackFrame]> {
+        context
+            .error_stack_traces
+            .get(o.inner())
+            .unwrap_or_default()
+    }
+
+    /// `get Error.prototype.stack`
+    ///
+    /// Returns the stack trace of the error object, formatted as a string in the same format
+    /// used by V8: the string representation of the error, followed by a line for every frame
+    /// of the stack trace captured when the error was created.
+    ///
+    /// More information:
+    ///  - [Error Stacks proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-error-stacks/
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack
+    pub(crate) fn get_stack(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let E be the this value.
+        // 2. If Type(E) is not Object, throw a TypeError exception.
+        let e = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Error.prototype.stack: `this` is not an Object")
+        })?;
+
+        // 3. If E does not have an [[ErrorData]] internal slot, return undefined.
+        if !e.is::<Self>() {
+            return Ok(JsValue::undefined());
+        }
+        let stack_trace = Self::stack_trace(e, context);
+
+        // 4. Return GetStackString(E).
+        let header = Self::to_string(this, &[], context)?.to_string(context)?;
+        let frames = stack_trace
+            .iter()
+            .map(|frame| JsString::from(format!("\n    at {frame}")))
+            .collect::<Vec<_>>();
+        let strings = std::iter::once(header.as_str())
+            .chain(frames.iter().map(JsString::as_str))
+            .collect::<Vec<_>>();
+
+        Ok(JsString::concat_array(&strings).into())
+    }
+
+    /// `set Error.prototype.stack`
+    ///
+    /// Overrides the stack trace of the error object by defining an own `stack` data property on it.
+    ///
+    /// More information:
+    ///  - [Error Stacks proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-error-stacks/
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Error/stack
+    pub(crate) fn set_stack(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let E be the this value.
+        // 2. If Type(E) is not Object, throw a TypeError exception.
+        let e = this.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("Error.prototype.stack: `this` is not an Object")
+        })?;
+
+        // 3. Perform ? DefinePropertyOrThrow(E, "stack", PropertyDescriptor { [[Value]]: value,
+        //    [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
+        e.define_property_or_throw(
+            js_string!("stack"),
+            PropertyDescriptor::builder()
+                .value(args.get_or_undefined(0).clone())
+                .writable(true)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+
+        // 4. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
     /// [`Error.isError`][spec].
     ///
     /// Returns a boolean indicating whether the argument is a built-in Error instance or not.
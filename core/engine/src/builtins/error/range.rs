@@ -86,6 +86,7 @@ impl BuiltInConstructor for RangeError {
             prototype,
             Error::Range,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
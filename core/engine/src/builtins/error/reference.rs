@@ -88,6 +88,7 @@ impl BuiltInConstructor for ReferenceError {
             prototype,
             Error::Reference,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
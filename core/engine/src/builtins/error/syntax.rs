@@ -91,6 +91,7 @@ impl BuiltInConstructor for SyntaxError {
             prototype,
             Error::Syntax,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -1,4 +1,4 @@
-use crate::{run_test_actions, TestAction};
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
 use boa_macros::js_str;
 use indoc::indoc;
 
@@ -68,3 +68,76 @@ fn error_lengths() {
         TestAction::assert_eq("AggregateError.length", 2),
     ]);
 }
+
+#[test]
+fn error_stack() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            function inner() {
+                return new Error("oops");
+            }
+            function outer() {
+                return inner();
+            }
+            var error = outer();
+        "#}),
+        TestAction::assert_eq(
+            "error.stack",
+            js_str!(
+                "Error: oops\n    at inner (<anonymous>:2:12)\n    at outer (<anonymous>:5:17)\n    at <anonymous>:7:18"
+            ),
+        ),
+        TestAction::assert("!Object.prototype.hasOwnProperty.call(error, 'stack')"),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function f() {
+                    null.x;
+                }
+                try {
+                    f();
+                } catch (e) {
+                    e.stack
+                }
+            "#},
+            js_str!(
+                "TypeError: cannot convert 'null' or 'undefined' to object\n    at f (<anonymous>:2:9)\n    at <anonymous>:5:6"
+            ),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                function g(obj) {
+                    return obj.a[0];
+                }
+                try {
+                    g({});
+                } catch (e) {
+                    e.stack
+                }
+            "#},
+            js_str!(
+                "TypeError: cannot convert 'null' or 'undefined' to object\n    at g (<anonymous>:2:17)\n    at <anonymous>:5:6"
+            ),
+        ),
+        TestAction::assert_eq(
+            "Error('oops').stack",
+            js_str!("Error: oops\n    at <anonymous>:1:6"),
+        ),
+        TestAction::assert_eq("Error.prototype.stack", JsValue::undefined()),
+        TestAction::assert_eq("({ __proto__: Error.prototype }).stack", JsValue::undefined()),
+    ]);
+}
+
+#[test]
+fn error_stack_setter() {
+    run_test_actions([
+        TestAction::run("var error = new Error('oops'); error.stack = 'custom';"),
+        TestAction::assert_eq("error.stack", js_str!("custom")),
+        TestAction::assert("Object.prototype.hasOwnProperty.call(error, 'stack')"),
+        TestAction::assert("!Object.getOwnPropertyDescriptor(error, 'stack').enumerable"),
+        TestAction::assert_native_error(
+            "Object.getOwnPropertyDescriptor(Error.prototype, 'stack').get.call(1)",
+            JsNativeErrorKind::Type,
+            "Error.prototype.stack: `this` is not an Object",
+        ),
+    ]);
+}
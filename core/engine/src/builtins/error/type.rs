@@ -94,6 +94,7 @@ impl BuiltInConstructor for TypeError {
             prototype,
             Error::Type,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
@@ -87,6 +87,7 @@ impl BuiltInConstructor for UriError {
             prototype,
             Error::Uri,
         );
+        Error::capture_stack_trace(&o, context);
 
         // 3. If message is not undefined, then
         let message = args.get_or_undefined(0);
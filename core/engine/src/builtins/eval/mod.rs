@@ -76,7 +76,7 @@ impl Eval {
         x: &JsValue,
         direct: bool,
         lexical_scope: Option<Scope>,
-        mut strict: bool,
+        strict: bool,
         context: &mut Context,
     ) -> JsResult<JsValue> {
         bitflags::bitflags! {
@@ -90,14 +90,6 @@ impl Eval {
             }
         }
 
-        /// Possible actions that can be executed after exiting this function to restore the environment to its
-        /// original state.
-        #[derive(Debug)]
-        enum EnvStackAction {
-            Truncate(usize),
-            Restore(Vec<Environment>),
-        }
-
         // 1. Assert: If direct is false, then strictCaller is also false.
         debug_assert!(direct || !strict);
 
@@ -130,7 +122,7 @@ impl Eval {
         if strict {
             parser.set_strict();
         }
-        let mut body = parser.parse_eval(direct, context.interner_mut())?;
+        let body = parser.parse_eval(direct, context.interner_mut())?;
 
         // 6. Let inFunction be false.
         // 7. Let inMethod be false.
@@ -195,6 +187,31 @@ impl Eval {
                 .into());
         }
 
+        Self::evaluate_body(body, direct, lexical_scope, strict, context)
+    }
+
+    /// Evaluates the parsed `body` of an `eval` call, after its early errors have been checked.
+    ///
+    /// This performs the steps 12 to 33 of [`PerformEval`][spec], and is shared with
+    /// `ShadowRealm.prototype.evaluate`, which executes its code like an indirect `eval` in the
+    /// realm of the `ShadowRealm`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performeval
+    pub(crate) fn evaluate_body(
+        mut body: boa_ast::Script,
+        direct: bool,
+        lexical_scope: Option<Scope>,
+        mut strict: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        /// Possible actions that can be executed after exiting this function to restore the environment to its
+        /// original state.
+        #[derive(Debug)]
+        enum EnvStackAction {
+            Truncate(usize),
+            Restore(Vec<Environment>),
+        }
+
         strict |= body.strict();
 
         // Because our environment model does not map directly to the spec, this section looks very different.
@@ -734,60 +734,11 @@ impl BuiltInFunctionObject {
         // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
         let f = BoundFunction::create(target.clone(), this_arg, bound_args, context)?;
 
-        // 4. Let L be 0.
-        let mut l = JsValue::new(0);
-
-        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
-        // 6. If targetHasLength is true, then
-        if target.has_own_property(StaticJsStrings::LENGTH, context)? {
-            // a. Let targetLen be ? Get(Target, "length").
-            let target_len = target.get(StaticJsStrings::LENGTH, context)?;
-            // b. If Type(targetLen) is Number, then
-            if target_len.is_number() {
-                // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
-                match target_len
-                    .to_integer_or_infinity(context)
-                    .expect("to_integer_or_infinity cannot fail for a number")
-                {
-                    // i. If targetLen is +∞𝔽, set L to +∞.
-                    IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
-                    // ii. Else if targetLen is -∞𝔽, set L to 0.
-                    IntegerOrInfinity::NegativeInfinity => {}
-                    // iii. Else,
-                    IntegerOrInfinity::Integer(target_len) => {
-                        // 2. Assert: targetLenAsInt is finite.
-                        // 3. Let argCount be the number of elements in args.
-                        // 4. Set L to max(targetLenAsInt - argCount, 0).
-                        l = (target_len - arg_count).max(0).into();
-                    }
-                }
-            }
-        }
-
-        // 7. Perform ! SetFunctionLength(F, L).
-        f.define_property_or_throw(
-            StaticJsStrings::LENGTH,
-            PropertyDescriptor::builder()
-                .value(l)
-                .writable(false)
-                .enumerable(false)
-                .configurable(true),
-            context,
-        )
-        .expect("defining the `length` property for a new object should not fail");
-
-        // 8. Let targetName be ? Get(Target, "name").
-        let target_name = target.get(js_string!("name"), context)?;
+        // 4. Let argCount be the number of elements in args.
+        // 5. Perform ? CopyNameAndLength(F, Target, "bound", argCount).
+        copy_name_and_length(&f, target, Some(js_str!("bound")), arg_count, context)?;
 
-        // 9. If Type(targetName) is not String, set targetName to the empty String.
-        let target_name = target_name
-            .as_string()
-            .map_or_else(JsString::default, Clone::clone);
-
-        // 10. Perform SetFunctionName(F, targetName, "bound").
-        set_function_name(&f, &target_name.into(), Some(js_str!("bound")), context);
-
-        // 11. Return F.
+        // 6. Return F.
         Ok(f.into())
     }
 
@@ -896,6 +847,79 @@ impl BuiltInFunctionObject {
     }
 }
 
+/// Abstract operation [`CopyNameAndLength ( F, Target [ , prefix [ , argCount ] ] )`][spec]
+///
+/// Copies the `length` and `name` properties of `target` to `function`, used by
+/// `Function.prototype.bind` and the wrapped functions of `ShadowRealm`.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-copynameandlength
+pub(crate) fn copy_name_and_length(
+    function: &JsObject,
+    target: &JsObject,
+    prefix: Option<JsStr<'_>>,
+    arg_count: i64,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If argCount is not present, set argCount to 0.
+    // 2. Let L be 0.
+    let mut l = JsValue::new(0);
+
+    // 3. Let targetHasLength be ? HasOwnProperty(Target, "length").
+    // 4. If targetHasLength is true, then
+    if target.has_own_property(StaticJsStrings::LENGTH, context)? {
+        // a. Let targetLen be ? Get(Target, "length").
+        let target_len = target.get(StaticJsStrings::LENGTH, context)?;
+        // b. If Type(targetLen) is Number, then
+        if target_len.is_number() {
+            // 1. Let targetLenAsInt be ! ToIntegerOrInfinity(targetLen).
+            match target_len
+                .to_integer_or_infinity(context)
+                .expect("to_integer_or_infinity cannot fail for a number")
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                IntegerOrInfinity::PositiveInfinity => l = f64::INFINITY.into(),
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                IntegerOrInfinity::NegativeInfinity => {}
+                // iii. Else,
+                IntegerOrInfinity::Integer(target_len) => {
+                    // 2. Assert: targetLenAsInt is finite.
+                    // 3. Set L to max(targetLenAsInt - argCount, 0).
+                    l = (target_len - arg_count).max(0).into();
+                }
+            }
+        }
+    }
+
+    // 5. Perform ! SetFunctionLength(F, L).
+    function
+        .define_property_or_throw(
+            StaticJsStrings::LENGTH,
+            PropertyDescriptor::builder()
+                .value(l)
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )
+        .expect("defining the `length` property for a new object should not fail");
+
+    // 6. Let targetName be ? Get(Target, "name").
+    let target_name = target.get(js_string!("name"), context)?;
+
+    // 7. If Type(targetName) is not String, set targetName to the empty String.
+    let target_name = target_name
+        .as_string()
+        .map_or_else(JsString::default, Clone::clone);
+
+    // 8. Perform SetFunctionName(F, targetName, prefix).
+    set_function_name(function, &target_name.into(), prefix, context);
+
+    Ok(())
+}
+
 /// Abstract operation `SetFunctionName`
 ///
 /// More information:
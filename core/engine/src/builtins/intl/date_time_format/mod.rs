@@ -0,0 +1,1786 @@
+//! This module implements the global `Intl.DateTimeFormat` object.
+//!
+//! `Intl.DateTimeFormat` is a built-in object that has properties and methods for date and time i18n.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+
+use std::cell::OnceCell;
+
+use crate::{
+    builtins::{
+        date::utils::{
+            date_from_time, hour_from_time, min_from_time, month_from_time, ms_from_time,
+            sec_from_time, time_clip, year_from_time,
+        },
+        options::get_option,
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    error::JsNativeError,
+    js_string,
+    native_function::NativeFunction,
+    object::{
+        internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsFunction,
+        JsObject, ObjectInitializer,
+    },
+    property::{Attribute, PropertyDescriptor},
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsResult, JsString, JsValue,
+};
+
+use boa_gc::{custom_trace, Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_calendar::{types::Time, AnyCalendar, AnyCalendarKind, DateTime};
+use icu_datetime::{
+    fields::{self, Field, FieldLength, FieldSymbol},
+    options::{
+        components::{self, Numeric, Text},
+        length,
+        preferences::{self, HourCycle},
+    },
+    pattern::{runtime::Pattern, CoarseHourCycle, PatternItem},
+    provider::calendar::{
+        BuddhistDateLengthsV1Marker, ChineseDateLengthsV1Marker, CopticDateLengthsV1Marker,
+        DangiDateLengthsV1Marker, DateSkeletonPatternsV1Marker, EthiopianDateLengthsV1Marker,
+        GregorianDateLengthsV1Marker, HebrewDateLengthsV1Marker, IndianDateLengthsV1Marker,
+        IslamicDateLengthsV1Marker, JapaneseDateLengthsV1Marker,
+        JapaneseExtendedDateLengthsV1Marker, PersianDateLengthsV1Marker, RocDateLengthsV1Marker,
+        TimeLengthsV1Marker,
+    },
+    skeleton::{self, BestSkeleton},
+    time_zone::TimeZoneFormatterOptions,
+    DateTimeFormatter, DateTimeFormatterOptions, ZonedDateTimeFormatter,
+};
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::{key, Value},
+    extensions_unicode_value as value, Locale,
+};
+use icu_provider::{
+    DataError, DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse,
+};
+use icu_timezone::{CustomTimeZone, GmtOffset};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale, validate_extension},
+    options::{get_number_option, IntlOptions},
+    Service,
+};
+use crate::context::icu::IntlProvider;
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+/// The `intervalFormatFallback` pattern of the root locale.
+///
+/// ICU4X doesn't support interval formatting yet, so `formatRange` always joins both dates with
+/// this separator.
+const RANGE_SEPARATOR: &str = "\u{2009}\u{2013}\u{2009}";
+
+/// JavaScript `Intl.DateTimeFormat` object.
+#[derive(Debug, Finalize, JsData)]
+pub(crate) struct DateTimeFormat {
+    locale: Locale,
+    calendar: Value,
+    numbering_system: Option<Value>,
+    time_zone: TimeZone,
+    hour_cycle: Option<HourCycle>,
+    components: components::Bag,
+    day_period: Option<Text>,
+    time_zone_name: Option<TimeZoneName>,
+    date_style: Option<length::Date>,
+    time_style: Option<length::Time>,
+    formatter: DateTimeFormatter,
+    zoned_formatter: Option<ZonedDateTimeFormatter>,
+    data_locale: DataLocale,
+    format_options: DateTimeFormatterOptions,
+    pattern: OnceCell<Vec<PatternPart>>,
+    bound_format: Option<JsFunction>,
+}
+
+// SAFETY: only `bound_format` is a traceable object.
+unsafe impl Trace for DateTimeFormat {
+    custom_trace!(this, mark, mark(&this.bound_format));
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct DateTimeFormatLocaleOptions {
+    calendar: Option<Value>,
+    numbering_system: Option<Value>,
+    hour_cycle: Option<HourCycle>,
+    hour12: Option<bool>,
+}
+
+impl Service for DateTimeFormat {
+    type LangMarker = GregorianDateLengthsV1Marker;
+
+    type LocaleOptions = DateTimeFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let calendar = options
+            .calendar
+            .take()
+            .and_then(|ca| supported_calendar(&ca, provider))
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("ca"))
+                    .and_then(|ca| supported_calendar(ca, provider))
+            });
+
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<DecimalSymbolsV1Marker>(
+                    locale.id.clone(),
+                    key!("nu"),
+                    nu,
+                    provider,
+                )
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<DecimalSymbolsV1Marker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            provider,
+                        )
+                    })
+            });
+
+        // `hour12` overrides both the `hourCycle` option and the `hc` extension key.
+        let hour_cycle = if options.hour12.is_some() {
+            None
+        } else {
+            options.hour_cycle.or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("hc"))
+                    .and_then(hour_cycle_from_value)
+            })
+        };
+
+        locale.extensions.unicode.clear();
+
+        if let Some(ca) = calendar.clone() {
+            locale.extensions.unicode.keywords.set(key!("ca"), ca);
+        }
+
+        if let Some(hc) = hour_cycle {
+            locale
+                .extensions
+                .unicode
+                .keywords
+                .set(key!("hc"), hour_cycle_to_value(hc));
+        }
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.calendar = calendar;
+        options.numbering_system = numbering_system;
+        options.hour_cycle = hour_cycle;
+    }
+}
+
+/// Checks if `calendar` is supported by the date time formatters, returning its canonical value.
+pub(in crate::builtins::intl) fn supported_calendar(
+    calendar: &Value,
+    provider: &IntlProvider,
+) -> Option<Value> {
+    let kind = AnyCalendarKind::get_for_bcp47_value(calendar)?;
+
+    // ICU4X has no formatting data for the ISO calendar.
+    if kind == AnyCalendarKind::Iso {
+        return None;
+    }
+
+    AnyCalendar::try_new_unstable(provider, kind)
+        .ok()
+        .map(|_| kind.as_bcp47_value())
+}
+
+impl IntrinsicObject for DateTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DateTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DateTimeFormat {
+    const NAME: JsString = StaticJsStrings::DATE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for DateTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 5;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::date_time_format;
+    /// The `Intl.DateTimeFormat` constructor is the `%DateTimeFormat%` intrinsic object and a standard built-in property of the `Intl` object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#datetimeformat-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target_inner = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .date_time_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let dateTimeFormat be ? CreateDateTimeFormat(newTarget, locales, options, any, date).
+        let date_time_format = create_date_time_format(
+            new_target_inner,
+            locales,
+            options,
+            &DateTimeReqs::AnyAll,
+            &DateTimeReqs::Date,
+            context,
+        )?;
+
+        // 3. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Let this be the this value.
+        //     b. Return ? ChainDateTimeFormat(dateTimeFormat, NewTarget, this).
+        // ChainDateTimeFormat ( dateTimeFormat, newTarget, this )
+        // <https://tc39.es/ecma402/#sec-chaindatetimeformat>
+
+        let this = context.vm.frame().this(&context.vm);
+        let Some(this_obj) = this.as_object() else {
+            return Ok(date_time_format.into());
+        };
+
+        let constructor = context
+            .intrinsics()
+            .constructors()
+            .date_time_format()
+            .constructor();
+
+        // 1. If newTarget is undefined and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, this) is true, then
+        if new_target.is_undefined()
+            && JsValue::ordinary_has_instance(&constructor.into(), &this, context)?
+        {
+            let fallback_symbol = context
+                .intrinsics()
+                .objects()
+                .intl()
+                .borrow()
+                .data
+                .fallback_symbol();
+
+            // a. Perform ? DefinePropertyOrThrow(this, %Intl%.[[FallbackSymbol]], PropertyDescriptor{ [[Value]]: dateTimeFormat, [[Writable]]: false, [[Enumerable]]: false, [[Configurable]]: false }).
+            this_obj.define_property_or_throw(
+                fallback_symbol,
+                PropertyDescriptor::builder()
+                    .value(date_time_format)
+                    .writable(false)
+                    .enumerable(false)
+                    .configurable(false),
+                context,
+            )?;
+            // b. Return this.
+            Ok(this)
+        } else {
+            // 4. Return dateTimeFormat.
+            Ok(date_time_format.into())
+        }
+    }
+}
+
+/// Abstract operation [`CreateDateTimeFormat ( newTarget, locales, options, required, defaults )`][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createdatetimeformat
+fn create_date_time_format(
+    new_target: &JsValue,
+    locales: &JsValue,
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. Let dateTimeFormat be ? OrdinaryCreateFromConstructor(newTarget, "%DateTimeFormat.prototype%",
+    // « [[InitializedDateTimeFormat]], [[Locale]], [[Calendar]], [[NumberingSystem]], [[TimeZone]], [[Weekday]],
+    // [[Era]], [[Year]], [[Month]], [[Day]], [[DayPeriod]], [[Hour]], [[Minute]], [[Second]],
+    // [[FractionalSecondDigits]], [[TimeZoneName]], [[HourCycle]], [[DateStyle]], [[TimeStyle]],
+    // [[Pattern]], [[RangePatterns]], [[BoundFormat]] »).
+    let prototype = get_prototype_from_constructor(
+        new_target,
+        StandardConstructors::date_time_format,
+        context,
+    )?;
+
+    // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+    let requested_locales = canonicalize_locale_list(locales, context)?;
+
+    // 3. Set options to ? ToDateTimeOptions(options, required, defaults).
+    let options = to_date_time_options(options, required, defaults, context)?;
+
+    // 4. Let opt be a new Record.
+    // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+    // 6. Set opt.[[localeMatcher]] to matcher.
+    let matcher = get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+    // 7. Let calendar be ? GetOption(options, "calendar", string, empty, undefined).
+    // 8. If calendar is not undefined, then
+    //     a. If calendar cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+    // 9. Set opt.[[ca]] to calendar.
+    let calendar = get_option(&options, js_string!("calendar"), context)?;
+
+    // 10. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+    // 11. If numberingSystem is not undefined, then
+    //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+    // 12. Set opt.[[nu]] to numberingSystem.
+    let numbering_system = get_option(&options, js_string!("numberingSystem"), context)?;
+
+    // 13. Let hour12 be ? GetOption(options, "hour12", boolean, empty, undefined).
+    let hour12: Option<bool> = get_option(&options, js_string!("hour12"), context)?;
+
+    // 14. Let hourCycle be ? GetOption(options, "hourCycle", string, « "h11", "h12", "h23", "h24" », undefined).
+    // 15. If hour12 is not undefined, then
+    //     a. Set hourCycle to null.
+    // 16. Set opt.[[hc]] to hourCycle.
+    let hour_cycle = get_option(&options, js_string!("hourCycle"), context)?;
+
+    let mut intl_options = IntlOptions {
+        matcher,
+        service_options: DateTimeFormatLocaleOptions {
+            calendar,
+            numbering_system,
+            hour_cycle,
+            hour12,
+        },
+    };
+
+    // 17. Let localeData be %DateTimeFormat%.[[LocaleData]].
+    // 18. Let r be ResolveLocale(%DateTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %DateTimeFormat%.[[RelevantExtensionKeys]], localeData).
+    // 19. Set dateTimeFormat.[[Locale]] to r.[[locale]].
+    let locale = resolve_locale::<DateTimeFormat>(
+        requested_locales,
+        &mut intl_options,
+        context.intl_provider(),
+    )?;
+
+    // 20. Let resolvedCalendar be r.[[ca]].
+    // 21. Set dateTimeFormat.[[Calendar]] to resolvedCalendar.
+    let calendar = intl_options
+        .service_options
+        .calendar
+        .unwrap_or(value!("gregory"));
+
+    // 22. Set dateTimeFormat.[[NumberingSystem]] to r.[[nu]].
+    let numbering_system = intl_options.service_options.numbering_system;
+
+    // 23. Let dataLocale be r.[[dataLocale]].
+    let mut data_locale = DataLocale::from(&locale);
+    data_locale.set_unicode_ext(key!("ca"), calendar.clone());
+
+    // 24. Let dataLocaleData be localeData.[[<dataLocale>]].
+    // 25. Let hc be r.[[hc]].
+    let hc = intl_options.service_options.hour_cycle;
+
+    // 26. Let timeZone be ? Get(options, "timeZone").
+    let time_zone = options.get(js_string!("timeZone"), context)?;
+
+    let time_zone = if time_zone.is_undefined() {
+        // 27. If timeZone is undefined, then
+        //     a. Set timeZone to SystemTimeZoneIdentifier().
+        TimeZone::system(context.host_hooks())
+    } else {
+        // 28. Else,
+        //     a. Set timeZone to ? ToString(timeZone).
+        let time_zone = time_zone.to_string(context)?.to_std_string_escaped();
+
+        // 29. If IsTimeZoneOffsetString(timeZone) is true, then
+        //     a. Let parseResult be ParseText(StringToCodePoints(timeZone), UTCOffset).
+        //     ...
+        //     e. Set timeZone to FormatOffsetTimeZoneIdentifier(offsetMinutes).
+        // 30. Else,
+        //     a. If IsValidTimeZoneName(timeZone) is false, then
+        //         i. Throw a RangeError exception.
+        //     b. Set timeZone to CanonicalizeTimeZoneName(timeZone).
+        TimeZone::from_identifier(&time_zone, context.intl_provider(), context.host_hooks())?
+    };
+
+    // 31. Set dateTimeFormat.[[TimeZone]] to timeZone.
+    // 32. Let formatOptions be a new Record.
+    // 33. Set formatOptions.[[hourCycle]] to hc.
+    // 34. Let hasExplicitFormatComponents be false.
+    // 35. For each row of Table 6, except the header row, in table order, do
+    //     a. Let prop be the name given in the Property column of the current row.
+    //     b. If prop is "fractionalSecondDigits", then
+    //         i. Let value be ? GetNumberOption(options, "fractionalSecondDigits", 1, 3, undefined).
+    //     c. Else,
+    //         i. Let values be a List whose elements are the strings given in the Values column of the current row.
+    //         ii. Let value be ? GetOption(options, prop, string, values, undefined).
+    //     d. Set formatOptions.[[<prop>]] to value.
+    //     e. If value is not undefined, then
+    //         i. Set hasExplicitFormatComponents to true.
+    let mut components = components::Bag::empty();
+    components.weekday = get_option(&options, js_string!("weekday"), context)?;
+    components.era = get_option(&options, js_string!("era"), context)?;
+    components.year = get_option(&options, js_string!("year"), context)?;
+    components.month = get_option(&options, js_string!("month"), context)?;
+    components.day = get_option(&options, js_string!("day"), context)?;
+    let day_period: Option<Text> = get_option(&options, js_string!("dayPeriod"), context)?;
+    components.hour = get_option(&options, js_string!("hour"), context)?;
+    components.minute = get_option(&options, js_string!("minute"), context)?;
+    components.second = get_option(&options, js_string!("second"), context)?;
+    components.fractional_second = get_number_option(
+        &options,
+        js_string!("fractionalSecondDigits"),
+        1,
+        3,
+        context,
+    )?;
+    let time_zone_name: Option<TimeZoneName> =
+        get_option(&options, js_string!("timeZoneName"), context)?;
+    components.time_zone_name = time_zone_name.map(TimeZoneName::to_icu);
+
+    let has_explicit_format_components =
+        components != components::Bag::empty() || day_period.is_some() || time_zone_name.is_some();
+
+    // 36. Let formatMatcher be ? GetOption(options, "formatMatcher", string, « "basic", "best fit" », "best fit").
+    // ICU4X uses the same skeleton matching algorithm for both matchers.
+    let _format_matcher: FormatMatcher =
+        get_option(&options, js_string!("formatMatcher"), context)?.unwrap_or_default();
+
+    // 37. Let dateStyle be ? GetOption(options, "dateStyle", string, « "full", "long", "medium", "short" », undefined).
+    // 38. Set dateTimeFormat.[[DateStyle]] to dateStyle.
+    let date_style: Option<length::Date> = get_option(&options, js_string!("dateStyle"), context)?;
+
+    // 39. Let timeStyle be ? GetOption(options, "timeStyle", string, « "full", "long", "medium", "short" », undefined).
+    // 40. Set dateTimeFormat.[[TimeStyle]] to timeStyle.
+    let time_style: Option<length::Time> = get_option(&options, js_string!("timeStyle"), context)?;
+
+    // 41. If dateStyle is not undefined or timeStyle is not undefined, then
+    //     a. If hasExplicitFormatComponents is true, then
+    //         i. Throw a TypeError exception.
+    let has_style = date_style.is_some() || time_style.is_some();
+    if has_style && has_explicit_format_components {
+        return Err(JsNativeError::typ()
+            .with_message(
+                "`dateStyle` and `timeStyle` cannot be used with explicit format components",
+            )
+            .into());
+    }
+
+    // 42. If dateTimeFormat.[[Hour]] is undefined, then
+    //     a. Set dateTimeFormat.[[HourCycle]] to undefined.
+    // 43. Else,
+    //     a. Let hcDefault be dataLocaleData.[[hourCycle]].
+    //     b. If hc is null, set hc to hcDefault.
+    //     c. If hour12 is true, then
+    //         i. Set hc to dataLocaleData.[[hourCycle12]].
+    //     d. Else if hour12 is false, then
+    //         i. Set hc to dataLocaleData.[[hourCycle24]].
+    //     e. Set dateTimeFormat.[[HourCycle]] to hc.
+    let has_hour = components.hour.is_some() || time_style.is_some();
+    let hour_cycle = has_hour.then(|| match (hour12, hc) {
+        (Some(true), _) => HourCycle::H12,
+        (Some(false), _) => HourCycle::H23,
+        (None, Some(hc)) => hc,
+        (None, None) => default_hour_cycle(context.intl_provider(), &data_locale),
+    });
+
+    if let Some(hc) = hour_cycle {
+        data_locale.set_unicode_ext(key!("hc"), hour_cycle_to_value(hc));
+        components.preferences = Some(preferences::Bag::from_hour_cycle(hc));
+    }
+
+    // 44. Set dateTimeFormat.[[Pattern]] to the best pattern of the data locale for the provided
+    //     options, as selected by ICU4X.
+    let format_options = if has_style {
+        DateTimeFormatterOptions::Length(match (date_style, time_style) {
+            (Some(date), Some(time)) => length::Bag::from_date_time_style(date, time),
+            (Some(date), None) => length::Bag::from_date_style(date),
+            (None, time) => length::Bag::from_time_style(time.unwrap_or(length::Time::Medium)),
+        })
+    } else {
+        DateTimeFormatterOptions::Components(components)
+    };
+
+    let provider = context.intl_provider();
+
+    let formatter =
+        DateTimeFormatter::try_new_experimental_unstable(provider, &data_locale, format_options)
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    // The long and full time styles include the name of the time zone.
+    let zoned_formatter = (time_zone_name.is_some()
+        || matches!(time_style, Some(length::Time::Full | length::Time::Long)))
+    .then(|| {
+        ZonedDateTimeFormatter::try_new_experimental_unstable(
+            provider,
+            &data_locale,
+            format_options,
+            TimeZoneFormatterOptions::default(),
+        )
+    })
+    .transpose()
+    .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    // 45. Return dateTimeFormat.
+    Ok(JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        prototype,
+        DateTimeFormat {
+            locale,
+            calendar,
+            numbering_system,
+            time_zone,
+            hour_cycle,
+            components,
+            day_period,
+            time_zone_name,
+            date_style,
+            time_style,
+            formatter,
+            zoned_formatter,
+            data_locale,
+            format_options,
+            pattern: OnceCell::new(),
+            bound_format: None,
+        },
+    ))
+}
+
+/// Formats the time value `x` with a new `Intl.DateTimeFormat` created from `locales` and
+/// `options`.
+///
+/// This is the shared implementation of the `toLocaleString`, `toLocaleDateString` and
+/// `toLocaleTimeString` methods of `Date.prototype`.
+pub(crate) fn format_date_time_locale(
+    locales: &JsValue,
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    x: f64,
+    context: &mut Context,
+) -> JsResult<JsString> {
+    // 1. Let dateFormat be ? CreateDateTimeFormat(%Intl.DateTimeFormat%, locales, options, required, defaults).
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .date_time_format()
+        .constructor();
+    let date_format = create_date_time_format(
+        &constructor.into(),
+        locales,
+        options,
+        required,
+        defaults,
+        context,
+    )?;
+    let date_format = date_format
+        .downcast_ref::<DateTimeFormat>()
+        .expect("`CreateDateTimeFormat` must return an `Intl.DateTimeFormat` object");
+
+    // 2. Return ! FormatDateTime(dateFormat, x).
+    date_format.format_date_time(x, context).map(JsString::from)
+}
+
+/// Gets the preferred hour cycle of the provided locale.
+pub(in crate::builtins::intl) fn default_hour_cycle(
+    provider: &IntlProvider,
+    locale: &DataLocale,
+) -> HourCycle {
+    let request = DataRequest {
+        locale,
+        metadata: DataRequestMetadata::default(),
+    };
+
+    let preferred = DataProvider::<TimeLengthsV1Marker>::load(provider, request)
+        .and_then(DataResponse::take_payload)
+        .map(|payload| payload.get().preferred_hour_cycle)
+        .unwrap_or_default();
+
+    match preferred {
+        CoarseHourCycle::H11H12 => HourCycle::H12,
+        CoarseHourCycle::H23H24 => HourCycle::H23,
+    }
+}
+
+impl DateTimeFormat {
+    /// [`Intl.DateTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in date and
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %DateTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`get Intl.DateTimeFormat.prototype.format`][spec].
+    ///
+    /// Returns a function that formats a date according to the locale and formatting options of
+    /// this `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/format
+    fn get_format(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf_clone = dtf.clone();
+        let mut dtf = dtf.borrow_mut();
+
+        let bound_format = if let Some(f) = dtf.data.bound_format.clone() {
+            f
+        } else {
+            // 4. If dtf.[[BoundFormat]] is undefined, then
+            //     a. Let F be a new built-in function object as defined in DateTime Format Functions (11.5.4.1).
+            //     b. Set F.[[DateTimeFormat]] to dtf.
+            //     c. Set dtf.[[BoundFormat]] to F.
+            let bound_format = FunctionObjectBuilder::new(
+                context.realm(),
+                // DateTime Format Functions
+                // <https://tc39.es/ecma402/#sec-datetime-format-functions>
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, dtf, context| {
+                        // 1. Let dtf be F.[[DateTimeFormat]].
+                        // 2. Assert: Type(dtf) is Object and dtf has an [[InitializedDateTimeFormat]] internal slot.
+
+                        // 3. If date is not provided or is undefined, then
+                        //     a. Let x be ! Call(%Date.now%, undefined).
+                        // 4. Else,
+                        //     a. Let x be ? ToNumber(date).
+                        let x = date_to_time_value(args.get_or_undefined(0), context)?;
+
+                        // 5. Return ? FormatDateTime(dtf, x).
+                        let formatted = dtf.borrow().data.format_date_time(x, context)?;
+                        Ok(js_string!(formatted).into())
+                    },
+                    dtf_clone,
+                ),
+            )
+            .length(1)
+            .build();
+
+            dtf.data.bound_format = Some(bound_format.clone());
+            bound_format
+        };
+
+        // 5. Return dtf.[[BoundFormat]].
+        Ok(bound_format.into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatToParts ( date )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted date and time in parts.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DateTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DateTimeFormat` object",
+                )
+            })?;
+
+        // 3. If date is undefined, then
+        //     a. Let x be ! Call(%Date.now%, undefined).
+        // 4. Else,
+        //     a. Let x be ? ToNumber(date).
+        let x = date_to_time_value(args.get_or_undefined(0), context)?;
+
+        // 5. Return ? FormatDateTimeToParts(dtf, x).
+        let parts = dtf.borrow().data.format_date_time_to_parts(x, context)?;
+
+        Ok(parts_to_array(parts, context).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.formatRange ( startDate, endDate )`][spec].
+    ///
+    /// Returns a string representing the date range between the two provided dates.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.formatRange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be this value.
+        // 2. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatRange` can only be called on an `Intl.DateTimeFormat` object",
+                )
+            })?;
+
+        let start_date = args.get_or_undefined(0);
+        let end_date = args.get_or_undefined(1);
+
+        // 3. If startDate is undefined or endDate is undefined, throw a TypeError exception.
+        if start_date.is_undefined() || end_date.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`formatRange` requires both a start and an end date")
+                .into());
+        }
+
+        // 4. Let x be ? ToNumber(startDate).
+        let x = start_date.to_number(context)?;
+
+        // 5. Let y be ? ToNumber(endDate).
+        let y = end_date.to_number(context)?;
+
+        // 6. Return ? FormatDateTimeRange(dtf, x, y).
+        let dtf = dtf.borrow();
+        let start = dtf.data.format_date_time(x, context)?;
+        let end = dtf.data.format_date_time(y, context)?;
+
+        // If both dates are practically equal, the range collapses into the formatted start date.
+        if start == end {
+            return Ok(js_string!(start).into());
+        }
+
+        Ok(js_string!(format!("{start}{RANGE_SEPARATOR}{end}")).into())
+    }
+
+    /// [`Intl.DateTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and date and time formatting
+    /// options computed during the construction of the current `Intl.DateTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.datetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DateTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let dtf be the this value.
+        // 2. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
+        //     a. Set dtf to ? UnwrapDateTimeFormat(dtf).
+        // 3. Perform ? RequireInternalSlot(dtf, [[InitializedDateTimeFormat]]).
+        let dtf = unwrap_date_time_format(this, context)?;
+        let dtf = dtf.borrow();
+        let dtf = &dtf.data;
+
+        // 4. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        // 5. For each row of Table 7, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. If there is an Internal Slot value in the current row, then
+        //         i. Let v be the value of dtf's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Else,
+        //         i. Let hc be dtf.[[HourCycle]].
+        //         ii. If hc is "h11" or "h12", let v be true.
+        //         iii. Else if, hc is "h23" or "h24", let v be false.
+        //         iv. Else, let v be undefined.
+        //     d. If v is not undefined, then
+        //         i. If there is a Conversion value in the current row, then
+        //             1. Assert: The Conversion value of the current row is number.
+        //             2. Set v to 𝔽(v).
+        //         ii. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property(
+                js_string!("locale"),
+                js_string!(dtf.locale.to_string()),
+                Attribute::all(),
+            )
+            .property(
+                js_string!("calendar"),
+                js_string!(dtf.calendar.to_string()),
+                Attribute::all(),
+            );
+
+        if let Some(nu) = &dtf.numbering_system {
+            options.property(
+                js_string!("numberingSystem"),
+                js_string!(nu.to_string()),
+                Attribute::all(),
+            );
+        }
+
+        options.property(
+            js_string!("timeZone"),
+            dtf.time_zone.identifier(),
+            Attribute::all(),
+        );
+
+        if let Some(hc) = dtf.hour_cycle {
+            options
+                .property(js_string!("hourCycle"), hc.to_js_string(), Attribute::all())
+                .property(
+                    js_string!("hour12"),
+                    matches!(hc, HourCycle::H11 | HourCycle::H12),
+                    Attribute::all(),
+                );
+        }
+
+        // The format components are only reported if no styles were provided.
+        if dtf.date_style.is_none() && dtf.time_style.is_none() {
+            let c = &dtf.components;
+            if let Some(weekday) = c.weekday {
+                options.property(
+                    js_string!("weekday"),
+                    weekday.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+            if let Some(era) = c.era {
+                options.property(js_string!("era"), era.to_js_string(), Attribute::all());
+            }
+            if let Some(year) = c.year {
+                options.property(js_string!("year"), year.to_js_string(), Attribute::all());
+            }
+            if let Some(month) = c.month {
+                options.property(js_string!("month"), month.to_js_string(), Attribute::all());
+            }
+            if let Some(day) = c.day {
+                options.property(js_string!("day"), day.to_js_string(), Attribute::all());
+            }
+            if let Some(day_period) = dtf.day_period {
+                options.property(
+                    js_string!("dayPeriod"),
+                    day_period.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+            if let Some(hour) = c.hour {
+                options.property(js_string!("hour"), hour.to_js_string(), Attribute::all());
+            }
+            if let Some(minute) = c.minute {
+                options.property(
+                    js_string!("minute"),
+                    minute.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+            if let Some(second) = c.second {
+                options.property(
+                    js_string!("second"),
+                    second.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+            if let Some(digits) = c.fractional_second {
+                options.property(
+                    js_string!("fractionalSecondDigits"),
+                    digits,
+                    Attribute::all(),
+                );
+            }
+            if let Some(time_zone_name) = dtf.time_zone_name {
+                options.property(
+                    js_string!("timeZoneName"),
+                    time_zone_name.to_js_string(),
+                    Attribute::all(),
+                );
+            }
+        }
+
+        if let Some(date_style) = dtf.date_style {
+            options.property(
+                js_string!("dateStyle"),
+                date_style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        if let Some(time_style) = dtf.time_style {
+            options.property(
+                js_string!("timeStyle"),
+                time_style.to_js_string(),
+                Attribute::all(),
+            );
+        }
+
+        // 6. Return options.
+        Ok(options.build().into())
+    }
+
+    /// Abstract operation [`ToLocalTime ( epochNs, calendar, timeZoneIdentifier )`][spec].
+    ///
+    /// Returns the ISO date and time of the time value `x` in the time zone of this formatter,
+    /// together with the time zone used to compute it.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-tolocaltime
+    fn to_local_time(
+        &self,
+        x: f64,
+        context: &Context,
+    ) -> JsResult<(DateTime<AnyCalendar>, CustomTimeZone)> {
+        let offset = self
+            .time_zone
+            .offset_seconds(x, context.host_hooks())
+            .ok_or_else(|| {
+                JsNativeError::range().with_message(format!(
+                    "the host cannot compute the offset of the time zone `{}`",
+                    self.time_zone.identifier().to_std_string_escaped()
+                ))
+            })?;
+        let t = x + f64::from(offset) * 1000.0;
+
+        let date = icu_calendar::Date::try_new_iso_date(
+            year_from_time(t),
+            month_from_time(t) + 1,
+            date_from_time(t),
+        )
+        .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+
+        // ICU4X pads the fractional seconds to the requested digits, but doesn't truncate them.
+        let mut nanoseconds = u32::from(ms_from_time(t)) * 1_000_000;
+        if let Some(digits) = self.components.fractional_second {
+            nanoseconds -= nanoseconds % 10u32.pow(9 - u32::from(digits.min(9)));
+        }
+        let time = Time::try_new(
+            hour_from_time(t),
+            min_from_time(t),
+            sec_from_time(t),
+            nanoseconds,
+        )
+        .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+        let datetime = DateTime::new(date, time);
+
+        let offset = GmtOffset::try_from_offset_seconds(offset)
+            .map_err(|e| JsNativeError::range().with_message(e.to_string()))?;
+        let mut time_zone = CustomTimeZone::new_with_offset(offset);
+
+        // The metazone of a named time zone is required to format its localized names, such as
+        // "Pacific Time".
+        if let Some(id) = self.time_zone.bcp47_id() {
+            time_zone.time_zone_id = Some(id);
+            if self.zoned_formatter.is_some() {
+                let calculator = context.intl_provider().metazone_calculator()?;
+                time_zone.maybe_calculate_metazone(calculator, &datetime);
+            }
+        }
+
+        Ok((datetime.to_any(), time_zone))
+    }
+
+    /// Abstract operation [`FormatDateTime ( dateTimeFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetime
+    fn format_date_time(&self, x: f64, context: &Context) -> JsResult<String> {
+        // PartitionDateTimePattern ( dateTimeFormat, x )
+        // https://tc39.es/ecma402/#sec-partitiondatetimepattern
+
+        // 1. Let x be TimeClip(x).
+        let x = time_clip(x);
+
+        // 2. If x is NaN, throw a RangeError exception.
+        if x.is_nan() {
+            return Err(JsNativeError::range()
+                .with_message("cannot format an invalid date")
+                .into());
+        }
+
+        // 3. Let epochNanoseconds be ℤ(ℝ(x) × 10^6).
+        // 4. Let tm be ToLocalTime(epochNanoseconds, dateTimeFormat.[[Calendar]], dateTimeFormat.[[TimeZone]]).
+        let (datetime, time_zone) = self.to_local_time(x, context)?;
+
+        // 5. Let result be FormatDateTimePattern(dateTimeFormat, patternParts, x, rangeFormatOptions).
+        if let Some(zoned) = &self.zoned_formatter {
+            zoned.format_to_string(&datetime, &time_zone)
+        } else {
+            self.formatter.format_to_string(&datetime)
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()).into())
+    }
+
+    /// Abstract operation [`FormatDateTimeToParts ( dateTimeFormat, x )`][spec].
+    ///
+    /// ICU4X doesn't annotate its formatted output with the field of each part yet, so this
+    /// resolves the pattern used by the formatter and matches its fields and literals against
+    /// the formatted date.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatdatetimetoparts
+    fn format_date_time_to_parts(
+        &self,
+        x: f64,
+        context: &Context,
+    ) -> JsResult<Vec<(&'static str, String)>> {
+        let formatted = self.format_date_time(x, context)?;
+
+        let pattern = if let Some(pattern) = self.pattern.get() {
+            pattern
+        } else {
+            let pattern = resolve_pattern(
+                context.intl_provider(),
+                &self.data_locale,
+                &self.calendar,
+                &self.format_options,
+                self.hour_cycle,
+            )?;
+            self.pattern.get_or_init(|| pattern)
+        };
+
+        // The formatted date always matches its pattern, unless ICU4X wrote a placeholder for
+        // missing data.
+        Ok(partition_pattern(&formatted, pattern).unwrap_or_else(|| vec![("literal", formatted)]))
+    }
+}
+
+/// A part of the pattern of an `Intl.DateTimeFormat`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PatternPart {
+    /// A string that is copied as is to the formatted date.
+    Literal(String),
+    /// A field of the date, with the `type` of its part in `formatToParts`.
+    Field { typ: &'static str, kind: FieldKind },
+}
+
+/// Describes the values that a field of a pattern can be formatted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldKind {
+    /// A run of digits.
+    Numeric,
+    /// A run of digits preceded by a decimal separator.
+    ///
+    /// ICU4X formats the fractional seconds together with the seconds as a single decimal number.
+    Fraction,
+    /// Any text, such as the name of a month.
+    Text,
+}
+
+impl From<Field> for PatternPart {
+    fn from(field: Field) -> Self {
+        let numeric = matches!(
+            field.length,
+            FieldLength::One | FieldLength::TwoDigit | FieldLength::NumericOverride(_)
+        );
+
+        let (typ, kind) = match field.symbol {
+            FieldSymbol::Era => ("era", FieldKind::Text),
+            FieldSymbol::Year(fields::Year::Cyclic) => ("yearName", FieldKind::Text),
+            FieldSymbol::Year(fields::Year::RelatedIso) => ("relatedYear", FieldKind::Numeric),
+            FieldSymbol::Year(_) => ("year", FieldKind::Numeric),
+            FieldSymbol::Month(_) if numeric => ("month", FieldKind::Numeric),
+            FieldSymbol::Month(_) => ("month", FieldKind::Text),
+            FieldSymbol::Day(_) => ("day", FieldKind::Numeric),
+            FieldSymbol::Weekday(fields::Weekday::Format) => ("weekday", FieldKind::Text),
+            FieldSymbol::Weekday(_) if numeric => ("weekday", FieldKind::Numeric),
+            FieldSymbol::Weekday(_) => ("weekday", FieldKind::Text),
+            FieldSymbol::DayPeriod(_) => ("dayPeriod", FieldKind::Text),
+            FieldSymbol::Hour(_) => ("hour", FieldKind::Numeric),
+            FieldSymbol::Minute => ("minute", FieldKind::Numeric),
+            FieldSymbol::Second(fields::Second::FractionalSecond) => {
+                ("fractionalSecond", FieldKind::Fraction)
+            }
+            FieldSymbol::Second(_) => ("second", FieldKind::Numeric),
+            FieldSymbol::TimeZone(_) => ("timeZoneName", FieldKind::Text),
+            // Week numbers don't have a part type, and are never requested by `Intl.DateTimeFormat`.
+            FieldSymbol::Week(_) => ("literal", FieldKind::Numeric),
+        };
+
+        Self::Field { typ, kind }
+    }
+}
+
+/// Splits a pattern into its parts, joining consecutive literal characters.
+fn pattern_parts(pattern: &Pattern<'_>) -> Vec<PatternPart> {
+    let mut parts = Vec::new();
+    for item in pattern.items.iter() {
+        match item {
+            PatternItem::Literal(ch) => {
+                if let Some(PatternPart::Literal(literal)) = parts.last_mut() {
+                    literal.push(ch);
+                } else {
+                    parts.push(PatternPart::Literal(ch.to_string()));
+                }
+            }
+            PatternItem::Field(field) => parts.push(field.into()),
+        }
+    }
+    parts
+}
+
+/// Resolves the pattern that the formatters of an `Intl.DateTimeFormat` use to format dates.
+///
+/// ICU4X doesn't expose the pattern of its formatters, so this replicates the pattern selection
+/// of `DateTimeFormatter`: the length patterns of the calendar for the style options, and the
+/// skeleton matching of ICU4X for the format components.
+fn resolve_pattern(
+    provider: &IntlProvider,
+    locale: &DataLocale,
+    calendar: &Value,
+    options: &DateTimeFormatterOptions,
+    hour_cycle: Option<HourCycle>,
+) -> JsResult<Vec<PatternPart>> {
+    let to_js_error = |e: &dyn std::fmt::Display| JsNativeError::typ().with_message(e.to_string());
+    let request = DataRequest {
+        locale,
+        metadata: DataRequestMetadata::default(),
+    };
+
+    let kind = AnyCalendarKind::get_for_bcp47_value(calendar).unwrap_or(AnyCalendarKind::Gregorian);
+    let date_lengths = load_date_lengths(provider, locale, kind).map_err(|e| to_js_error(&e))?;
+    let date_lengths = date_lengths.get();
+
+    let parts = match options {
+        DateTimeFormatterOptions::Length(bag) => {
+            let date = bag.date.map(|length| {
+                let patterns = &date_lengths.date;
+                match length {
+                    length::Date::Full => patterns.full.clone(),
+                    length::Date::Long => patterns.long.clone(),
+                    length::Date::Medium => patterns.medium.clone(),
+                    _ => patterns.short.clone(),
+                }
+            });
+
+            let time_lengths = bag
+                .time
+                .map(|_| {
+                    DataProvider::<TimeLengthsV1Marker>::load(provider, request)
+                        .and_then(DataResponse::take_payload)
+                })
+                .transpose()
+                .map_err(|e| to_js_error(&e))?;
+            let time = time_lengths
+                .as_ref()
+                .zip(bag.time)
+                .map(|(lengths, length)| {
+                    let lengths = lengths.get();
+                    let twelve_hour = match hour_cycle {
+                        Some(hc) => matches!(hc, HourCycle::H11 | HourCycle::H12),
+                        None => lengths.preferred_hour_cycle == CoarseHourCycle::H11H12,
+                    };
+                    let patterns = if twelve_hour {
+                        &lengths.time_h11_h12
+                    } else {
+                        &lengths.time_h23_h24
+                    };
+                    match length {
+                        length::Time::Full => patterns.full.clone(),
+                        length::Time::Long => patterns.long.clone(),
+                        length::Time::Medium => patterns.medium.clone(),
+                        _ => patterns.short.clone(),
+                    }
+                });
+
+            match (date, time) {
+                (Some(date), Some(time)) => {
+                    let combinations = &date_lengths.length_combinations;
+                    let glue = match bag.date {
+                        Some(length::Date::Full) => &combinations.full,
+                        Some(length::Date::Long) => &combinations.long,
+                        Some(length::Date::Medium) => &combinations.medium,
+                        _ => &combinations.short,
+                    };
+                    let pattern = glue
+                        .clone()
+                        .combined(date, time)
+                        .map_err(|e| to_js_error(&e))?;
+                    pattern_parts(&pattern)
+                }
+                (Some(pattern), None) | (None, Some(pattern)) => pattern_parts(&pattern),
+                (None, None) => Vec::new(),
+            }
+        }
+        DateTimeFormatterOptions::Components(bag) => {
+            // The skeletons of the Ethiopian and Islamic calendars are shared by all their variants.
+            let skeletons_calendar = match kind {
+                AnyCalendarKind::EthiopianAmeteAlem => value!("ethiopic"),
+                AnyCalendarKind::IslamicCivil
+                | AnyCalendarKind::IslamicObservational
+                | AnyCalendarKind::IslamicTabular
+                | AnyCalendarKind::IslamicUmmAlQura => value!("islamic"),
+                _ => kind.as_bcp47_value(),
+            };
+            let mut skeletons_locale = locale.clone();
+            skeletons_locale.set_unicode_ext(key!("ca"), skeletons_calendar);
+            let skeletons = DataProvider::<DateSkeletonPatternsV1Marker>::load(
+                provider,
+                DataRequest {
+                    locale: &skeletons_locale,
+                    metadata: DataRequestMetadata::default(),
+                },
+            )
+            .and_then(DataResponse::take_payload)
+            .map_err(|e| to_js_error(&e))?;
+
+            match skeleton::create_best_pattern_for_fields(
+                skeletons.get(),
+                &date_lengths.length_combinations,
+                &skeleton_fields(bag),
+                bag,
+                false,
+            ) {
+                BestSkeleton::AllFieldsMatch(patterns)
+                | BestSkeleton::MissingOrExtraFields(patterns) => {
+                    // Only the week-of-year patterns, which are never requested, have plural
+                    // variants.
+                    patterns
+                        .patterns_iter()
+                        .next()
+                        .map(pattern_parts)
+                        .unwrap_or_default()
+                }
+                BestSkeleton::NoMatch => {
+                    return Err(JsNativeError::typ()
+                        .with_message("no pattern matches the provided format options")
+                        .into())
+                }
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    Ok(parts)
+}
+
+/// Loads the date length patterns of the calendar `kind`.
+///
+/// All calendars share the same data struct, so the payload is cast to the marker of the
+/// Gregorian calendar.
+fn load_date_lengths(
+    provider: &IntlProvider,
+    locale: &DataLocale,
+    kind: AnyCalendarKind,
+) -> Result<DataPayload<GregorianDateLengthsV1Marker>, DataError> {
+    let request = DataRequest {
+        locale,
+        metadata: DataRequestMetadata::default(),
+    };
+
+    macro_rules! load {
+        ($marker:ty) => {
+            DataProvider::<$marker>::load(provider, request)?
+                .take_payload()?
+                .cast()
+        };
+    }
+
+    Ok(match kind {
+        AnyCalendarKind::Buddhist => load!(BuddhistDateLengthsV1Marker),
+        AnyCalendarKind::Chinese => load!(ChineseDateLengthsV1Marker),
+        AnyCalendarKind::Coptic => load!(CopticDateLengthsV1Marker),
+        AnyCalendarKind::Dangi => load!(DangiDateLengthsV1Marker),
+        AnyCalendarKind::Ethiopian | AnyCalendarKind::EthiopianAmeteAlem => {
+            load!(EthiopianDateLengthsV1Marker)
+        }
+        AnyCalendarKind::Hebrew => load!(HebrewDateLengthsV1Marker),
+        AnyCalendarKind::Indian => load!(IndianDateLengthsV1Marker),
+        AnyCalendarKind::IslamicCivil
+        | AnyCalendarKind::IslamicObservational
+        | AnyCalendarKind::IslamicTabular
+        | AnyCalendarKind::IslamicUmmAlQura => load!(IslamicDateLengthsV1Marker),
+        AnyCalendarKind::Japanese => load!(JapaneseDateLengthsV1Marker),
+        AnyCalendarKind::JapaneseExtended => load!(JapaneseExtendedDateLengthsV1Marker),
+        AnyCalendarKind::Persian => load!(PersianDateLengthsV1Marker),
+        AnyCalendarKind::Roc => load!(RocDateLengthsV1Marker),
+        _ => {
+            DataProvider::<GregorianDateLengthsV1Marker>::load(provider, request)?.take_payload()?
+        }
+    })
+}
+
+/// Gets the fields of the skeleton of the components `bag`, sorted from the most significant
+/// field to the least significant one.
+///
+/// This is the skeleton that `DateTimeFormatter` matches against the skeletons of the locale.
+fn skeleton_fields(bag: &components::Bag) -> Vec<Field> {
+    let field = |symbol, length| Field { symbol, length };
+    let numeric = |n: Numeric| {
+        if n == Numeric::TwoDigit {
+            FieldLength::TwoDigit
+        } else {
+            FieldLength::One
+        }
+    };
+    let text = |t: Text| match t {
+        Text::Long => FieldLength::Wide,
+        Text::Narrow => FieldLength::Narrow,
+        _ => FieldLength::Abbreviated,
+    };
+
+    let mut fields = Vec::new();
+
+    if let Some(era) = bag.era {
+        fields.push(field(FieldSymbol::Era, text(era)));
+    }
+
+    if let Some(year) = bag.year {
+        let length = if year == components::Year::TwoDigit {
+            FieldLength::TwoDigit
+        } else {
+            FieldLength::One
+        };
+        fields.push(field(FieldSymbol::Year(fields::Year::Calendar), length));
+    }
+
+    if let Some(month) = bag.month {
+        let length = match month {
+            components::Month::Numeric => FieldLength::One,
+            components::Month::TwoDigit => FieldLength::TwoDigit,
+            components::Month::Long => FieldLength::Wide,
+            components::Month::Narrow => FieldLength::Narrow,
+            _ => FieldLength::Abbreviated,
+        };
+        fields.push(field(FieldSymbol::Month(fields::Month::Format), length));
+    }
+
+    if let Some(day) = bag.day {
+        let length = if day == components::Day::TwoDigitDayOfMonth {
+            FieldLength::TwoDigit
+        } else {
+            FieldLength::One
+        };
+        fields.push(field(FieldSymbol::Day(fields::Day::DayOfMonth), length));
+    }
+
+    if let Some(weekday) = bag.weekday {
+        // Short weekdays are `E` in skeletons.
+        let length = match weekday {
+            Text::Long => FieldLength::Wide,
+            Text::Narrow => FieldLength::Narrow,
+            _ => FieldLength::One,
+        };
+        fields.push(field(FieldSymbol::Weekday(fields::Weekday::Format), length));
+    }
+
+    if let Some(hour) = bag.hour {
+        // Skeletons only use the `h` and `H` hour symbols.
+        let symbol = match bag.preferences.and_then(|p| p.hour_cycle) {
+            Some(HourCycle::H11 | HourCycle::H12) => fields::Hour::H12,
+            _ => fields::Hour::H23,
+        };
+        fields.push(field(FieldSymbol::Hour(symbol), numeric(hour)));
+    }
+
+    if let Some(minute) = bag.minute {
+        fields.push(field(FieldSymbol::Minute, numeric(minute)));
+    }
+
+    if let Some(second) = bag.second {
+        fields.push(field(
+            FieldSymbol::Second(fields::Second::Second),
+            numeric(second),
+        ));
+    }
+
+    if let Some(digits) = bag.fractional_second {
+        fields.push(field(
+            FieldSymbol::Second(fields::Second::FractionalSecond),
+            FieldLength::Fixed(digits),
+        ));
+    }
+
+    if bag.time_zone_name.is_some() {
+        // Only the `v` time zone symbol is used in skeletons.
+        fields.push(field(
+            FieldSymbol::TimeZone(fields::TimeZone::LowerV),
+            FieldLength::One,
+        ));
+    }
+
+    fields
+}
+
+/// Splits the formatted date `formatted` into the parts of its `pattern`.
+///
+/// Literals must match exactly, numeric fields match a whole run of digits, and text fields
+/// match the shortest string that lets the rest of the pattern match. Returns `None` if
+/// `formatted` doesn't match the pattern.
+fn partition_pattern(
+    formatted: &str,
+    pattern: &[PatternPart],
+) -> Option<Vec<(&'static str, String)>> {
+    // The parts are collected in reverse order while unwinding the matches.
+    let mut parts = match_parts(formatted, pattern)?;
+    parts.reverse();
+    Some(parts)
+}
+
+/// Matches `s` against `pattern`, returning its parts in reverse order.
+fn match_parts(s: &str, pattern: &[PatternPart]) -> Option<Vec<(&'static str, String)>> {
+    let Some((first, rest)) = pattern.split_first() else {
+        return s.is_empty().then(Vec::new);
+    };
+
+    let digits = |s: &str| s.find(|c: char| !c.is_numeric()).unwrap_or(s.len());
+
+    match *first {
+        PatternPart::Literal(ref literal) => {
+            let mut parts = match_parts(s.strip_prefix(literal.as_str())?, rest)?;
+            parts.push(("literal", literal.clone()));
+            Some(parts)
+        }
+        PatternPart::Field {
+            typ,
+            kind: FieldKind::Numeric,
+        } => {
+            let end = digits(s);
+            if end == 0 {
+                return None;
+            }
+            let mut parts = match_parts(&s[end..], rest)?;
+            parts.push((typ, s[..end].to_owned()));
+            Some(parts)
+        }
+        PatternPart::Field {
+            typ,
+            kind: FieldKind::Fraction,
+        } => {
+            let start = s.find(char::is_numeric)?;
+            let end = start + digits(&s[start..]);
+            let mut parts = match_parts(&s[end..], rest)?;
+            parts.push((typ, s[start..end].to_owned()));
+            if start > 0 {
+                parts.push(("literal", s[..start].to_owned()));
+            }
+            Some(parts)
+        }
+        PatternPart::Field {
+            typ,
+            kind: FieldKind::Text,
+        } => s
+            .char_indices()
+            .map(|(i, _)| i)
+            .skip(1)
+            .chain(std::iter::once(s.len()))
+            .filter(|&end| end > 0)
+            .find_map(|end| {
+                let mut parts = match_parts(&s[end..], rest)?;
+                parts.push((typ, s[..end].to_owned()));
+                Some(parts)
+            }),
+    }
+}
+
+/// Creates an array of `{ type, value }` objects from a list of parts.
+fn parts_to_array(parts: Vec<(&'static str, String)>, context: &mut Context) -> JsObject {
+    // 1. Let parts be ? PartitionDateTimePattern(dateTimeFormat, x).
+    // 2. Let result be ! ArrayCreate(0).
+    let result = Array::array_create(0, None, context)
+        .expect("creating an empty array with default proto must not fail");
+
+    // 3. Let n be 0.
+    // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+    for (n, (typ, value)) in parts.into_iter().enumerate() {
+        // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+        let o = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+        o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+            .expect("operation must not fail per the spec");
+
+        // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+        o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+            .expect("operation must not fail per the spec");
+
+        // d. Perform ! CreateDataProperty(result, ! ToString(𝔽(n)), O).
+        result
+            .create_data_property_or_throw(n, o, context)
+            .expect("operation must not fail per the spec");
+
+        // e. Increment n by 1.
+    }
+
+    // 5. Return result.
+    result
+}
+
+/// Converts the `date` argument of the formatting methods to a time value, defaulting to the
+/// current time if it is undefined.
+fn date_to_time_value(date: &JsValue, context: &mut Context) -> JsResult<f64> {
+    if date.is_undefined() {
+        // a. Let x be ! Call(%Date.now%, undefined).
+        #[allow(clippy::cast_precision_loss)]
+        return Ok(context.host_hooks().utc_now() as f64);
+    }
+
+    // a. Let x be ? ToNumber(date).
+    date.to_number(context)
+}
+
+/// Abstract operation [`UnwrapDateTimeFormat ( dtf )`][spec].
+///
+/// This also checks that the returned object is a `DateTimeFormat`, which skips the
+/// call to `RequireInternalSlot`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-unwrapdatetimeformat
+fn unwrap_date_time_format(
+    dtf: &JsValue,
+    context: &mut Context,
+) -> JsResult<JsObject<DateTimeFormat>> {
+    // 1. If Type(dtf) is not Object, throw a TypeError exception.
+    let dtf_o = dtf.as_object().ok_or_else(|| {
+        JsNativeError::typ().with_message("value was not an `Intl.DateTimeFormat` object")
+    })?;
+
+    if let Ok(dtf) = dtf_o.clone().downcast::<DateTimeFormat>() {
+        // 3. Return dtf.
+        return Ok(dtf);
+    }
+
+    // 2. If dtf does not have an [[InitializedDateTimeFormat]] internal slot and ? OrdinaryHasInstance(%Intl.DateTimeFormat%, dtf)
+    //    is true, then
+    let constructor = context
+        .intrinsics()
+        .constructors()
+        .date_time_format()
+        .constructor();
+    if JsValue::ordinary_has_instance(&constructor.into(), dtf, context)? {
+        let fallback_symbol = context
+            .intrinsics()
+            .objects()
+            .intl()
+            .borrow()
+            .data
+            .fallback_symbol();
+
+        //    a. Return ? Get(dtf, %Intl%.[[FallbackSymbol]]).
+        let dtf = dtf_o.get(fallback_symbol, context)?;
+        if let Some(dtf) = dtf.as_object() {
+            if let Ok(dtf) = dtf.clone().downcast::<DateTimeFormat>() {
+                return Ok(dtf);
+            }
+        }
+    }
+
+    Err(JsNativeError::typ()
+        .with_message("object was not an `Intl.DateTimeFormat` object")
+        .into())
+}
+
+/// Represents the `required` and `defaults` arguments in the abstract operation
+/// `toDateTimeOptions`.
+///
+/// Since `required` and `defaults` differ only in the `any` and `all` variants,
+/// we combine both in a single variant `AnyAll`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum DateTimeReqs {
+    Date,
+    Time,
+    AnyAll,
+}
+
+/// The abstract operation `toDateTimeOptions` is called with arguments `options`, `required` and
+/// `defaults`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-todatetimeoptions
+pub(crate) fn to_date_time_options(
+    options: &JsValue,
+    required: &DateTimeReqs,
+    defaults: &DateTimeReqs,
+    context: &mut Context,
+) -> JsResult<JsObject> {
+    // 1. If options is undefined, let options be null;
+    // otherwise let options be ? ToObject(options).
+    // 2. Let options be ! OrdinaryObjectCreate(options).
+    let options = if options.is_undefined() {
+        None
+    } else {
+        Some(options.to_object(context)?)
+    };
+    let options = JsObject::from_proto_and_data_with_shared_shape(
+        context.root_shape(),
+        options,
+        OrdinaryObject,
+    );
+
+    // 3. Let needDefaults be true.
+    let mut need_defaults = true;
+
+    // 4. If required is "date" or "any", then
+    if [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "weekday", "year", "month", "day" », do
+        for property in [
+            js_string!("weekday"),
+            js_string!("year"),
+            js_string!("month"),
+            js_string!("day"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 5. If required is "time" or "any", then
+    if [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(required) {
+        // a. For each property name prop of « "dayPeriod", "hour", "minute", "second",
+        // "fractionalSecondDigits" », do
+        for property in [
+            js_string!("dayPeriod"),
+            js_string!("hour"),
+            js_string!("minute"),
+            js_string!("second"),
+            js_string!("fractionalSecondDigits"),
+        ] {
+            // i. Let value be ? Get(options, prop).
+            let value = options.get(property, context)?;
+
+            // ii. If value is not undefined, let needDefaults be false.
+            if !value.is_undefined() {
+                need_defaults = false;
+            }
+        }
+    }
+
+    // 6. Let dateStyle be ? Get(options, "dateStyle").
+    let date_style = options.get(js_string!("dateStyle"), context)?;
+
+    // 7. Let timeStyle be ? Get(options, "timeStyle").
+    let time_style = options.get(js_string!("timeStyle"), context)?;
+
+    // 8. If dateStyle is not undefined or timeStyle is not undefined, let needDefaults be false.
+    if !date_style.is_undefined() || !time_style.is_undefined() {
+        need_defaults = false;
+    }
+
+    // 9. If required is "date" and timeStyle is not undefined, then
+    if required == &DateTimeReqs::Date && !time_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'date' is required, but timeStyle was defined")
+            .into());
+    }
+
+    // 10. If required is "time" and dateStyle is not undefined, then
+    if required == &DateTimeReqs::Time && !date_style.is_undefined() {
+        // a. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("'time' is required, but dateStyle was defined")
+            .into());
+    }
+
+    // 11. If needDefaults is true and defaults is either "date" or "all", then
+    if need_defaults && [DateTimeReqs::Date, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "year", "month", "day" », do
+        for property in [js_string!("year"), js_string!("month"), js_string!("day")] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, js_string!("numeric"), context)?;
+        }
+    }
+
+    // 12. If needDefaults is true and defaults is either "time" or "all", then
+    if need_defaults && [DateTimeReqs::Time, DateTimeReqs::AnyAll].contains(defaults) {
+        // a. For each property name prop of « "hour", "minute", "second" », do
+        for property in [
+            js_string!("hour"),
+            js_string!("minute"),
+            js_string!("second"),
+        ] {
+            // i. Perform ? CreateDataPropertyOrThrow(options, prop, "numeric").
+            options.create_data_property_or_throw(property, js_string!("numeric"), context)?;
+        }
+    }
+
+    // 13. Return options.
+    Ok(options)
+}
@@ -0,0 +1,541 @@
+use std::{fmt::Write, str::FromStr};
+
+use icu_datetime::options::{
+    components::{self, Day, Month, Numeric, Text, Year},
+    length,
+    preferences::HourCycle,
+};
+use icu_locid::{extensions::unicode::Value, extensions_unicode_value as value};
+use icu_timezone::TimeZoneBcp47Id;
+
+use crate::{
+    builtins::options::{OptionType, ParsableOptionType},
+    context::{icu::IntlProvider, HostHooks},
+    js_string, Context, JsNativeError, JsResult, JsString, JsValue,
+};
+
+impl OptionType for HourCycle {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "h11" => Ok(Self::H11),
+            "h12" => Ok(Self::H12),
+            "h23" => Ok(Self::H23),
+            "h24" => Ok(Self::H24),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `h11`, `h12`, `h23` or `h24`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Text {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `narrow`, `short` or `long`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Year {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `numeric` or `2-digit`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Month {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            "narrow" => Ok(Self::Narrow),
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            _ => Err(JsNativeError::range()
+                .with_message(
+                    "provided string was not `numeric`, `2-digit`, `narrow`, `short` or `long`",
+                )
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Day {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::NumericDayOfMonth),
+            "2-digit" => Ok(Self::TwoDigitDayOfMonth),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `numeric` or `2-digit`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for Numeric {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `numeric` or `2-digit`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for length::Date {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `full`, `long`, `medium` or `short`")
+                .into()),
+        }
+    }
+}
+
+impl OptionType for length::Time {
+    fn from_value(value: JsValue, context: &mut Context) -> JsResult<Self> {
+        match value.to_string(context)?.to_std_string_escaped().as_str() {
+            "full" => Ok(Self::Full),
+            "long" => Ok(Self::Long),
+            "medium" => Ok(Self::Medium),
+            "short" => Ok(Self::Short),
+            _ => Err(JsNativeError::range()
+                .with_message("provided string was not `full`, `long`, `medium` or `short`")
+                .into()),
+        }
+    }
+}
+
+/// Converts a resolved ICU4X formatting option back into the string value reported by
+/// `Intl.DateTimeFormat.prototype.resolvedOptions`.
+pub(super) trait ToJsString {
+    fn to_js_string(&self) -> JsString;
+}
+
+impl ToJsString for HourCycle {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::H11 => js_string!("h11"),
+            Self::H12 => js_string!("h12"),
+            Self::H23 => js_string!("h23"),
+            Self::H24 => js_string!("h24"),
+        }
+    }
+}
+
+/// Gets the hour cycle represented by the value of an `hc` Unicode extension keyword.
+pub(super) fn hour_cycle_from_value(value: &Value) -> Option<HourCycle> {
+    match &**value.as_single_subtag()? {
+        "h11" => Some(HourCycle::H11),
+        "h12" => Some(HourCycle::H12),
+        "h23" => Some(HourCycle::H23),
+        "h24" => Some(HourCycle::H24),
+        _ => None,
+    }
+}
+
+/// Gets the value of the `hc` Unicode extension keyword representing an hour cycle.
+pub(super) const fn hour_cycle_to_value(hour_cycle: HourCycle) -> Value {
+    match hour_cycle {
+        HourCycle::H11 => value!("h11"),
+        HourCycle::H12 => value!("h12"),
+        HourCycle::H23 => value!("h23"),
+        HourCycle::H24 => value!("h24"),
+    }
+}
+
+impl ToJsString for Text {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            _ => js_string!("long"),
+        }
+    }
+}
+
+impl ToJsString for Year {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::TwoDigit | Self::TwoDigitWeekOf => js_string!("2-digit"),
+            _ => js_string!("numeric"),
+        }
+    }
+}
+
+impl ToJsString for Month {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::Numeric => js_string!("numeric"),
+            Self::TwoDigit => js_string!("2-digit"),
+            Self::Narrow => js_string!("narrow"),
+            Self::Short => js_string!("short"),
+            _ => js_string!("long"),
+        }
+    }
+}
+
+impl ToJsString for Day {
+    fn to_js_string(&self) -> JsString {
+        if *self == Self::TwoDigitDayOfMonth {
+            js_string!("2-digit")
+        } else {
+            js_string!("numeric")
+        }
+    }
+}
+
+impl ToJsString for Numeric {
+    fn to_js_string(&self) -> JsString {
+        if *self == Self::TwoDigit {
+            js_string!("2-digit")
+        } else {
+            js_string!("numeric")
+        }
+    }
+}
+
+impl ToJsString for length::Date {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::Full => js_string!("full"),
+            Self::Long => js_string!("long"),
+            Self::Medium => js_string!("medium"),
+            _ => js_string!("short"),
+        }
+    }
+}
+
+impl ToJsString for length::Time {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::Full => js_string!("full"),
+            Self::Long => js_string!("long"),
+            Self::Medium => js_string!("medium"),
+            _ => js_string!("short"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TimeZoneName {
+    Short,
+    Long,
+    ShortOffset,
+    LongOffset,
+    ShortGeneric,
+    LongGeneric,
+}
+
+impl TimeZoneName {
+    /// Converts the time zone name option to the closest ICU4X component.
+    ///
+    /// ICU4X uses the preferred localized GMT format of the locale for both offset styles.
+    pub(crate) const fn to_icu(self) -> components::TimeZoneName {
+        match self {
+            Self::Short => components::TimeZoneName::ShortSpecific,
+            Self::Long => components::TimeZoneName::LongSpecific,
+            Self::ShortOffset | Self::LongOffset => components::TimeZoneName::GmtOffset,
+            Self::ShortGeneric => components::TimeZoneName::ShortGeneric,
+            Self::LongGeneric => components::TimeZoneName::LongGeneric,
+        }
+    }
+}
+
+impl ToJsString for TimeZoneName {
+    fn to_js_string(&self) -> JsString {
+        match self {
+            Self::Short => js_string!("short"),
+            Self::Long => js_string!("long"),
+            Self::ShortOffset => js_string!("shortOffset"),
+            Self::LongOffset => js_string!("longOffset"),
+            Self::ShortGeneric => js_string!("shortGeneric"),
+            Self::LongGeneric => js_string!("longGeneric"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseTimeZoneNameError;
+
+impl std::fmt::Display for ParseTimeZoneNameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(
+            "provided string was not `short`, `long`, `shortOffset`, `longOffset`, \
+            `shortGeneric` or `longGeneric`",
+        )
+    }
+}
+
+impl FromStr for TimeZoneName {
+    type Err = ParseTimeZoneNameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "short" => Ok(Self::Short),
+            "long" => Ok(Self::Long),
+            "shortOffset" => Ok(Self::ShortOffset),
+            "longOffset" => Ok(Self::LongOffset),
+            "shortGeneric" => Ok(Self::ShortGeneric),
+            "longGeneric" => Ok(Self::LongGeneric),
+            _ => Err(ParseTimeZoneNameError),
+        }
+    }
+}
+
+impl ParsableOptionType for TimeZoneName {}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum FormatMatcher {
+    Basic,
+    #[default]
+    BestFit,
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseFormatMatcherError;
+
+impl std::fmt::Display for ParseFormatMatcherError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `basic` or `best fit`")
+    }
+}
+
+impl FromStr for FormatMatcher {
+    type Err = ParseFormatMatcherError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "basic" => Ok(Self::Basic),
+            "best fit" => Ok(Self::BestFit),
+            _ => Err(ParseFormatMatcherError),
+        }
+    }
+}
+
+impl ParsableOptionType for FormatMatcher {}
+
+/// The time zone used by an `Intl.DateTimeFormat` to compute the local time of a date.
+///
+/// Named time zones are validated and canonicalized with the time zone data of ICU4X, but their
+/// offsets from UTC come from [`HostHooks::time_zone_offset_seconds`], which only knows the IANA
+/// time zone database with the `intl_bundled` feature. The identifiers equivalent to UTC, the fixed `Etc/GMT±N` zones,
+/// UTC offset strings such as `"+05:30"` and the host's own time zone are always supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TimeZone {
+    identifier: JsString,
+    /// The BCP-47 identifier of a named time zone, used to find its metazone.
+    bcp47_id: Option<TimeZoneBcp47Id>,
+    offset: TimeZoneOffset,
+}
+
+/// Describes where the offset from UTC of a [`TimeZone`] comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeZoneOffset {
+    /// A fixed offset from UTC in seconds.
+    Fixed(i32),
+    /// The offset of the host's local time zone.
+    Local,
+    /// The offset of the named time zone, as reported by the host.
+    Named,
+}
+
+impl TimeZone {
+    /// Gets the time zone of the host, as defined by [`SystemTimeZoneIdentifier ( )`][spec].
+    ///
+    /// Since hosts only report offsets from UTC, the identifier is the offset of the host at the
+    /// current time, or `"UTC"` if the host is in UTC.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-systemtimezoneidentifier
+    pub(crate) fn system(hooks: &dyn HostHooks) -> Self {
+        let now = hooks.utc_now() / 1000;
+        let offset = hooks.local_timezone_offset_seconds(now);
+        let identifier = if offset == 0 {
+            js_string!("UTC")
+        } else {
+            format_offset(offset / 60)
+        };
+
+        Self {
+            identifier,
+            bcp47_id: None,
+            offset: TimeZoneOffset::Local,
+        }
+    }
+
+    /// Parses and canonicalizes a time zone identifier.
+    ///
+    /// This combines the abstract operations [`IsTimeZoneOffsetString`][offset],
+    /// [`IsValidTimeZoneName`][valid] and [`CanonicalizeTimeZoneName`][canonical].
+    ///
+    /// # Errors
+    ///
+    /// Throws a `RangeError` if the identifier is invalid, or if the host cannot compute the
+    /// offsets of the time zone.
+    ///
+    /// [offset]: https://tc39.es/ecma402/#sec-istimezoneoffsetstring
+    /// [valid]: https://tc39.es/ecma402/#sec-isvalidtimezonename
+    /// [canonical]: https://tc39.es/ecma402/#sec-canonicalizetimezonename
+    pub(crate) fn from_identifier(
+        identifier: &str,
+        provider: &IntlProvider,
+        hooks: &dyn HostHooks,
+    ) -> JsResult<Self> {
+        if let Some(time_zone) = Self::from_fixed_identifier(identifier) {
+            return Ok(time_zone);
+        }
+
+        // `Etc/Unknown` is mapped to the placeholder `unk` zone, which is not a valid time zone.
+        let mapper = provider.time_zone_id_mapper()?;
+        let Some((name, bcp47_id)) = mapper
+            .as_borrowed()
+            .canonicalize_iana(identifier)
+            .filter(|(_, id)| id.0.as_str() != "unk")
+        else {
+            return Err(JsNativeError::range()
+                .with_message(format!("invalid time zone `{identifier}`"))
+                .into());
+        };
+
+        if hooks
+            .time_zone_offset_seconds(&name, hooks.utc_now() / 1000)
+            .is_none()
+        {
+            return Err(JsNativeError::range()
+                .with_message(format!("the host doesn't support the time zone `{name}`"))
+                .into());
+        }
+
+        Ok(Self {
+            identifier: js_string!(&*name),
+            bcp47_id: Some(bcp47_id),
+            offset: TimeZoneOffset::Named,
+        })
+    }
+
+    /// Parses and canonicalizes the identifiers of the time zones with a fixed offset from UTC,
+    /// returning `None` if `identifier` is not one of them.
+    pub(crate) fn from_fixed_identifier(identifier: &str) -> Option<Self> {
+        let fixed = |identifier, offset| Self {
+            identifier,
+            bcp47_id: None,
+            offset: TimeZoneOffset::Fixed(offset),
+        };
+
+        if let Some(minutes) = parse_offset(identifier) {
+            return Some(fixed(format_offset(minutes), minutes * 60));
+        }
+
+        let lowercase = identifier.to_ascii_lowercase();
+        let name = lowercase.strip_prefix("etc/").unwrap_or(&lowercase);
+
+        if matches!(
+            name,
+            "utc" | "uct" | "gmt" | "gmt0" | "gmt+0" | "gmt-0" | "greenwich" | "universal" | "zulu"
+        ) {
+            return Some(fixed(js_string!("UTC"), 0));
+        }
+
+        // `Etc/GMT+N` zones have inverted signs, e.g. `Etc/GMT+5` is UTC-05:00.
+        let (sign, hours) = if let Some(hours) = lowercase.strip_prefix("etc/gmt+") {
+            (-1, hours)
+        } else {
+            (1, lowercase.strip_prefix("etc/gmt-")?)
+        };
+        if hours.starts_with('0') {
+            return None;
+        }
+        let hours = hours.parse::<i32>().ok()?;
+        if hours > if sign < 0 { 12 } else { 14 } {
+            return None;
+        }
+
+        Some(fixed(
+            js_string!(format!(
+                "Etc/GMT{}{hours}",
+                if sign < 0 { '+' } else { '-' }
+            )),
+            sign * hours * 3600,
+        ))
+    }
+
+    /// Gets the canonical identifier of this time zone.
+    pub(crate) fn identifier(&self) -> JsString {
+        self.identifier.clone()
+    }
+
+    /// Gets the BCP-47 identifier of this time zone, if it is a named time zone.
+    pub(crate) fn bcp47_id(&self) -> Option<TimeZoneBcp47Id> {
+        self.bcp47_id
+    }
+
+    /// Gets the offset from UTC in seconds of this time zone at the time value `t`, or `None` if
+    /// the host cannot compute it.
+    pub(crate) fn offset_seconds(&self, t: f64, hooks: &dyn HostHooks) -> Option<i32> {
+        #[allow(clippy::cast_possible_truncation)]
+        let seconds = (t / 1000.0).floor() as i64;
+        match self.offset {
+            TimeZoneOffset::Fixed(offset) => Some(offset),
+            TimeZoneOffset::Local => Some(hooks.local_timezone_offset_seconds(seconds)),
+            TimeZoneOffset::Named => {
+                hooks.time_zone_offset_seconds(&self.identifier.to_std_string_escaped(), seconds)
+            }
+        }
+    }
+}
+
+/// Parses a UTC offset string of the form `±HH`, `±HHMM` or `±HH:MM`, returning the offset in
+/// minutes.
+fn parse_offset(offset: &str) -> Option<i32> {
+    let (sign, rest) = match offset.as_bytes().first()? {
+        b'+' => (1, &offset[1..]),
+        b'-' => (-1, &offset[1..]),
+        _ => return None,
+    };
+
+    let digits = |s: &str| -> Option<i32> {
+        if s.len() != 2 || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        s.parse().ok()
+    };
+
+    let (hours, minutes) = match rest.len() {
+        2 => (digits(rest)?, 0),
+        4 => (digits(&rest[..2])?, digits(&rest[2..])?),
+        5 if rest.as_bytes()[2] == b':' => (digits(&rest[..2])?, digits(&rest[3..])?),
+        _ => return None,
+    };
+
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    Some(sign * (hours * 60 + minutes))
+}
+
+/// Formats an offset in minutes as a string of the form `±HH:MM`.
+fn format_offset(minutes: i32) -> JsString {
+    let mut result = String::with_capacity(6);
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.abs();
+    write!(result, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+        .expect("writing to a `String` cannot fail");
+    js_string!(result)
+}
@@ -0,0 +1,266 @@
+use std::str::FromStr;
+
+use crate::builtins::intl::date_time_format::{
+    partition_pattern, pattern_parts, FieldKind, PatternPart, TimeZone,
+};
+#[cfg(feature = "intl_bundled")]
+use crate::{
+    context::{ContextBuilder, HostHooks},
+    js_str, run_test_actions, run_test_actions_with, JsNativeErrorKind, TestAction,
+};
+use icu_datetime::pattern::runtime::Pattern;
+
+#[test]
+fn time_zone_from_fixed_identifier_sunny_day() {
+    let valid_cases = [
+        ("UTC", "UTC"),
+        ("etc/utc", "UTC"),
+        ("Etc/GMT", "UTC"),
+        ("Zulu", "UTC"),
+        ("+05:30", "+05:30"),
+        ("-0800", "-08:00"),
+        ("+03", "+03:00"),
+        ("Etc/GMT+5", "Etc/GMT+5"),
+        ("etc/gmt-14", "Etc/GMT-14"),
+    ];
+
+    for (identifier, canonical) in valid_cases {
+        let time_zone = TimeZone::from_fixed_identifier(identifier).unwrap();
+        assert_eq!(time_zone.identifier().to_std_string_escaped(), canonical);
+    }
+}
+
+#[test]
+fn time_zone_from_fixed_identifier_rainy_day() {
+    const INVALID_CASES: [&str; 8] = [
+        "",
+        "Mars/Olympus_Mons",
+        "America/New_York",
+        "+25:00",
+        "05:30",
+        "Etc/GMT+13",
+        "Etc/GMT-15",
+        "Etc/GMT+05",
+    ];
+
+    for identifier in INVALID_CASES {
+        assert!(TimeZone::from_fixed_identifier(identifier).is_none());
+    }
+}
+
+fn parts(pattern: &str) -> Vec<PatternPart> {
+    pattern_parts(&Pattern::from_str(pattern).unwrap())
+}
+
+#[test]
+fn pattern_parts_join_literals() {
+    assert_eq!(
+        parts("h 'o''clock'"),
+        [
+            PatternPart::Field {
+                typ: "hour",
+                kind: FieldKind::Numeric,
+            },
+            PatternPart::Literal(" o'clock".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn partition_formatted_date_into_parts() {
+    let parts = partition_pattern("Monday, March 4, 2024", &parts("EEEE, MMMM d, y"));
+
+    assert_eq!(
+        parts.unwrap(),
+        [
+            ("weekday", "Monday".to_owned()),
+            ("literal", ", ".to_owned()),
+            ("month", "March".to_owned()),
+            ("literal", " ".to_owned()),
+            ("day", "4".to_owned()),
+            ("literal", ", ".to_owned()),
+            ("year", "2024".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn partition_pattern_matches_whole_numbers() {
+    let parts = partition_pattern("12/1/2021, 1:01:10.5 PM", &parts("M/d/y, h:mm:ss.S a"));
+
+    assert_eq!(
+        parts.unwrap(),
+        [
+            ("month", "12".to_owned()),
+            ("literal", "/".to_owned()),
+            ("day", "1".to_owned()),
+            ("literal", "/".to_owned()),
+            ("year", "2021".to_owned()),
+            ("literal", ", ".to_owned()),
+            ("hour", "1".to_owned()),
+            ("literal", ":".to_owned()),
+            ("minute", "01".to_owned()),
+            ("literal", ":".to_owned()),
+            ("second", "10".to_owned()),
+            ("literal", ".".to_owned()),
+            ("fractionalSecond", "5".to_owned()),
+            ("literal", " ".to_owned()),
+            ("dayPeriod", "PM".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn partition_pattern_rejects_mismatches() {
+    assert!(partition_pattern("March 4", &parts("MMMM d, y")).is_none());
+    assert!(partition_pattern("March", &parts("d")).is_none());
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: 'UTC' }).format(0)",
+            js_str!("1/1/1970"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('en', { timeZone: '+05:30', timeStyle: 'short' }).format(0)",
+            js_str!("5:30\u{202f}AM"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DateTimeFormat('de', { timeZone: 'UTC', dateStyle: 'long' }).format(0)",
+            js_str!("1. Januar 1970"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DateTimeFormat('en', { timeZone: 'Mars/Olympus_Mons' })",
+            JsNativeErrorKind::Range,
+            "invalid time zone `Mars/Olympus_Mons`",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "function parts(p) { return p.map(p => `${p.type}:${p.value}`).join('|'); }",
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.DateTimeFormat('en', { timeZone: 'UTC', dateStyle: 'full' })
+                .formatToParts(0))",
+            js_str!(
+                "weekday:Thursday|literal:, |month:January|literal: |day:1|literal:, |year:1970"
+            ),
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.DateTimeFormat('en', {
+                timeZone: 'UTC',
+                hour: 'numeric',
+                minute: '2-digit',
+                second: '2-digit',
+                fractionalSecondDigits: 2,
+            }).formatToParts(45296789))",
+            js_str!(
+                "hour:12|literal::|minute:34|literal::|second:56|literal:.\
+                 |fractionalSecond:78|literal:\u{202f}|dayPeriod:PM"
+            ),
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_range() {
+    run_test_actions([
+        TestAction::run("var dtf = new Intl.DateTimeFormat('en', { timeZone: 'UTC' });"),
+        TestAction::assert_eq(
+            "dtf.formatRange(0, 86400000)",
+            js_str!("1/1/1970\u{2009}\u{2013}\u{2009}1/2/1970"),
+        ),
+        TestAction::assert_eq("dtf.formatRange(0, 1000)", js_str!("1/1/1970")),
+        TestAction::assert_native_error(
+            "dtf.formatRange(0)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end date",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.DateTimeFormat('en', {
+                timeZone: 'etc/gmt+5',
+                hour: 'numeric',
+                hour12: false,
+            }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.locale", js_str!("en")),
+        TestAction::assert_eq("options.calendar", js_str!("gregory")),
+        TestAction::assert_eq("options.timeZone", js_str!("Etc/GMT+5")),
+        TestAction::assert_eq("options.hourCycle", js_str!("h23")),
+        TestAction::assert_eq("options.hour12", false),
+        TestAction::assert_eq("options.hour", js_str!("numeric")),
+        TestAction::assert("options.year === undefined"),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn named_time_zones() {
+    run_test_actions([
+        TestAction::run(
+            "function time(timeZone, date) {
+                return new Intl.DateTimeFormat('en', { timeZone, timeStyle: 'short' }).format(date);
+            }",
+        ),
+        TestAction::assert_eq("time('America/New_York', 0)", js_str!("7:00\u{202f}PM")),
+        TestAction::assert_eq("time('Asia/Calcutta', 0)", js_str!("5:30\u{202f}AM")),
+        // Daylight saving time.
+        TestAction::assert_eq(
+            "time('Europe/Paris', Date.UTC(2024, 6, 1, 12))",
+            js_str!("2:00\u{202f}PM"),
+        ),
+        TestAction::assert_eq(
+            "time('Europe/Paris', Date.UTC(2024, 0, 1, 12))",
+            js_str!("1:00\u{202f}PM"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn named_time_zones_from_host_hooks() {
+    struct Hooks;
+
+    impl HostHooks for Hooks {
+        fn time_zone_offset_seconds(&self, time_zone: &str, _: i64) -> Option<i32> {
+            (time_zone == "Asia/Kolkata").then_some(19800)
+        }
+    }
+
+    let context = &mut ContextBuilder::new().host_hooks(&Hooks).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::run(
+                "var dtf = new Intl.DateTimeFormat('en', {
+                    timeZone: 'asia/calcutta',
+                    timeStyle: 'short',
+                });",
+            ),
+            TestAction::assert_eq("dtf.resolvedOptions().timeZone", js_str!("Asia/Kolkata")),
+            TestAction::assert_eq("dtf.format(0)", js_str!("5:30\u{202f}AM")),
+            TestAction::assert_native_error(
+                "new Intl.DateTimeFormat('en', { timeZone: 'Europe/Paris' })",
+                JsNativeErrorKind::Range,
+                "the host doesn't support the time zone `Europe/Paris`",
+            ),
+        ],
+        context,
+    );
+}
@@ -0,0 +1,540 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use icu_experimental::displaynames::{
+    provider::LanguageDisplayNamesV1Marker, DisplayNamesOptions, Fallback, LanguageDisplay,
+    LanguageDisplayNames, LocaleDisplayNamesFormatter, RegionDisplayNames, ScriptDisplayNames,
+    Style,
+};
+use icu_locid::{
+    subtags::{Region, Script},
+    LanguageIdentifier, Locale,
+};
+use icu_locid_transform::LocaleCanonicalizer;
+use icu_provider::{DataError, DataLocale};
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    options::IntlOptions,
+    Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DisplayNames` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DisplayNames {
+    locale: Locale,
+    style: Style,
+    typ: DisplayNamesType,
+    fallback: Fallback,
+    language_display: LanguageDisplay,
+    native: NativeDisplayNames,
+}
+
+/// The ICU4X display names data used by a `DisplayNames` object.
+enum NativeDisplayNames {
+    Language {
+        locale: Box<LocaleDisplayNamesFormatter>,
+        language: Box<LanguageDisplayNames>,
+    },
+    Region(Box<RegionDisplayNames>),
+    Script(Box<ScriptDisplayNames>),
+    /// ICU4X doesn't provide display names for currencies, calendars and date-time fields yet,
+    /// so these types can only return the fallback value.
+    Unsupported,
+}
+
+impl std::fmt::Debug for NativeDisplayNames {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Language { .. } => "Language",
+            Self::Region(_) => "Region",
+            Self::Script(_) => "Script",
+            Self::Unsupported => "Unsupported",
+        })
+    }
+}
+
+impl Service for DisplayNames {
+    type LangMarker = LanguageDisplayNamesV1Marker;
+
+    type LocaleOptions = ();
+}
+
+impl IntrinsicObject for DisplayNames {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DisplayNames"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::of, js_string!("of"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DisplayNames {
+    const NAME: JsString = StaticJsStrings::DISPLAY_NAMES;
+}
+
+impl BuiltInConstructor for DisplayNames {
+    const LENGTH: usize = 2;
+    const P: usize = 3;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::display_names;
+
+    /// Constructor [`Intl.DisplayNames ( locales, options )`][spec].
+    ///
+    /// Constructor for `DisplayNames` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/DisplayNames
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DisplayNames` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let displayNames be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DisplayNames.prototype%", « [[InitializedDisplayNames]], [[Locale]], [[Style]], [[Type]], [[Fallback]], [[LanguageDisplay]], [[Fields]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::display_names,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. If options is undefined, throw a TypeError exception.
+        if options.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("`Intl.DisplayNames` requires an options object")
+                .into());
+        }
+
+        // 5. Set options to ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 6. Let opt be a new Record.
+        // 7. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 8. Set opt.[[localeMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 9. Let r be ResolveLocale(%Intl.DisplayNames%.[[AvailableLocales]], requestedLocales, opt, %Intl.DisplayNames%.[[RelevantExtensionKeys]], %Intl.DisplayNames%.[[LocaleData]]).
+        // 10. Set displayNames.[[Locale]] to r.[[locale]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut IntlOptions {
+                matcher,
+                ..Default::default()
+            },
+            context.intl_provider(),
+        )?;
+
+        // 11. Let style be ? GetOption(options, "style", string, « "narrow", "short", "long" », "long").
+        // 12. Set displayNames.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or(Style::Long);
+
+        // 13. Let type be ? GetOption(options, "type", string, « "language", "region", "script", "currency", "calendar", "dateTimeField" », undefined).
+        // 14. If type is undefined, throw a TypeError exception.
+        // 15. Set displayNames.[[Type]] to type.
+        let typ = get_option::<DisplayNamesType>(&options, js_string!("type"), context)?
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message("`Intl.DisplayNames` requires a `type` option")
+            })?;
+
+        // 16. Let fallback be ? GetOption(options, "fallback", string, « "code", "none" », "code").
+        // 17. Set displayNames.[[Fallback]] to fallback.
+        let fallback = get_option(&options, js_string!("fallback"), context)?.unwrap_or_default();
+
+        // 18. Let languageDisplay be ? GetOption(options, "languageDisplay", string, « "dialect", "standard" », "dialect").
+        // 19. If type is "language", then
+        //     a. Set displayNames.[[LanguageDisplay]] to languageDisplay.
+        let language_display =
+            get_option(&options, js_string!("languageDisplay"), context)?.unwrap_or_default();
+
+        // 20. Let styleFields be r.[[LocaleData]].[[<style>]].
+        // 21. Let typeFields be styleFields.[[<type>]].
+        // 22. Set displayNames.[[Fields]] to typeFields.
+        let mut icu_options = DisplayNamesOptions::default();
+        // ICU4X uses `Style::Long` for the alternative long names, which is not what
+        // `Intl.DisplayNames` means by a "long" style.
+        icu_options.style = (style != Style::Long).then_some(style);
+        icu_options.fallback = fallback;
+        icu_options.language_display = language_display;
+
+        let provider = context.intl_provider();
+        let data_locale = &DataLocale::from(&locale);
+        let data_error = |err: DataError| JsNativeError::typ().with_message(err.to_string());
+        let native = match typ {
+            DisplayNamesType::Language => NativeDisplayNames::Language {
+                locale: Box::new(
+                    LocaleDisplayNamesFormatter::try_new_unstable(
+                        provider,
+                        data_locale,
+                        icu_options,
+                    )
+                    .map_err(data_error)?,
+                ),
+                language: Box::new(
+                    LanguageDisplayNames::try_new_unstable(provider, data_locale, icu_options)
+                        .map_err(data_error)?,
+                ),
+            },
+            DisplayNamesType::Region => NativeDisplayNames::Region(Box::new(
+                RegionDisplayNames::try_new_unstable(provider, data_locale, icu_options)
+                    .map_err(data_error)?,
+            )),
+            DisplayNamesType::Script => NativeDisplayNames::Script(Box::new(
+                ScriptDisplayNames::try_new_unstable(provider, data_locale, icu_options)
+                    .map_err(data_error)?,
+            )),
+            DisplayNamesType::Currency
+            | DisplayNamesType::Calendar
+            | DisplayNamesType::DateTimeField => NativeDisplayNames::Unsupported,
+        };
+
+        let display_names = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                style,
+                typ,
+                fallback,
+                language_display,
+                native,
+            },
+        );
+
+        // 23. Return displayNames.
+        Ok(display_names.into())
+    }
+}
+
+impl DisplayNames {
+    /// [`Intl.DisplayNames.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in display
+    /// names without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DisplayNames%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DisplayNames.prototype.of ( code )`][spec].
+    ///
+    /// Returns the localized display name of `code`, according to the type of this
+    /// `DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.of
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/of
+    fn of(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let dn = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`of` can only be called on an `Intl.DisplayNames` object")
+            })?;
+
+        // 3. Let code be ? ToString(code).
+        let code = args
+            .get_or_undefined(0)
+            .to_string(context)?
+            .to_std_string_escaped();
+
+        let dn = dn.borrow();
+        let dn = &dn.data;
+
+        // 4. Set code to ? CanonicalCodeForDisplayNames(displayNames.[[Type]], code).
+        let code = canonical_code_for_display_names(
+            dn.typ,
+            &code,
+            context.intl_provider().locale_canonicalizer()?,
+        )?;
+
+        // 5. Let fields be displayNames.[[Fields]].
+        // 6. If fields has a field [[<code>]], return fields.[[<code>]].
+        let name = match &dn.native {
+            NativeDisplayNames::Language { locale, language } => {
+                let code = code
+                    .parse::<Locale>()
+                    .expect("canonicalized language codes must be valid locales");
+                language
+                    .of(code.id.language)
+                    .map(|_| locale.of(&code).into_owned())
+            }
+            NativeDisplayNames::Region(names) => code
+                .parse::<Region>()
+                .ok()
+                .and_then(|region| names.of(region))
+                .map(str::to_owned),
+            NativeDisplayNames::Script(names) => code
+                .parse::<Script>()
+                .ok()
+                .and_then(|script| names.of(script))
+                .map(str::to_owned),
+            NativeDisplayNames::Unsupported => None,
+        };
+
+        match (name, dn.fallback) {
+            (Some(name), _) => Ok(js_string!(name).into()),
+            // 7. If displayNames.[[Fallback]] is "code", return code.
+            (None, Fallback::Code) => Ok(js_string!(code).into()),
+            // 8. Return undefined.
+            (None, _) => Ok(JsValue::undefined()),
+        }
+    }
+
+    /// [`Intl.DisplayNames.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and options computed during
+    /// the construction of the current `Intl.DisplayNames` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DisplayNames.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DisplayNames/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let displayNames be this value.
+        // 2. Perform ? RequireInternalSlot(displayNames, [[InitializedDisplayNames]]).
+        let dn = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+        let dn = dn.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DisplayNames` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 19, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of displayNames's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(dn.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("style"),
+                match dn.style {
+                    Style::Narrow => js_string!("narrow"),
+                    Style::Short => js_string!("short"),
+                    _ => js_string!("long"),
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(js_string!("type"), js_string!(dn.typ.as_str()), context)
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("fallback"),
+                if dn.fallback == Fallback::None {
+                    js_string!("none")
+                } else {
+                    js_string!("code")
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if dn.typ == DisplayNamesType::Language {
+            options
+                .create_data_property_or_throw(
+                    js_string!("languageDisplay"),
+                    if dn.language_display == LanguageDisplay::Standard {
+                        js_string!("standard")
+                    } else {
+                        js_string!("dialect")
+                    },
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+}
+
+/// Abstract operation [`CanonicalCodeForDisplayNames ( type, code )`][spec].
+///
+/// Verifies that `code` is a well-formed code for `typ`, and returns its canonical form.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalcodefordisplaynames
+fn canonical_code_for_display_names(
+    typ: DisplayNamesType,
+    code: &str,
+    canonicalizer: &LocaleCanonicalizer,
+) -> JsResult<String> {
+    let valid = match typ {
+        // 1. If type is "language", then
+        DisplayNamesType::Language => {
+            // a. If code cannot be matched by the unicode_language_id Unicode locale nonterminal, throw a RangeError exception.
+            // b. If IsStructurallyValidLanguageTag(code) is false, throw a RangeError exception.
+            let id = (!code.contains('_'))
+                .then(|| code.parse::<LanguageIdentifier>().ok())
+                .flatten()
+                .ok_or_else(|| {
+                    JsNativeError::range()
+                        .with_message("code is not a structurally valid language identifier")
+                })?;
+
+            // c. Return CanonicalizeUnicodeLocaleId(code).
+            let mut locale = Locale::from(id);
+            canonicalizer.canonicalize(&mut locale);
+            return Ok(locale.to_string());
+        }
+        // 2. If type is "region", then
+        //     a. If code cannot be matched by the unicode_region_subtag Unicode locale nonterminal, throw a RangeError exception.
+        //     b. Return the ASCII-uppercase of code.
+        DisplayNamesType::Region => {
+            (code.len() == 2 && code.bytes().all(|c| c.is_ascii_alphabetic()))
+                || (code.len() == 3 && code.bytes().all(|c| c.is_ascii_digit()))
+        }
+        // 3. If type is "script", then
+        //     a. If code cannot be matched by the unicode_script_subtag Unicode locale nonterminal, throw a RangeError exception.
+        //     b. Assert: The length of code is 4, and every code unit of code represents an ASCII letter.
+        //     c. Let first be the ASCII-uppercase of the substring of code from 0 to 1.
+        //     d. Let rest be the ASCII-lowercase of the substring of code from 1.
+        //     e. Return the string-concatenation of first and rest.
+        DisplayNamesType::Script => {
+            code.len() == 4 && code.bytes().all(|c| c.is_ascii_alphabetic())
+        }
+        // 4. If type is "calendar", then
+        //     a. If code cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        //     b. If code uses any of the backwards compatibility syntax described in Unicode Technical Standard #35 LDML § 3.3 BCP 47 Conformance, throw a RangeError exception.
+        //     c. Return the ASCII-lowercase of code.
+        DisplayNamesType::Calendar => code.split('-').all(|part| {
+            (3..=8).contains(&part.len()) && part.bytes().all(|c| c.is_ascii_alphanumeric())
+        }),
+        // 5. If type is "dateTimeField", then
+        //     a. If the result of IsValidDateTimeFieldCode(code) is false, throw a RangeError exception.
+        //     b. Return code.
+        DisplayNamesType::DateTimeField => matches!(
+            code,
+            "era"
+                | "year"
+                | "quarter"
+                | "month"
+                | "weekOfYear"
+                | "weekday"
+                | "day"
+                | "dayPeriod"
+                | "hour"
+                | "minute"
+                | "second"
+                | "timeZoneName"
+        ),
+        // 6. Assert: type is "currency".
+        // 7. If IsWellFormedCurrencyCode(code) is false, throw a RangeError exception.
+        // 8. Return the ASCII-uppercase of code.
+        DisplayNamesType::Currency => {
+            code.len() == 3 && code.bytes().all(|c| c.is_ascii_alphabetic())
+        }
+    };
+
+    if !valid {
+        return Err(JsNativeError::range()
+            .with_message(format!("`{code}` is not a valid {} code", typ.as_str()))
+            .into());
+    }
+
+    Ok(match typ {
+        DisplayNamesType::Region | DisplayNamesType::Currency => code.to_ascii_uppercase(),
+        DisplayNamesType::Script => {
+            let (first, rest) = code.split_at(1);
+            first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+        }
+        DisplayNamesType::Calendar => code.to_ascii_lowercase(),
+        DisplayNamesType::Language | DisplayNamesType::DateTimeField => code.to_owned(),
+    })
+}
@@ -0,0 +1,74 @@
+use crate::{js_str, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+fn of() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('us')",
+            js_str!("United States"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'script' }).of('latn')",
+            js_str!("Latin"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('en-US')",
+            js_str!("American English"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'language', languageDisplay: 'standard' }).of('en-US')",
+            js_str!("English (United States)"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'region', fallback: 'none' }).of('XY')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'region' }).of('usa')",
+            JsNativeErrorKind::Range,
+            "`usa` is not a valid region code",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'language' }).of('en_US')",
+            JsNativeErrorKind::Range,
+            "code is not a structurally valid language identifier",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en')",
+            JsNativeErrorKind::Type,
+            "`Intl.DisplayNames` requires an options object",
+        ),
+    ]);
+}
+
+#[test]
+fn of_types_without_data() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('eur')",
+            js_str!("EUR"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'calendar' }).of('Gregory')",
+            js_str!("gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('weekOfYear')",
+            js_str!("weekOfYear"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DisplayNames('en', { type: 'currency', fallback: 'none' }).of('EUR')",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'currency' }).of('euro')",
+            JsNativeErrorKind::Range,
+            "`euro` is not a valid currency code",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DisplayNames('en', { type: 'dateTimeField' }).of('weeks')",
+            JsNativeErrorKind::Range,
+            "`weeks` is not a valid dateTimeField code",
+        ),
+    ]);
+}
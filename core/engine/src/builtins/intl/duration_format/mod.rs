@@ -0,0 +1,1190 @@
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, Sign};
+use icu_list::{provider::UnitListV1Marker, ListFormatter, ListLength};
+use icu_locid::{extensions::unicode::Value, Locale};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::{get_option, get_options_object},
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::{ErasedProvider, IntlProvider},
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    list_format::create_parts_from_list,
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::{IntlMathematicalValue, NumberFormatLocaleOptions},
+    options::{get_number_option, IntlOptions},
+    NumberFormat, Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(all(test, feature = "intl_bundled"))]
+mod tests;
+
+/// A formatted part, with its type, its value and the unit it belongs to.
+type Part = (&'static str, String, Option<DurationUnit>);
+
+#[derive(Debug, Clone, Copy)]
+struct UnitOptions {
+    style: UnitStyle,
+    display: Display,
+}
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `DurationFormat` only contains non-traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct DurationFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: BaseStyle,
+    units: [UnitOptions; 10],
+    fractional_digits: Option<u8>,
+    list_formatter: ListFormatter,
+}
+
+impl Service for DurationFormat {
+    type LangMarker = UnitListV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is `nu`, which is resolved in the same way as
+        // `Intl.NumberFormat` does it.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for DurationFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.DurationFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 1)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for DurationFormat {
+    const NAME: JsString = StaticJsStrings::DURATION_FORMAT;
+}
+
+impl BuiltInConstructor for DurationFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::duration_format;
+
+    /// Constructor [`Intl.DurationFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `DurationFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-durationformat-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/DurationFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.DurationFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let durationFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.DurationFormatPrototype%", « [[InitializedDurationFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[YearsStyle]], [[YearsDisplay]], [[MonthsStyle]], [[MonthsDisplay]], [[WeeksStyle]], [[WeeksDisplay]], [[DaysStyle]], [[DaysDisplay]], [[HoursStyle]], [[HoursDisplay]], [[MinutesStyle]], [[MinutesDisplay]], [[SecondsStyle]], [[SecondsDisplay]], [[MillisecondsStyle]], [[MillisecondsDisplay]], [[MicrosecondsStyle]], [[MicrosecondsDisplay]], [[NanosecondsStyle]], [[NanosecondsDisplay]], [[HourMinuteSeparator]], [[MinuteSecondSeparator]], [[FractionalDigits]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::duration_format,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Let options be ? GetOptionsObject(options).
+        let options = get_options_object(options)?;
+
+        // 5. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 6. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 7. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        let numbering_system = get_option(&options, js_string!("numberingSystem"), context)?;
+
+        // 8. Let opt be the Record { [[localeMatcher]]: matcher, [[nu]]: numberingSystem }.
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 9. Let r be ResolveLocale(%Intl.DurationFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.DurationFormat%.[[RelevantExtensionKeys]], %Intl.DurationFormat%.[[LocaleData]]).
+        // 10. Let locale be r.[[Locale]].
+        // 11. Set durationFormat.[[Locale]] to locale.
+        // 12. Set durationFormat.[[DataLocale]] to r.[[DataLocale]].
+        // 13. Set durationFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 14. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow", "digital" », "short").
+        // 15. Set durationFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 16. Let prevStyle be the empty String.
+        let mut prev_style = None;
+
+        // 17. For each row of Table 20, except the header row, in table order, do
+        let mut units = [UnitOptions {
+            style: UnitStyle::Short,
+            display: Display::Auto,
+        }; 10];
+        for unit in DurationUnit::ALL {
+            // a-f. Let unitOptions be ? GetDurationUnitOptions(unit, options, style, valueList, digitalBase, prevStyle, twoDigitHours).
+            let unit_options =
+                get_duration_unit_options(unit, &options, style, prev_style, context)?;
+
+            // g. Set the value of durationFormat's internal slot whose name is styleSlot to unitOptions.[[Style]].
+            // h. Set the value of durationFormat's internal slot whose name is displaySlot to unitOptions.[[Display]].
+            units[unit as usize] = unit_options;
+
+            // i. If unit is one of "hours", "minutes", "seconds", "milliseconds", or "microseconds", then
+            if matches!(
+                unit,
+                DurationUnit::Hours
+                    | DurationUnit::Minutes
+                    | DurationUnit::Seconds
+                    | DurationUnit::Milliseconds
+                    | DurationUnit::Microseconds
+            ) {
+                // i. Set prevStyle to unitOptions.[[Style]].
+                prev_style = Some(unit_options.style);
+            }
+        }
+
+        // 18. Set durationFormat.[[FractionalDigits]] to ? GetNumberOption(options, "fractionalDigits", 0, 9, undefined).
+        let fractional_digits =
+            get_number_option(&options, js_string!("fractionalDigits"), 0u8, 9, context)?;
+
+        // The list formatter used by `ListFormatParts` doesn't depend on the formatted duration,
+        // so it is loaded only once.
+        let length = match style {
+            BaseStyle::Long => ListLength::Wide,
+            BaseStyle::Short | BaseStyle::Digital => ListLength::Short,
+            BaseStyle::Narrow => ListLength::Narrow,
+        };
+        let data_locale = &DataLocale::from(&locale);
+        let list_formatter = match context.intl_provider().erased_provider() {
+            ErasedProvider::Any(a) => {
+                ListFormatter::try_new_unit_with_length_with_any_provider(a, data_locale, length)
+            }
+            ErasedProvider::Buffer(b) => {
+                ListFormatter::try_new_unit_with_length_with_buffer_provider(b, data_locale, length)
+            }
+        }
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        let duration_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                units,
+                fractional_digits,
+                list_formatter,
+            },
+        );
+
+        // 19. Return durationFormat.
+        Ok(duration_format.into())
+    }
+}
+
+impl DurationFormat {
+    /// [`Intl.DurationFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in duration
+    /// formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.DurationFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.DurationFormat.prototype.format ( duration )`][spec].
+    ///
+    /// Returns a language-specific string representing `duration`, which can either be a
+    /// `Temporal.Duration` or a duration-like object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`format` can only be called on an `Intl.DurationFormat` object")
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be ? PartitionDurationFormatPattern(df, record).
+        let parts = df
+            .borrow()
+            .data
+            .partition_duration_format_pattern(&record, context)?;
+
+        // 5. Let result be the empty String.
+        // 6. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect::<String>();
+
+        // 7. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.formatToParts ( duration )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted duration in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let df be this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.DurationFormat` object",
+                )
+            })?;
+
+        // 3. Let record be ? ToDurationRecord(duration).
+        let record = DurationRecord::from_value(args.get_or_undefined(0), context)?;
+
+        // 4. Let parts be ? PartitionDurationFormatPattern(df, record).
+        let parts = df
+            .borrow()
+            .data
+            .partition_duration_format_pattern(&record, context)?;
+
+        // 5. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 6. Let n be 0.
+        // 7. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (typ, value, unit)) in parts.into_iter().enumerate() {
+            // a. Let obj be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(obj, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, perform ! CreateDataPropertyOrThrow(obj, "unit", part.[[Unit]]).
+            if let Some(unit) = unit {
+                o.create_data_property_or_throw(
+                    js_string!("unit"),
+                    js_string!(unit.singular()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), obj).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 8. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.DurationFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.DurationFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.DurationFormat.prototype.resolvedOptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/DurationFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let df be the this value.
+        // 2. Perform ? RequireInternalSlot(df, [[InitializedDurationFormat]]).
+        let df = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+        let df = df.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.DurationFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 21, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of df's internal slot whose name is the Internal Slot value of the current row.
+        //     c. If p is "fractionalDigits", then
+        //         i. If v is not undefined, set v to 𝔽(v).
+        //     d. Else,
+        //         i. Assert: v is not undefined.
+        //     e. If v is "fractional", then
+        //         i. Set v to "numeric".
+        //     f. If v is not undefined, then
+        //         i. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(df.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(nu) = &df.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    js_string!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        options
+            .create_data_property_or_throw(
+                js_string!("style"),
+                js_string!(df.style.as_str()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        for unit in DurationUnit::ALL {
+            let UnitOptions { style, display } = df.units[unit as usize];
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.as_str()),
+                    js_string!(style.as_str()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            options
+                .create_data_property_or_throw(
+                    js_string!(unit.display_option()),
+                    js_string!(display.as_str()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        if let Some(fractional_digits) = df.fractional_digits {
+            options
+                .create_data_property_or_throw(
+                    js_string!("fractionalDigits"),
+                    fractional_digits,
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Returns the style and display options of `unit`.
+    const fn unit_options(&self, unit: DurationUnit) -> UnitOptions {
+        self.units[unit as usize]
+    }
+
+    /// Abstract operation [`PartitionDurationFormatPattern ( durationFormat, duration )`][spec].
+    ///
+    /// ICU4X doesn't provide unit display names yet, so the labels of the units are always
+    /// written in English. Likewise, `:` is used as the separator of numeric units for every
+    /// locale.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitiondurationformatpattern
+    fn partition_duration_format_pattern(
+        &self,
+        duration: &DurationRecord,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Let result be a new empty List.
+        let mut result = Vec::new();
+
+        // 2. Let signDisplayed be true.
+        let mut sign_displayed = true;
+
+        // 3. Let numericUnitFound be false.
+        // 4. While numericUnitFound is false, repeat for each row of Table 20 in table order, except the header row:
+        for unit in DurationUnit::ALL {
+            // a. Let value be the value of duration's field whose name is the Value Field value of the current row.
+            // b. Let style be the value of durationFormat's internal slot whose name is the Style Slot value of the current row.
+            // c. Let display be the value of durationFormat's internal slot whose name is the Display Slot value of the current row.
+            let UnitOptions { style, display } = self.unit_options(unit);
+
+            // d. If style is "numeric" or "2-digit", then
+            if matches!(style, UnitStyle::Numeric | UnitStyle::TwoDigit) {
+                // i. Append FormatNumericUnits(durationFormat, duration, unit, signDisplayed) to result.
+                result.push(self.format_numeric_units(duration, unit, sign_displayed, context)?);
+                // ii. Set numericUnitFound to true.
+                break;
+            }
+
+            // e. Else,
+            //     i. Let nfOpts be OrdinaryObjectCreate(null).
+            let mut nf_options = Vec::new();
+            let mut value = FixedDecimal::from(duration.get(unit));
+            let mut numeric_unit_found = false;
+
+            //     ii. If unit is "seconds", "milliseconds", or "microseconds", then
+            //         1-3. Let nextStyle be the style of the next smaller unit.
+            let next_unit = match unit {
+                DurationUnit::Seconds => Some(DurationUnit::Milliseconds),
+                DurationUnit::Milliseconds => Some(DurationUnit::Microseconds),
+                DurationUnit::Microseconds => Some(DurationUnit::Nanoseconds),
+                _ => None,
+            };
+            //         4. If nextStyle is "fractional", then
+            if next_unit.is_some_and(|next| self.unit_options(next).style == UnitStyle::Fractional)
+            {
+                // a. Set value to DurationToFractional(duration, unit).
+                value = duration.to_fractional(unit);
+                // b-f. Set the fraction digit options and the rounding mode of nfOpts.
+                self.fraction_options(&mut nf_options);
+                // g. Set numericUnitFound to true.
+                numeric_unit_found = true;
+            }
+
+            //     iii. If display is "always" or value is not zero, then
+            if display == Display::Always || !value.is_zero() {
+                // 1. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", durationFormat.[[NumberingSystem]]).
+                // 2. If signDisplayed is true, then
+                if sign_displayed {
+                    // a. Set signDisplayed to false.
+                    sign_displayed = false;
+                    // b. If value is 0 and DurationSign(duration) is -1, set value to negative-zero.
+                    if value.is_zero() && duration.sign() == -1 {
+                        value.set_sign(Sign::Negative);
+                    }
+                } else {
+                    // 3. Else,
+                    //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+                    nf_options.push(("signDisplay", js_string!("never").into()));
+                }
+
+                // 4-8. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »),
+                //      with nfOpts set to format `unit` in `style`.
+                // 9. Let parts be PartitionNumberPattern(nf, value).
+                // 10-11. Append the parts, tagged with numberFormatUnit, to result.
+                let (mut parts, value) =
+                    self.partition_number_pattern(value, unit, &nf_options, context)?;
+                let one = value.to_string().trim_start_matches(['-', '+']) == "1";
+                if style != UnitStyle::Narrow {
+                    parts.push(("literal", " ".to_string(), Some(unit)));
+                }
+                parts.push(("unit", unit.label(style, one).to_string(), Some(unit)));
+                result.push(parts);
+            }
+
+            if numeric_unit_found {
+                break;
+            }
+        }
+
+        // 5. Return ListFormatParts(durationFormat, result).
+        self.list_format_parts(result)
+    }
+
+    /// Abstract operation [`FormatNumericUnits ( durationFormat, duration, firstNumericUnit, signDisplayed )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumericunits
+    fn format_numeric_units(
+        &self,
+        duration: &DurationRecord,
+        first_numeric_unit: DurationUnit,
+        mut sign_displayed: bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Assert: firstNumericUnit is "hours", "minutes", or "seconds".
+        debug_assert!(matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ));
+
+        // 2. Let numericPartsList be a new empty List.
+        let mut numeric_parts = Vec::new();
+
+        // 3-4. Let hoursValue be duration.[[Hours]] and hoursDisplay be durationFormat.[[HoursDisplay]].
+        let hours_value = duration.get(DurationUnit::Hours);
+        let hours_display = self.unit_options(DurationUnit::Hours).display;
+
+        // 5-6. Let minutesValue be duration.[[Minutes]] and minutesDisplay be durationFormat.[[MinutesDisplay]].
+        let minutes_value = duration.get(DurationUnit::Minutes);
+        let minutes_display = self.unit_options(DurationUnit::Minutes).display;
+
+        // 7. Let secondsValue be duration.[[Seconds]].
+        // 8. If duration.[[Milliseconds]] is not 0 or duration.[[Microseconds]] is not 0 or duration.[[Nanoseconds]] is not 0, then
+        //     a. Set secondsValue to DurationToFractional(duration, "seconds").
+        let seconds_value =
+            if self.unit_options(DurationUnit::Milliseconds).style == UnitStyle::Fractional {
+                duration.to_fractional(DurationUnit::Seconds)
+            } else {
+                FixedDecimal::from(duration.get(DurationUnit::Seconds))
+            };
+
+        // 9. Let secondsDisplay be durationFormat.[[SecondsDisplay]].
+        let seconds_display = self.unit_options(DurationUnit::Seconds).display;
+
+        // 10. Let hoursFormatted be false.
+        // 11. If firstNumericUnit is "hours", then
+        //     a. If hoursValue is not 0 or hoursDisplay is "always", then
+        //         i. Set hoursFormatted to true.
+        let hours_formatted = first_numeric_unit == DurationUnit::Hours
+            && (hours_value != 0 || hours_display == Display::Always);
+
+        // 12. If secondsValue is not 0 or secondsDisplay is "always", then
+        //     a. Let secondsFormatted be true.
+        // 13. Else,
+        //     a. Let secondsFormatted be false.
+        let seconds_formatted = !seconds_value.is_zero() || seconds_display == Display::Always;
+
+        // 14. Let minutesFormatted be false.
+        // 15. If firstNumericUnit is "hours" or firstNumericUnit is "minutes", then
+        //     a. If hoursFormatted is true and secondsFormatted is true, then
+        //         i. Set minutesFormatted to true.
+        //     b. Else if minutesValue is not 0 or minutesDisplay is "always", then
+        //         i. Set minutesFormatted to true.
+        let minutes_formatted = matches!(
+            first_numeric_unit,
+            DurationUnit::Hours | DurationUnit::Minutes
+        ) && ((hours_formatted && seconds_formatted)
+            || minutes_value != 0
+            || minutes_display == Display::Always);
+
+        // 16. If hoursFormatted is true, then
+        if hours_formatted {
+            // a-c. Append FormatNumericHours(durationFormat, hoursValue, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                FixedDecimal::from(hours_value),
+                DurationUnit::Hours,
+                duration.sign(),
+                &mut sign_displayed,
+                context,
+            )?);
+        }
+
+        // 17. If minutesFormatted is true, then
+        if minutes_formatted {
+            // FormatNumericMinutes: If hoursDisplayed is true, append the hour-minute separator.
+            if hours_formatted {
+                numeric_parts.push(("literal", ":".to_string(), None));
+            }
+            // a-c. Append FormatNumericMinutes(durationFormat, minutesValue, hoursFormatted, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                FixedDecimal::from(minutes_value),
+                DurationUnit::Minutes,
+                duration.sign(),
+                &mut sign_displayed,
+                context,
+            )?);
+        }
+
+        // 18. If secondsFormatted is true, then
+        if seconds_formatted {
+            // FormatNumericSeconds: If minutesDisplayed is true, append the minute-second separator.
+            if minutes_formatted {
+                numeric_parts.push(("literal", ":".to_string(), None));
+            }
+            // a. Append FormatNumericSeconds(durationFormat, secondsValue, minutesFormatted, signDisplayed) to numericPartsList.
+            numeric_parts.extend(self.format_numeric_unit(
+                seconds_value,
+                DurationUnit::Seconds,
+                duration.sign(),
+                &mut sign_displayed,
+                context,
+            )?);
+        }
+
+        // 19. Return numericPartsList.
+        Ok(numeric_parts)
+    }
+
+    /// Abstract operations [`FormatNumericHours`][hours], [`FormatNumericMinutes`][minutes]
+    /// and [`FormatNumericSeconds`][seconds], without the separators.
+    ///
+    /// [hours]: https://tc39.es/ecma402/#sec-formatnumerichours
+    /// [minutes]: https://tc39.es/ecma402/#sec-formatnumericminutes
+    /// [seconds]: https://tc39.es/ecma402/#sec-formatnumericseconds
+    fn format_numeric_unit(
+        &self,
+        mut value: FixedDecimal,
+        unit: DurationUnit,
+        duration_sign: i8,
+        sign_displayed: &mut bool,
+        context: &mut Context,
+    ) -> JsResult<Vec<Part>> {
+        // 1. Let nfOpts be OrdinaryObjectCreate(null).
+        // 2. Let numberingSystem be durationFormat.[[NumberingSystem]].
+        // 3. Perform ! CreateDataPropertyOrThrow(nfOpts, "numberingSystem", numberingSystem).
+        let mut nf_options = Vec::new();
+
+        // 4. If the style of unit is "2-digit", then
+        //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumIntegerDigits", 2𝔽).
+        if self.unit_options(unit).style == UnitStyle::TwoDigit {
+            nf_options.push(("minimumIntegerDigits", 2.into()));
+        }
+
+        // 5. If signDisplayed is false, then
+        //     a. Perform ! CreateDataPropertyOrThrow(nfOpts, "signDisplay", "never").
+        if *sign_displayed {
+            if value.is_zero() && duration_sign == -1 {
+                value.set_sign(Sign::Negative);
+            }
+            *sign_displayed = false;
+        } else {
+            nf_options.push(("signDisplay", js_string!("never").into()));
+        }
+
+        // 6. Perform ! CreateDataPropertyOrThrow(nfOpts, "useGrouping", false).
+        nf_options.push(("useGrouping", false.into()));
+
+        // 7. If unit is "seconds", then
+        //     a-f. Set the fraction digit options and the rounding mode of nfOpts.
+        if unit == DurationUnit::Seconds {
+            self.fraction_options(&mut nf_options);
+        }
+
+        // 8. Let nf be ! Construct(%Intl.NumberFormat%, « durationFormat.[[Locale]], nfOpts »).
+        // 9. Let parts be ! PartitionNumberPattern(nf, value).
+        // 10. Let result be a new empty List.
+        // 11. For each Record { [[Type]], [[Value]] } part of parts, do
+        //     a. Append the Record { [[Type]]: part.[[Type]], [[Value]]: part.[[Value]], [[Unit]]: unit } to result.
+        // 12. Return result.
+        self.partition_number_pattern(value, unit, &nf_options, context)
+            .map(|(parts, _)| parts)
+    }
+
+    /// Pushes the options used to format a value including fractional digits.
+    fn fraction_options(&self, nf_options: &mut Vec<(&'static str, JsValue)>) {
+        // 1. If durationFormat.[[FractionalDigits]] is undefined, then
+        //     a. Let maximumFractionDigits be 9𝔽.
+        //     b. Let minimumFractionDigits be +0𝔽.
+        // 2. Else,
+        //     a. Let maximumFractionDigits be durationFormat.[[FractionalDigits]].
+        //     b. Let minimumFractionDigits be durationFormat.[[FractionalDigits]].
+        let (maximum, minimum) = self
+            .fractional_digits
+            .map_or((9, 0), |digits| (digits, digits));
+
+        // 3. Perform ! CreateDataPropertyOrThrow(nfOpts, "maximumFractionDigits", maximumFractionDigits).
+        // 4. Perform ! CreateDataPropertyOrThrow(nfOpts, "minimumFractionDigits", minimumFractionDigits).
+        // 5. Perform ! CreateDataPropertyOrThrow(nfOpts, "roundingMode", "trunc").
+        nf_options.push(("maximumFractionDigits", maximum.into()));
+        nf_options.push(("minimumFractionDigits", minimum.into()));
+        nf_options.push(("roundingMode", js_string!("trunc").into()));
+    }
+
+    /// Formats `value` with a new `Intl.NumberFormat` for the locale of this `DurationFormat`,
+    /// returning its parts tagged with `unit` and the value after rounding.
+    fn partition_number_pattern(
+        &self,
+        value: FixedDecimal,
+        unit: DurationUnit,
+        nf_options: &[(&'static str, JsValue)],
+        context: &mut Context,
+    ) -> JsResult<(Vec<Part>, FixedDecimal)> {
+        let options = JsObject::with_null_proto();
+        if let Some(nu) = &self.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    js_string!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+        for (key, option) in nf_options {
+            options
+                .create_data_property_or_throw(js_string!(*key), option.clone(), context)
+                .expect("operation must not fail per the spec");
+        }
+
+        let nf = NumberFormat::new(
+            &js_string!(self.locale.to_string()).into(),
+            &options.into(),
+            context,
+        )?;
+        let mut value = IntlMathematicalValue::Finite(value);
+        let parts = nf
+            .partition_number_pattern(&mut value)
+            .into_iter()
+            .map(|(typ, part)| (typ, part, Some(unit)))
+            .collect();
+        let IntlMathematicalValue::Finite(value) = value else {
+            unreachable!("formatting a finite value keeps it finite");
+        };
+
+        Ok((parts, value))
+    }
+
+    /// Abstract operation [`ListFormatParts ( durationFormat, partitionedPartsList )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-listformatparts
+    fn list_format_parts(&self, partitioned_parts: Vec<Vec<Part>>) -> JsResult<Vec<Part>> {
+        // 1-6. Let lf be ! Construct(%Intl.ListFormat%, « durationFormat.[[Locale]], lfOpts »).
+        // 7. Let strings be a new empty List.
+        // 8. For each element parts of partitionedPartsList, do
+        //     a. Let string be the empty String.
+        //     b. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //         i. Set string to the string-concatenation of string and part.[[Value]].
+        //     c. Append string to strings.
+        let strings = partitioned_parts
+            .iter()
+            .map(|parts| parts.iter().map(|(_, value, _)| value.as_str()).collect())
+            .collect::<Vec<String>>();
+
+        // 9. Let formattedPartsList be CreatePartsFromList(lf, strings).
+        let formatted_parts = create_parts_from_list(&self.list_formatter, strings.into_iter())
+            .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+        // 10. Let partitionedPartsIndex be 0.
+        // 11. Let partitionedLength be the number of elements in partitionedPartsList.
+        let mut partitioned_parts = partitioned_parts.into_iter();
+
+        // 12. Let flattenedPartsList be a new empty List.
+        let mut flattened_parts = Vec::new();
+
+        // 13. For each Record { [[Type]], [[Value]] } listPart in formattedPartsList, do
+        for (typ, value) in formatted_parts {
+            // a. If listPart.[[Type]] is "element", then
+            if typ == "element" {
+                // i. Assert: partitionedPartsIndex < partitionedLength.
+                // ii. Let parts be partitionedPartsList[partitionedPartsIndex].
+                // iii. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+                //     1. Append part to flattenedPartsList.
+                // iv. Set partitionedPartsIndex to partitionedPartsIndex + 1.
+                flattened_parts.extend(
+                    partitioned_parts
+                        .next()
+                        .expect("the list must have one element for each partitioned part"),
+                );
+            } else {
+                // b. Else,
+                //     i. Assert: listPart.[[Type]] is "literal".
+                //     ii. Append the Record { [[Type]]: "literal", [[Value]]: listPart.[[Value]], [[Unit]]: empty } to flattenedPartsList.
+                flattened_parts.push(("literal", value, None));
+            }
+        }
+
+        // 14. Return flattenedPartsList.
+        Ok(flattened_parts)
+    }
+}
+
+/// Abstract operation [`GetDurationUnitOptions ( unit, options, baseStyle, stylesList, digitalBase, prevStyle, twoDigitHours )`][spec].
+///
+/// The locale data doesn't specify if hours are displayed with two digits, so `twoDigitHours`
+/// is always `false`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getdurationunitoptions
+fn get_duration_unit_options(
+    unit: DurationUnit,
+    options: &JsObject,
+    base_style: BaseStyle,
+    prev_style: Option<UnitStyle>,
+    context: &mut Context,
+) -> JsResult<UnitOptions> {
+    // 1. Let style be ? GetOption(options, unit, string, stylesList, undefined).
+    let style = get_option::<UnitStyle>(options, js_string!(unit.as_str()), context)?;
+    if let Some(style) = style.filter(|&style| !unit.allows(style)) {
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` is not a valid style for `{}`",
+                style.as_str(),
+                unit.as_str()
+            ))
+            .into());
+    }
+
+    // 2. Let displayDefault be "always".
+    let mut display_default = Display::Always;
+
+    // 3. If style is undefined, then
+    let mut style = if let Some(style) = style {
+        style
+    } else if base_style == BaseStyle::Digital {
+        // a. If baseStyle is "digital", then
+        //     i. If unit is not one of "hours", "minutes", or "seconds", then
+        //         1. Set displayDefault to "auto".
+        if !matches!(
+            unit,
+            DurationUnit::Hours | DurationUnit::Minutes | DurationUnit::Seconds
+        ) {
+            display_default = Display::Auto;
+        }
+        //     ii. Set style to digitalBase.
+        unit.digital_base()
+    } else if prev_style.is_some_and(UnitStyle::is_numeric) {
+        // b. Else,
+        //     i. If prevStyle is "fractional", "numeric" or "2-digit", then
+        //         1. If unit is not one of "minutes" or "seconds", then
+        //             a. Set displayDefault to "auto".
+        if !matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+            display_default = Display::Auto;
+        }
+        //         2. Set style to "numeric".
+        UnitStyle::Numeric
+    } else {
+        //     ii. Else,
+        //         1. Set displayDefault to "auto".
+        display_default = Display::Auto;
+        //         2. Set style to baseStyle.
+        base_style.into()
+    };
+
+    // 4. If style is "numeric" and unit is one of "milliseconds", "microseconds", or "nanoseconds", then
+    if style == UnitStyle::Numeric
+        && matches!(
+            unit,
+            DurationUnit::Milliseconds | DurationUnit::Microseconds | DurationUnit::Nanoseconds
+        )
+    {
+        // a. Set style to "fractional".
+        style = UnitStyle::Fractional;
+        // b. Set displayDefault to "auto".
+        display_default = Display::Auto;
+    }
+
+    // 5. Let displayField be the string-concatenation of unit and "Display".
+    // 6. Let display be ? GetOption(options, displayField, string, « "auto", "always" », displayDefault).
+    let display =
+        get_option(options, js_string!(unit.display_option()), context)?.unwrap_or(display_default);
+
+    // 7. If display is "always" and style is "fractional", then
+    if display == Display::Always && style == UnitStyle::Fractional {
+        // a. Throw a RangeError exception.
+        return Err(JsNativeError::range()
+            .with_message(format!(
+                "`{}` cannot be always displayed when formatted as a fraction",
+                unit.as_str()
+            ))
+            .into());
+    }
+
+    match prev_style {
+        // 8. If prevStyle is "fractional", then
+        Some(UnitStyle::Fractional) => {
+            // a. If style is not "fractional", then
+            if style != UnitStyle::Fractional {
+                // i. Throw a RangeError exception.
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` must be numeric when the previous unit is formatted as a fraction",
+                        unit.as_str()
+                    ))
+                    .into());
+            }
+        }
+        // 9. If prevStyle is "numeric" or "2-digit", then
+        Some(UnitStyle::Numeric | UnitStyle::TwoDigit) => {
+            // a. If style is not "fractional", "numeric" or "2-digit", then
+            if !style.is_numeric() {
+                // i. Throw a RangeError exception.
+                return Err(JsNativeError::range()
+                    .with_message(format!(
+                        "`{}` must be numeric when the previous unit is numeric",
+                        unit.as_str()
+                    ))
+                    .into());
+            }
+            // b. If unit is "minutes" or "seconds", then
+            if matches!(unit, DurationUnit::Minutes | DurationUnit::Seconds) {
+                // i. Set style to "2-digit".
+                style = UnitStyle::TwoDigit;
+            }
+        }
+        _ => {}
+    }
+
+    // 10. If unit is "hours" and twoDigitHours is true, then
+    //     a. Set style to "2-digit".
+
+    // 11. Return the Duration Unit Options Record { [[Style]]: style, [[Display]]: display }.
+    Ok(UnitOptions { style, display })
+}
+
+/// A [Duration Record][spec], with the value of each unit in table order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-duration-records
+#[derive(Debug, Default)]
+struct DurationRecord([i128; 10]);
+
+impl DurationRecord {
+    /// Abstract operation [`ToDurationRecord ( input )`][spec].
+    ///
+    /// `Temporal.Duration` objects are read through the getters of their fields, like any
+    /// other duration-like object.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-todurationrecord
+    fn from_value(input: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If input is not an Object, then
+        let Some(input) = input.as_object() else {
+            // a. If input is a String, throw a RangeError exception.
+            if input.is_string() {
+                return Err(JsNativeError::range()
+                    .with_message("duration strings are not supported")
+                    .into());
+            }
+            // b. Throw a TypeError exception.
+            return Err(JsNativeError::typ()
+                .with_message("duration must be an object")
+                .into());
+        };
+
+        // 2. Let result be a new Duration Record with each field set to 0.
+        let mut result = Self::default();
+        let mut any_defined = false;
+
+        // 3-22. For each field of the duration record, in alphabetical order, do
+        for unit in DurationUnit::ALPHABETICAL {
+            // a. Let value be ? Get(input, field).
+            let value = input.get(js_string!(unit.as_str()), context)?;
+
+            // b. If value is not undefined, set the field of result to ? ToIntegerIfIntegral(value).
+            if !value.is_undefined() {
+                any_defined = true;
+                result.0[unit as usize] = to_integer_if_integral(&value, context)?;
+            }
+        }
+
+        // 23. If years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds,
+        //     and nanoseconds are all undefined, throw a TypeError exception.
+        if !any_defined {
+            return Err(JsNativeError::typ()
+                .with_message("duration object must have at least one duration field")
+                .into());
+        }
+
+        // 24. If IsValidDuration( ... ) is false, then
+        if !result.is_valid() {
+            // a. Throw a RangeError exception.
+            return Err(JsNativeError::range()
+                .with_message("duration is out of range or has mixed signs")
+                .into());
+        }
+
+        // 25. Return result.
+        Ok(result)
+    }
+
+    /// Returns the value of `unit` in this record.
+    const fn get(&self, unit: DurationUnit) -> i128 {
+        self.0[unit as usize]
+    }
+
+    /// Abstract operation [`DurationSign ( duration )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-durationsign
+    fn sign(&self) -> i8 {
+        // 1. For each value v of « duration.[[Years]], ..., duration.[[Nanoseconds]] », do
+        //     a. If v < 0, return -1.
+        //     b. If v > 0, return 1.
+        // 2. Return 0.
+        self.0
+            .iter()
+            .find(|v| **v != 0)
+            .map_or(0, |v| if *v < 0 { -1 } else { 1 })
+    }
+
+    /// Abstract operation [`IsValidDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-isvalidduration
+    fn is_valid(&self) -> bool {
+        // 1. Let sign be DurationSign(...).
+        let sign = i128::from(self.sign());
+
+        // 2. For each value v of « years, ..., nanoseconds », do
+        //     a. If 𝔽(v) is not finite, return false.
+        //     b. If v < 0 and sign > 0, return false.
+        //     c. If v > 0 and sign < 0, return false.
+        if self.0.iter().any(|v| v.signum() != 0 && v.signum() != sign) {
+            return false;
+        }
+
+        // 3. If abs(years) ≥ 2**32, return false.
+        // 4. If abs(months) ≥ 2**32, return false.
+        // 5. If abs(weeks) ≥ 2**32, return false.
+        if [
+            DurationUnit::Years,
+            DurationUnit::Months,
+            DurationUnit::Weeks,
+        ]
+        .into_iter()
+        .any(|unit| self.get(unit).unsigned_abs() >= 1 << 32)
+        {
+            return false;
+        }
+
+        // 6. Let normalizedSeconds be days × 86,400 + hours × 3600 + minutes × 60 + seconds +
+        //    ℝ(𝔽(milliseconds)) × 10**-3 + ℝ(𝔽(microseconds)) × 10**-6 + ℝ(𝔽(nanoseconds)) × 10**-9.
+        // 7. If abs(normalizedSeconds) ≥ 2**53, return false.
+        let nanoseconds = [
+            (DurationUnit::Days, 86_400_000_000_000),
+            (DurationUnit::Hours, 3_600_000_000_000),
+            (DurationUnit::Minutes, 60_000_000_000),
+            (DurationUnit::Seconds, 1_000_000_000),
+            (DurationUnit::Milliseconds, 1_000_000),
+            (DurationUnit::Microseconds, 1_000),
+            (DurationUnit::Nanoseconds, 1),
+        ]
+        .into_iter()
+        .try_fold(0i128, |total, (unit, factor)| {
+            total.checked_add(self.get(unit).checked_mul(factor)?)
+        });
+
+        // 8. Return true.
+        nanoseconds.is_some_and(|ns| ns.unsigned_abs() < (1 << 53) * 1_000_000_000)
+    }
+
+    /// Abstract operation [`DurationToFractional ( duration, unit )`][spec].
+    ///
+    /// Returns the value of `unit` plus all smaller units as an exact decimal.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-durationtofractional
+    fn to_fractional(&self, unit: DurationUnit) -> FixedDecimal {
+        let (exponent, units): (i16, &[DurationUnit]) = match unit {
+            DurationUnit::Seconds => (9, &DurationUnit::ALL[6..]),
+            DurationUnit::Milliseconds => (6, &DurationUnit::ALL[7..]),
+            DurationUnit::Microseconds => (3, &DurationUnit::ALL[8..]),
+            _ => unreachable!("only seconds and subsecond units can have a fractional part"),
+        };
+
+        // This cannot overflow since the total of a valid duration is less than 2**53 seconds.
+        let value = units
+            .iter()
+            .fold(0i128, |total, &unit| total * 1000 + self.get(unit));
+
+        let mut value = FixedDecimal::from(value).multiplied_pow10(-exponent);
+        value.trim_end();
+        value
+    }
+}
+
+/// Abstract operation [`ToIntegerIfIntegral ( argument )`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-tointegerifintegral
+fn to_integer_if_integral(argument: &JsValue, context: &mut Context) -> JsResult<i128> {
+    // 1. Let number be ? ToNumber(argument).
+    let number = argument.to_number(context)?;
+
+    // 2. If number is not an integral Number, throw a RangeError exception.
+    if !number.is_finite() || number.fract() != 0.0 {
+        return Err(JsNativeError::range()
+            .with_message("duration fields must be integral numbers")
+            .into());
+    }
+
+    // 3. Return ℝ(number).
+    // Values that don't fit are saturated, which makes the duration invalid anyways.
+    Ok(number as i128)
+}
@@ -0,0 +1,300 @@
+use std::str::FromStr;
+
+use crate::builtins::options::ParsableOptionType;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum BaseStyle {
+    Long,
+    #[default]
+    Short,
+    Narrow,
+    Digital,
+}
+
+impl BaseStyle {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            Self::Digital => "digital",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseBaseStyleError;
+
+impl std::fmt::Display for ParseBaseStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow` or `digital`")
+    }
+}
+
+impl FromStr for BaseStyle {
+    type Err = ParseBaseStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "digital" => Ok(Self::Digital),
+            _ => Err(ParseBaseStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for BaseStyle {}
+
+/// The style used to display a single unit of a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum UnitStyle {
+    Long,
+    Short,
+    Narrow,
+    Numeric,
+    TwoDigit,
+    /// Only set internally, for subsecond units that are displayed as the fractional part of
+    /// the previous unit.
+    Fractional,
+}
+
+impl UnitStyle {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Long => "long",
+            Self::Short => "short",
+            Self::Narrow => "narrow",
+            // `fractional` is reported as `numeric` by `resolvedOptions`.
+            Self::Numeric | Self::Fractional => "numeric",
+            Self::TwoDigit => "2-digit",
+        }
+    }
+
+    /// Returns `true` if this style displays its unit as part of a `hh:mm:ss` sequence.
+    pub(crate) const fn is_numeric(self) -> bool {
+        matches!(self, Self::Numeric | Self::TwoDigit | Self::Fractional)
+    }
+}
+
+impl From<BaseStyle> for UnitStyle {
+    fn from(style: BaseStyle) -> Self {
+        match style {
+            BaseStyle::Long => Self::Long,
+            BaseStyle::Short => Self::Short,
+            BaseStyle::Narrow => Self::Narrow,
+            BaseStyle::Digital => Self::Numeric,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseUnitStyleError;
+
+impl std::fmt::Display for ParseUnitStyleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `long`, `short`, `narrow`, `numeric` or `2-digit`")
+    }
+}
+
+impl FromStr for UnitStyle {
+    type Err = ParseUnitStyleError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "long" => Ok(Self::Long),
+            "short" => Ok(Self::Short),
+            "narrow" => Ok(Self::Narrow),
+            "numeric" => Ok(Self::Numeric),
+            "2-digit" => Ok(Self::TwoDigit),
+            _ => Err(ParseUnitStyleError),
+        }
+    }
+}
+
+impl ParsableOptionType for UnitStyle {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Display {
+    Auto,
+    Always,
+}
+
+impl Display {
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ParseDisplayError;
+
+impl std::fmt::Display for ParseDisplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("provided string was not `auto` or `always`")
+    }
+}
+
+impl FromStr for Display {
+    type Err = ParseDisplayError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            _ => Err(ParseDisplayError),
+        }
+    }
+}
+
+impl ParsableOptionType for Display {}
+
+/// The units of a duration, in the order of the [`DurationFormat` units table][spec].
+///
+/// [spec]: https://tc39.es/ecma402/#table-durationformat
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DurationUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl DurationUnit {
+    /// All units, in table order.
+    pub(crate) const ALL: [Self; 10] = [
+        Self::Years,
+        Self::Months,
+        Self::Weeks,
+        Self::Days,
+        Self::Hours,
+        Self::Minutes,
+        Self::Seconds,
+        Self::Milliseconds,
+        Self::Microseconds,
+        Self::Nanoseconds,
+    ];
+
+    /// All units, in the alphabetical order used to read duration records.
+    pub(crate) const ALPHABETICAL: [Self; 10] = [
+        Self::Days,
+        Self::Hours,
+        Self::Microseconds,
+        Self::Milliseconds,
+        Self::Minutes,
+        Self::Months,
+        Self::Nanoseconds,
+        Self::Seconds,
+        Self::Weeks,
+        Self::Years,
+    ];
+
+    /// Returns the name of the option and of the duration record field of this unit.
+    pub(crate) const fn as_str(self) -> &'static str {
+        match self {
+            Self::Years => "years",
+            Self::Months => "months",
+            Self::Weeks => "weeks",
+            Self::Days => "days",
+            Self::Hours => "hours",
+            Self::Minutes => "minutes",
+            Self::Seconds => "seconds",
+            Self::Milliseconds => "milliseconds",
+            Self::Microseconds => "microseconds",
+            Self::Nanoseconds => "nanoseconds",
+        }
+    }
+
+    /// Returns the singular name of this unit, as used by the `unit` field of formatted parts.
+    pub(crate) const fn singular(self) -> &'static str {
+        match self {
+            Self::Years => "year",
+            Self::Months => "month",
+            Self::Weeks => "week",
+            Self::Days => "day",
+            Self::Hours => "hour",
+            Self::Minutes => "minute",
+            Self::Seconds => "second",
+            Self::Milliseconds => "millisecond",
+            Self::Microseconds => "microsecond",
+            Self::Nanoseconds => "nanosecond",
+        }
+    }
+
+    /// Returns the name of the display option of this unit.
+    pub(crate) const fn display_option(self) -> &'static str {
+        match self {
+            Self::Years => "yearsDisplay",
+            Self::Months => "monthsDisplay",
+            Self::Weeks => "weeksDisplay",
+            Self::Days => "daysDisplay",
+            Self::Hours => "hoursDisplay",
+            Self::Minutes => "minutesDisplay",
+            Self::Seconds => "secondsDisplay",
+            Self::Milliseconds => "millisecondsDisplay",
+            Self::Microseconds => "microsecondsDisplay",
+            Self::Nanoseconds => "nanosecondsDisplay",
+        }
+    }
+
+    /// Returns `true` if `style` is one of the styles that can be requested for this unit.
+    pub(crate) const fn allows(self, style: UnitStyle) -> bool {
+        match style {
+            UnitStyle::Long | UnitStyle::Short | UnitStyle::Narrow => true,
+            UnitStyle::Numeric => {
+                !matches!(self, Self::Years | Self::Months | Self::Weeks | Self::Days)
+            }
+            UnitStyle::TwoDigit => matches!(self, Self::Hours | Self::Minutes | Self::Seconds),
+            UnitStyle::Fractional => false,
+        }
+    }
+
+    /// Returns the style of this unit when the base style is `digital`.
+    pub(crate) const fn digital_base(self) -> UnitStyle {
+        match self {
+            Self::Years | Self::Months | Self::Weeks | Self::Days => UnitStyle::Short,
+            _ => UnitStyle::Numeric,
+        }
+    }
+
+    /// Returns the English label of this unit for the given style, choosing the singular form if
+    /// `one` is `true`.
+    ///
+    /// ICU4X doesn't provide unit display names yet, so these are used for every locale.
+    pub(crate) const fn label(self, style: UnitStyle, one: bool) -> &'static str {
+        match (style, self, one) {
+            (UnitStyle::Long, _, true) => self.singular(),
+            (UnitStyle::Long, _, false) => self.as_str(),
+            (UnitStyle::Short, Self::Years, true) => "yr",
+            (UnitStyle::Short, Self::Years, false) => "yrs",
+            (UnitStyle::Short, Self::Months, true) => "mth",
+            (UnitStyle::Short, Self::Months, false) => "mths",
+            (UnitStyle::Short, Self::Weeks, true) => "wk",
+            (UnitStyle::Short, Self::Weeks, false) => "wks",
+            (UnitStyle::Short, Self::Days, true) => "day",
+            (UnitStyle::Short, Self::Days, false) => "days",
+            (UnitStyle::Short, Self::Hours, _) => "hr",
+            (UnitStyle::Short, Self::Minutes, _) => "min",
+            (UnitStyle::Short, Self::Seconds, _) => "sec",
+            (_, Self::Years, _) => "y",
+            (_, Self::Months | Self::Minutes, _) => "m",
+            (_, Self::Weeks, _) => "w",
+            (_, Self::Days, _) => "d",
+            (_, Self::Hours, _) => "h",
+            (_, Self::Seconds, _) => "s",
+            (_, Self::Milliseconds, _) => "ms",
+            (_, Self::Microseconds, _) => "μs",
+            (_, Self::Nanoseconds, _) => "ns",
+        }
+    }
+}
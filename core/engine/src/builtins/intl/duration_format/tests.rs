@@ -0,0 +1,150 @@
+use crate::{js_str, run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').format({ hours: 1, minutes: 46, seconds: 40 })",
+            js_str!("1 hr, 46 min, 40 sec"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format({ years: 1, months: 2, days: 1 })",
+            js_str!("1 year, 2 months, 1 day"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'narrow' }).format({ hours: 1, minutes: 46 })",
+            js_str!("1h 46m"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long', secondsDisplay: 'always' }).format({ minutes: 2 })",
+            js_str!("2 minutes, 0 seconds"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { seconds: 'long', milliseconds: 'numeric' }).format({ seconds: 1, milliseconds: 500 })",
+            js_str!("1.5 seconds"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_digital() {
+    run_test_actions([
+        TestAction::run("var df = new Intl.DurationFormat('en', { style: 'digital' });"),
+        TestAction::assert_eq(
+            "df.format({ hours: 1, minutes: 2, seconds: 3, milliseconds: 400 })",
+            js_str!("1:02:03.4"),
+        ),
+        TestAction::assert_eq(
+            "df.format({ days: 1, hours: 2, minutes: 3 })",
+            js_str!("1 day, 2:03:00"),
+        ),
+        TestAction::assert_eq(
+            "df.format({ hours: -1, minutes: -2 })",
+            js_str!("-1:02:00"),
+        ),
+        TestAction::assert_eq("df.format({ minutes: -2 })", js_str!("-0:02:00")),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 2 }).format({ seconds: 5, microseconds: 7 })",
+            js_str!("0:00:05.00"),
+        ),
+    ]);
+}
+
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "function show(parts) { return parts.map(p => `${p.type}:${p.value}:${p.unit}`).join('|'); }",
+        ),
+        TestAction::assert_eq(
+            "show(new Intl.DurationFormat('en').formatToParts({ hours: 2, minutes: 1 }))",
+            js_str!(
+                "integer:2:hour|literal: :hour|unit:hr:hour|literal:, :undefined|integer:1:minute|literal: :minute|unit:min:minute"
+            ),
+        ),
+        TestAction::assert_eq(
+            "show(new Intl.DurationFormat('en', { style: 'digital' }).formatToParts({ minutes: 5 }))",
+            js_str!(
+                "integer:0:hour|literal:::undefined|integer:05:minute|literal:::undefined|integer:00:second"
+            ),
+        ),
+    ]);
+}
+
+#[test]
+fn resolved_options() {
+    run_test_actions([
+        TestAction::run(
+            "var options = new Intl.DurationFormat('en', { style: 'digital', fractionalDigits: 3 }).resolvedOptions();",
+        ),
+        TestAction::assert_eq("options.style", js_str!("digital")),
+        TestAction::assert_eq("options.days", js_str!("short")),
+        TestAction::assert_eq("options.daysDisplay", js_str!("auto")),
+        TestAction::assert_eq("options.hours", js_str!("numeric")),
+        TestAction::assert_eq("options.minutes", js_str!("2-digit")),
+        TestAction::assert_eq("options.secondsDisplay", js_str!("always")),
+        TestAction::assert_eq("options.milliseconds", js_str!("numeric")),
+        TestAction::assert_eq("options.fractionalDigits", 3),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en').resolvedOptions().fractionalDigits",
+            JsValue::undefined(),
+        ),
+    ]);
+}
+
+#[test]
+fn errors() {
+    run_test_actions([
+        TestAction::run("var df = new Intl.DurationFormat('en');"),
+        TestAction::assert_native_error(
+            "df.format('PT1H')",
+            JsNativeErrorKind::Range,
+            "duration strings are not supported",
+        ),
+        TestAction::assert_native_error(
+            "df.format(1)",
+            JsNativeErrorKind::Type,
+            "duration must be an object",
+        ),
+        TestAction::assert_native_error(
+            "df.format({})",
+            JsNativeErrorKind::Type,
+            "duration object must have at least one duration field",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1.5 })",
+            JsNativeErrorKind::Range,
+            "duration fields must be integral numbers",
+        ),
+        TestAction::assert_native_error(
+            "df.format({ hours: 1, minutes: -1 })",
+            JsNativeErrorKind::Range,
+            "duration is out of range or has mixed signs",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { hours: 'numeric', minutes: 'long' })",
+            JsNativeErrorKind::Range,
+            "`minutes` must be numeric when the previous unit is numeric",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.DurationFormat('en', { days: 'numeric' })",
+            JsNativeErrorKind::Range,
+            "`numeric` is not a valid style for `days`",
+        ),
+    ]);
+}
+
+#[cfg(feature = "temporal")]
+#[test]
+fn temporal_duration() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'long' }).format(new Temporal.Duration(1, 2, 0, 0, 3))",
+            js_str!("1 year, 2 months, 3 hours"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.DurationFormat('en', { style: 'digital' }).format(Temporal.Duration.from({ minutes: 90 }))",
+            js_str!("0:90:00"),
+        ),
+    ]);
+}
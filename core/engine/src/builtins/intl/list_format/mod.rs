@@ -265,87 +265,6 @@ impl ListFormat {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        // TODO: maybe try to move this into icu4x?
-        use writeable::{PartsWrite, Writeable};
-
-        #[derive(Debug, Clone)]
-        enum Part {
-            Literal(String),
-            Element(String),
-        }
-
-        impl Part {
-            const fn typ(&self) -> &'static str {
-                match self {
-                    Self::Literal(_) => "literal",
-                    Self::Element(_) => "element",
-                }
-            }
-
-            #[allow(clippy::missing_const_for_fn)]
-            fn value(self) -> String {
-                match self {
-                    Self::Literal(s) | Self::Element(s) => s,
-                }
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct WriteString(String);
-
-        impl Write for WriteString {
-            fn write_str(&mut self, s: &str) -> std::fmt::Result {
-                self.0.write_str(s)
-            }
-
-            fn write_char(&mut self, c: char) -> std::fmt::Result {
-                self.0.write_char(c)
-            }
-        }
-
-        impl PartsWrite for WriteString {
-            type SubPartsWrite = Self;
-
-            fn with_part(
-                &mut self,
-                _part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
-            ) -> std::fmt::Result {
-                f(self)
-            }
-        }
-
-        #[derive(Debug, Clone)]
-        struct PartsCollector(Vec<Part>);
-
-        impl Write for PartsCollector {
-            fn write_str(&mut self, _: &str) -> std::fmt::Result {
-                Ok(())
-            }
-        }
-
-        impl PartsWrite for PartsCollector {
-            type SubPartsWrite = WriteString;
-
-            fn with_part(
-                &mut self,
-                part: writeable::Part,
-                mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
-            ) -> core::fmt::Result {
-                assert!(part.category == "list");
-                let mut string = WriteString(String::new());
-                f(&mut string)?;
-                if !string.0.is_empty() {
-                    match part.value {
-                        "element" => self.0.push(Part::Element(string.0)),
-                        "literal" => self.0.push(Part::Literal(string.0)),
-                        _ => unreachable!(),
-                    };
-                }
-                Ok(())
-            }
-        }
-
         // 1. Let lf be the this value.
         // 2. Perform ? RequireInternalSlot(lf, [[InitializedListFormat]]).
         let lf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
@@ -369,10 +288,7 @@ impl ListFormat {
         // https://tc39.es/ecma402/#sec-formatlisttoparts
 
         // 1. Let parts be ! CreatePartsFromList(listFormat, list).
-        let mut parts = PartsCollector(Vec::new());
-        lf.native
-            .format(strings)
-            .write_to_parts(&mut parts)
+        let parts = create_parts_from_list(&lf.native, strings)
             .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
 
         // 2. Let result be ! ArrayCreate(0).
@@ -381,7 +297,7 @@ impl ListFormat {
 
         // 3. Let n be 0.
         // 4. For each Record { [[Type]], [[Value]] } part in parts, do
-        for (n, part) in parts.0.into_iter().enumerate() {
+        for (n, (typ, value)) in parts.into_iter().enumerate() {
             // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
             let o = context
                 .intrinsics()
@@ -390,11 +306,11 @@ impl ListFormat {
                 .create(OrdinaryObject, vec![]);
 
             // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
-            o.create_data_property_or_throw(js_string!("type"), js_string!(part.typ()), context)
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
                 .expect("operation must not fail per the spec");
 
             // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
-            o.create_data_property_or_throw(js_string!("value"), js_string!(part.value()), context)
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
                 .expect("operation must not fail per the spec");
 
             // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(n), O).
@@ -479,6 +395,80 @@ impl ListFormat {
     }
 }
 
+/// Abstract operation [`CreatePartsFromList ( listFormat, list )`][spec]
+///
+/// Returns the `"element"` and `"literal"` parts of the formatted `list`.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-createpartsfromlist
+pub(crate) fn create_parts_from_list(
+    formatter: &ListFormatter,
+    list: impl Iterator<Item = String> + Clone,
+) -> Result<Vec<(&'static str, String)>, std::fmt::Error> {
+    // TODO: maybe try to move this into icu4x?
+    use writeable::{PartsWrite, Writeable};
+
+    #[derive(Debug, Clone)]
+    struct WriteString(String);
+
+    impl Write for WriteString {
+        fn write_str(&mut self, s: &str) -> std::fmt::Result {
+            self.0.write_str(s)
+        }
+
+        fn write_char(&mut self, c: char) -> std::fmt::Result {
+            self.0.write_char(c)
+        }
+    }
+
+    impl PartsWrite for WriteString {
+        type SubPartsWrite = Self;
+
+        fn with_part(
+            &mut self,
+            _part: writeable::Part,
+            mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+        ) -> std::fmt::Result {
+            f(self)
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct PartsCollector(Vec<(&'static str, String)>);
+
+    impl Write for PartsCollector {
+        fn write_str(&mut self, _: &str) -> std::fmt::Result {
+            Ok(())
+        }
+    }
+
+    impl PartsWrite for PartsCollector {
+        type SubPartsWrite = WriteString;
+
+        fn with_part(
+            &mut self,
+            part: writeable::Part,
+            mut f: impl FnMut(&mut Self::SubPartsWrite) -> core::fmt::Result,
+        ) -> core::fmt::Result {
+            assert!(part.category == "list");
+            let mut string = WriteString(String::new());
+            f(&mut string)?;
+            if !string.0.is_empty() {
+                match part.value {
+                    "element" => self.0.push(("element", string.0)),
+                    "literal" => self.0.push(("literal", string.0)),
+                    _ => unreachable!(),
+                }
+            }
+            Ok(())
+        }
+    }
+
+    let mut parts = PartsCollector(Vec::new());
+    formatter.format(list).write_to_parts(&mut parts)?;
+
+    Ok(parts.0)
+}
+
 /// Abstract operation [`StringListFromIterable ( iterable )`][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-createstringlistfromiterable
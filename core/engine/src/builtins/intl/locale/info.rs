@@ -0,0 +1,511 @@
+//! Locale data used by `Intl.supportedValuesOf` and the locale information methods of
+//! `Intl.Locale`.
+//!
+//! ICU4X cannot enumerate the locales or the extension values available in a data provider, so
+//! the candidate values of this module come from the CLDR tables, and are only reported as
+//! supported if the provider can actually load data for them.
+
+use icu_calendar::{types::IsoWeekday, week::WeekCalculator, AnyCalendarKind};
+use icu_collator::provider::CollationMetadataV1Marker;
+use icu_datetime::options::preferences::HourCycle;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_locid::{
+    extensions::unicode::Value, extensions_unicode_key as key, LanguageIdentifier, Locale,
+};
+use icu_locid_transform::{Direction, LocaleDirectionality};
+use icu_provider::{DataLocale, DataProvider, DataRequest, DataRequestMetadata, DataResponse};
+use icu_timezone::provider::names::IanaToBcp47MapV2Marker;
+
+use crate::{
+    builtins::intl::date_time_format::{default_hour_cycle, supported_calendar, TimeZone},
+    context::{icu::IntlProvider, HostHooks},
+    JsNativeError, JsResult,
+};
+
+use super::validate_extension;
+
+/// Collation types with the locales that tailor them, excluding `standard` and `search`.
+const COLLATIONS: &[(&str, &[&str])] = &[
+    ("big5han", &["zh"]),
+    ("compat", &["ar"]),
+    ("dict", &["si"]),
+    ("emoji", &["und"]),
+    ("eor", &["und"]),
+    ("gb2312", &["zh"]),
+    ("phonebk", &["de"]),
+    ("phonetic", &["ln"]),
+    ("pinyin", &["zh", "zh-Hant"]),
+    ("searchjl", &["ko"]),
+    ("stroke", &["zh", "zh-Hant"]),
+    ("trad", &["es"]),
+    ("unihan", &["zh", "ja", "ko"]),
+    ("zhuyin", &["zh", "zh-Hant"]),
+];
+
+/// Numbering systems with simple digit mappings, with their zero digit and the locales that
+/// use them natively.
+const NUMBERING_SYSTEMS: &[(&str, char, &[&str])] = &[
+    ("adlm", '\u{1E950}', &["ff-Adlm"]),
+    ("arab", '\u{0660}', &["ar"]),
+    ("arabext", '\u{06F0}', &["fa"]),
+    ("beng", '\u{09E6}', &["bn"]),
+    ("cakm", '\u{11136}', &["ccp"]),
+    ("deva", '\u{0966}', &["hi", "mr", "ne"]),
+    ("gujr", '\u{0AE6}', &["gu"]),
+    ("guru", '\u{0A66}', &["pa"]),
+    ("hanidec", '\u{3007}', &["zh", "yue"]),
+    ("hmnp", '\u{1E140}', &["hnj"]),
+    ("java", '\u{A9D0}', &["jv"]),
+    ("khmr", '\u{17E0}', &["km"]),
+    ("knda", '\u{0CE6}', &["kn"]),
+    ("laoo", '\u{0ED0}', &["lo"]),
+    ("latn", '0', &["en"]),
+    ("mlym", '\u{0D66}', &["ml"]),
+    ("mong", '\u{1810}', &["mn-Mong"]),
+    ("mtei", '\u{ABF0}', &["mni"]),
+    ("mymr", '\u{1040}', &["my"]),
+    ("nkoo", '\u{07C0}', &["nqo"]),
+    ("olck", '\u{1C50}', &["sat"]),
+    ("orya", '\u{0B66}', &["or"]),
+    ("rohg", '\u{10D30}', &["rhg"]),
+    ("sund", '\u{1BB0}', &["su"]),
+    ("tamldec", '\u{0BE6}', &["ta"]),
+    ("telu", '\u{0C66}', &["te"]),
+    ("thai", '\u{0E50}', &["th"]),
+    ("tibt", '\u{0F20}', &["dz", "bo"]),
+    ("vaii", '\u{A620}', &["vai"]),
+];
+
+/// The CLDR calendar preferences of the regions, in order of preference and restricted to the
+/// calendars known by ICU4X. Regions not in this table only use the Gregorian calendar.
+const CALENDAR_PREFERENCES: &[(&[&str], &[&str])] = &[
+    (
+        &[
+            "BD", "DJ", "DZ", "EH", "ER", "ID", "IQ", "JO", "KM", "LB", "LY", "MA", "MR", "MY",
+            "NE", "OM", "PK", "PS", "SD", "SY", "TD", "TN", "YE",
+        ],
+        &["gregory", "islamic", "islamic-civil", "islamic-tbla"],
+    ),
+    (
+        &["AL", "AZ", "MV", "TJ", "TM", "TR", "UZ", "XK"],
+        &["gregory", "islamic-civil", "islamic-tbla"],
+    ),
+    (
+        &["AE", "BH", "KW", "QA"],
+        &[
+            "gregory",
+            "islamic-umalqura",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (
+        &["AF", "IR"],
+        &[
+            "persian",
+            "gregory",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["CN", "CX", "HK", "MO", "SG"], &["gregory", "chinese"]),
+    (
+        &["EG"],
+        &[
+            "gregory",
+            "coptic",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["ET"], &["gregory", "ethiopic"]),
+    (
+        &["IL"],
+        &[
+            "gregory",
+            "hebrew",
+            "islamic",
+            "islamic-civil",
+            "islamic-tbla",
+        ],
+    ),
+    (&["IN"], &["gregory", "indian"]),
+    (&["JP"], &["gregory", "japanese"]),
+    (&["KR"], &["gregory", "dangi"]),
+    (&["SA"], &["islamic-umalqura", "gregory", "islamic"]),
+    (&["TH"], &["buddhist", "gregory"]),
+    (&["TW"], &["gregory", "roc"]),
+];
+
+/// All the calendars known by ICU4X.
+const CALENDARS: [AnyCalendarKind; 18] = [
+    AnyCalendarKind::Buddhist,
+    AnyCalendarKind::Chinese,
+    AnyCalendarKind::Coptic,
+    AnyCalendarKind::Dangi,
+    AnyCalendarKind::Ethiopian,
+    AnyCalendarKind::EthiopianAmeteAlem,
+    AnyCalendarKind::Gregorian,
+    AnyCalendarKind::Hebrew,
+    AnyCalendarKind::Indian,
+    AnyCalendarKind::IslamicCivil,
+    AnyCalendarKind::IslamicObservational,
+    AnyCalendarKind::IslamicTabular,
+    AnyCalendarKind::IslamicUmmAlQura,
+    AnyCalendarKind::Iso,
+    AnyCalendarKind::Japanese,
+    AnyCalendarKind::JapaneseExtended,
+    AnyCalendarKind::Persian,
+    AnyCalendarKind::Roc,
+];
+
+/// Parses the locales of a candidate table.
+fn parse_locales<'a>(locales: &'a [&'a str]) -> impl Iterator<Item = LanguageIdentifier> + 'a {
+    locales
+        .iter()
+        .filter_map(|loc| LanguageIdentifier::try_from_bytes(loc.as_bytes()).ok())
+}
+
+/// Checks if the numbering system with the provided zero digit is used to format numbers in
+/// `locale` when requested with the `nu` extension key.
+fn formats_with_digits(
+    locale: LanguageIdentifier,
+    nu: &str,
+    zero: char,
+    provider: &IntlProvider,
+) -> bool {
+    let Ok(nu) = Value::try_from_bytes(nu.as_bytes()) else {
+        return false;
+    };
+    let mut locale = DataLocale::from(locale);
+    locale.set_unicode_ext(key!("nu"), nu);
+
+    DataProvider::<DecimalSymbolsV1Marker>::load(
+        provider,
+        DataRequest {
+            locale: &locale,
+            metadata: DataRequestMetadata::default(),
+        },
+    )
+    .and_then(DataResponse::take_payload)
+    .is_ok_and(|payload| payload.get().digits[0] == zero)
+}
+
+/// Abstract operation [`AvailableCanonicalCalendars ( )`][spec].
+///
+/// Returns the calendars supported by `Intl.DateTimeFormat`, sorted by code unit order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcalendars
+pub(in crate::builtins::intl) fn available_calendars(provider: &IntlProvider) -> Vec<String> {
+    let mut calendars = CALENDARS
+        .into_iter()
+        .filter_map(|kind| supported_calendar(&kind.as_bcp47_value(), provider))
+        .map(|ca| ca.to_string())
+        .collect::<Vec<_>>();
+    calendars.sort_unstable();
+    calendars
+}
+
+/// Abstract operation [`AvailableCanonicalCollations ( )`][spec].
+///
+/// Returns the collation types supported by `Intl.Collator`, sorted by code unit order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalcollations
+pub(in crate::builtins::intl) fn available_collations(provider: &IntlProvider) -> Vec<String> {
+    COLLATIONS
+        .iter()
+        .filter(|(co, locales)| {
+            let Ok(co) = Value::try_from_bytes(co.as_bytes()) else {
+                return false;
+            };
+            parse_locales(locales).any(|locale| {
+                validate_extension::<CollationMetadataV1Marker>(locale, key!("co"), &co, provider)
+            })
+        })
+        .map(|(co, _)| (*co).to_string())
+        .collect()
+}
+
+/// Abstract operation [`AvailableCanonicalNumberingSystems ( )`][spec].
+///
+/// Returns the numbering systems with digit data in the provider, sorted by code unit order.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availablecanonicalnumberingsystems
+pub(in crate::builtins::intl) fn available_numbering_systems(
+    provider: &IntlProvider,
+) -> Vec<String> {
+    NUMBERING_SYSTEMS
+        .iter()
+        .filter(|(nu, zero, locales)| {
+            parse_locales(locales).any(|locale| formats_with_digits(locale, nu, *zero, provider))
+        })
+        .map(|(nu, _, _)| (*nu).to_string())
+        .collect()
+}
+
+/// Gets the primary identifiers of the time zones whose BCP-47 identifier satisfies `filter`,
+/// sorted by code unit order.
+///
+/// Named time zones are only returned if the host can compute their offsets, which matches the
+/// time zones accepted by `Intl.DateTimeFormat`.
+fn primary_time_zones(
+    provider: &IntlProvider,
+    hooks: &dyn HostHooks,
+    filter: impl Fn(&str) -> bool,
+) -> JsResult<Vec<String>> {
+    let mapper = provider.time_zone_id_mapper()?.as_borrowed();
+    let ids = DataProvider::<IanaToBcp47MapV2Marker>::load(provider, DataRequest::default())
+        .and_then(DataResponse::take_payload)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    let now = hooks.utc_now() / 1000;
+    let mut zones = ids
+        .get()
+        .bcp47_ids
+        .iter()
+        // `unk` is the placeholder zone of `Etc/Unknown`, which is not a valid time zone.
+        .filter(|id| id.0.as_str() != "unk" && filter(id.0.as_str()))
+        .filter_map(|id| mapper.find_canonical_iana_from_bcp47(id))
+        .filter_map(|name| {
+            if let Some(time_zone) = TimeZone::from_fixed_identifier(&name) {
+                return Some(time_zone.identifier().to_std_string_escaped());
+            }
+            hooks.time_zone_offset_seconds(&name, now).map(|_| name)
+        })
+        .collect::<Vec<_>>();
+    zones.sort_unstable();
+    zones.dedup();
+    Ok(zones)
+}
+
+/// Abstract operation [`AvailablePrimaryTimeZoneIdentifiers ( )`][spec].
+///
+/// Returns the time zones of the provider's time zone data, keeping only the named time zones
+/// supported by the host.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-availableprimarytimezoneidentifiers
+pub(in crate::builtins::intl) fn available_time_zones(
+    provider: &IntlProvider,
+    hooks: &dyn HostHooks,
+) -> JsResult<Vec<String>> {
+    primary_time_zones(provider, hooks, |_| true)
+}
+
+/// Abstract operation [`CalendarsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-calendars-of-locale
+pub(in crate::builtins::intl) fn calendars_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Vec<String>> {
+    if let Some(ca) = locale.extensions.unicode.keywords.get(&key!("ca")) {
+        return Ok(vec![ca.to_string()]);
+    }
+
+    // Calendar preferences are keyed by region, so the likely region is added if the locale
+    // doesn't have one.
+    let mut id = locale.id.clone();
+    provider.locale_expander()?.maximize(&mut id);
+
+    let preferences = id
+        .region
+        .and_then(|region| {
+            CALENDAR_PREFERENCES
+                .iter()
+                .find(|(regions, _)| regions.contains(&region.as_str()))
+        })
+        .map_or(&["gregory"][..], |(_, calendars)| calendars);
+
+    let mut calendars = Vec::with_capacity(preferences.len());
+    for ca in preferences {
+        let Some(ca) = Value::try_from_bytes(ca.as_bytes())
+            .ok()
+            .and_then(|ca| supported_calendar(&ca, provider))
+            .map(|ca| ca.to_string())
+        else {
+            continue;
+        };
+        if !calendars.contains(&ca) {
+            calendars.push(ca);
+        }
+    }
+
+    if calendars.is_empty() {
+        calendars.push(String::from("gregory"));
+    }
+    Ok(calendars)
+}
+
+/// Abstract operation [`CollationsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-collations-of-locale
+pub(in crate::builtins::intl) fn collations_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> Vec<String> {
+    if let Some(co) = locale.extensions.unicode.keywords.get(&key!("co")) {
+        return vec![co.to_string()];
+    }
+
+    COLLATIONS
+        .iter()
+        .filter(|(co, _)| {
+            Value::try_from_bytes(co.as_bytes()).is_ok_and(|co| {
+                validate_extension::<CollationMetadataV1Marker>(
+                    locale.id.clone(),
+                    key!("co"),
+                    &co,
+                    provider,
+                )
+            })
+        })
+        .map(|(co, _)| (*co).to_string())
+        .collect()
+}
+
+/// Abstract operation [`HourCyclesOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-hour-cycles-of-locale
+pub(in crate::builtins::intl) fn hour_cycles_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> Vec<String> {
+    if let Some(hc) = locale.extensions.unicode.keywords.get(&key!("hc")) {
+        return vec![hc.to_string()];
+    }
+
+    let hc = match default_hour_cycle(provider, &DataLocale::from(&locale.id)) {
+        HourCycle::H11 => "h11",
+        HourCycle::H12 => "h12",
+        HourCycle::H23 => "h23",
+        HourCycle::H24 => "h24",
+    };
+    vec![hc.to_string()]
+}
+
+/// Abstract operation [`NumberingSystemsOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-numbering-systems-of-locale
+pub(in crate::builtins::intl) fn numbering_systems_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> Vec<String> {
+    if let Some(nu) = locale.extensions.unicode.keywords.get(&key!("nu")) {
+        return vec![nu.to_string()];
+    }
+
+    let zero = DataProvider::<DecimalSymbolsV1Marker>::load(
+        provider,
+        DataRequest {
+            locale: &DataLocale::from(&locale.id),
+            metadata: DataRequestMetadata::default(),
+        },
+    )
+    .and_then(DataResponse::take_payload)
+    .map_or('0', |payload| payload.get().digits[0]);
+
+    let nu = NUMBERING_SYSTEMS
+        .iter()
+        .find(|(_, z, _)| *z == zero)
+        .map_or("latn", |(nu, _, _)| *nu);
+    vec![nu.to_string()]
+}
+
+/// Abstract operation [`TimeZonesOfLocale ( loc )`][spec].
+///
+/// Returns `None` if the locale doesn't have a region.
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-time-zones-of-locale
+pub(in crate::builtins::intl) fn time_zones_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+    hooks: &dyn HostHooks,
+) -> JsResult<Option<Vec<String>>> {
+    let Some(region) = locale.id.region else {
+        return Ok(None);
+    };
+
+    // The BCP-47 identifiers of the time zones of a region are the lowercase region code followed
+    // by a location code, which excludes the three-letter `gmt` and `utc` zones.
+    let region = region.as_str().to_ascii_lowercase();
+    primary_time_zones(provider, hooks, |id| {
+        id.len() > 3 && id.starts_with(&region)
+    })
+    .map(Some)
+}
+
+/// Abstract operation [`TextDirectionOfLocale ( loc )`][spec].
+///
+/// Returns `None` if the direction of the locale's script is unknown.
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-text-direction-of-locale
+pub(in crate::builtins::intl) fn text_direction_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<Option<&'static str>> {
+    let directionality = LocaleDirectionality::try_new_unstable(provider)
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    Ok(match directionality.get(&locale.id) {
+        Some(Direction::LeftToRight) => Some("ltr"),
+        Some(Direction::RightToLeft) => Some("rtl"),
+        _ => None,
+    })
+}
+
+/// The week data of a locale, as returned by `Intl.Locale.prototype.getWeekInfo`.
+#[derive(Debug)]
+pub(in crate::builtins::intl) struct WeekInfo {
+    pub(in crate::builtins::intl) first_day: u8,
+    pub(in crate::builtins::intl) weekend: Vec<u8>,
+    pub(in crate::builtins::intl) minimal_days: u8,
+}
+
+/// Abstract operation [`WeekInfoOfLocale ( loc )`][spec].
+///
+/// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-week-info-of-locale
+pub(in crate::builtins::intl) fn week_info_of_locale(
+    locale: &Locale,
+    provider: &IntlProvider,
+) -> JsResult<WeekInfo> {
+    // Week data is keyed by region, so the likely region is added if the locale doesn't have one.
+    let mut id = locale.id.clone();
+    provider.locale_expander()?.maximize(&mut id);
+
+    let calculator = WeekCalculator::try_new_unstable(provider, &DataLocale::from(&id))
+        .map_err(|e| JsNativeError::typ().with_message(e.to_string()))?;
+
+    // The `fw` extension key overrides the first day of the week of the locale.
+    let first_day = locale
+        .extensions
+        .unicode
+        .keywords
+        .get(&key!("fw"))
+        .and_then(Value::as_single_subtag)
+        .and_then(|fw| match &**fw {
+            "mon" => Some(IsoWeekday::Monday),
+            "tue" => Some(IsoWeekday::Tuesday),
+            "wed" => Some(IsoWeekday::Wednesday),
+            "thu" => Some(IsoWeekday::Thursday),
+            "fri" => Some(IsoWeekday::Friday),
+            "sat" => Some(IsoWeekday::Saturday),
+            "sun" => Some(IsoWeekday::Sunday),
+            _ => None,
+        })
+        .unwrap_or(calculator.first_weekday);
+
+    let mut weekend = calculator
+        .weekend()
+        .map(|day| day as u8)
+        .collect::<Vec<_>>();
+    weekend.sort_unstable();
+
+    Ok(WeekInfo {
+        first_day: first_day as u8,
+        weekend,
+        minimal_days: calculator.min_week_days,
+    })
+}
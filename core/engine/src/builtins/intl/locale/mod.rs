@@ -12,10 +12,14 @@ mod tests;
 mod utils;
 pub(crate) use utils::*;
 
+pub(super) mod info;
+
 mod options;
 
 use crate::{
-    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    builtins::{
+        Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
+    },
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     js_string,
     object::{internal_methods::get_prototype_from_constructor, JsObject},
@@ -82,6 +86,17 @@ impl IntrinsicObject for Locale {
             .method(Self::maximize, js_string!("maximize"), 0)
             .method(Self::minimize, js_string!("minimize"), 0)
             .method(Self::to_string, js_string!("toString"), 0)
+            .method(Self::get_calendars, js_string!("getCalendars"), 0)
+            .method(Self::get_collations, js_string!("getCollations"), 0)
+            .method(Self::get_hour_cycles, js_string!("getHourCycles"), 0)
+            .method(
+                Self::get_numbering_systems,
+                js_string!("getNumberingSystems"),
+                0,
+            )
+            .method(Self::get_time_zones, js_string!("getTimeZones"), 0)
+            .method(Self::get_text_info, js_string!("getTextInfo"), 0)
+            .method(Self::get_week_info, js_string!("getWeekInfo"), 0)
             .accessor(
                 js_string!("baseName"),
                 Some(base_name),
@@ -156,7 +171,7 @@ impl BuiltInObject for Locale {
 
 impl BuiltInConstructor for Locale {
     const LENGTH: usize = 1;
-    const P: usize = 14;
+    const P: usize = 21;
     const SP: usize = 0;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -483,6 +498,283 @@ impl Locale {
         Ok(js_string!(loc.to_string()).into())
     }
 
+    /// [`Intl.Locale.prototype.getCalendars ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCalendars
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCalendars
+    pub(crate) fn get_calendars(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCalendars` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getCalendars` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return CalendarsOfLocale(loc).
+        let list = info::calendars_of_locale(&loc, context.intl_provider())?;
+        Ok(JsValue::new(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )))
+    }
+
+    /// [`Intl.Locale.prototype.getCollations ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getCollations
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getCollations
+    pub(crate) fn get_collations(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getCollations` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getCollations` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return CollationsOfLocale(loc).
+        let list = info::collations_of_locale(&loc, context.intl_provider());
+        Ok(JsValue::new(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )))
+    }
+
+    /// [`Intl.Locale.prototype.getHourCycles ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getHourCycles
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getHourCycles
+    pub(crate) fn get_hour_cycles(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getHourCycles` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getHourCycles` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return HourCyclesOfLocale(loc).
+        let list = info::hour_cycles_of_locale(&loc, context.intl_provider());
+        Ok(JsValue::new(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )))
+    }
+
+    /// [`Intl.Locale.prototype.getNumberingSystems ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getNumberingSystems
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getNumberingSystems
+    pub(crate) fn get_numbering_systems(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getNumberingSystems` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Return NumberingSystemsOfLocale(loc).
+        let list = info::numbering_systems_of_locale(&loc, context.intl_provider());
+        Ok(JsValue::new(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )))
+    }
+
+    /// [`Intl.Locale.prototype.getTimeZones ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTimeZones
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTimeZones
+    pub(crate) fn get_time_zones(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTimeZones` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getTimeZones` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Let locale be loc.[[Locale]].
+        // 4. If the unicode_language_id production of locale does not contain the ["-" unicode_region_subtag] sequence, return undefined.
+        // 5. Return TimeZonesOfLocale(loc).
+        let Some(list) =
+            info::time_zones_of_locale(&loc, context.intl_provider(), context.host_hooks())?
+        else {
+            return Ok(JsValue::undefined());
+        };
+        Ok(JsValue::new(Array::create_array_from_list(
+            list.into_iter().map(|value| js_string!(value).into()),
+            context,
+        )))
+    }
+
+    /// [`Intl.Locale.prototype.getTextInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getTextInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getTextInfo
+    pub(crate) fn get_text_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getTextInfo` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getTextInfo` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let dir be TextDirectionOfLocale(loc).
+        let dir = info::text_direction_of_locale(&loc, context.intl_provider())?
+            .map(|dir| JsValue::from(js_string!(dir)))
+            .unwrap_or_default();
+
+        // 5. Perform ! CreateDataPropertyOrThrow(info, "direction", dir).
+        info.create_data_property_or_throw(js_string!("direction"), dir, context)
+            .expect("operation must not fail per the spec");
+
+        // 6. Return info.
+        Ok(info.into())
+    }
+
+    /// [`Intl.Locale.prototype.getWeekInfo ( )`][spec].
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-intl-locale-info/#sec-Intl.Locale.prototype.getWeekInfo
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Locale/getWeekInfo
+    pub(crate) fn get_week_info(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let loc be the this value.
+        // 2. Perform ? RequireInternalSlot(loc, [[InitializedLocale]]).
+        let loc = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("`getWeekInfo` can only be called on a `Locale` object")
+        })?;
+        let loc = loc
+            .downcast_ref::<icu_locid::Locale>()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("`getWeekInfo` can only be called on a `Locale` object")
+            })?
+            .clone();
+
+        // 3. Let info be OrdinaryObjectCreate(%Object.prototype%).
+        let info = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. Let wi be WeekInfoOfLocale(loc).
+        let wi = info::week_info_of_locale(&loc, context.intl_provider())?;
+
+        // 5. Let we be CreateArrayFromList(wi.[[Weekend]]).
+        let we = Array::create_array_from_list(wi.weekend.into_iter().map(JsValue::from), context);
+
+        // 6. Perform ! CreateDataPropertyOrThrow(info, "firstDay", wi.[[FirstDay]]).
+        info.create_data_property_or_throw(js_string!("firstDay"), wi.first_day, context)
+            .expect("operation must not fail per the spec");
+
+        // 7. Perform ! CreateDataPropertyOrThrow(info, "weekend", we).
+        info.create_data_property_or_throw(js_string!("weekend"), we, context)
+            .expect("operation must not fail per the spec");
+
+        // 8. Perform ! CreateDataPropertyOrThrow(info, "minimalDays", wi.[[MinimalDays]]).
+        info.create_data_property_or_throw(js_string!("minimalDays"), wi.minimal_days, context)
+            .expect("operation must not fail per the spec");
+
+        // 9. Return info.
+        Ok(info.into())
+    }
+
     /// [`get Intl.Locale.prototype.baseName`][spec].
     ///
     /// More information:
@@ -16,6 +16,7 @@ use crate::{
         Service,
     },
     context::icu::IntlProvider,
+    js_str, run_test_actions, JsNativeErrorKind, JsValue, TestAction,
 };
 
 #[derive(Debug)]
@@ -112,3 +113,104 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("es-AR")], &mut options, &provider).unwrap();
     assert_eq!(locale, "es-u-hc-h23".parse().unwrap());
 }
+
+#[test]
+fn supported_values_of() {
+    run_test_actions([
+        TestAction::assert("Intl.supportedValuesOf('calendar').includes('gregory')"),
+        TestAction::assert("Intl.supportedValuesOf('numberingSystem').includes('latn')"),
+        TestAction::assert("Intl.supportedValuesOf('unit').includes('kilometer')"),
+        TestAction::assert_eq(
+            "Intl.supportedValuesOf('currency').slice(0, 3).join()",
+            js_str!("AED,AFN,ALL"),
+        ),
+        TestAction::assert(
+            "Intl.supportedValuesOf('currency').every(c => new Intl.NumberFormat('en', { style: 'currency', currency: c }).resolvedOptions().currency === c)",
+        ),
+        TestAction::assert_eq(
+            "Intl.supportedValuesOf('timeZone').slice(0, 3).join()",
+            js_str!("Africa/Abidjan,Africa/Accra,Africa/Addis_Ababa"),
+        ),
+        TestAction::assert_eq("Intl.supportedValuesOf('timeZone').at(-1)", js_str!("UTC")),
+        TestAction::assert(
+            "['America/New_York', 'Asia/Kolkata', 'Etc/GMT+5'].every(z => Intl.supportedValuesOf('timeZone').includes(z))",
+        ),
+        TestAction::assert(
+            "Intl.supportedValuesOf('timeZone').every(z => new Intl.DateTimeFormat('en', { timeZone: z }).resolvedOptions().timeZone === z)",
+        ),
+        TestAction::assert_native_error(
+            "Intl.supportedValuesOf('locale')",
+            JsNativeErrorKind::Range,
+            "invalid key `locale` for `Intl.supportedValuesOf`",
+        ),
+    ]);
+}
+
+#[test]
+fn locale_info() {
+    run_test_actions([
+        TestAction::assert_eq(
+            "new Intl.Locale('en').getCalendars().join()",
+            js_str!("gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-u-ca-buddhist').getCalendars().join()",
+            js_str!("buddhist"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('th').getCalendars().join()",
+            js_str!("buddhist,gregory"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('ja-JP').getCalendars().join()",
+            js_str!("gregory,japanese"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-US').getHourCycles().join()",
+            js_str!("h12"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('de').getHourCycles().join()",
+            js_str!("h23"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en').getNumberingSystems().join()",
+            js_str!("latn"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('hi', { numberingSystem: 'deva' }).getNumberingSystems().join()",
+            js_str!("deva"),
+        ),
+        TestAction::assert_eq("new Intl.Locale('en').getTimeZones()", JsValue::undefined()),
+        TestAction::assert_eq(
+            "new Intl.Locale('de-DE').getTimeZones().join()",
+            js_str!("Europe/Berlin,Europe/Busingen"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-GM').getTimeZones().join()",
+            js_str!("Africa/Banjul"),
+        ),
+        TestAction::assert("new Intl.Locale('en-US').getTimeZones().includes('America/New_York')"),
+        TestAction::assert_eq(
+            "new Intl.Locale('en').getTextInfo().direction",
+            js_str!("ltr"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.Locale('ar').getTextInfo().direction",
+            js_str!("rtl"),
+        ),
+        TestAction::run("var info = new Intl.Locale('en-US').getWeekInfo();"),
+        TestAction::assert_eq("info.firstDay", 7),
+        TestAction::assert_eq("info.weekend.join()", js_str!("6,7")),
+        TestAction::assert_eq("info.minimalDays", 1),
+        TestAction::assert_eq(
+            "new Intl.Locale('en-US-u-fw-mon').getWeekInfo().firstDay",
+            1,
+        ),
+        TestAction::assert_native_error(
+            "Intl.Locale.prototype.getWeekInfo.call({})",
+            JsNativeErrorKind::Type,
+            "`getWeekInfo` can only be called on a `Locale` object",
+        ),
+    ]);
+}
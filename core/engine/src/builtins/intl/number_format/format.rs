@@ -0,0 +1,351 @@
+//! The formatted output of `Intl.NumberFormat`, annotated with the parts of the number.
+
+use std::fmt::{self, Write};
+
+use fixed_decimal::Sign;
+use icu_decimal::{
+    options::GroupingStrategy,
+    provider::{DecimalSymbolsV1, GroupingSizesV1},
+};
+use icu_experimental::dimension::provider::{
+    currency::{CurrencyEssentialsV1, PatternSelection, PlaceholderValue},
+    percent::PercentEssentialsV1,
+};
+use tinystr::TinyAsciiStr;
+use writeable::{Part, PartsWrite, Writeable};
+
+use super::{CurrencyDisplay, IntlMathematicalValue};
+
+/// The category of the parts written by a [`FormattedNumber`].
+const CATEGORY: &str = "ecma402/number";
+
+pub(super) const INTEGER: Part = Part {
+    category: CATEGORY,
+    value: "integer",
+};
+pub(super) const GROUP: Part = Part {
+    category: CATEGORY,
+    value: "group",
+};
+pub(super) const DECIMAL: Part = Part {
+    category: CATEGORY,
+    value: "decimal",
+};
+pub(super) const FRACTION: Part = Part {
+    category: CATEGORY,
+    value: "fraction",
+};
+pub(super) const MINUS_SIGN: Part = Part {
+    category: CATEGORY,
+    value: "minusSign",
+};
+pub(super) const PLUS_SIGN: Part = Part {
+    category: CATEGORY,
+    value: "plusSign",
+};
+pub(super) const PERCENT_SIGN: Part = Part {
+    category: CATEGORY,
+    value: "percentSign",
+};
+pub(super) const CURRENCY: Part = Part {
+    category: CATEGORY,
+    value: "currency",
+};
+pub(super) const NAN: Part = Part {
+    category: CATEGORY,
+    value: "nan",
+};
+pub(super) const INFINITY: Part = Part {
+    category: CATEGORY,
+    value: "infinity",
+};
+
+/// The `nan` symbol of the root locale.
+///
+/// ICU4X doesn't provide the symbols of the non-finite numbers yet. The root values are shared
+/// by almost every locale, and the sign and style patterns of the locale still apply to them.
+const NAN_SYMBOL: &str = "NaN";
+
+/// The `infinity` symbol of the root locale.
+const INFINITY_SYMBOL: &str = "\u{221e}";
+
+/// The pattern of the style of a [`FormattedNumber`], together with its locale data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum StylePattern<'a> {
+    Decimal,
+    Percent(&'a PercentEssentialsV1<'a>),
+    Currency {
+        essentials: &'a CurrencyEssentialsV1<'a>,
+        currency: TinyAsciiStr<3>,
+        display: CurrencyDisplay,
+    },
+}
+
+/// A number formatted by [`NumberFormat::format`][super::NumberFormat::format].
+///
+/// ICU4X doesn't annotate the output of its `FixedDecimalFormatter`, so this writes the number
+/// from the decimal symbols of the locale, tagging every part with its `[[Type]]`.
+#[derive(Debug)]
+pub(crate) struct FormattedNumber<'a> {
+    pub(super) value: &'a IntlMathematicalValue,
+    pub(super) grouping: GroupingStrategy,
+    pub(super) symbols: &'a DecimalSymbolsV1<'a>,
+    pub(super) pattern: StylePattern<'a>,
+}
+
+impl FormattedNumber<'_> {
+    /// Returns the `[[Type]]` and `[[Value]]` of every part of the formatted number.
+    pub(crate) fn to_parts(&self) -> Vec<(&'static str, String)> {
+        let mut collector = PartsCollector {
+            parts: Vec::new(),
+            typ: "literal",
+        };
+        self.write_to_parts(&mut collector)
+            .expect("collecting the parts of a number cannot fail");
+        collector.parts
+    }
+}
+
+impl Writeable for FormattedNumber<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let sign = match self.value.sign() {
+            Sign::None => None,
+            Sign::Negative => Some((MINUS_SIGN, &self.symbols.minus_sign_affixes)),
+            Sign::Positive => Some((PLUS_SIGN, &self.symbols.plus_sign_affixes)),
+        };
+
+        if let Some((part, affixes)) = sign.filter(|(_, a)| !a.prefix.is_empty()) {
+            sink.with_part(part, |s| s.write_str(&affixes.prefix))?;
+        }
+
+        let number = Digits {
+            value: self.value,
+            grouping: self.grouping,
+            symbols: self.symbols,
+        };
+
+        match self.pattern {
+            StylePattern::Decimal => number.write_to_parts(sink)?,
+            StylePattern::Percent(data) => {
+                let percent = |sink: &mut S| {
+                    sink.write_str(&data.percent_sign_affixes.prefix)?;
+                    sink.with_part(PERCENT_SIGN, |s| s.write_str(&data.percent_sign_symbol))?;
+                    sink.write_str(&data.percent_sign_affixes.suffix)
+                };
+
+                if data.percent_symbol_index < data.number_index {
+                    percent(sink)?;
+                    number.write_to_parts(sink)?;
+                } else {
+                    number.write_to_parts(sink)?;
+                    percent(sink)?;
+                }
+            }
+            StylePattern::Currency {
+                essentials,
+                currency,
+                display,
+            } => {
+                let config = essentials
+                    .pattern_config_map
+                    .get_copied(&currency.to_unvalidated())
+                    .unwrap_or(essentials.default_pattern_config);
+
+                let (selection, placeholder) = match display {
+                    CurrencyDisplay::Code => (
+                        PatternSelection::StandardAlphaNextToNumber,
+                        Some(PlaceholderValue::ISO),
+                    ),
+                    CurrencyDisplay::NarrowSymbol => (
+                        config.narrow_pattern_selection,
+                        config.narrow_placeholder_value,
+                    ),
+                    CurrencyDisplay::Symbol | CurrencyDisplay::Name => (
+                        config.short_pattern_selection,
+                        config.short_placeholder_value,
+                    ),
+                };
+
+                let symbol = match placeholder {
+                    Some(PlaceholderValue::Index(index)) => essentials
+                        .placeholders
+                        .get(usize::from(index))
+                        .ok_or(fmt::Error)?,
+                    Some(PlaceholderValue::ISO) | None => currency.as_str(),
+                };
+
+                let pattern = match selection {
+                    PatternSelection::Standard => essentials.standard_pattern.as_ref(),
+                    PatternSelection::StandardAlphaNextToNumber => essentials
+                        .standard_alpha_next_to_number_pattern
+                        .as_ref()
+                        .or(essentials.standard_pattern.as_ref()),
+                }
+                .ok_or(fmt::Error)?;
+
+                // The first placeholder of the pattern is the number, and the second one is the
+                // currency symbol.
+                pattern
+                    .interpolate((number, Tagged(CURRENCY, symbol)))
+                    .write_to_parts(sink)?;
+            }
+        }
+
+        if let Some((part, affixes)) = sign.filter(|(_, a)| !a.suffix.is_empty()) {
+            sink.with_part(part, |s| s.write_str(&affixes.suffix))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The digits of a [`FormattedNumber`], without its sign nor the pattern of its style.
+struct Digits<'a> {
+    value: &'a IntlMathematicalValue,
+    grouping: GroupingStrategy,
+    symbols: &'a DecimalSymbolsV1<'a>,
+}
+
+impl Writeable for Digits<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        let value = match self.value {
+            IntlMathematicalValue::Finite(value) => value,
+            IntlMathematicalValue::Infinity(_) => {
+                return sink.with_part(INFINITY, |s| s.write_str(INFINITY_SYMBOL))
+            }
+            IntlMathematicalValue::NotANumber(_) => {
+                return sink.with_part(NAN, |s| s.write_str(NAN_SYMBOL))
+            }
+        };
+
+        let write_digit = |sink: &mut S::SubPartsWrite, magnitude: i16| {
+            sink.write_char(self.symbols.digits[usize::from(value.digit_at(magnitude))])
+        };
+
+        let range = value.magnitude_range();
+        let upper_magnitude = *range.end();
+
+        // Every run of integer digits ends at a grouping separator or at the units digit.
+        let mut magnitude = upper_magnitude;
+        while magnitude >= 0 {
+            let mut end = magnitude;
+            while end > 0
+                && !is_grouping_position(
+                    upper_magnitude,
+                    end,
+                    self.grouping,
+                    self.symbols.grouping_sizes,
+                )
+            {
+                end -= 1;
+            }
+
+            sink.with_part(INTEGER, |s| {
+                (end..=magnitude).rev().try_for_each(|m| write_digit(s, m))
+            })?;
+            if end > 0 {
+                sink.with_part(GROUP, |s| s.write_str(&self.symbols.grouping_separator))?;
+            }
+
+            magnitude = end - 1;
+        }
+
+        let lower_magnitude = *range.start();
+        if lower_magnitude < 0 {
+            sink.with_part(DECIMAL, |s| s.write_str(&self.symbols.decimal_separator))?;
+            sink.with_part(FRACTION, |s| {
+                (lower_magnitude..0)
+                    .rev()
+                    .try_for_each(|m| write_digit(s, m))
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A string written as a single part.
+struct Tagged<'a>(Part, &'a str);
+
+impl Writeable for Tagged<'_> {
+    fn write_to_parts<S: PartsWrite + ?Sized>(&self, sink: &mut S) -> fmt::Result {
+        sink.with_part(self.0, |s| s.write_str(self.1))
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedNumber<'_>);
+
+/// Returns `true` if a grouping separator must be written after the digit at `magnitude` of a
+/// number whose most significant digit is at `upper_magnitude`.
+///
+/// This mirrors the grouping algorithm of ICU4X's `FixedDecimalFormatter`.
+fn is_grouping_position(
+    upper_magnitude: i16,
+    magnitude: i16,
+    strategy: GroupingStrategy,
+    sizes: GroupingSizesV1,
+) -> bool {
+    let primary = i16::from(sizes.primary);
+    if primary == 0 || magnitude < primary {
+        return false;
+    }
+
+    let min_grouping = match strategy {
+        GroupingStrategy::Min2 => sizes.min_grouping.max(2),
+        GroupingStrategy::Never => return false,
+        _ => sizes.min_grouping.max(1),
+    };
+    if upper_magnitude < primary + i16::from(min_grouping) - 1 {
+        return false;
+    }
+
+    let secondary = match sizes.secondary {
+        0 => primary,
+        secondary => i16::from(secondary),
+    };
+    (magnitude - primary) % secondary == 0
+}
+
+/// Collects the parts written by a [`FormattedNumber`] into `[[Type]]` and `[[Value]]` pairs.
+///
+/// Text written outside of a number part is a `"literal"`, and consecutive writes of the
+/// same type are merged into a single part.
+#[derive(Debug)]
+struct PartsCollector {
+    parts: Vec<(&'static str, String)>,
+    typ: &'static str,
+}
+
+impl Write for PartsCollector {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        match self.parts.last_mut() {
+            Some((typ, value)) if *typ == self.typ => value.push_str(s),
+            _ => self.parts.push((self.typ, s.to_owned())),
+        }
+
+        Ok(())
+    }
+}
+
+impl PartsWrite for PartsCollector {
+    type SubPartsWrite = Self;
+
+    fn with_part(
+        &mut self,
+        part: Part,
+        mut f: impl FnMut(&mut Self::SubPartsWrite) -> fmt::Result,
+    ) -> fmt::Result {
+        if part.category != CATEGORY {
+            return f(self);
+        }
+
+        let outer = std::mem::replace(&mut self.typ, part.value);
+        let result = f(self);
+        self.typ = outer;
+        result
+    }
+}
@@ -2,19 +2,23 @@ use std::borrow::Cow;
 
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
-use fixed_decimal::{FixedDecimal, FloatPrecision, SignDisplay};
-use icu_decimal::{
-    options::{FixedDecimalFormatterOptions, GroupingStrategy},
-    provider::DecimalSymbolsV1Marker,
-    FixedDecimalFormatter, FormattedFixedDecimal,
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign, SignDisplay};
+use icu_decimal::{options::GroupingStrategy, provider::DecimalSymbolsV1Marker};
+use icu_experimental::dimension::provider::{
+    currency::CurrencyEssentialsV1Marker, percent::PercentEssentialsV1Marker,
 };
 
+mod format;
 mod options;
+pub(crate) use format::{FormattedNumber, StylePattern};
 use icu_locid::{
     extensions::unicode::{key, Value},
     Locale,
 };
-use icu_provider::DataLocale;
+use icu_provider::{
+    DataLocale, DataPayload, DataProvider, DataRequest, DataRequestMetadata, DataResponse,
+    KeyedDataMarker,
+};
 use num_bigint::BigInt;
 use num_traits::Num;
 pub(crate) use options::*;
@@ -27,11 +31,11 @@ use super::{
 use crate::value::JsVariant;
 use crate::{
     builtins::{
-        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace,
-        BuiltInConstructor, BuiltInObject, IntrinsicObject,
+        builder::BuiltInBuilder, options::get_option, string::is_trimmable_whitespace, Array,
+        BuiltInConstructor, BuiltInObject, IntrinsicObject, OrdinaryObject,
     },
     context::{
-        icu::ErasedProvider,
+        icu::IntlProvider,
         intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     },
     js_string,
@@ -55,9 +59,10 @@ mod tests;
 #[boa_gc(unsafe_empty_trace)]
 pub(crate) struct NumberFormat {
     locale: Locale,
-    formatter: FixedDecimalFormatter,
+    symbols: DataPayload<DecimalSymbolsV1Marker>,
     numbering_system: Option<Value>,
     unit_options: UnitFormatOptions,
+    style_data: StyleData,
     digit_options: DigitFormatOptions,
     notation: Notation,
     use_grouping: GroupingStrategy,
@@ -66,157 +71,17 @@ pub(crate) struct NumberFormat {
 }
 
 impl NumberFormat {
-    /// [`FormatNumeric ( numberFormat, x )`][full] and [`FormatNumericToParts ( numberFormat, x )`][parts].
+    /// Abstract operation [`InitializeNumberFormat ( numberFormat, locales, options )`][spec].
     ///
-    /// The returned struct implements `Writable`, allowing to either write the number as a full
-    /// string or by parts.
+    /// Returns the internal state of a new `Intl.NumberFormat`, which can also be used to format
+    /// numbers without allocating a full object.
     ///
-    /// [full]: https://tc39.es/ecma402/#sec-formatnumber
-    /// [parts]: https://tc39.es/ecma402/#sec-formatnumbertoparts
-    fn format<'a>(&'a self, value: &'a mut FixedDecimal) -> FormattedFixedDecimal<'a> {
-        // TODO: Missing support from ICU4X for Percent/Currency/Unit formatting.
-        // TODO: Missing support from ICU4X for Scientific/Engineering/Compact notation.
-
-        self.digit_options.format_fixed_decimal(value);
-        value.apply_sign_display(self.sign_display);
-
-        self.formatter.format(value)
-    }
-}
-
-#[derive(Debug, Clone)]
-pub(super) struct NumberFormatLocaleOptions {
-    numbering_system: Option<Value>,
-}
-
-impl Service for NumberFormat {
-    type LangMarker = DecimalSymbolsV1Marker;
-
-    type LocaleOptions = NumberFormatLocaleOptions;
-
-    fn resolve(
-        locale: &mut Locale,
-        options: &mut Self::LocaleOptions,
-        provider: &crate::context::icu::IntlProvider,
-    ) {
-        let numbering_system = options
-            .numbering_system
-            .take()
-            .filter(|nu| {
-                validate_extension::<Self::LangMarker>(locale.id.clone(), key!("nu"), nu, provider)
-            })
-            .or_else(|| {
-                locale
-                    .extensions
-                    .unicode
-                    .keywords
-                    .get(&key!("nu"))
-                    .cloned()
-                    .filter(|nu| {
-                        validate_extension::<Self::LangMarker>(
-                            locale.id.clone(),
-                            key!("nu"),
-                            nu,
-                            provider,
-                        )
-                    })
-            });
-
-        locale.extensions.unicode.clear();
-
-        if let Some(nu) = numbering_system.clone() {
-            locale.extensions.unicode.keywords.set(key!("nu"), nu);
-        }
-
-        options.numbering_system = numbering_system;
-    }
-}
-
-impl IntrinsicObject for NumberFormat {
-    fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
-
-        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
-            .name(js_string!("get format"))
-            .build();
-
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
-            .static_method(
-                Self::supported_locales_of,
-                js_string!("supportedLocalesOf"),
-                1,
-            )
-            .property(
-                JsSymbol::to_string_tag(),
-                js_string!("Intl.NumberFormat"),
-                Attribute::CONFIGURABLE,
-            )
-            .accessor(
-                js_string!("format"),
-                Some(get_format),
-                None,
-                Attribute::CONFIGURABLE,
-            )
-            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
-            .build();
-    }
-
-    fn get(intrinsics: &Intrinsics) -> JsObject {
-        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
-    }
-}
-
-impl BuiltInObject for NumberFormat {
-    const NAME: JsString = StaticJsStrings::NUMBER_FORMAT;
-}
-
-impl BuiltInConstructor for NumberFormat {
-    const LENGTH: usize = 0;
-    const P: usize = 3;
-    const SP: usize = 1;
-
-    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
-        StandardConstructors::number_format;
-
-    /// [`Intl.NumberFormat ( [ locales [ , options ] ] )`][spec].
-    ///
-    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
-    fn constructor(
-        new_target: &JsValue,
-        args: &[JsValue],
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    pub(crate) fn new(
+        locales: &JsValue,
+        options: &JsValue,
         context: &mut Context,
-    ) -> JsResult<JsValue> {
-        let locales = args.get_or_undefined(0);
-        let options = args.get_or_undefined(1);
-
-        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
-        let new_target_inner = &if new_target.is_undefined() {
-            context
-                .active_function_object()
-                .unwrap_or_else(|| {
-                    context
-                        .intrinsics()
-                        .constructors()
-                        .number_format()
-                        .constructor()
-                })
-                .into()
-        } else {
-            new_target.clone()
-        };
-
-        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%Intl.NumberFormat.prototype%", « [[InitializedNumberFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[Unit]], [[UnitDisplay]], [[Currency]], [[CurrencyDisplay]], [[CurrencySign]], [[MinimumIntegerDigits]], [[MinimumFractionDigits]], [[MaximumFractionDigits]], [[MinimumSignificantDigits]], [[MaximumSignificantDigits]], [[RoundingType]], [[Notation]], [[CompactDisplay]], [[UseGrouping]], [[SignDisplay]], [[RoundingIncrement]], [[RoundingMode]], [[ComputedRoundingPriority]], [[TrailingZeroDisplay]], [[BoundFormat]] »).
-        let prototype = get_prototype_from_constructor(
-            new_target_inner,
-            StandardConstructors::number_format,
-            context,
-        )?;
-
-        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
-
-        // `InitializeNumberFormat ( numberFormat, locales, options )`
-        // https://tc39.es/ecma402/#sec-initializenumberformat
-
+    ) -> JsResult<Self> {
         // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
         let requested_locales = canonicalize_locale_list(locales, context)?;
         // 2. Set options to ? CoerceOptionsToObject(options).
@@ -257,29 +122,30 @@ impl BuiltInConstructor for NumberFormat {
 
         // 15. Let style be numberFormat.[[Style]].
         // 16. If style is "currency", then
-        let (min_fractional, max_fractional) = if unit_options.style() == Style::Currency {
-            // TODO: Missing support from ICU4X
-            // a. Let currency be numberFormat.[[Currency]].
-            // b. Let cDigits be CurrencyDigits(currency).
-            // c. Let mnfdDefault be cDigits.
-            // d. Let mxfdDefault be cDigits.
-            return Err(JsNativeError::typ().with_message("unimplemented").into());
-        } else {
-            // 17. Else,
-            (
-                // a. Let mnfdDefault be 0.
-                0,
-                // b. If style is "percent", then
-                if unit_options.style() == Style::Percent {
-                    // i. Let mxfdDefault be 0.
-                    0
-                } else {
-                    // c. Else,
-                    //    i. Let mxfdDefault be 3.
-                    3
-                },
-            )
-        };
+        let (min_fractional, max_fractional) =
+            if let UnitFormatOptions::Currency { currency, .. } = &unit_options {
+                // a. Let currency be numberFormat.[[Currency]].
+                // b. Let cDigits be CurrencyDigits(currency).
+                let c_digits = currency.digits();
+                // c. Let mnfdDefault be cDigits.
+                // d. Let mxfdDefault be cDigits.
+                (c_digits, c_digits)
+            } else {
+                // 17. Else,
+                (
+                    // a. Let mnfdDefault be 0.
+                    0,
+                    // b. If style is "percent", then
+                    if unit_options.style() == Style::Percent {
+                        // i. Let mxfdDefault be 0.
+                        0
+                    } else {
+                        // c. Else,
+                        //    i. Let mxfdDefault be 3.
+                        3
+                    },
+                )
+            };
 
         // 18. Let notation be ? GetOption(options, "notation", string, « "standard", "scientific", "engineering", "compact" », "standard").
         // 19. Set numberFormat.[[Notation]] to notation.
@@ -371,39 +237,376 @@ impl BuiltInConstructor for NumberFormat {
         let sign_display =
             get_option(&options, js_string!("signDisplay"), context)?.unwrap_or(SignDisplay::Auto);
 
-        let mut options = FixedDecimalFormatterOptions::default();
-        options.grouping_strategy = use_grouping;
-
-        let data_locale = &DataLocale::from(&locale);
+        let provider = context.intl_provider();
+        let symbols = load_data::<DecimalSymbolsV1Marker>(&locale, provider)?;
 
-        let formatter = match context.intl_provider().erased_provider() {
-            ErasedProvider::Any(a) => {
-                FixedDecimalFormatter::try_new_with_any_provider(a, data_locale, options)
+        // ICU4X doesn't provide the data to format currency names, accounting patterns and units
+        // yet, so those options are rejected instead of silently formatting a plain number.
+        let style_data = match &unit_options {
+            UnitFormatOptions::Decimal => StyleData::Decimal,
+            UnitFormatOptions::Percent => StyleData::Percent(load_data(&locale, provider)?),
+            UnitFormatOptions::Currency {
+                display: CurrencyDisplay::Name,
+                ..
+            } => {
+                return Err(JsNativeError::range()
+                    .with_message("the `name` currency display is not supported yet")
+                    .into())
+            }
+            UnitFormatOptions::Currency {
+                sign: CurrencySign::Accounting,
+                ..
+            } => {
+                return Err(JsNativeError::range()
+                    .with_message("the `accounting` currency sign is not supported yet")
+                    .into())
+            }
+            UnitFormatOptions::Currency { .. } => {
+                StyleData::Currency(load_data(&locale, provider)?)
             }
-            ErasedProvider::Buffer(b) => {
-                FixedDecimalFormatter::try_new_with_buffer_provider(b, data_locale, options)
+            UnitFormatOptions::Unit { .. } => {
+                return Err(JsNativeError::range()
+                    .with_message("the `unit` style is not supported yet")
+                    .into())
+            }
+        };
+
+        // 31. Return unused.
+        Ok(Self {
+            locale,
+            numbering_system: intl_options.service_options.numbering_system,
+            symbols,
+            unit_options,
+            style_data,
+            digit_options,
+            notation,
+            use_grouping,
+            sign_display,
+            bound_format: None,
+        })
+    }
+
+    /// [`FormatNumeric ( numberFormat, x )`][full] and [`FormatNumericToParts ( numberFormat, x )`][parts].
+    ///
+    /// The returned struct implements `Writable`, allowing to either write the number as a full
+    /// string or by parts.
+    ///
+    /// [full]: https://tc39.es/ecma402/#sec-formatnumber
+    /// [parts]: https://tc39.es/ecma402/#sec-formatnumbertoparts
+    pub(crate) fn format<'a>(
+        &'a self,
+        value: &'a mut IntlMathematicalValue,
+    ) -> FormattedNumber<'a> {
+        // TODO: Missing support from ICU4X for Scientific/Engineering/Compact notation.
+
+        if let IntlMathematicalValue::Finite(x) = value {
+            // Percentages are formatted as the number of hundredths of `x`.
+            if matches!(self.style_data, StyleData::Percent(_)) {
+                x.multiply_pow10(2);
             }
+            self.digit_options.format_fixed_decimal(x);
         }
-        .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+        value.apply_sign_display(self.sign_display);
 
+        let pattern = match (&self.style_data, &self.unit_options) {
+            (StyleData::Percent(data), _) => StylePattern::Percent(data.get()),
+            (
+                StyleData::Currency(data),
+                UnitFormatOptions::Currency {
+                    currency, display, ..
+                },
+            ) => StylePattern::Currency {
+                essentials: data.get(),
+                currency: currency.code(),
+                display: *display,
+            },
+            _ => StylePattern::Decimal,
+        };
+
+        FormattedNumber {
+            value,
+            grouping: self.use_grouping,
+            symbols: self.symbols.get(),
+            pattern,
+        }
+    }
+
+    /// Abstract operation [`PartitionNumberPattern ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberpattern
+    pub(crate) fn partition_number_pattern(
+        &self,
+        value: &mut IntlMathematicalValue,
+    ) -> Vec<(&'static str, String)> {
+        self.format(value).to_parts()
+    }
+
+    /// Abstract operation [`PartitionNumberRangePattern ( numberFormat, x, y )`][spec].
+    ///
+    /// Returns the parts of the formatted range, together with the source of each part.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-partitionnumberrangepattern
+    fn partition_number_range_pattern(
+        &self,
+        mut x: IntlMathematicalValue,
+        mut y: IntlMathematicalValue,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        // 1. If x is not-a-number or y is not-a-number, throw a RangeError exception.
+        if matches!(x, IntlMathematicalValue::NotANumber(_))
+            || matches!(y, IntlMathematicalValue::NotANumber(_))
+        {
+            return Err(JsNativeError::range()
+                .with_message("cannot format a range with a NaN bound")
+                .into());
+        }
+
+        // 2. Let xResult be ? PartitionNumberPattern(numberFormat, x).
+        let x_result = self.partition_number_pattern(&mut x);
+
+        // 3. Let yResult be ? PartitionNumberPattern(numberFormat, y).
+        let y_result = self.partition_number_pattern(&mut y);
+
+        let symbols = RangeSymbols::for_locale(&self.locale);
+
+        // 4. If ! FormatNumeric(numberFormat, x) is equal to ! FormatNumeric(numberFormat, y), then
+        if x_result == y_result {
+            // a. Let appxResult be ? FormatApproximately(numberFormat, xResult).
+            // b. For each element r in appxResult, do
+            //     i. Set r.[[Source]] to "shared".
+            // c. Return appxResult.
+            return Ok(
+                std::iter::once(("approximatelySign", symbols.approximately.to_owned()))
+                    .chain(x_result)
+                    .map(|(typ, value)| (typ, value, "shared"))
+                    .collect(),
+            );
+        }
+
+        // 5. Let result be a new empty List.
+        // 6. For each element r in xResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "startRange" } as the last element of result.
+        // 7. Let rangeSeparator be an ILND String value used to separate two numbers.
+        // 8. Append a new Record { [[Type]]: "literal", [[Value]]: rangeSeparator, [[Source]]: "shared" } element to result.
+        // 9. For each element r in yResult, do
+        //     a. Append a new Record { [[Type]]: r.[[Type]], [[Value]]: r.[[Value]], [[Source]]: "endRange" } as the last element of result.
+        // 10. Return ! CollapseNumberRange(numberFormat, result).
+        Ok(x_result
+            .into_iter()
+            .map(|(typ, value)| (typ, value, "startRange"))
+            .chain(std::iter::once((
+                "literal",
+                symbols.separator.to_owned(),
+                "shared",
+            )))
+            .chain(
+                y_result
+                    .into_iter()
+                    .map(|(typ, value)| (typ, value, "endRange")),
+            )
+            .collect())
+    }
+}
+
+/// The locale data used to format the `percent` and `currency` styles.
+#[derive(Debug)]
+enum StyleData {
+    Decimal,
+    Percent(DataPayload<PercentEssentialsV1Marker>),
+    Currency(DataPayload<CurrencyEssentialsV1Marker>),
+}
+
+/// Loads the data of `M` for `locale`.
+fn load_data<M>(locale: &Locale, provider: &IntlProvider) -> JsResult<DataPayload<M>>
+where
+    M: KeyedDataMarker,
+    IntlProvider: DataProvider<M>,
+{
+    DataProvider::<M>::load(
+        provider,
+        DataRequest {
+            locale: &DataLocale::from(locale),
+            metadata: DataRequestMetadata::default(),
+        },
+    )
+    .and_then(DataResponse::take_payload)
+    .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+}
+
+/// The symbols used to format approximate numbers and number ranges in a locale.
+///
+/// ICU4X doesn't provide the number range data of CLDR yet, so this contains the
+/// `approximatelySign` and the separator of the `range` pattern of the languages whose data
+/// differs from the root locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RangeSymbols {
+    approximately: &'static str,
+    separator: &'static str,
+}
+
+impl RangeSymbols {
+    fn for_locale(locale: &Locale) -> Self {
+        let language = locale.id.language.as_str();
+
+        let approximately = match language {
+            "de" | "ru" => "\u{2248}",
+            "fr" => "\u{2243}",
+            "ja" => "\u{7d04}",
+            _ => "~",
+        };
+
+        let separator = match language {
+            "es" | "zh" => "-",
+            "ja" => "\u{ff5e}",
+            "ko" => "~",
+            _ => "\u{2013}",
+        };
+
+        Self {
+            approximately,
+            separator,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct NumberFormatLocaleOptions {
+    pub(super) numbering_system: Option<Value>,
+}
+
+impl Service for NumberFormat {
+    type LangMarker = DecimalSymbolsV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        let numbering_system = options
+            .numbering_system
+            .take()
+            .filter(|nu| {
+                validate_extension::<Self::LangMarker>(locale.id.clone(), key!("nu"), nu, provider)
+            })
+            .or_else(|| {
+                locale
+                    .extensions
+                    .unicode
+                    .keywords
+                    .get(&key!("nu"))
+                    .cloned()
+                    .filter(|nu| {
+                        validate_extension::<Self::LangMarker>(
+                            locale.id.clone(),
+                            key!("nu"),
+                            nu,
+                            provider,
+                        )
+                    })
+            });
+
+        locale.extensions.unicode.clear();
+
+        if let Some(nu) = numbering_system.clone() {
+            locale.extensions.unicode.keywords.set(key!("nu"), nu);
+        }
+
+        options.numbering_system = numbering_system;
+    }
+}
+
+impl IntrinsicObject for NumberFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        let get_format = BuiltInBuilder::callable(realm, Self::get_format)
+            .name(js_string!("get format"))
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.NumberFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .accessor(
+                js_string!("format"),
+                Some(get_format),
+                None,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format_to_parts, js_string!("formatToParts"), 1)
+            .method(Self::format_range, js_string!("formatRange"), 2)
+            .method(
+                Self::format_range_to_parts,
+                js_string!("formatRangeToParts"),
+                2,
+            )
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for NumberFormat {
+    const NAME: JsString = StaticJsStrings::NUMBER_FORMAT;
+}
+
+impl BuiltInConstructor for NumberFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 6;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::number_format;
+
+    /// [`Intl.NumberFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. If NewTarget is undefined, let newTarget be the active function object, else let newTarget be NewTarget.
+        let new_target_inner = &if new_target.is_undefined() {
+            context
+                .active_function_object()
+                .unwrap_or_else(|| {
+                    context
+                        .intrinsics()
+                        .constructors()
+                        .number_format()
+                        .constructor()
+                })
+                .into()
+        } else {
+            new_target.clone()
+        };
+
+        // 2. Let numberFormat be ? OrdinaryCreateFromConstructor(newTarget, "%Intl.NumberFormat.prototype%", « [[InitializedNumberFormat]], [[Locale]], [[DataLocale]], [[NumberingSystem]], [[Style]], [[Unit]], [[UnitDisplay]], [[Currency]], [[CurrencyDisplay]], [[CurrencySign]], [[MinimumIntegerDigits]], [[MinimumFractionDigits]], [[MaximumFractionDigits]], [[MinimumSignificantDigits]], [[MaximumSignificantDigits]], [[RoundingType]], [[Notation]], [[CompactDisplay]], [[UseGrouping]], [[SignDisplay]], [[RoundingIncrement]], [[RoundingMode]], [[ComputedRoundingPriority]], [[TrailingZeroDisplay]], [[BoundFormat]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target_inner,
+            StandardConstructors::number_format,
+            context,
+        )?;
+
+        // 3. Perform ? InitializeNumberFormat(numberFormat, locales, options).
+        let number_format = Self::new(locales, options, context)?;
         let number_format = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             prototype,
-            NumberFormat {
-                locale,
-                numbering_system: intl_options.service_options.numbering_system,
-                formatter,
-                unit_options,
-                digit_options,
-                notation,
-                use_grouping,
-                sign_display,
-                bound_format: None,
-            },
+            number_format,
         );
 
-        // 31. Return unused.
-
         // 4. If the implementation supports the normative optional constructor mode of 4.3 Note 1, then
         //     a. Let this be the this value.
         //     b. Return ? ChainNumberFormat(numberFormat, NewTarget, this).
@@ -531,6 +734,184 @@ impl NumberFormat {
         Ok(bound_format.into())
     }
 
+    /// [`Intl.NumberFormat.prototype.formatToParts ( value )`][spec].
+    ///
+    /// Returns an array of objects representing the formatted number in parts.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formattoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        let nf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.NumberFormat` object",
+                )
+            })?;
+
+        // 3. Let x be ? ToIntlMathematicalValue(value).
+        let mut x = to_intl_mathematical_value(args.get_or_undefined(0), context)?;
+
+        // 4. Return FormatNumericToParts(nf, x).
+
+        // FormatNumericToParts ( numberFormat, x )
+        // https://tc39.es/ecma402/#sec-formatnumbertoparts
+
+        // 1. Let parts be ? PartitionNumberPattern(numberFormat, x).
+        let parts = nf.borrow().data.partition_number_pattern(&mut x);
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]] } part in parts, do
+        for (n, (typ, value)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            let o = part_to_object(typ, value, None, context);
+
+            // d. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // e. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRange ( start, end )`][spec].
+    ///
+    /// Returns a string representing the range between the two provided numbers.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrange
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRange
+    fn format_range(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let parts = Self::partition_range_args(this, args, "formatRange", context)?;
+
+        // 6. Return ? FormatNumericRange(nf, x, y).
+
+        // FormatNumericRange ( numberFormat, x, y )
+        // https://tc39.es/ecma402/#sec-formatnumericrange
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        // 2. Let result be the empty String.
+        // 3. For each part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result: String = parts.into_iter().map(|(_, value, _)| value).collect();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.NumberFormat.prototype.formatRangeToParts ( start, end )`][spec].
+    ///
+    /// Returns an array of objects representing the range between the two provided numbers in
+    /// parts.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.formatrangetoparts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/formatRangeToParts
+    fn format_range_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let nf be the this value.
+        // 2. Perform ? RequireInternalSlot(nf, [[InitializedNumberFormat]]).
+        // 3. If start is undefined or end is undefined, throw a TypeError exception.
+        // 4. Let x be ? ToIntlMathematicalValue(start).
+        // 5. Let y be ? ToIntlMathematicalValue(end).
+        let parts = Self::partition_range_args(this, args, "formatRangeToParts", context)?;
+
+        // 6. Return ? FormatNumericRangeToParts(nf, x, y).
+
+        // FormatNumericRangeToParts ( numberFormat, x, y )
+        // https://tc39.es/ecma402/#sec-formatnumericrangetoparts
+
+        // 1. Let parts be ? PartitionNumberRangePattern(numberFormat, x, y).
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Source]] } part in parts, do
+        for (n, (typ, value, source)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            // d. Perform ! CreateDataPropertyOrThrow(O, "source", part.[[Source]]).
+            let o = part_to_object(typ, value, Some(source), context);
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// Validates the arguments of `formatRange` and `formatRangeToParts`, returning the parts of
+    /// the formatted range.
+    fn partition_range_args(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<Vec<(&'static str, String, &'static str)>> {
+        let nf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(format!(
+                    "`{method}` can only be called on an `Intl.NumberFormat` object"
+                ))
+            })?;
+
+        let start = args.get_or_undefined(0);
+        let end = args.get_or_undefined(1);
+
+        if start.is_undefined() || end.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message(format!("`{method}` requires both a start and an end value"))
+                .into());
+        }
+
+        let x = to_intl_mathematical_value(start, context)?;
+        let y = to_intl_mathematical_value(end, context)?;
+
+        let nf = nf.borrow();
+        nf.data.partition_number_range_pattern(x, y)
+    }
+
     /// [`Intl.NumberFormat.prototype.resolvedOptions ( )`][spec].
     ///
     /// Returns a new object with properties reflecting the locale and options computed during the
@@ -715,6 +1096,31 @@ impl NumberFormat {
     }
 }
 
+/// Creates an object representing a single part of a formatted number.
+fn part_to_object(
+    typ: &'static str,
+    value: String,
+    source: Option<&'static str>,
+    context: &mut Context,
+) -> JsObject {
+    let o = context
+        .intrinsics()
+        .templates()
+        .ordinary_object()
+        .create(OrdinaryObject, vec![]);
+
+    o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+        .expect("operation must not fail per the spec");
+    o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+        .expect("operation must not fail per the spec");
+    if let Some(source) = source {
+        o.create_data_property_or_throw(js_string!("source"), js_string!(source), context)
+            .expect("operation must not fail per the spec");
+    }
+
+    o
+}
+
 /// Abstract operation [`UnwrapNumberFormat ( nf )`][spec].
 ///
 /// This also checks that the returned object is a `NumberFormat`, which skips the
@@ -762,20 +1168,99 @@ fn unwrap_number_format(nf: &JsValue, context: &mut Context) -> JsResult<JsObjec
         .into())
 }
 
+/// An [Intl mathematical value][spec], which extends the decimal numbers with the non-finite
+/// values that can be formatted by a `NumberFormat`.
+///
+/// The sign of the non-finite values is the sign that is displayed: it is [`Sign::None`] for
+/// positive infinity, until a [`SignDisplay`] is applied.
+///
+/// [spec]: https://tc39.es/ecma402/#intl-mathematical-value
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum IntlMathematicalValue {
+    /// A finite value, including negative zero.
+    Finite(FixedDecimal),
+    /// Positive or negative infinity.
+    Infinity(Sign),
+    /// Not-a-number.
+    NotANumber(Sign),
+}
+
+impl IntlMathematicalValue {
+    /// Gets the sign to display when formatting this value.
+    pub(crate) fn sign(&self) -> Sign {
+        match self {
+            Self::Finite(x) => x.sign(),
+            Self::Infinity(sign) | Self::NotANumber(sign) => *sign,
+        }
+    }
+
+    /// Applies `display` to the sign of this value, following the rules of
+    /// [`GetNumberFormatPattern ( numberFormat, x )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-getnumberformatpattern
+    pub(crate) fn apply_sign_display(&mut self, display: SignDisplay) {
+        match self {
+            Self::Finite(x) => x.apply_sign_display(display),
+            Self::Infinity(sign) => {
+                *sign = match (display, *sign) {
+                    (SignDisplay::Never, _) => Sign::None,
+                    (_, Sign::Negative) => Sign::Negative,
+                    (SignDisplay::Always | SignDisplay::ExceptZero, _) => Sign::Positive,
+                    _ => Sign::None,
+                };
+            }
+            Self::NotANumber(sign) => {
+                *sign = if display == SignDisplay::Always {
+                    Sign::Positive
+                } else {
+                    Sign::None
+                };
+            }
+        }
+    }
+}
+
+impl From<FixedDecimal> for IntlMathematicalValue {
+    fn from(value: FixedDecimal) -> Self {
+        Self::Finite(value)
+    }
+}
+
+impl From<f64> for IntlMathematicalValue {
+    fn from(value: f64) -> Self {
+        if value.is_nan() {
+            Self::NotANumber(Sign::None)
+        } else if value.is_infinite() {
+            Self::Infinity(if value < 0.0 {
+                Sign::Negative
+            } else {
+                Sign::None
+            })
+        } else {
+            Self::Finite(
+                FixedDecimal::try_from_f64(value, FloatPrecision::Floating)
+                    .expect("finite numbers can always be converted to a `FixedDecimal`"),
+            )
+        }
+    }
+}
+
 /// Abstract operation [`ToIntlMathematicalValue ( value )`][spec].
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-tointlmathematicalvalue
-fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResult<FixedDecimal> {
+fn to_intl_mathematical_value(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<IntlMathematicalValue> {
     // 1. Let primValue be ? ToPrimitive(value, number).
     let prim_value = value.to_primitive(context, PreferredType::Number)?;
 
-    // TODO: Add support in `FixedDecimal` for infinity and NaN, which
-    // should remove the returned errors.
     match prim_value.variant() {
         // 2. If Type(primValue) is BigInt, return ℝ(primValue).
         JsVariant::BigInt(bi) => {
             let bi = bi.to_string();
             FixedDecimal::try_from(bi.as_bytes())
+                .map(IntlMathematicalValue::Finite)
                 .map_err(|err| JsNativeError::range().with_message(err.to_string()).into())
         }
         // 3. If Type(primValue) is String, then
@@ -791,10 +1276,15 @@ fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResul
             //     c. If rounded is +∞𝔽, return positive-infinity.
             //     d. If rounded is +0𝔽 and intlMV < 0, return negative-zero.
             //     e. If rounded is +0𝔽, return 0.
-            js_string_to_fixed_decimal(s).ok_or_else(|| {
-                JsNativeError::syntax()
-                    .with_message("could not parse the provided string")
-                    .into()
+            if let Some(x) = js_string_to_fixed_decimal(s) {
+                return Ok(IntlMathematicalValue::Finite(x));
+            }
+
+            let s = s.to_std_string_escaped();
+            Ok(match s.trim_matches(is_trimmable_whitespace) {
+                "Infinity" | "+Infinity" => IntlMathematicalValue::Infinity(Sign::None),
+                "-Infinity" => IntlMathematicalValue::Infinity(Sign::Negative),
+                _ => IntlMathematicalValue::NotANumber(Sign::None),
             })
         }
         // 4. Else,
@@ -804,8 +1294,7 @@ fn to_intl_mathematical_value(value: &JsValue, context: &mut Context) -> JsResul
             // c. Let str be Number::toString(x, 10).
             let x = prim_value.to_number(context)?;
 
-            FixedDecimal::try_from_f64(x, FloatPrecision::Floating)
-                .map_err(|err| JsNativeError::range().with_message(err.to_string()).into())
+            Ok(IntlMathematicalValue::from(x))
         }
     }
 }
@@ -1,5 +1,16 @@
-use crate::builtins::intl::number_format::RoundingIncrement;
-use fixed_decimal::RoundingIncrement::*;
+use std::{borrow::Cow, str::FromStr};
+
+use crate::builtins::intl::number_format::{
+    FormattedNumber, IntlMathematicalValue, RoundingIncrement, StylePattern,
+};
+#[cfg(feature = "intl_bundled")]
+use crate::{js_str, run_test_actions, JsNativeErrorKind, TestAction};
+use fixed_decimal::{FixedDecimal, RoundingIncrement::*, Sign, SignDisplay};
+use icu_decimal::{
+    options::GroupingStrategy,
+    provider::{AffixesV1, DecimalSymbolsV1, GroupingSizesV1},
+};
+use icu_experimental::dimension::provider::percent::{PercentAffixesV1, PercentEssentialsV1};
 
 #[test]
 fn u16_to_rounding_increment_sunny_day() {
@@ -39,3 +50,337 @@ fn u16_to_rounding_increment_rainy_day() {
         assert!(RoundingIncrement::from_u16(num).is_none());
     }
 }
+
+fn symbols(
+    digits: [char; 10],
+    decimal: &'static str,
+    group: &'static str,
+) -> DecimalSymbolsV1<'static> {
+    DecimalSymbolsV1 {
+        minus_sign_affixes: AffixesV1 {
+            prefix: Cow::Borrowed("-"),
+            suffix: Cow::Borrowed(""),
+        },
+        plus_sign_affixes: AffixesV1 {
+            prefix: Cow::Borrowed(""),
+            suffix: Cow::Borrowed("+"),
+        },
+        decimal_separator: Cow::Borrowed(decimal),
+        grouping_separator: Cow::Borrowed(group),
+        grouping_sizes: GroupingSizesV1 {
+            primary: 3,
+            secondary: 3,
+            min_grouping: 1,
+        },
+        digits,
+    }
+}
+
+#[test]
+fn formatted_number_parts_latn() {
+    const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+    let symbols = symbols(DIGITS, ".", ",");
+
+    let value = FixedDecimal::from_str("-1234567.891").unwrap().into();
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Auto,
+        symbols: &symbols,
+        pattern: StylePattern::Decimal,
+    };
+    assert_eq!(formatted.to_string(), "-1,234,567.891");
+    assert_eq!(
+        formatted.to_parts(),
+        [
+            ("minusSign", "-".to_owned()),
+            ("integer", "1".to_owned()),
+            ("group", ",".to_owned()),
+            ("integer", "234".to_owned()),
+            ("group", ",".to_owned()),
+            ("integer", "567".to_owned()),
+            ("decimal", ".".to_owned()),
+            ("fraction", "891".to_owned()),
+        ]
+    );
+
+    let value = FixedDecimal::from_str("0.5").unwrap().into();
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Auto,
+        symbols: &symbols,
+        pattern: StylePattern::Decimal,
+    };
+    assert_eq!(
+        formatted.to_parts(),
+        [
+            ("integer", "0".to_owned()),
+            ("decimal", ".".to_owned()),
+            ("fraction", "5".to_owned()),
+        ]
+    );
+
+    let value = FixedDecimal::from_str("1234").unwrap().into();
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Min2,
+        symbols: &symbols,
+        pattern: StylePattern::Decimal,
+    };
+    assert_eq!(formatted.to_parts(), [("integer", "1234".to_owned())]);
+}
+
+#[test]
+fn formatted_number_parts_custom_symbols() {
+    const DIGITS: [char; 10] = ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'];
+    let symbols = symbols(DIGITS, "٫", "\u{a0}");
+
+    // Numbering systems with non-ASCII digits and separators, with a sign suffix.
+    let mut value = FixedDecimal::from_str("12345.6").unwrap();
+    value.set_sign(Sign::Positive);
+    let value = value.into();
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Auto,
+        symbols: &symbols,
+        pattern: StylePattern::Decimal,
+    };
+    assert_eq!(
+        formatted.to_parts(),
+        [
+            ("integer", "١٢".to_owned()),
+            ("group", "\u{a0}".to_owned()),
+            ("integer", "٣٤٥".to_owned()),
+            ("decimal", "٫".to_owned()),
+            ("fraction", "٦".to_owned()),
+            ("plusSign", "+".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn formatted_number_parts_percent() {
+    const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+    let symbols = symbols(DIGITS, ".", ",");
+    let percent = PercentEssentialsV1 {
+        number_index: 0,
+        percent_sign_affixes: PercentAffixesV1 {
+            prefix: Cow::Borrowed("\u{a0}"),
+            suffix: Cow::Borrowed(""),
+        },
+        percent_sign_symbol: Cow::Borrowed("%"),
+        percent_symbol_index: 7,
+        standard: Cow::Borrowed("#,##0\u{a0}%"),
+    };
+
+    let value = FixedDecimal::from_str("-1250").unwrap().into();
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Auto,
+        symbols: &symbols,
+        pattern: StylePattern::Percent(&percent),
+    };
+    assert_eq!(
+        formatted.to_parts(),
+        [
+            ("minusSign", "-".to_owned()),
+            ("integer", "1".to_owned()),
+            ("group", ",".to_owned()),
+            ("integer", "250".to_owned()),
+            ("literal", "\u{a0}".to_owned()),
+            ("percentSign", "%".to_owned()),
+        ]
+    );
+
+    let value = IntlMathematicalValue::Infinity(Sign::None);
+    let formatted = FormattedNumber {
+        value: &value,
+        grouping: GroupingStrategy::Auto,
+        symbols: &symbols,
+        pattern: StylePattern::Percent(&percent),
+    };
+    assert_eq!(formatted.to_string(), "\u{221e}\u{a0}%");
+}
+
+#[test]
+fn non_finite_sign_display() {
+    let cases = [
+        (
+            SignDisplay::Auto,
+            Sign::Negative,
+            Sign::Negative,
+            Sign::None,
+        ),
+        (SignDisplay::Never, Sign::Negative, Sign::None, Sign::None),
+        (
+            SignDisplay::Always,
+            Sign::None,
+            Sign::Positive,
+            Sign::Positive,
+        ),
+        (
+            SignDisplay::ExceptZero,
+            Sign::None,
+            Sign::Positive,
+            Sign::None,
+        ),
+        (SignDisplay::Negative, Sign::None, Sign::None, Sign::None),
+    ];
+
+    for (display, infinity, expected_infinity, expected_nan) in cases {
+        let mut value = IntlMathematicalValue::Infinity(infinity);
+        value.apply_sign_display(display);
+        assert_eq!(value.sign(), expected_infinity);
+
+        let mut value = IntlMathematicalValue::from(f64::NAN);
+        value.apply_sign_display(display);
+        assert_eq!(value.sign(), expected_nan);
+    }
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "function parts(p) { return p.map(p => `${p.type}:${p.value}`).join('|'); }",
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.NumberFormat('en').formatToParts(-1234.5))",
+            js_str!("minusSign:-|integer:1|group:,|integer:234|decimal:.|fraction:5"),
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.NumberFormat('en', { signDisplay: 'always' }).formatToParts(0))",
+            js_str!("plusSign:+|integer:0"),
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.NumberFormat('de').formatToParts(1234.5))",
+            js_str!("integer:1|group:.|integer:234|decimal:,|fraction:5"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_range() {
+    run_test_actions([
+        TestAction::run("var nf = new Intl.NumberFormat('en', { maximumFractionDigits: 0 });"),
+        TestAction::assert_eq("nf.formatRange(3, 5)", js_str!("3\u{2013}5")),
+        TestAction::assert_eq("nf.formatRange(1000, 2500)", js_str!("1,000\u{2013}2,500")),
+        TestAction::assert_eq("nf.formatRange(2.9, 3.1)", js_str!("~3")),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('es').formatRange(3, 5)",
+            js_str!("3-5"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('de', { maximumFractionDigits: 0 }).formatRange(2.9, 3.1)",
+            js_str!("\u{2248}3"),
+        ),
+        TestAction::assert_native_error(
+            "nf.formatRange(3)",
+            JsNativeErrorKind::Type,
+            "`formatRange` requires both a start and an end value",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_range_to_parts() {
+    run_test_actions([
+        TestAction::run(
+            "function parts(p) { return p.map(p => `${p.type}:${p.value}:${p.source}`).join('|'); }",
+        ),
+        TestAction::run("var nf = new Intl.NumberFormat('en', { maximumFractionDigits: 0 });"),
+        TestAction::assert_eq(
+            "parts(nf.formatRangeToParts(-3, 1000))",
+            js_str!(
+                "minusSign:-:startRange|integer:3:startRange|literal:\u{2013}:shared|\
+                 integer:1:endRange|group:,:endRange|integer:000:endRange"
+            ),
+        ),
+        TestAction::assert_eq(
+            "parts(nf.formatRangeToParts(2.9, 3.1))",
+            js_str!("approximatelySign:~:shared|integer:3:shared"),
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_styles() {
+    run_test_actions([
+        TestAction::run(
+            "function parts(p) { return p.map(p => `${p.type}:${p.value}`).join('|'); }",
+        ),
+        TestAction::run(
+            "var usd = new Intl.NumberFormat('en-US', { style: 'currency', currency: 'usd' });",
+        ),
+        TestAction::assert_eq("usd.format(1234.5)", js_str!("$1,234.50")),
+        TestAction::assert_eq("usd.format(-0.5)", js_str!("-$0.50")),
+        TestAction::assert_eq(
+            "parts(usd.formatToParts(-1234.5))",
+            js_str!("minusSign:-|currency:$|integer:1|group:,|integer:234|decimal:.|fraction:50"),
+        ),
+        TestAction::assert_eq("usd.resolvedOptions().currency", js_str!("USD")),
+        TestAction::assert_eq("usd.resolvedOptions().minimumFractionDigits", 2),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en-US', { style: 'currency', currency: 'JPY' }).format(1234.5)",
+            js_str!("\u{a5}1,235"),
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en-US', { style: 'currency', currency: 'KWD' }).resolvedOptions().maximumFractionDigits",
+            3,
+        ),
+        TestAction::assert_eq(
+            "new Intl.NumberFormat('en-US', { style: 'currency', currency: 'USD', currencyDisplay: 'code' }).format(1)",
+            js_str!("USD\u{a0}1.00"),
+        ),
+        TestAction::assert_eq(
+            "parts(new Intl.NumberFormat('de', { style: 'currency', currency: 'EUR' }).formatToParts(1234.5))",
+            js_str!("integer:1|group:.|integer:234|decimal:,|fraction:50|literal:\u{a0}|currency:\u{20ac}"),
+        ),
+        TestAction::run("var percent = new Intl.NumberFormat('en', { style: 'percent' });"),
+        TestAction::assert_eq("percent.format(0.256)", js_str!("26%")),
+        TestAction::assert_eq(
+            "parts(percent.formatToParts(-0.5))",
+            js_str!("minusSign:-|integer:50|percentSign:%"),
+        ),
+        TestAction::assert_eq("percent.format(NaN)", js_str!("NaN%")),
+        TestAction::assert_eq(
+            "parts(percent.formatToParts(-Infinity))",
+            js_str!("minusSign:-|infinity:\u{221e}|percentSign:%"),
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en', { style: 'unit', unit: 'meter' })",
+            JsNativeErrorKind::Range,
+            "the `unit` style is not supported yet",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'EUR', currencyDisplay: 'name' })",
+            JsNativeErrorKind::Range,
+            "the `name` currency display is not supported yet",
+        ),
+        TestAction::assert_native_error(
+            "new Intl.NumberFormat('en', { style: 'currency', currency: 'EUR', currencySign: 'accounting' })",
+            JsNativeErrorKind::Range,
+            "the `accounting` currency sign is not supported yet",
+        ),
+    ]);
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format_non_finite() {
+    run_test_actions([
+        TestAction::run("var nf = new Intl.NumberFormat('en', { signDisplay: 'always' });"),
+        TestAction::assert_eq("nf.format(NaN)", js_str!("+NaN")),
+        TestAction::assert_eq("nf.format(Infinity)", js_str!("+\u{221e}")),
+        TestAction::assert_eq("nf.format('-Infinity')", js_str!("-\u{221e}")),
+        TestAction::assert_eq("nf.format('not a number')", js_str!("+NaN")),
+        TestAction::assert_native_error(
+            "nf.formatRange(NaN, 1)",
+            JsNativeErrorKind::Range,
+            "cannot format a range with a NaN bound",
+        ),
+    ]);
+}
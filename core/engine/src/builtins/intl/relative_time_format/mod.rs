@@ -0,0 +1,630 @@
+use std::fmt::Write;
+
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+use fixed_decimal::{FixedDecimal, FloatPrecision, Sign};
+use icu_experimental::relativetime::{
+    options::Numeric, provider::LongDayRelativeTimeFormatDataV1Marker, RelativeTimeFormatter,
+    RelativeTimeFormatterOptions,
+};
+use icu_locid::{extensions::unicode::Value, Locale};
+use icu_provider::DataLocale;
+
+use crate::{
+    builtins::{
+        options::get_option, Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject,
+        IntrinsicObject, OrdinaryObject,
+    },
+    context::{
+        icu::IntlProvider,
+        intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    },
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+use super::{
+    locale::{canonicalize_locale_list, filter_locales, resolve_locale},
+    number_format::{IntlMathematicalValue, NumberFormatLocaleOptions},
+    options::{coerce_options_to_object, IntlOptions},
+    NumberFormat, Service,
+};
+
+mod options;
+pub(crate) use options::*;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Trace, Finalize, JsData)]
+// Safety: `RelativeTimeFormat` only contains non-traceable types, and its inner `NumberFormat`
+// never stores a bound format function.
+#[boa_gc(unsafe_empty_trace)]
+pub(crate) struct RelativeTimeFormat {
+    locale: Locale,
+    numbering_system: Option<Value>,
+    style: RelativeTimeStyle,
+    numeric: Numeric,
+    number_format: NumberFormat,
+}
+
+impl Service for RelativeTimeFormat {
+    type LangMarker = LongDayRelativeTimeFormatDataV1Marker;
+
+    type LocaleOptions = NumberFormatLocaleOptions;
+
+    fn resolve(locale: &mut Locale, options: &mut Self::LocaleOptions, provider: &IntlProvider) {
+        // The only relevant extension key is `nu`, which is resolved in the same way as
+        // `Intl.NumberFormat` does it.
+        <NumberFormat as Service>::resolve(locale, options, provider);
+    }
+}
+
+impl IntrinsicObject for RelativeTimeFormat {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(
+                Self::supported_locales_of,
+                js_string!("supportedLocalesOf"),
+                1,
+            )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("Intl.RelativeTimeFormat"),
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::format, js_string!("format"), 2)
+            .method(Self::format_to_parts, js_string!("formatToParts"), 2)
+            .method(Self::resolved_options, js_string!("resolvedOptions"), 0)
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for RelativeTimeFormat {
+    const NAME: JsString = StaticJsStrings::RELATIVE_TIME_FORMAT;
+}
+
+impl BuiltInConstructor for RelativeTimeFormat {
+    const LENGTH: usize = 0;
+    const P: usize = 4;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::relative_time_format;
+
+    /// Constructor [`Intl.RelativeTimeFormat ( [ locales [ , options ] ] )`][spec].
+    ///
+    /// Constructor for `RelativeTimeFormat` objects.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl-relativetimeformat-constructor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/RelativeTimeFormat
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("cannot call `Intl.RelativeTimeFormat` constructor without `new`")
+                .into());
+        }
+
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 2. Let relativeTimeFormat be ? OrdinaryCreateFromConstructor(NewTarget, "%Intl.RelativeTimeFormat.prototype%", « [[InitializedRelativeTimeFormat]], [[Locale]], [[DataLocale]], [[Style]], [[Numeric]], [[NumberFormat]], [[NumberingSystem]], [[PluralRules]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::relative_time_format,
+            context,
+        )?;
+
+        // 3. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 4. Set options to ? CoerceOptionsToObject(options).
+        let options = coerce_options_to_object(options, context)?;
+
+        // 5. Let opt be a new Record.
+        // 6. Let matcher be ? GetOption(options, "localeMatcher", string, « "lookup", "best fit" », "best fit").
+        // 7. Set opt.[[LocaleMatcher]] to matcher.
+        let matcher =
+            get_option(&options, js_string!("localeMatcher"), context)?.unwrap_or_default();
+
+        // 8. Let numberingSystem be ? GetOption(options, "numberingSystem", string, empty, undefined).
+        // 9. If numberingSystem is not undefined, then
+        //     a. If numberingSystem cannot be matched by the type Unicode locale nonterminal, throw a RangeError exception.
+        // 10. Set opt.[[nu]] to numberingSystem.
+        let numbering_system = get_option(&options, js_string!("numberingSystem"), context)?;
+
+        let mut intl_options = IntlOptions {
+            matcher,
+            service_options: NumberFormatLocaleOptions { numbering_system },
+        };
+
+        // 11. Let r be ResolveLocale(%Intl.RelativeTimeFormat%.[[AvailableLocales]], requestedLocales, opt, %Intl.RelativeTimeFormat%.[[RelevantExtensionKeys]], %Intl.RelativeTimeFormat%.[[LocaleData]]).
+        // 12. Let locale be r.[[Locale]].
+        // 13. Set relativeTimeFormat.[[Locale]] to locale.
+        // 14. Set relativeTimeFormat.[[DataLocale]] to r.[[DataLocale]].
+        // 15. Set relativeTimeFormat.[[NumberingSystem]] to r.[[nu]].
+        let locale = resolve_locale::<Self>(
+            requested_locales,
+            &mut intl_options,
+            context.intl_provider(),
+        )?;
+
+        // 16. Let style be ? GetOption(options, "style", string, « "long", "short", "narrow" », "long").
+        // 17. Set relativeTimeFormat.[[Style]] to style.
+        let style = get_option(&options, js_string!("style"), context)?.unwrap_or_default();
+
+        // 18. Let numeric be ? GetOption(options, "numeric", string, « "always", "auto" », "always").
+        // 19. Set relativeTimeFormat.[[Numeric]] to numeric.
+        let numeric = get_option(&options, js_string!("numeric"), context)?.unwrap_or_default();
+
+        // 20. Let relativeTimeFormat.[[NumberFormat]] be ! Construct(%Intl.NumberFormat%, « locale »).
+        let number_format = NumberFormat::new(
+            &js_string!(locale.to_string()).into(),
+            &JsValue::undefined(),
+            context,
+        )?;
+
+        // 21. Let relativeTimeFormat.[[PluralRules]] be ! Construct(%Intl.PluralRules%, « locale »).
+        // The plural rules are loaded by ICU4X alongside the relative time patterns on each format.
+
+        let relative_time_format = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                locale,
+                numbering_system: intl_options.service_options.numbering_system,
+                style,
+                numeric,
+                number_format,
+            },
+        );
+
+        // 22. Return relativeTimeFormat.
+        Ok(relative_time_format.into())
+    }
+}
+
+impl RelativeTimeFormat {
+    /// [`Intl.RelativeTimeFormat.supportedLocalesOf ( locales [ , options ] )`][spec].
+    ///
+    /// Returns an array containing those of the provided locales that are supported in relative
+    /// time formatting without having to fall back to the runtime's default locale.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.supportedLocalesOf
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/supportedLocalesOf
+    fn supported_locales_of(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let locales = args.get_or_undefined(0);
+        let options = args.get_or_undefined(1);
+
+        // 1. Let availableLocales be %Intl.RelativeTimeFormat%.[[AvailableLocales]].
+        // 2. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+        let requested_locales = canonicalize_locale_list(locales, context)?;
+
+        // 3. Return ? FilterLocales(availableLocales, requestedLocales, options).
+        filter_locales::<<Self as Service>::LangMarker>(requested_locales, options, context)
+            .map(JsValue::from)
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.format ( value, unit )`][spec].
+    ///
+    /// Returns a language-specific string describing the relative time `value` in `unit`s.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/format
+    fn format(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`format` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTime(relativeTimeFormat, value, unit).
+
+        // FormatRelativeTime ( relativeTimeFormat, value, unit )
+        // https://tc39.es/ecma402/#sec-FormatRelativeTime
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf.borrow().data.partition_relative_time_pattern(
+            value,
+            &unit,
+            context.intl_provider(),
+        )?;
+
+        // 2. Let result be the empty String.
+        // 3. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        //     a. Set result to the string-concatenation of result and part.[[Value]].
+        let result = parts
+            .into_iter()
+            .map(|(_, value, _)| value)
+            .collect::<String>();
+
+        // 4. Return result.
+        Ok(js_string!(result).into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.formatToParts ( value, unit )`][spec].
+    ///
+    /// Returns an array of objects representing the relative time format in parts that can be
+    /// used for custom locale-aware formatting.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-Intl.RelativeTimeFormat.prototype.formatToParts
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/formatToParts
+    fn format_to_parts(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this
+            .as_object()
+            .and_then(|o| o.clone().downcast::<Self>().ok())
+            .ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "`formatToParts` can only be called on an `Intl.RelativeTimeFormat` object",
+                )
+            })?;
+
+        // 3. Let value be ? ToNumber(value).
+        let value = args.get_or_undefined(0).to_number(context)?;
+
+        // 4. Let unit be ? ToString(unit).
+        let unit = args.get_or_undefined(1).to_string(context)?;
+
+        // 5. Return ? FormatRelativeTimeToParts(relativeTimeFormat, value, unit).
+
+        // FormatRelativeTimeToParts ( relativeTimeFormat, value, unit )
+        // https://tc39.es/ecma402/#sec-FormatRelativeTimeToParts
+
+        // 1. Let parts be ? PartitionRelativeTimePattern(relativeTimeFormat, value, unit).
+        let parts = rtf.borrow().data.partition_relative_time_pattern(
+            value,
+            &unit,
+            context.intl_provider(),
+        )?;
+
+        // 2. Let result be ! ArrayCreate(0).
+        let result = Array::array_create(0, None, context)
+            .expect("creating an empty array with default proto must not fail");
+
+        // 3. Let n be 0.
+        // 4. For each Record { [[Type]], [[Value]], [[Unit]] } part in parts, do
+        for (n, (typ, value, unit)) in parts.into_iter().enumerate() {
+            // a. Let O be OrdinaryObjectCreate(%Object.prototype%).
+            let o = context
+                .intrinsics()
+                .templates()
+                .ordinary_object()
+                .create(OrdinaryObject, vec![]);
+
+            // b. Perform ! CreateDataPropertyOrThrow(O, "type", part.[[Type]]).
+            o.create_data_property_or_throw(js_string!("type"), js_string!(typ), context)
+                .expect("operation must not fail per the spec");
+
+            // c. Perform ! CreateDataPropertyOrThrow(O, "value", part.[[Value]]).
+            o.create_data_property_or_throw(js_string!("value"), js_string!(value), context)
+                .expect("operation must not fail per the spec");
+
+            // d. If part.[[Unit]] is not empty, then
+            if let Some(unit) = unit {
+                // i. Perform ! CreateDataPropertyOrThrow(O, "unit", part.[[Unit]]).
+                o.create_data_property_or_throw(
+                    js_string!("unit"),
+                    js_string!(unit.as_str()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+            }
+
+            // e. Perform ! CreateDataPropertyOrThrow(result, ! ToString(𝔽(n)), O).
+            result
+                .create_data_property_or_throw(n, o, context)
+                .expect("operation must not fail per the spec");
+
+            // f. Increment n by 1.
+        }
+
+        // 5. Return result.
+        Ok(result.into())
+    }
+
+    /// [`Intl.RelativeTimeFormat.prototype.resolvedOptions ( )`][spec].
+    ///
+    /// Returns a new object with properties reflecting the locale and formatting options
+    /// computed during the construction of the current `Intl.RelativeTimeFormat` object.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.relativetimeformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/RelativeTimeFormat/resolvedOptions
+    fn resolved_options(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let relativeTimeFormat be the this value.
+        // 2. Perform ? RequireInternalSlot(relativeTimeFormat, [[InitializedRelativeTimeFormat]]).
+        let rtf = this.as_object().map(JsObject::borrow).ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+        let rtf = rtf.downcast_ref::<Self>().ok_or_else(|| {
+            JsNativeError::typ().with_message(
+                "`resolvedOptions` can only be called on an `Intl.RelativeTimeFormat` object",
+            )
+        })?;
+
+        // 3. Let options be OrdinaryObjectCreate(%Object.prototype%).
+        let options = context
+            .intrinsics()
+            .templates()
+            .ordinary_object()
+            .create(OrdinaryObject, vec![]);
+
+        // 4. For each row of Table 16, except the header row, in table order, do
+        //     a. Let p be the Property value of the current row.
+        //     b. Let v be the value of relativeTimeFormat's internal slot whose name is the Internal Slot value of the current row.
+        //     c. Assert: v is not undefined.
+        //     d. Perform ! CreateDataPropertyOrThrow(options, p, v).
+        options
+            .create_data_property_or_throw(
+                js_string!("locale"),
+                js_string!(rtf.locale.to_string()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("style"),
+                js_string!(rtf.style.as_str()),
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        options
+            .create_data_property_or_throw(
+                js_string!("numeric"),
+                match rtf.numeric {
+                    Numeric::Always => js_string!("always"),
+                    Numeric::Auto => js_string!("auto"),
+                },
+                context,
+            )
+            .expect("operation must not fail per the spec");
+        if let Some(nu) = &rtf.numbering_system {
+            options
+                .create_data_property_or_throw(
+                    js_string!("numberingSystem"),
+                    js_string!(nu.to_string()),
+                    context,
+                )
+                .expect("operation must not fail per the spec");
+        }
+
+        // 5. Return options.
+        Ok(options.into())
+    }
+
+    /// Abstract operation [`PartitionRelativeTimePattern ( relativeTimeFormat, value, unit )`][spec].
+    ///
+    /// ICU4X only tags the literal sections of its output, so the number is split into parts
+    /// by the inner `NumberFormat` and spliced back in place of the untagged section.
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-PartitionRelativeTimePattern
+    fn partition_relative_time_pattern(
+        &self,
+        value: f64,
+        unit: &JsString,
+        provider: &IntlProvider,
+    ) -> JsResult<Vec<(&'static str, String, Option<RelativeTimeUnit>)>> {
+        use writeable::{PartsWrite, Writeable};
+
+        #[derive(Debug)]
+        enum Part {
+            Literal(String),
+            Number,
+        }
+
+        #[derive(Debug)]
+        struct WriteString(String);
+
+        impl Write for WriteString {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                self.0.write_str(s)
+            }
+        }
+
+        impl PartsWrite for WriteString {
+            type SubPartsWrite = Self;
+
+            fn with_part(
+                &mut self,
+                _part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                f(self)
+            }
+        }
+
+        #[derive(Debug)]
+        struct PartsCollector(Vec<Part>);
+
+        impl Write for PartsCollector {
+            // Anything written outside of a part is the formatted number.
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                if !s.is_empty() && !matches!(self.0.last(), Some(Part::Number)) {
+                    self.0.push(Part::Number);
+                }
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for PartsCollector {
+            type SubPartsWrite = WriteString;
+
+            fn with_part(
+                &mut self,
+                _part: writeable::Part,
+                mut f: impl FnMut(&mut Self::SubPartsWrite) -> std::fmt::Result,
+            ) -> std::fmt::Result {
+                let mut string = WriteString(String::new());
+                f(&mut string)?;
+                if !string.0.is_empty() {
+                    self.0.push(Part::Literal(string.0));
+                }
+                Ok(())
+            }
+        }
+
+        // 1. Assert: relativeTimeFormat has an [[InitializedRelativeTimeFormat]] internal slot.
+        // 2. If value is NaN, +∞𝔽, or -∞𝔽, throw a RangeError exception.
+        if !value.is_finite() {
+            return Err(JsNativeError::range()
+                .with_message("relative time value must be a finite number")
+                .into());
+        }
+
+        // 3. Let unit be ? SingularRelativeTimeUnit(unit).
+        let unit = unit
+            .to_std_string_escaped()
+            .parse::<RelativeTimeUnit>()
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        // 4. Let fields be relativeTimeFormat.[[Fields]].
+        // 5. Let style be relativeTimeFormat.[[Style]].
+        // 6-9. Let patterns be fields.[[<unit-style>]].
+        let formatter = self.formatter(unit, provider)?;
+
+        // 10. If value is -0𝔽 or if value is less than -0𝔽, then
+        //     a. Let tl be "past".
+        //     b. Set value to -value.
+        // 11. Else,
+        //     a. Let tl be "future".
+        let past = value.is_sign_negative();
+        let x = FixedDecimal::try_from_f64(value.abs(), FloatPrecision::Floating)
+            .map_err(|err| JsNativeError::range().with_message(err.to_string()))?;
+
+        // 12. Let fv be ! PartitionNumberPattern(relativeTimeFormat.[[NumberFormat]], ℝ(value)).
+        // This also rounds `x` to the digit options of the `NumberFormat`, which is the value
+        // used to select the plural category below.
+        let mut x = IntlMathematicalValue::Finite(x);
+        let mut number = self.number_format.partition_number_pattern(&mut x);
+        let IntlMathematicalValue::Finite(mut x) = x else {
+            unreachable!("formatting a finite value keeps it finite");
+        };
+        if past {
+            x.set_sign(Sign::Negative);
+        }
+
+        // 13. If the numeric is "auto" and patterns has a field for ToString(value), return
+        //     the literal string of that field.
+        // 14. Let pr be ! ResolvePlural(relativeTimeFormat.[[PluralRules]], value).[[PluralCategory]].
+        // 15. Let pattern be patterns.[[<tl>]].[[<pr>]].
+        // 16. Return MakePartsList(pattern, unit, fv).
+        let mut collector = PartsCollector(Vec::new());
+        formatter
+            .format(x)
+            .write_to_parts(&mut collector)
+            .map_err(|err| JsNativeError::typ().with_message(err.to_string()))?;
+
+        let mut parts = Vec::new();
+        for part in collector.0 {
+            match part {
+                Part::Literal(value) => parts.push(("literal", value, None)),
+                Part::Number => parts.extend(
+                    std::mem::take(&mut number)
+                        .into_iter()
+                        .map(|(typ, value)| (typ, value, Some(unit))),
+                ),
+            }
+        }
+
+        Ok(parts)
+    }
+
+    /// Creates the ICU4X formatter for the style of this `RelativeTimeFormat` and `unit`.
+    fn formatter(
+        &self,
+        unit: RelativeTimeUnit,
+        provider: &IntlProvider,
+    ) -> JsResult<RelativeTimeFormatter> {
+        use RelativeTimeFormatter as F;
+        use RelativeTimeStyle as S;
+        use RelativeTimeUnit as U;
+
+        let locale = &DataLocale::from(&self.locale);
+        let options = RelativeTimeFormatterOptions {
+            numeric: self.numeric,
+        };
+
+        match (self.style, unit) {
+            (S::Long, U::Second) => F::try_new_long_second_unstable(provider, locale, options),
+            (S::Long, U::Minute) => F::try_new_long_minute_unstable(provider, locale, options),
+            (S::Long, U::Hour) => F::try_new_long_hour_unstable(provider, locale, options),
+            (S::Long, U::Day) => F::try_new_long_day_unstable(provider, locale, options),
+            (S::Long, U::Week) => F::try_new_long_week_unstable(provider, locale, options),
+            (S::Long, U::Month) => F::try_new_long_month_unstable(provider, locale, options),
+            (S::Long, U::Quarter) => F::try_new_long_quarter_unstable(provider, locale, options),
+            (S::Long, U::Year) => F::try_new_long_year_unstable(provider, locale, options),
+            (S::Short, U::Second) => F::try_new_short_second_unstable(provider, locale, options),
+            (S::Short, U::Minute) => F::try_new_short_minute_unstable(provider, locale, options),
+            (S::Short, U::Hour) => F::try_new_short_hour_unstable(provider, locale, options),
+            (S::Short, U::Day) => F::try_new_short_day_unstable(provider, locale, options),
+            (S::Short, U::Week) => F::try_new_short_week_unstable(provider, locale, options),
+            (S::Short, U::Month) => F::try_new_short_month_unstable(provider, locale, options),
+            (S::Short, U::Quarter) => F::try_new_short_quarter_unstable(provider, locale, options),
+            (S::Short, U::Year) => F::try_new_short_year_unstable(provider, locale, options),
+            (S::Narrow, U::Second) => F::try_new_narrow_second_unstable(provider, locale, options),
+            (S::Narrow, U::Minute) => F::try_new_narrow_minute_unstable(provider, locale, options),
+            (S::Narrow, U::Hour) => F::try_new_narrow_hour_unstable(provider, locale, options),
+            (S::Narrow, U::Day) => F::try_new_narrow_day_unstable(provider, locale, options),
+            (S::Narrow, U::Week) => F::try_new_narrow_week_unstable(provider, locale, options),
+            (S::Narrow, U::Month) => F::try_new_narrow_month_unstable(provider, locale, options),
+            (S::Narrow, U::Quarter) => {
+                F::try_new_narrow_quarter_unstable(provider, locale, options)
+            }
+            (S::Narrow, U::Year) => F::try_new_narrow_year_unstable(provider, locale, options),
+        }
+        .map_err(|err| JsNativeError::typ().with_message(err.to_string()).into())
+    }
+}
@@ -0,0 +1,61 @@
+use crate::builtins::intl::relative_time_format::RelativeTimeUnit;
+#[cfg(feature = "intl_bundled")]
+use crate::{js_str, run_test_actions, JsNativeErrorKind, TestAction};
+
+#[test]
+fn singular_relative_time_unit() {
+    let valid_cases = [
+        ("second", RelativeTimeUnit::Second),
+        ("seconds", RelativeTimeUnit::Second),
+        ("minute", RelativeTimeUnit::Minute),
+        ("minutes", RelativeTimeUnit::Minute),
+        ("hours", RelativeTimeUnit::Hour),
+        ("day", RelativeTimeUnit::Day),
+        ("weeks", RelativeTimeUnit::Week),
+        ("month", RelativeTimeUnit::Month),
+        ("quarters", RelativeTimeUnit::Quarter),
+        ("year", RelativeTimeUnit::Year),
+    ];
+
+    for (unit, expected) in valid_cases {
+        assert_eq!(unit.parse::<RelativeTimeUnit>().ok(), Some(expected));
+    }
+}
+
+#[test]
+fn invalid_relative_time_unit() {
+    const INVALID_CASES: [&str; 5] = ["", "Day", "days ", "millisecond", "decade"];
+
+    for unit in INVALID_CASES {
+        assert!(unit.parse::<RelativeTimeUnit>().is_err());
+    }
+}
+
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn format() {
+    run_test_actions([
+        TestAction::run("var rtf = new Intl.RelativeTimeFormat('en', { numeric: 'auto' });"),
+        TestAction::assert_eq("rtf.format(3, 'days')", js_str!("in 3 days")),
+        TestAction::assert_eq("rtf.format(-2, 'hour')", js_str!("2 hours ago")),
+        TestAction::assert_eq("rtf.format(-1, 'day')", js_str!("yesterday")),
+        TestAction::assert_eq(
+            "new Intl.RelativeTimeFormat('en').format(-1, 'day')",
+            js_str!("1 day ago"),
+        ),
+        TestAction::assert_eq(
+            "rtf.formatToParts(100, 'day').map(p => `${p.type}:${p.value}:${p.unit}`).join('|')",
+            js_str!("literal:in :undefined|integer:100:day|literal: days:undefined"),
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(Infinity, 'day')",
+            JsNativeErrorKind::Range,
+            "relative time value must be a finite number",
+        ),
+        TestAction::assert_native_error(
+            "rtf.format(1, 'decade')",
+            JsNativeErrorKind::Range,
+            "provided string was not a valid relative time unit",
+        ),
+    ]);
+}
@@ -0,0 +1,458 @@
+//! This module implements the `%IteratorHelperPrototype%` object and the iterator helper objects
+//! returned by the lazy methods of `Iterator.prototype`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%-object
+
+use crate::{
+    builtins::{
+        iterable::{create_iter_result_object, get_iterator_flattenable, PrimitiveHandling},
+        BuiltInBuilder, IntrinsicObject,
+    },
+    context::intrinsics::Intrinsics,
+    error::JsNativeError,
+    js_string,
+    object::JsObject,
+    property::Attribute,
+    realm::Realm,
+    symbol::JsSymbol,
+    value::IntegerOrInfinity,
+    Context, JsData, JsResult, JsValue,
+};
+use boa_gc::{Finalize, Trace};
+use boa_profiler::Profiler;
+
+use super::IteratorRecord;
+
+/// The state of the generator-like object backing an iterator helper.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// The abstract closure captured by an iterator helper object.
+///
+/// Each variant stores the values that the spec closure keeps alive between `Yield`s, which
+/// allows resuming the closure on every call to `next`.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) enum HelperClosure {
+    /// Closure of `Iterator.prototype.map ( mapper )`.
+    Map { mapper: JsObject, counter: u64 },
+
+    /// Closure of `Iterator.prototype.filter ( predicate )`.
+    Filter { predicate: JsObject, counter: u64 },
+
+    /// Closure of `Iterator.prototype.take ( limit )`.
+    Take {
+        #[unsafe_ignore_trace]
+        remaining: IntegerOrInfinity,
+    },
+
+    /// Closure of `Iterator.prototype.drop ( limit )`.
+    Drop {
+        #[unsafe_ignore_trace]
+        remaining: IntegerOrInfinity,
+    },
+
+    /// Closure of `Iterator.prototype.flatMap ( mapper )`.
+    FlatMap {
+        mapper: JsObject,
+        counter: u64,
+        inner: Option<IteratorRecord>,
+    },
+}
+
+impl HelperClosure {
+    /// Runs the closure until the next `Yield`, returning `None` if the closure returned.
+    fn resume(
+        &mut self,
+        iterated: &mut IteratorRecord,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>> {
+        match self {
+            Self::Map { mapper, counter } => {
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                let mapped =
+                    mapper.call(&JsValue::undefined(), &[value, (*counter).into()], context);
+
+                //     iv. IfAbruptCloseIterator(mapped, iterated).
+                let mapped = match mapped {
+                    Ok(mapped) => mapped,
+                    Err(err) => return iterated.close(Err(err), context).map(Some),
+                };
+
+                //     vii. Set counter to counter + 1.
+                *counter += 1;
+
+                //     v. Let completion be Completion(Yield(mapped)).
+                Ok(Some(mapped))
+            }
+            Self::Filter { predicate, counter } => loop {
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+                let selected = predicate.call(
+                    &JsValue::undefined(),
+                    &[value.clone(), (*counter).into()],
+                    context,
+                );
+
+                //     iv. IfAbruptCloseIterator(selected, iterated).
+                let selected = match selected {
+                    Ok(selected) => selected,
+                    Err(err) => return iterated.close(Err(err), context).map(Some),
+                };
+
+                //     vi. Set counter to counter + 1.
+                *counter += 1;
+
+                //     v. If ToBoolean(selected) is true, then
+                //         1. Let completion be Completion(Yield(value)).
+                if selected.to_boolean() {
+                    return Ok(Some(value));
+                }
+            },
+            Self::Take { remaining } => {
+                // b. Repeat,
+                match remaining {
+                    //     i. If remaining = 0, then
+                    //         1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+                    IntegerOrInfinity::Integer(0) => {
+                        iterated.close(Ok(JsValue::undefined()), context)?;
+                        return Ok(None);
+                    }
+                    //     ii. If remaining ≠ +∞, then
+                    //         1. Set remaining to remaining - 1.
+                    IntegerOrInfinity::Integer(i) => *i -= 1,
+                    IntegerOrInfinity::PositiveInfinity | IntegerOrInfinity::NegativeInfinity => {}
+                }
+
+                //     iii. Let value be ? IteratorStepValue(iterated).
+                //     iv. If value is done, return ReturnCompletion(undefined).
+                //     v. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::Drop { remaining } => {
+                // b. Repeat, while remaining > 0,
+                while *remaining > 0 {
+                    //     i. If remaining ≠ +∞, then
+                    //         1. Set remaining to remaining - 1.
+                    if let IntegerOrInfinity::Integer(i) = remaining {
+                        *i -= 1;
+                    }
+
+                    //     ii. Let next be ? IteratorStep(iterated).
+                    //     iii. If next is done, return ReturnCompletion(undefined).
+                    if iterated.step(context)? {
+                        return Ok(None);
+                    }
+                }
+
+                // c. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                //     iii. Let completion be Completion(Yield(value)).
+                iterated.step_value(context)
+            }
+            Self::FlatMap {
+                mapper,
+                counter,
+                inner,
+            } => loop {
+                // viii. Repeat, while innerAlive is true,
+                if let Some(inner_iterator) = inner {
+                    // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+                    // 2. IfAbruptCloseIterator(innerValue, iterated).
+                    match inner_iterator.step_value(context) {
+                        Err(err) => return iterated.close(Err(err), context).map(Some),
+                        // 4. Else,
+                        //     a. Let completion be Completion(Yield(innerValue)).
+                        Ok(Some(value)) => return Ok(Some(value)),
+                        // 3. If innerValue is done, then
+                        //     a. Set innerAlive to false.
+                        Ok(None) => {
+                            *inner = None;
+
+                            // ix. Set counter to counter + 1.
+                            *counter += 1;
+                        }
+                    }
+                }
+
+                // b. Repeat,
+                //     i. Let value be ? IteratorStepValue(iterated).
+                //     ii. If value is done, return ReturnCompletion(undefined).
+                let Some(value) = iterated.step_value(context)? else {
+                    return Ok(None);
+                };
+
+                //     iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+                //     iv. IfAbruptCloseIterator(mapped, iterated).
+                //     v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+                //     vi. IfAbruptCloseIterator(innerIterator, iterated).
+                let inner_iterator = mapper
+                    .call(&JsValue::undefined(), &[value, (*counter).into()], context)
+                    .and_then(|mapped| {
+                        get_iterator_flattenable(
+                            &mapped,
+                            PrimitiveHandling::RejectPrimitives,
+                            context,
+                        )
+                    });
+                match inner_iterator {
+                    //     vii. Let innerAlive be true.
+                    Ok(inner_iterator) => *inner = Some(inner_iterator),
+                    Err(err) => return iterated.close(Err(err), context).map(Some),
+                }
+            },
+        }
+    }
+
+    /// Resumes the closure with a return completion, closing any open iterators.
+    fn resume_return(&self, iterated: &IteratorRecord, context: &mut Context) -> JsResult<()> {
+        // If the closure is suspended inside `flatMap`'s inner loop:
+        if let Self::FlatMap {
+            inner: Some(inner_iterator),
+            ..
+        } = self
+        {
+            // a. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+            // b. IfAbruptCloseIterator(backupCompletion, iterated).
+            if let Err(err) = inner_iterator.close(Ok(JsValue::undefined()), context) {
+                return iterated.close(Err(err), context).map(|_| ());
+            }
+        }
+
+        // Let completion be Completion(Yield(value)).
+        // IfAbruptCloseIterator(completion, iterated).
+        iterated.close(Ok(JsValue::undefined()), context)?;
+        Ok(())
+    }
+}
+
+/// An iterator helper object, created by methods like `Iterator.prototype.map`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-helper-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct IteratorHelper {
+    /// `[[UnderlyingIterator]]`
+    underlying: IteratorRecord,
+    closure: HelperClosure,
+    #[unsafe_ignore_trace]
+    state: HelperState,
+}
+
+impl IntrinsicObject for IteratorHelper {
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::with_intrinsic::<Self>(realm)
+            .prototype(
+                realm
+                    .intrinsics()
+                    .objects()
+                    .iterator_prototypes()
+                    .iterator(),
+            )
+            .static_method(Self::next, js_string!("next"), 0)
+            .static_method(Self::r#return, js_string!("return"), 0)
+            .static_property(
+                JsSymbol::to_string_tag(),
+                js_string!("Iterator Helper"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().iterator_prototypes().iterator_helper()
+    }
+}
+
+impl IteratorHelper {
+    /// Abstract operation `CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype% )`
+    ///
+    /// Creates a new iterator helper object over `underlying`, which is driven by `closure`.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createiteratorfromclosure
+    pub(crate) fn create(
+        underlying: IteratorRecord,
+        closure: HelperClosure,
+        context: &mut Context,
+    ) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context
+                .intrinsics()
+                .objects()
+                .iterator_prototypes()
+                .iterator_helper(),
+            Self {
+                underlying,
+                closure,
+                state: HelperState::SuspendedStart,
+            },
+        )
+    }
+
+    /// `%IteratorHelperPrototype%.next ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next
+    pub(crate) fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        let object = this.as_object();
+        let (mut underlying, mut closure) = {
+            let mut helper = object
+                .and_then(JsObject::downcast_mut::<Self>)
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message("`this` is not an Iterator Helper")
+                })?;
+
+            match helper.state {
+                HelperState::Executing => {
+                    return Err(JsNativeError::typ()
+                        .with_message("Iterator Helper is already running")
+                        .into());
+                }
+                HelperState::Completed => {
+                    return Ok(create_iter_result_object(
+                        JsValue::undefined(),
+                        true,
+                        context,
+                    ));
+                }
+                HelperState::SuspendedStart | HelperState::SuspendedYield => {}
+            }
+
+            helper.state = HelperState::Executing;
+            (helper.underlying.clone(), helper.closure.clone())
+        };
+
+        // The closure can call back into user code, so it must run without holding a borrow
+        // of the helper object.
+        let result = closure.resume(&mut underlying, context);
+
+        let mut helper = object
+            .and_then(JsObject::downcast_mut::<Self>)
+            .expect("`this` must still be an Iterator Helper");
+        helper.underlying = underlying;
+        helper.closure = closure;
+
+        match result {
+            Ok(Some(value)) => {
+                helper.state = HelperState::SuspendedYield;
+                drop(helper);
+                Ok(create_iter_result_object(value, false, context))
+            }
+            Ok(None) => {
+                helper.state = HelperState::Completed;
+                drop(helper);
+                Ok(create_iter_result_object(
+                    JsValue::undefined(),
+                    true,
+                    context,
+                ))
+            }
+            Err(err) => {
+                helper.state = HelperState::Completed;
+                Err(err)
+            }
+        }
+    }
+
+    /// `%IteratorHelperPrototype%.return ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return
+    pub(crate) fn r#return(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterator]]).
+        let object = this.as_object();
+        let (underlying, closure, state) = {
+            let mut helper = object
+                .and_then(JsObject::downcast_mut::<Self>)
+                .ok_or_else(|| {
+                    JsNativeError::typ().with_message("`this` is not an Iterator Helper")
+                })?;
+
+            let state = helper.state;
+            if state == HelperState::Executing {
+                return Err(JsNativeError::typ()
+                    .with_message("Iterator Helper is already running")
+                    .into());
+            }
+            helper.state = HelperState::Completed;
+            (helper.underlying.clone(), helper.closure.clone(), state)
+        };
+
+        match state {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            HelperState::SuspendedStart => {
+                // a. Set O.[[GeneratorState]] to completed.
+                // b. NOTE: Once a generator enters the completed state it never leaves it and
+                //    its associated execution context is never resumed. Any execution state
+                //    associated with O can be discarded at this point.
+                // c. Perform ? IteratorClose(O.[[UnderlyingIterator]], NormalCompletion(unused)).
+                underlying.close(Ok(JsValue::undefined()), context)?;
+            }
+            // 5. Let C be Completion { [[Type]]: return, [[Value]]: undefined, [[Target]]: empty }.
+            // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+            HelperState::SuspendedYield => {
+                if let Some(object) = object {
+                    object
+                        .downcast_mut::<Self>()
+                        .expect("`this` must still be an Iterator Helper")
+                        .state = HelperState::Executing;
+                }
+                let result = closure.resume_return(&underlying, context);
+                if let Some(object) = object {
+                    object
+                        .downcast_mut::<Self>()
+                        .expect("`this` must still be an Iterator Helper")
+                        .state = HelperState::Completed;
+                }
+                result?;
+            }
+            HelperState::Executing | HelperState::Completed => {}
+        }
+
+        // d. Return CreateIterResultObject(undefined, true).
+        Ok(create_iter_result_object(
+            JsValue::undefined(),
+            true,
+            context,
+        ))
+    }
+}
@@ -1,20 +1,36 @@
-//! Boa's implementation of ECMAScript's `IteratorRecord` and iterator prototype objects.
+//! Boa's implementation of ECMAScript's `Iterator` constructor, `IteratorRecord` and iterator prototype objects.
 
 use crate::{
-    builtins::{BuiltInBuilder, IntrinsicObject},
-    context::intrinsics::Intrinsics,
+    builtins::{Array, BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     error::JsNativeError,
     js_string,
-    object::JsObject,
+    object::{
+        internal_methods::{get_prototype_from_constructor, InternalMethodContext},
+        JsObject, CONSTRUCTOR,
+    },
+    property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
+    string::StaticJsStrings,
     symbol::JsSymbol,
-    Context, JsResult, JsValue,
+    value::IntegerOrInfinity,
+    Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
 use boa_profiler::Profiler;
 
 mod async_from_sync_iterator;
+mod iterator_helper;
+mod wrap_for_valid_iterator;
+
+#[cfg(test)]
+mod tests;
+
 pub(crate) use async_from_sync_iterator::AsyncFromSyncIterator;
+pub(crate) use iterator_helper::IteratorHelper;
+pub(crate) use wrap_for_valid_iterator::WrapForValidIterator;
+
+use iterator_helper::HelperClosure;
 
 /// `IfAbruptCloseIterator ( value, iteratorRecord )`
 ///
@@ -71,6 +87,12 @@ pub struct IteratorPrototypes {
     /// The `ForInIteratorPrototype` prototype object.
     for_in: JsObject,
 
+    /// The `%IteratorHelperPrototype%` prototype object.
+    iterator_helper: JsObject,
+
+    /// The `%WrapForValidIteratorPrototype%` prototype object.
+    wrap_for_valid_iterator: JsObject,
+
     /// The `%SegmentIteratorPrototype%` prototype object.
     #[cfg(feature = "intl")]
     segment: JsObject,
@@ -140,6 +162,20 @@ impl IteratorPrototypes {
         self.for_in.clone()
     }
 
+    /// Returns the `%IteratorHelperPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn iterator_helper(&self) -> JsObject {
+        self.iterator_helper.clone()
+    }
+
+    /// Returns the `%WrapForValidIteratorPrototype%` object.
+    #[inline]
+    #[must_use]
+    pub fn wrap_for_valid_iterator(&self) -> JsObject {
+        self.wrap_for_valid_iterator.clone()
+    }
+
     /// Returns the `%SegmentIteratorPrototype%` object.
     #[inline]
     #[must_use]
@@ -149,28 +185,757 @@ impl IteratorPrototypes {
     }
 }
 
-/// `%IteratorPrototype%` object
+/// The global `Iterator` constructor and the `%Iterator.prototype%` object.
 ///
 /// More information:
 ///  - [ECMA reference][spec]
 ///
-/// [spec]: https://tc39.es/ecma262/#sec-%iteratorprototype%-object
+/// [spec]: https://tc39.es/ecma262/#sec-iterator-constructor
 pub(crate) struct Iterator;
 
 impl IntrinsicObject for Iterator {
     fn init(realm: &Realm) {
-        let _timer = Profiler::global().start_event("Iterator Prototype", "init");
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
 
-        BuiltInBuilder::with_intrinsic::<Self>(realm)
-            .static_method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+        let get_to_string_tag = BuiltInBuilder::callable(realm, Self::get_to_string_tag)
+            .name(js_string!("get [Symbol.toStringTag]"))
+            .build();
+        let set_to_string_tag = BuiltInBuilder::callable(realm, Self::set_to_string_tag)
+            .name(js_string!("set [Symbol.toStringTag]"))
+            .length(1)
             .build();
+        let get_constructor = BuiltInBuilder::callable(realm, Self::get_constructor)
+            .name(js_string!("get constructor"))
+            .build();
+        let set_constructor = BuiltInBuilder::callable(realm, Self::set_constructor)
+            .name(js_string!("set constructor"))
+            .length(1)
+            .build();
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .static_method(Self::from, js_string!("from"), 1)
+            .method(|v, _, _| Ok(v.clone()), JsSymbol::iterator(), 0)
+            .method(Self::map, js_string!("map"), 1)
+            .method(Self::filter, js_string!("filter"), 1)
+            .method(Self::take, js_string!("take"), 1)
+            .method(Self::drop, js_string!("drop"), 1)
+            .method(Self::flat_map, js_string!("flatMap"), 1)
+            .method(Self::reduce, js_string!("reduce"), 1)
+            .method(Self::to_array, js_string!("toArray"), 0)
+            .method(Self::for_each, js_string!("forEach"), 1)
+            .method(Self::some, js_string!("some"), 1)
+            .method(Self::every, js_string!("every"), 1)
+            .method(Self::find, js_string!("find"), 1)
+            .accessor(
+                JsSymbol::to_string_tag(),
+                Some(get_to_string_tag),
+                Some(set_to_string_tag),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        // `Iterator.prototype.constructor` is an accessor instead of the usual data property,
+        // so it must be patched after building the prototype.
+        realm
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .prototype()
+            .insert(
+                CONSTRUCTOR,
+                PropertyDescriptor::builder()
+                    .get(get_constructor)
+                    .set(set_constructor)
+                    .enumerable(false)
+                    .configurable(true),
+            );
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
-        intrinsics.objects().iterator_prototypes().iterator()
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+}
+
+impl BuiltInObject for Iterator {
+    const NAME: JsString = StaticJsStrings::ITERATOR;
+}
+
+impl BuiltInConstructor for Iterator {
+    const LENGTH: usize = 0;
+    const P: usize = 14;
+    const SP: usize = 1;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::iterator;
+
+    /// `Iterator ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is either undefined or the active function object, throw a TypeError exception.
+        let iterator = context.intrinsics().constructors().iterator().constructor();
+        if new_target.is_undefined()
+            || new_target
+                .as_object()
+                .is_some_and(|new_target| JsObject::equals(new_target, &iterator))
+        {
+            return Err(JsNativeError::typ()
+                .with_message("Iterator: abstract class cannot be constructed directly")
+                .into());
+        }
+
+        // 2. Return ? OrdinaryCreateFromConstructor(NewTarget, "%Iterator.prototype%").
+        let prototype =
+            get_prototype_from_constructor(new_target, StandardConstructors::iterator, context)?;
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            OrdinaryObject,
+        )
+        .into())
     }
 }
 
+impl Iterator {
+    /// `Iterator.from ( O )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.from
+    fn from(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = get_iterator_flattenable(
+            args.get_or_undefined(0),
+            PrimitiveHandling::IterateStringPrimitives,
+            context,
+        )?;
+
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let iterator = context.intrinsics().constructors().iterator().constructor();
+        let has_instance = JsValue::ordinary_has_instance(
+            &iterator.into(),
+            &iterator_record.iterator().clone().into(),
+            context,
+        )?;
+
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator_record.iterator().clone().into());
+        }
+
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(WrapForValidIterator::create(iterator_record, context).into())
+    }
+
+    /// Gets the `this` object of an `Iterator.prototype` method.
+    fn this_object(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, throw a TypeError exception.
+        this.as_object().cloned().ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: `this` is not an object"
+                ))
+                .into()
+        })
+    }
+
+    /// Checks that `value` is callable, closing `iterated` with a `TypeError` otherwise.
+    fn require_callable(
+        value: &JsValue,
+        iterated: &IteratorRecord,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // If IsCallable(value) is false, then
+        if let Some(callable) = value.as_callable() {
+            return Ok(callable.clone());
+        }
+
+        // a. Let error be ThrowCompletion(a newly created TypeError object).
+        let error = JsNativeError::typ()
+            .with_message(format!(
+                "Iterator.prototype.{method}: argument is not callable"
+            ))
+            .into();
+
+        // b. Return ? IteratorClose(iterated, error).
+        iterated.close(Err(error), context)?;
+        unreachable!("closing an iterator with a throw completion must return an error")
+    }
+
+    /// Converts `limit` to a non-negative integer or +∞, closing `iterated` on failure.
+    fn to_limit(
+        limit: &JsValue,
+        iterated: &IteratorRecord,
+        method: &str,
+        context: &mut Context,
+    ) -> JsResult<IntegerOrInfinity> {
+        // 4. Let numLimit be Completion(ToNumber(limit)).
+        // 5. IfAbruptCloseIterator(numLimit, iterated).
+        let num_limit = match limit.to_number(context) {
+            Ok(num_limit) => num_limit,
+            Err(err) => {
+                iterated.close(Err(err), context)?;
+                unreachable!("closing an iterator with a throw completion must return an error");
+            }
+        };
+
+        // 6. If numLimit is NaN, then
+        if num_limit.is_nan() {
+            // a. Let error be ThrowCompletion(a newly created RangeError object).
+            let error = JsNativeError::range()
+                .with_message(format!("Iterator.prototype.{method}: limit cannot be NaN"))
+                .into();
+
+            // b. Return ? IteratorClose(iterated, error).
+            iterated.close(Err(error), context)?;
+            unreachable!("closing an iterator with a throw completion must return an error");
+        }
+
+        // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+        let integer_limit = IntegerOrInfinity::from(num_limit);
+
+        // 8. If integerLimit < 0, then
+        if integer_limit < 0 {
+            // a. Let error be ThrowCompletion(a newly created RangeError object).
+            let error = JsNativeError::range()
+                .with_message(format!(
+                    "Iterator.prototype.{method}: limit cannot be negative"
+                ))
+                .into();
+
+            // b. Return ? IteratorClose(iterated, error).
+            iterated.close(Err(error), context)?;
+            unreachable!("closing an iterator with a throw completion must return an error");
+        }
+
+        Ok(integer_limit)
+    }
+
+    /// `Iterator.prototype.map ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.map
+    fn map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "map")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper = Self::require_callable(args.get_or_undefined(0), &iterated, "map", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(
+            IteratorHelper::create(iterated, HelperClosure::Map { mapper, counter: 0 }, context)
+                .into(),
+        )
+    }
+
+    /// `Iterator.prototype.filter ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.filter
+    fn filter(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "filter")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::require_callable(args.get_or_undefined(0), &iterated, "filter", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and predicate [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperClosure::Filter {
+                predicate,
+                counter: 0,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.take ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.take
+    fn take(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "take")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4-8. Validate `limit`, closing `iterated` on failure.
+        let remaining = Self::to_limit(args.get_or_undefined(0), &iterated, "take", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit [...]
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Take { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.drop ( limit )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.drop
+    fn drop(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "drop")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4-8. Validate `limit`, closing `iterated` on failure.
+        let remaining = Self::to_limit(args.get_or_undefined(0), &iterated, "drop", context)?;
+
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 10. Let closure be a new Abstract Closure with no parameters that captures iterated and integerLimit [...]
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 12. Set result.[[UnderlyingIterator]] to iterated.
+        // 13. Return result.
+        Ok(IteratorHelper::create(iterated, HelperClosure::Drop { remaining }, context).into())
+    }
+
+    /// `Iterator.prototype.flatMap ( mapper )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.flatmap
+    fn flat_map(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "flatMap")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(mapper) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let mapper =
+            Self::require_callable(args.get_or_undefined(0), &iterated, "flatMap", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let closure be a new Abstract Closure with no parameters that captures iterated and mapper [...]
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterator]] »).
+        // 8. Set result.[[UnderlyingIterator]] to iterated.
+        // 9. Return result.
+        Ok(IteratorHelper::create(
+            iterated,
+            HelperClosure::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+            context,
+        )
+        .into())
+    }
+
+    /// `Iterator.prototype.reduce ( reducer [ , initialValue ] )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.reduce
+    fn reduce(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "reduce")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(reducer) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let reducer =
+            Self::require_callable(args.get_or_undefined(0), &iterated, "reduce", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        let (mut accumulator, mut counter) = if let Some(initial_value) = args.get(1) {
+            // 7. Else,
+            //     a. Let accumulator be initialValue.
+            //     b. Let counter be 0.
+            (initial_value.clone(), 0u64)
+        } else {
+            // 6. If initialValue is not present, then
+            //     a. Let accumulator be ? IteratorStepValue(iterated).
+            //     b. If accumulator is done, throw a TypeError exception.
+            let accumulator = iterated.step_value(context)?.ok_or_else(|| {
+                JsNativeError::typ().with_message(
+                    "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+                )
+            })?;
+
+            //     c. Let counter be 1.
+            (accumulator, 1)
+        };
+
+        // 8. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            //     c. Let result be Completion(Call(reducer, undefined, « accumulator, value, 𝔽(counter) »)).
+            let result = reducer.call(
+                &JsValue::undefined(),
+                &[accumulator, value, counter.into()],
+                context,
+            );
+
+            //     d. IfAbruptCloseIterator(result, iterated).
+            //     e. Set accumulator to result.
+            accumulator = if_abrupt_close_iterator!(result, iterated, context);
+
+            //     f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return accumulator.
+        Ok(accumulator)
+    }
+
+    /// `Iterator.prototype.toArray ( )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.toarray
+    fn to_array(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "toArray")?;
+
+        // 3. Let iterated be ? GetIteratorDirect(O).
+        // 4. Let items be a new empty List.
+        // 5. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        //     b. If value is done, return CreateArrayFromList(items).
+        //     c. Append value to items.
+        let items = get_iterator_direct(&o, context)?.into_list(context)?;
+        Ok(Array::create_array_from_list(items, context).into())
+    }
+
+    /// `Iterator.prototype.forEach ( procedure )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.foreach
+    fn for_each(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let o = Self::this_object(this, "forEach")?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(procedure) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let procedure =
+            Self::require_callable(args.get_or_undefined(0), &iterated, "forEach", context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            //     c. Let result be Completion(Call(procedure, undefined, « value, 𝔽(counter) »)).
+            let result = procedure.call(&JsValue::undefined(), &[value, counter.into()], context);
+
+            //     d. IfAbruptCloseIterator(result, iterated).
+            if_abrupt_close_iterator!(result, iterated, context);
+
+            //     e. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        //     b. If value is done, return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Shared implementation of the short-circuiting `some`, `every` and `find` methods.
+    ///
+    /// Calls `predicate` with every value of the iterator until `stop` returns a result for the
+    /// current value and its `ToBoolean`'d predicate result, then closes the iterator with it.
+    fn find_with<F>(
+        this: &JsValue,
+        args: &[JsValue],
+        method: &str,
+        stop: F,
+        context: &mut Context,
+    ) -> JsResult<Option<JsValue>>
+    where
+        F: Fn(&JsValue, bool) -> Option<JsValue>,
+    {
+        let o = Self::this_object(this, method)?;
+
+        // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+        let iterated = IteratorRecord::new(o.clone(), JsValue::undefined());
+
+        // 4. If IsCallable(predicate) is false, then
+        //     a. Let error be ThrowCompletion(a newly created TypeError object).
+        //     b. Return ? IteratorClose(iterated, error).
+        let predicate =
+            Self::require_callable(args.get_or_undefined(0), &iterated, method, context)?;
+
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let mut iterated = get_iterator_direct(&o, context)?;
+
+        // 6. Let counter be 0.
+        let mut counter = 0u64;
+
+        // 7. Repeat,
+        //     a. Let value be ? IteratorStepValue(iterated).
+        while let Some(value) = iterated.step_value(context)? {
+            //     c. Let result be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+            let result = predicate.call(
+                &JsValue::undefined(),
+                &[value.clone(), counter.into()],
+                context,
+            );
+
+            //     d. IfAbruptCloseIterator(result, iterated).
+            let result = match result {
+                Ok(result) => result,
+                Err(err) => return iterated.close(Err(err), context).map(Some),
+            };
+
+            //     e. If ToBoolean(result) [...], return ? IteratorClose(iterated, NormalCompletion(...)).
+            if let Some(found) = stop(&value, result.to_boolean()) {
+                return iterated.close(Ok(found), context).map(Some);
+            }
+
+            //     f. Set counter to counter + 1.
+            counter += 1;
+        }
+
+        Ok(None)
+    }
+
+    /// `Iterator.prototype.some ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.some
+    fn some(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(true)).
+        // b. If value is done, return false.
+        Ok(Self::find_with(
+            this,
+            args,
+            "some",
+            |_, result| result.then(|| true.into()),
+            context,
+        )?
+        .unwrap_or_else(|| false.into()))
+    }
+
+    /// `Iterator.prototype.every ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.every
+    fn every(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // e. If ToBoolean(result) is false, return ? IteratorClose(iterated, NormalCompletion(false)).
+        // b. If value is done, return true.
+        Ok(Self::find_with(
+            this,
+            args,
+            "every",
+            |_, result| (!result).then(|| false.into()),
+            context,
+        )?
+        .unwrap_or_else(|| true.into()))
+    }
+
+    /// `Iterator.prototype.find ( predicate )`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iterator.prototype.find
+    fn find(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // e. If ToBoolean(result) is true, return ? IteratorClose(iterated, NormalCompletion(value)).
+        // b. If value is done, return undefined.
+        Ok(Self::find_with(
+            this,
+            args,
+            "find",
+            |value, result| result.then(|| value.clone()),
+            context,
+        )?
+        .unwrap_or_default())
+    }
+
+    /// `get Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype-%symbol.tostringtag%
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_to_string_tag(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<JsValue> {
+        // 1. Return "Iterator".
+        Ok(StaticJsStrings::ITERATOR.into())
+    }
+
+    /// `set Iterator.prototype [ %Symbol.toStringTag% ]`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype-%symbol.tostringtag%
+    fn set_to_string_tag(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, %Symbol.toStringTag%, v).
+        // 2. Return undefined.
+        setter_that_ignores_prototype_properties(
+            this,
+            JsSymbol::to_string_tag().into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `get Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-iterator.prototype.constructor
+    #[allow(clippy::unnecessary_wraps)]
+    fn get_constructor(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Return %Iterator%.
+        Ok(context
+            .intrinsics()
+            .constructors()
+            .iterator()
+            .constructor()
+            .into())
+    }
+
+    /// `set Iterator.prototype.constructor`
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set-iterator.prototype.constructor
+    fn set_constructor(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? SetterThatIgnoresPrototypeProperties(this value, %Iterator.prototype%, "constructor", v).
+        // 2. Return undefined.
+        setter_that_ignores_prototype_properties(
+            this,
+            CONSTRUCTOR.into(),
+            args.get_or_undefined(0),
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+}
+
+/// `SetterThatIgnoresPrototypeProperties ( thisValue, home, p, v )`, with `home` being
+/// `%Iterator.prototype%`.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-SetterThatIgnoresPrototypeProperties
+fn setter_that_ignores_prototype_properties(
+    this: &JsValue,
+    p: PropertyKey,
+    v: &JsValue,
+    context: &mut Context,
+) -> JsResult<()> {
+    // 1. If thisValue is not an Object, throw a TypeError exception.
+    let Some(this) = this.as_object() else {
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of a non-object value")
+            .into());
+    };
+
+    // 2. If SameValue(thisValue, home) is true, then
+    let home = context.intrinsics().constructors().iterator().prototype();
+    if JsObject::equals(this, &home) {
+        // a. NOTE: Throwing here emulates assignment to a non-writable data property on the home object in strict mode code.
+        // b. Throw a TypeError exception.
+        return Err(JsNativeError::typ()
+            .with_message("cannot set a property of %Iterator.prototype%")
+            .into());
+    }
+
+    // 3. Let desc be ? thisValue.[[GetOwnProperty]](p).
+    let desc = this.__get_own_property__(&p, &mut InternalMethodContext::new(context))?;
+
+    if desc.is_none() {
+        // 4. If desc is undefined, then
+        //     a. Perform ? CreateDataPropertyOrThrow(thisValue, p, v).
+        this.create_data_property_or_throw(p, v.clone(), context)?;
+    } else {
+        // 5. Else,
+        //     a. Perform ? Set(thisValue, p, v, true).
+        this.set(p, v.clone(), true, context)?;
+    }
+
+    // 6. Return unused.
+    Ok(())
+}
+
 /// `%AsyncIteratorPrototype%` object
 ///
 /// More information:
@@ -304,6 +1069,79 @@ impl JsValue {
     }
 }
 
+/// `GetIteratorDirect ( obj )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratordirect
+pub(crate) fn get_iterator_direct(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = object.get(js_string!("next"), context)?;
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(IteratorRecord::new(object.clone(), next_method))
+}
+
+/// How `GetIteratorFlattenable` handles primitive values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    /// Iterates string primitives, rejecting any other primitive.
+    IterateStringPrimitives,
+
+    /// Rejects all primitives.
+    RejectPrimitives,
+}
+
+/// `GetIteratorFlattenable ( obj, primitiveHandling )`
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiteratorflattenable
+pub(crate) fn get_iterator_flattenable(
+    value: &JsValue,
+    primitive_handling: PrimitiveHandling,
+    context: &mut Context,
+) -> JsResult<IteratorRecord> {
+    // 1. If obj is not an Object, then
+    if !value.is_object() {
+        // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+        // b. Assert: primitiveHandling is iterate-string-primitives.
+        // c. If obj is not a String, throw a TypeError exception.
+        if primitive_handling == PrimitiveHandling::RejectPrimitives || !value.is_string() {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "value with type `{}` is not an iterable object",
+                    value.type_of()
+                ))
+                .into());
+        }
+    }
+
+    // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+    let iterator = match value.get_method(JsSymbol::iterator(), context)? {
+        // 3. If method is undefined, then
+        //     a. Let iterator be obj.
+        None => value.clone(),
+        // 4. Else,
+        //     a. Let iterator be ? Call(method, obj).
+        Some(method) => method.call(value, &[], context)?,
+    };
+
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let iterator = iterator
+        .as_object()
+        .ok_or_else(|| JsNativeError::typ().with_message("returned iterator is not an object"))?;
+
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_iterator_direct(iterator, context)
+}
+
 /// The result of the iteration process.
 #[derive(Debug, Clone, Trace, Finalize)]
 pub struct IteratorResult {
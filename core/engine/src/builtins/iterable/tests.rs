@@ -0,0 +1,151 @@
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn iterator_constructor() {
+    run_test_actions([
+        TestAction::assert_eq("typeof Iterator", js_str!("function")),
+        TestAction::assert("Iterator.prototype === Object.getPrototypeOf(Object.getPrototypeOf([][Symbol.iterator]()))"),
+        TestAction::assert_native_error(
+            "new Iterator()",
+            JsNativeErrorKind::Type,
+            "Iterator: abstract class cannot be constructed directly",
+        ),
+        TestAction::assert(indoc! {r#"
+            class MyIterator extends Iterator {}
+            new MyIterator() instanceof Iterator
+        "#}),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(Iterator.prototype)",
+            js_str!("[object Iterator]"),
+        ),
+        TestAction::assert("Iterator.prototype.constructor === Iterator"),
+    ]);
+}
+
+#[test]
+fn lazy_helpers() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+            function* naturals() {
+                let i = 0;
+                while (true) {
+                    yield i++;
+                }
+            }
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                naturals()
+                    .filter((n) => n % 2 === 0)
+                    .map((n) => n * n)
+                    .drop(1)
+                    .take(3)
+                    .toArray(),
+                [4, 16, 36]
+            )
+        "#}),
+        TestAction::assert(indoc! {r#"
+            arrayEquals(
+                naturals().take(3).flatMap((n) => [n, n]).toArray(),
+                [0, 0, 1, 1, 2, 2]
+            )
+        "#}),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(naturals().map((x) => x))",
+            js_str!("[object Iterator Helper]"),
+        ),
+    ]);
+}
+
+#[test]
+fn eager_helpers() {
+    run_test_actions([
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b)", 6),
+        TestAction::assert_eq("[1, 2, 3].values().reduce((a, b) => a + b, 10)", 16),
+        TestAction::assert("[1, 2, 3].values().some((x) => x === 2)"),
+        TestAction::assert("![1, 2, 3].values().every((x) => x < 3)"),
+        TestAction::assert_eq("new Set([1, 2, 3]).values().find((x) => x > 1)", 2),
+        TestAction::assert_eq(
+            "new Map([[1, 2]]).keys().find((x) => x > 1)",
+            JsValue::undefined(),
+        ),
+        TestAction::assert_eq(
+            indoc! {r#"
+                let sum = 0;
+                [1, 2, 3].values().forEach((x, i) => { sum += x * i; });
+                sum
+            "#},
+            8,
+        ),
+        TestAction::assert_native_error(
+            "[].values().reduce((a, b) => a + b)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.reduce: cannot reduce an empty iterator without an initial value",
+        ),
+    ]);
+}
+
+#[test]
+fn closes_underlying_iterator() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var closed = 0;
+            var iter = {
+                __proto__: Iterator.prototype,
+                i: 0,
+                next() { return { value: this.i++, done: false }; },
+                return() { closed++; return {}; },
+            };
+        "#}),
+        TestAction::assert_native_error(
+            "iter.map(1)",
+            JsNativeErrorKind::Type,
+            "Iterator.prototype.map: argument is not callable",
+        ),
+        TestAction::assert_eq("closed", 1),
+        TestAction::assert_native_error(
+            "iter.take(-1)",
+            JsNativeErrorKind::Range,
+            "Iterator.prototype.take: limit cannot be negative",
+        ),
+        TestAction::assert_eq("closed", 2),
+        TestAction::assert("iter.some((x) => x > 3)"),
+        TestAction::assert_eq("closed", 3),
+        TestAction::run(indoc! {r#"
+            var helper = iter.map((x) => x);
+            helper.next();
+            helper.return();
+        "#}),
+        TestAction::assert_eq("closed", 4),
+        TestAction::assert("helper.next().done"),
+    ]);
+}
+
+#[test]
+fn iterator_from() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::assert(r#"arrayEquals(Iterator.from("abc").toArray(), ["a", "b", "c"])"#),
+        TestAction::run(indoc! {r#"
+            var plain = {
+                i: 0,
+                next() { return { value: this.i++, done: this.i > 3 }; },
+            };
+            var wrapped = Iterator.from(plain);
+        "#}),
+        TestAction::assert("wrapped instanceof Iterator"),
+        TestAction::assert("wrapped !== plain"),
+        TestAction::assert("arrayEquals(wrapped.toArray(), [0, 1, 2])"),
+        TestAction::assert("wrapped.return().done"),
+        TestAction::run("var it = [1].values();"),
+        TestAction::assert("Iterator.from(it) === it"),
+        TestAction::assert_native_error(
+            "Iterator.from(5)",
+            JsNativeErrorKind::Type,
+            "value with type `number` is not an iterable object",
+        ),
+    ]);
+}
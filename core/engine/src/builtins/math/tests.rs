@@ -160,6 +160,28 @@ fn fround() {
     ]);
 }
 
+#[test]
+fn f16round() {
+    run_test_actions([
+        TestAction::assert_eq("Math.f16round(NaN)", f64::NAN),
+        TestAction::assert_eq("Math.f16round(Infinity)", f64::INFINITY),
+        TestAction::assert_eq("Math.f16round(5)", 5.0),
+        TestAction::assert_eq("Math.f16round(5.5)", 5.5),
+        TestAction::assert_eq("Math.f16round(5.05)", 5.050_781_25),
+        TestAction::assert_eq("Math.f16round(-5.05)", -5.050_781_25),
+        TestAction::assert_eq("Math.f16round(65504)", 65504.0),
+        TestAction::assert_eq("Math.f16round(65519.99)", 65504.0),
+        TestAction::assert_eq("Math.f16round(65520)", f64::INFINITY),
+        TestAction::assert_eq("Math.f16round(2 ** -24)", 2f64.powi(-24)),
+        TestAction::assert_eq("Math.f16round(2 ** -25)", 0.0),
+        // Ties round to even.
+        TestAction::assert_eq("Math.f16round(1 + 2 ** -11)", 1.0),
+        // Rounding through binary32 first would produce `1` here.
+        TestAction::assert_eq("Math.f16round(1 + 2 ** -11 + 2 ** -52)", 1.000_976_562_5),
+        TestAction::assert_eq("Math.f16round()", f64::NAN),
+    ]);
+}
+
 #[test]
 fn hypot() {
     run_test_actions([
@@ -10,6 +10,7 @@ pub mod bigint;
 pub mod boolean;
 pub mod dataview;
 pub mod date;
+pub mod disposable;
 pub mod error;
 pub mod eval;
 pub mod function;
@@ -52,6 +53,10 @@ pub(crate) mod options;
 #[cfg(feature = "temporal")]
 pub mod temporal;
 
+// TODO: remove `cfg` when `ShadowRealm` gets to stage 4.
+#[cfg(feature = "experimental")]
+pub mod shadow_realm;
+
 pub(crate) use self::{
     array::Array,
     async_function::AsyncFunction,
@@ -78,8 +83,8 @@ pub(crate) use self::{
     string::String,
     symbol::Symbol,
     typed_array::{
-        BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array,
-        Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
+        BigInt64Array, BigUint64Array, Float16Array, Float32Array, Float64Array, Int16Array,
+        Int32Array, Int8Array, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
     },
 };
 
@@ -93,7 +98,9 @@ use crate::{
         error::r#type::ThrowTypeError,
         generator::Generator,
         generator_function::GeneratorFunction,
-        iterable::{AsyncFromSyncIterator, AsyncIterator, Iterator},
+        iterable::{
+            AsyncFromSyncIterator, AsyncIterator, Iterator, IteratorHelper, WrapForValidIterator,
+        },
         map::MapIterator,
         object::for_in_iterator::ForInIterator,
         regexp::RegExpStringIterator,
@@ -101,7 +108,7 @@ use crate::{
         string::StringIterator,
         typed_array::BuiltinTypedArray,
         uri::{DecodeUri, DecodeUriComponent, EncodeUri, EncodeUriComponent},
-        weak::WeakRef,
+        weak::{FinalizationRegistry, WeakRef},
         weak_map::WeakMap,
         weak_set::WeakSet,
     },
@@ -203,6 +210,8 @@ impl Realm {
         BuiltInFunctionObject::init(self);
         OrdinaryObject::init(self);
         Iterator::init(self);
+        IteratorHelper::init(self);
+        WrapForValidIterator::init(self);
         AsyncIterator::init(self);
         AsyncFromSyncIterator::init(self);
         ForInIterator::init(self);
@@ -241,6 +250,7 @@ impl Realm {
         Uint32Array::init(self);
         BigInt64Array::init(self);
         BigUint64Array::init(self);
+        Float16Array::init(self);
         Float32Array::init(self);
         Float64Array::init(self);
         Symbol::init(self);
@@ -265,6 +275,7 @@ impl Realm {
         DecodeUri::init(self);
         DecodeUriComponent::init(self);
         WeakRef::init(self);
+        FinalizationRegistry::init(self);
         WeakMap::init(self);
         WeakSet::init(self);
         Atomics::init(self);
@@ -282,11 +293,14 @@ impl Realm {
             intl::ListFormat::init(self);
             intl::Locale::init(self);
             intl::DateTimeFormat::init(self);
+            intl::DisplayNames::init(self);
+            intl::DurationFormat::init(self);
             intl::Segmenter::init(self);
             intl::segmenter::Segments::init(self);
             intl::segmenter::SegmentIterator::init(self);
             intl::PluralRules::init(self);
             intl::NumberFormat::init(self);
+            intl::RelativeTimeFormat::init(self);
         }
 
         #[cfg(feature = "temporal")]
@@ -302,6 +316,14 @@ impl Realm {
             temporal::PlainYearMonth::init(self);
             temporal::ZonedDateTime::init(self);
         }
+
+        #[cfg(feature = "experimental")]
+        {
+            error::SuppressedError::init(self);
+            disposable::DisposableStack::init(self);
+            disposable::AsyncDisposableStack::init(self);
+            shadow_realm::ShadowRealm::init(self);
+        }
     }
 }
 
@@ -347,6 +369,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Math>(context)?;
     global_binding::<Json>(context)?;
     global_binding::<Array>(context)?;
+    global_binding::<Iterator>(context)?;
     global_binding::<Proxy>(context)?;
     global_binding::<ArrayBuffer>(context)?;
     global_binding::<SharedArrayBuffer>(context)?;
@@ -374,6 +397,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<Uint32Array>(context)?;
     global_binding::<BigInt64Array>(context)?;
     global_binding::<BigUint64Array>(context)?;
+    global_binding::<Float16Array>(context)?;
     global_binding::<Float32Array>(context)?;
     global_binding::<Float64Array>(context)?;
     global_binding::<Symbol>(context)?;
@@ -392,6 +416,7 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
     global_binding::<DecodeUri>(context)?;
     global_binding::<DecodeUriComponent>(context)?;
     global_binding::<WeakRef>(context)?;
+    global_binding::<FinalizationRegistry>(context)?;
     global_binding::<WeakMap>(context)?;
     global_binding::<WeakSet>(context)?;
     global_binding::<Atomics>(context)?;
@@ -410,5 +435,13 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    #[cfg(feature = "experimental")]
+    {
+        global_binding::<error::SuppressedError>(context)?;
+        global_binding::<disposable::DisposableStack>(context)?;
+        global_binding::<disposable::AsyncDisposableStack>(context)?;
+        global_binding::<shadow_realm::ShadowRealm>(context)?;
+    }
+
     Ok(())
 }
@@ -1,3 +1,5 @@
+use half::f16;
+
 /// Converts a 64-bit floating point number to an `i32` according to the [`ToInt32`][ToInt32] algorithm.
 ///
 /// [ToInt32]: https://tc39.es/ecma262/#sec-toint32
@@ -109,6 +111,89 @@ pub(crate) fn f64_to_uint32(number: f64) -> u32 {
     f64_to_int32(number) as u32
 }
 
+/// Converts a 64-bit floating point number to the nearest 16-bit floating point number using
+/// `roundTiesToEven` mode.
+///
+/// This rounds directly from binary64 to binary16; `half::f16::from_f64` ignores the lower bits of
+/// the significand, which can produce an incorrectly rounded result on ties.
+pub(crate) fn f64_to_f16(number: f64) -> f16 {
+    const SIGNIFICAND_MASK: u64 = 0x000F_FFFF_FFFF_FFFF;
+    const HIDDEN_BIT: u64 = 0x0010_0000_0000_0000;
+
+    let bits = number.to_bits();
+    let sign = ((bits >> 48) & 0x8000) as u16;
+
+    if number.is_nan() {
+        return f16::NAN;
+    }
+    if number.is_infinite() {
+        return f16::from_bits(sign | 0x7C00);
+    }
+
+    let exponent = ((bits >> 52) & 0x7FF) as i32 - 0x3FF;
+
+    // Anything smaller than half of the smallest subnormal rounds to zero, and anything with an
+    // exponent bigger than the biggest binary16 exponent overflows to infinity.
+    if exponent < -25 {
+        return f16::from_bits(sign);
+    }
+    if exponent > 15 {
+        return f16::from_bits(sign | 0x7C00);
+    }
+
+    let significand = (bits & SIGNIFICAND_MASK) | HIDDEN_BIT;
+
+    // Normal numbers keep 11 bits of the significand (including the hidden bit), while
+    // subnormal numbers are expressed in units of 2^-24.
+    let shift = if exponent < -14 { 28 - exponent } else { 42 };
+    let mut result = significand >> shift;
+    let remainder = significand & ((1 << shift) - 1);
+    let halfway = 1 << (shift - 1);
+    if remainder > halfway || (remainder == halfway && result & 1 == 1) {
+        result += 1;
+    }
+
+    // The hidden bit is added to the exponent field here, which also handles the cases where
+    // rounding carries into the next binade.
+    if exponent >= -14 {
+        result += ((exponent + 14) as u64) << 10;
+    }
+
+    if result >= 0x7C00 {
+        return f16::from_bits(sign | 0x7C00);
+    }
+
+    f16::from_bits(sign | result as u16)
+}
+
+#[test]
+fn f64_to_f16_conversion() {
+    assert!(f64_to_f16(f64::NAN).is_nan());
+    assert_eq!(f64_to_f16(f64::INFINITY), f16::INFINITY);
+    assert_eq!(f64_to_f16(f64::NEG_INFINITY), f16::NEG_INFINITY);
+    assert_eq!(f64_to_f16(-0.0).to_bits(), 0x8000);
+    assert_eq!(f64_to_f16(65504.0), f16::MAX);
+    assert_eq!(f64_to_f16(65519.999), f16::MAX);
+    assert_eq!(f64_to_f16(65520.0), f16::INFINITY);
+    assert_eq!(f64_to_f16(2f64.powi(-14)), f16::MIN_POSITIVE);
+    assert_eq!(
+        f64_to_f16(2f64.powi(-14) - 2f64.powi(-26)),
+        f16::MIN_POSITIVE
+    );
+    assert_eq!(f64_to_f16(2f64.powi(-24)), f16::MIN_POSITIVE_SUBNORMAL);
+    assert_eq!(f64_to_f16(2f64.powi(-25)).to_bits(), 0);
+    assert_eq!(
+        f64_to_f16(2f64.powi(-25) + 2f64.powi(-60)),
+        f16::MIN_POSITIVE_SUBNORMAL
+    );
+    assert_eq!(f64_to_f16(1.0 + 2f64.powi(-11)), f16::ONE);
+    assert_eq!(f64_to_f16(1.0 + 3.0 * 2f64.powi(-11)).to_bits(), 0x3C02);
+    assert_eq!(
+        f64_to_f16(1.0 + 2f64.powi(-11) + 2f64.powi(-52)).to_bits(),
+        0x3C01
+    );
+}
+
 #[test]
 fn f64_to_int32_conversion() {
     use crate::builtins::Number;
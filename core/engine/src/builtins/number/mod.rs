@@ -35,7 +35,7 @@ mod conversions;
 
 use super::{BuiltInBuilder, BuiltInConstructor, IntrinsicObject};
 use crate::value::JsVariant;
-pub(crate) use conversions::{f64_to_int32, f64_to_uint32};
+pub(crate) use conversions::{f64_to_f16, f64_to_int32, f64_to_uint32};
 
 #[cfg(test)]
 mod tests;
@@ -293,23 +293,43 @@ impl Number {
     ///
     /// The `toLocaleString()` method returns a string with a language-sensitive representation of this number.
     ///
-    /// Note that while this technically conforms to the Ecma standard, it does no actual
-    /// internationalization logic.
+    /// Without the `intl` feature, this returns the same string as `Number.prototype.toString()`.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-number.prototype.tolocalestring
+    /// [spec]: https://tc39.es/ecma402/#sup-number.prototype.tolocalestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toLocaleString
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_locale_string(
         this: &JsValue,
-        _: &[JsValue],
-        _: &mut Context,
+        args: &[JsValue],
+        context: &mut Context,
     ) -> JsResult<JsValue> {
+        // 1. Let x be ? thisNumberValue(this value).
         let this_num = Self::this_number_value(this)?;
-        Ok(JsValue::new(js_string!(this_num)))
+
+        #[cfg(feature = "intl")]
+        {
+            use crate::builtins::intl::number_format::{IntlMathematicalValue, NumberFormat};
+
+            // 2. Let numberFormat be ? Construct(%Intl.NumberFormat%, « locales, options »).
+            let number_format =
+                NumberFormat::new(args.get_or_undefined(0), args.get_or_undefined(1), context)?;
+
+            // 3. Return FormatNumeric(numberFormat, ! ToIntlMathematicalValue(x)).
+            let mut x = IntlMathematicalValue::from(this_num);
+
+            Ok(js_string!(number_format.format(&mut x).to_string()).into())
+        }
+
+        #[cfg(not(feature = "intl"))]
+        {
+            let _ = (args, context);
+
+            Ok(JsValue::new(js_string!(this_num)))
+        }
     }
 
     /// `flt_str_to_exp` - used in `to_precision`
@@ -165,10 +165,9 @@ fn issue_2609() {
     ]);
 }
 
+#[cfg(not(feature = "intl"))]
 #[test]
 fn to_locale_string() {
-    // TODO: We don't actually do any locale checking here
-    // To honor the spec we should print numbers according to user locale.
     run_test_actions([
         TestAction::assert_eq("Number().toLocaleString()", js_str!("0")),
         TestAction::assert_eq("Number(5).toLocaleString()", js_str!("5")),
@@ -177,6 +176,40 @@ fn to_locale_string() {
     ]);
 }
 
+#[cfg(feature = "intl_bundled")]
+#[test]
+fn to_locale_string_intl() {
+    run_test_actions([
+        TestAction::assert_eq("Number(5).toLocaleString('en-US')", js_str!("5")),
+        TestAction::assert_eq(
+            "Number('345600.125').toLocaleString('en-US')",
+            js_str!("345,600.125"),
+        ),
+        TestAction::assert_eq(
+            "Number(345600).toLocaleString('en-US', { useGrouping: false })",
+            js_str!("345600"),
+        ),
+        TestAction::assert_eq(
+            "Number(1.23456).toLocaleString('en-US', { maximumFractionDigits: 2 })",
+            js_str!("1.23"),
+        ),
+        TestAction::assert_eq("NaN.toLocaleString('en-US')", js_str!("NaN")),
+        TestAction::assert_eq("(-Infinity).toLocaleString('en-US')", js_str!("-\u{221e}")),
+        TestAction::assert_eq(
+            "Infinity.toLocaleString('de', { style: 'percent' })",
+            js_str!("\u{221e}\u{a0}%"),
+        ),
+        TestAction::assert_eq(
+            "NaN.toLocaleString('en-US', { style: 'currency', currency: 'USD' })",
+            js_str!("$NaN"),
+        ),
+        TestAction::assert_eq(
+            "Number(0.5).toLocaleString('en-US', { style: 'percent' })",
+            js_str!("50%"),
+        ),
+    ]);
+}
+
 #[test]
 fn to_precision() {
     const ERROR: &str = "precision must be an integer at least 1 and no greater than 100";
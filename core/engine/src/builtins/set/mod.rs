@@ -28,14 +28,16 @@ use crate::{
     realm::Realm,
     string::StaticJsStrings,
     symbol::JsSymbol,
+    value::IntegerOrInfinity,
     Context, JsArgs, JsResult, JsString, JsValue,
 };
 use boa_profiler::Profiler;
 use num_traits::Zero;
+use std::ops::ControlFlow;
 
 pub(crate) use set_iterator::SetIterator;
 
-use super::iterable::IteratorHint;
+use super::iterable::{IteratorHint, IteratorRecord};
 
 #[derive(Debug, Clone)]
 pub(crate) struct Set;
@@ -72,6 +74,17 @@ impl IntrinsicObject for Set {
             .method(Self::entries, js_string!("entries"), 0)
             .method(Self::for_each, js_string!("forEach"), 1)
             .method(Self::has, js_string!("has"), 1)
+            .method(Self::union, js_string!("union"), 1)
+            .method(Self::intersection, js_string!("intersection"), 1)
+            .method(Self::difference, js_string!("difference"), 1)
+            .method(
+                Self::symmetric_difference,
+                js_string!("symmetricDifference"),
+                1,
+            )
+            .method(Self::is_subset_of, js_string!("isSubsetOf"), 1)
+            .method(Self::is_superset_of, js_string!("isSupersetOf"), 1)
+            .method(Self::is_disjoint_from, js_string!("isDisjointFrom"), 1)
             .property(
                 js_string!("keys"),
                 values_function.clone(),
@@ -108,7 +121,7 @@ impl BuiltInObject for Set {
 
 impl BuiltInConstructor for Set {
     const LENGTH: usize = 0;
-    const P: usize = 11;
+    const P: usize = 18;
     const SP: usize = 1;
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::set;
@@ -459,6 +472,429 @@ impl Set {
         Ok(set.contains(value).into())
     }
 
+    /// `Set.prototype.union ( other )`
+    ///
+    /// This method returns a new set containing the elements of both this set and the set-like
+    /// object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.union
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/union
+    pub(crate) fn union(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "union")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_data(&set);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            // ii. If SetDataHas(resultSetData, next) is false, then
+            //     1. Append next to resultSetData.
+            result.add(canonicalize_keyed_collection_key(next));
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_data(result, context).into())
+    }
+
+    /// `Set.prototype.intersection ( other )`
+    ///
+    /// This method returns a new set containing the elements of this set that are also in the
+    /// set-like object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.intersection
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/intersection
+    pub(crate) fn intersection(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "intersection")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a new empty List.
+        let mut result = OrderedSet::new();
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::data_size(&set) as i64 <= other.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            Self::for_each_element(&set, |e| {
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, then
+                if other.has(e, context)? {
+                    // a. NOTE: It is possible for earlier calls to otherRec.[[Has]] to remove and re-add an element of O.[[SetData]], which can cause the same element to be visited twice during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    //     i. Append e to resultSetData.
+                    result.add(e.clone());
+                }
+
+                // 3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+                // 4. Set thisSize to the number of elements in O.[[SetData]].
+                Ok(ControlFlow::Continue(()))
+            })?;
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other.keys_iterator(context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while let Some(next) = keys_iter.step_value(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(next);
+
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                // 3. If inThis is true, then
+                //     a. NOTE: Because other is an arbitrary object, it is possible for its "keys" iterator to produce the same value more than once.
+                //     b. If SetDataHas(resultSetData, next) is false, then
+                //         i. Append next to resultSetData.
+                if Self::data_has(&set, &next) {
+                    result.add(next);
+                }
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_data(result, context).into())
+    }
+
+    /// `Set.prototype.difference ( other )`
+    ///
+    /// This method returns a new set containing the elements of this set that are not in the
+    /// set-like object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.difference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/difference
+    pub(crate) fn difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "difference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_data(&set);
+
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::data_size(&set) as i64 <= other.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be resultSetData[index].
+            //     ii. If e is not empty, then
+            //     iii. Set index to index + 1.
+            let elements = result.iter().cloned().collect::<Vec<_>>();
+            for e in elements {
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, then
+                if other.has(&e, context)? {
+                    // a. Set resultSetData[index] to empty.
+                    result.delete(&e);
+                }
+            }
+        } else {
+            // 6. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other.keys_iterator(context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while let Some(next) = keys_iter.step_value(context)? {
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not not-found, then
+                //     a. Set resultSetData[valueIndex] to empty.
+                result.delete(&canonicalize_keyed_collection_key(next));
+            }
+        }
+
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(Self::create_from_data(result, context).into())
+    }
+
+    /// `Set.prototype.symmetricDifference ( other )`
+    ///
+    /// This method returns a new set containing the elements which are either in this set or in
+    /// the set-like object `other`, but not in both.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.symmetricdifference
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/symmetricDifference
+    pub(crate) fn symmetric_difference(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "symmetricDifference")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys_iterator(context)?;
+
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let mut result = Self::copy_data(&set);
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(next);
+
+            // ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            // iii. If resultIndex is not-found, let alreadyInResult be false. Otherwise let alreadyInResult be true.
+            let already_in_result = result.contains(&next);
+
+            // iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if Self::data_has(&set, &next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to empty.
+                if already_in_result {
+                    result.delete(&next);
+                }
+            }
+            // v. Else,
+            //     1. If alreadyInResult is false, append next to resultSetData.
+            else if !already_in_result {
+                result.add(next);
+            }
+        }
+
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(Self::create_from_data(result, context).into())
+    }
+
+    /// `Set.prototype.isSubsetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of this set are in the set-like object
+    /// `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issubsetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSubsetOf
+    pub(crate) fn is_subset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isSubsetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if Self::data_size(&set) as i64 > other.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        // 6. Let index be 0.
+        // 7. Repeat, while index < thisSize,
+        //     a. Let e be O.[[SetData]][index].
+        //     b. Set index to index + 1.
+        //     c. If e is not empty, then
+        let completed = Self::for_each_element(&set, |e| {
+            // i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            // ii. If inOther is false, return false.
+            if !other.has(e, context)? {
+                return Ok(ControlFlow::Break(()));
+            }
+
+            // iii. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+            // iv. Set thisSize to the number of elements in O.[[SetData]].
+            Ok(ControlFlow::Continue(()))
+        })?;
+
+        // 8. Return true.
+        Ok(completed.into())
+    }
+
+    /// `Set.prototype.isSupersetOf ( other )`
+    ///
+    /// This method returns `true` if all the elements of the set-like object `other` are in this
+    /// set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.issupersetof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isSupersetOf
+    pub(crate) fn is_superset_of(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isSupersetOf")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if (Self::data_size(&set) as i64) < other.size {
+            return Ok(false.into());
+        }
+
+        // 5. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let mut keys_iter = other.keys_iterator(context)?;
+
+        // 6. Let next be not-started.
+        // 7. Repeat, while next is not done,
+        //     a. Set next to ? IteratorStepValue(keysIter).
+        //     b. If next is not done, then
+        while let Some(next) = keys_iter.step_value(context)? {
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !Self::data_has(&set, &canonicalize_keyed_collection_key(next)) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                // 2. Return false.
+                return Ok(false.into());
+            }
+        }
+
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// `Set.prototype.isDisjointFrom ( other )`
+    ///
+    /// This method returns `true` if this set has no elements in common with the set-like
+    /// object `other`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-set.prototype.isdisjointfrom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set/isDisjointFrom
+    pub(crate) fn is_disjoint_from(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let set = Self::this_set(this, "isDisjointFrom")?;
+
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other = SetRecord::get(args.get_or_undefined(0), context)?;
+
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if Self::data_size(&set) as i64 <= other.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            // b. Let index be 0.
+            // c. Repeat, while index < thisSize,
+            //     i. Let e be O.[[SetData]][index].
+            //     ii. Set index to index + 1.
+            //     iii. If e is not empty, then
+            let completed = Self::for_each_element(&set, |e| {
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                // 2. If inOther is true, return false.
+                if other.has(e, context)? {
+                    return Ok(ControlFlow::Break(()));
+                }
+
+                // 3. NOTE: The number of elements in O.[[SetData]] may have increased during execution of otherRec.[[Has]].
+                // 4. Set thisSize to the number of elements in O.[[SetData]].
+                Ok(ControlFlow::Continue(()))
+            })?;
+
+            if !completed {
+                return Ok(false.into());
+            }
+        } else {
+            // 5. Else,
+            //     a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let mut keys_iter = other.keys_iterator(context)?;
+
+            //     b. Let next be not-started.
+            //     c. Repeat, while next is not done,
+            //         i. Set next to ? IteratorStepValue(keysIter).
+            //         ii. If next is not done, then
+            while let Some(next) = keys_iter.step_value(context)? {
+                // 1. If SetDataHas(O.[[SetData]], next) is true, then
+                if Self::data_has(&set, &canonicalize_keyed_collection_key(next)) {
+                    // a. Perform ? IteratorClose(keysIter, NormalCompletion(unused)).
+                    keys_iter.close(Ok(JsValue::undefined()), context)?;
+
+                    // b. Return false.
+                    return Ok(false.into());
+                }
+            }
+        }
+
+        // 6. Return true.
+        Ok(true.into())
+    }
+
     /// `Set.prototype.values( )`
     ///
     /// This method returns an iterator over the values of the set
@@ -524,4 +960,232 @@ impl Set {
                     .into()
             })
     }
+
+    /// Gets the `this` value as a `Set` object, throwing a `TypeError` otherwise.
+    fn this_set(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.is::<OrderedSet>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "Method Set.prototype.{method} called on incompatible receiver"
+                    ))
+                    .into()
+            })
+    }
+
+    /// Creates a new `Set` object with `data` as its `[[SetData]]`.
+    fn create_from_data(data: OrderedSet, context: &mut Context) -> JsObject {
+        JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            context.intrinsics().constructors().set().prototype(),
+            data,
+        )
+    }
+
+    /// Creates a copy of the `[[SetData]]` of `set`, without its empty elements.
+    fn copy_data(set: &JsObject) -> OrderedSet {
+        let set = set
+            .downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object");
+        let mut data = OrderedSet::with_capacity(set.len());
+        for value in set.iter() {
+            data.add(value.clone());
+        }
+        data
+    }
+
+    /// Abstract operation `SetDataSize ( setData )`.
+    fn data_size(set: &JsObject) -> usize {
+        set.downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .len()
+    }
+
+    /// Abstract operation `SetDataHas ( setData, value )`.
+    fn data_has(set: &JsObject, value: &JsValue) -> bool {
+        set.downcast_ref::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .contains(value)
+    }
+
+    /// Calls `f` on each element of the `[[SetData]]` of `set` in insertion order, including
+    /// the elements added by `f` itself, until `f` returns [`ControlFlow::Break`].
+    ///
+    /// Returns `false` if the iteration was stopped by `f`, or `true` otherwise.
+    ///
+    /// The set is locked during the iteration, which means elements removed by `f` leave an
+    /// empty slot behind instead of shifting the position of the remaining elements.
+    fn for_each_element<F>(set: &JsObject, mut f: F) -> JsResult<bool>
+    where
+        F: FnMut(&JsValue) -> JsResult<ControlFlow<()>>,
+    {
+        let _lock = set
+            .downcast_mut::<OrderedSet>()
+            .expect("`set` must be a `Set` object")
+            .lock(set.clone());
+
+        let mut index = 0;
+        loop {
+            let element = {
+                let data = set
+                    .downcast_ref::<OrderedSet>()
+                    .expect("`set` must be a `Set` object");
+                if index >= data.full_len() {
+                    return Ok(true);
+                }
+                data.get_index(index).cloned()
+            };
+            index += 1;
+
+            if let Some(element) = element {
+                if f(&element)?.is_break() {
+                    return Ok(false);
+                }
+            }
+        }
+    }
+}
+
+/// Abstract operation `CanonicalizeKeyedCollectionKey ( key )`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalizekeyedcollectionkey
+fn canonicalize_keyed_collection_key(value: JsValue) -> JsValue {
+    // 1. If key is -0𝔽, return +0𝔽.
+    // 2. Return key.
+    match value.as_number() {
+        Some(n) if n.is_zero() => JsValue::new(0),
+        _ => value,
+    }
+}
+
+/// A record representing a set-like object, returned by `GetSetRecord`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-set-records
+#[derive(Debug)]
+struct SetRecord {
+    /// `[[SetObject]]`
+    set: JsObject,
+    /// `[[Size]]`
+    size: IntegerOrInfinity,
+    /// `[[Has]]`
+    has: JsObject,
+    /// `[[Keys]]`
+    keys: JsObject,
+}
+
+impl SetRecord {
+    /// Abstract operation [`GetSetRecord ( obj )`][spec].
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsetrecord
+    fn get(obj: &JsValue, context: &mut Context) -> JsResult<Self> {
+        // 1. If obj is not an Object, throw a TypeError exception.
+        let Some(obj) = obj.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "expected a set-like object, got value of type `{}`",
+                    obj.type_of()
+                ))
+                .into());
+        };
+
+        // 2. Let rawSize be ? Get(obj, "size").
+        // 3. Let numSize be ? ToNumber(rawSize).
+        // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+        let num_size = obj.get(js_string!("size"), context)?.to_number(context)?;
+
+        // 5. If numSize is NaN, throw a TypeError exception.
+        if num_size.is_nan() {
+            return Err(JsNativeError::typ()
+                .with_message("`size` of set-like object must be a number")
+                .into());
+        }
+
+        // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+        let size = IntegerOrInfinity::from(num_size);
+
+        // 7. If intSize < 0, throw a RangeError exception.
+        if size < 0 {
+            return Err(JsNativeError::range()
+                .with_message("`size` of set-like object cannot be negative")
+                .into());
+        }
+
+        // 8. Let has be ? Get(obj, "has").
+        // 9. If IsCallable(has) is false, throw a TypeError exception.
+        let has = obj.get(js_string!("has"), context)?;
+        let Some(has) = has.as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("`has` of set-like object must be callable")
+                .into());
+        };
+
+        // 10. Let keys be ? Get(obj, "keys").
+        // 11. If IsCallable(keys) is false, throw a TypeError exception.
+        let keys = obj.get(js_string!("keys"), context)?;
+        let Some(keys) = keys.as_callable() else {
+            return Err(JsNativeError::typ()
+                .with_message("`keys` of set-like object must be callable")
+                .into());
+        };
+
+        // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+        Ok(Self {
+            set: obj.clone(),
+            size,
+            has: has.clone(),
+            keys: keys.clone(),
+        })
+    }
+
+    /// Returns `ToBoolean(? Call(setRec.[[Has]], setRec.[[SetObject]], « value »))`.
+    fn has(&self, value: &JsValue, context: &mut Context) -> JsResult<bool> {
+        self.has
+            .call(
+                &self.set.clone().into(),
+                std::slice::from_ref(value),
+                context,
+            )
+            .map(|result| result.to_boolean())
+    }
+
+    /// Abstract operation `GetIteratorFromMethod ( obj, method )`, specialized for the `keys`
+    /// method of the set-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getiteratorfrommethod
+    fn keys_iterator(&self, context: &mut Context) -> JsResult<IteratorRecord> {
+        // 1. Let iterator be ? Call(method, obj).
+        let iterator = self.keys.call(&self.set.clone().into(), &[], context)?;
+
+        // 2. If iterator is not an Object, throw a TypeError exception.
+        let Some(iterator) = iterator.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message("`keys` of set-like object must return an object")
+                .into());
+        };
+
+        // 3. Let nextMethod be ? Get(iterator, "next").
+        let next_method = iterator.get(js_string!("next"), context)?;
+
+        // 4. If IsCallable(nextMethod) is false, throw a TypeError exception.
+        if !next_method.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message("`next` of the keys iterator of a set-like object must be callable")
+                .into());
+        }
+
+        // 5. Let iteratorRecord be the Iterator Record { [[Iterator]]: iterator, [[NextMethod]]: nextMethod, [[Done]]: false }.
+        // 6. Return iteratorRecord.
+        Ok(IteratorRecord::new(iterator.clone(), next_method))
+    }
 }
@@ -174,3 +174,110 @@ fn not_a_function() {
         "calling a builtin Set constructor without new is forbidden",
     )]);
 }
+
+#[test]
+fn set_methods() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                const a = new Set([1, 2, 3, 4]);
+                const b = new Set([3, 4, 5]);
+            "#}),
+        TestAction::assert("arrayEquals([...a.union(b)], [1, 2, 3, 4, 5])"),
+        TestAction::assert("arrayEquals([...a.intersection(b)], [3, 4])"),
+        TestAction::assert("arrayEquals([...b.intersection(a)], [3, 4])"),
+        TestAction::assert("arrayEquals([...a.difference(b)], [1, 2])"),
+        TestAction::assert("arrayEquals([...a.symmetricDifference(b)], [1, 2, 5])"),
+        TestAction::assert("new Set([3]).isSubsetOf(a)"),
+        TestAction::assert("!b.isSubsetOf(a)"),
+        TestAction::assert("a.isSupersetOf(new Set([1, 4]))"),
+        TestAction::assert("!a.isSupersetOf(b)"),
+        TestAction::assert("a.isDisjointFrom(new Set([6, 7]))"),
+        TestAction::assert("!a.isDisjointFrom(b)"),
+        TestAction::assert("a.union(b) !== a"),
+        TestAction::assert_eq("a.size", 4),
+    ]);
+}
+
+#[test]
+fn set_methods_set_like() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                const set = new Set([1, 2, 3]);
+                const map = new Map([[2, "two"], [-0, "zero"]]);
+                let closed = 0;
+                const setLike = {
+                    size: Infinity,
+                    has: (x) => x > 1,
+                    keys() {
+                        return {
+                            next() { return { done: false, value: 2 }; },
+                            return() { closed++; return {}; },
+                        };
+                    },
+                };
+            "#}),
+        TestAction::assert("arrayEquals([...set.intersection(map)], [2])"),
+        TestAction::assert("arrayEquals([...set.union(map)], [1, 2, 3, 0])"),
+        TestAction::assert("Object.is([...set.union(map)][3], 0)"),
+        TestAction::assert("arrayEquals([...set.intersection(setLike)], [2, 3])"),
+        TestAction::assert("arrayEquals([...set.difference(setLike)], [1])"),
+        TestAction::assert("!set.isDisjointFrom(setLike)"),
+        TestAction::assert("set.isSubsetOf({ size: 3, has: () => true, keys() {} })"),
+        TestAction::assert(
+            "!new Set([1]).isSupersetOf({ size: 1, has() {}, keys: () => setLike.keys() })",
+        ),
+        TestAction::assert_eq("closed", 1),
+        TestAction::assert_native_error(
+            "set.union([1, 2])",
+            JsNativeErrorKind::Type,
+            "`size` of set-like object must be a number",
+        ),
+        TestAction::assert_native_error(
+            "set.union({ size: -1, has() {}, keys() {} })",
+            JsNativeErrorKind::Range,
+            "`size` of set-like object cannot be negative",
+        ),
+        TestAction::assert_native_error(
+            "set.union({ size: 1, has: 1, keys() {} })",
+            JsNativeErrorKind::Type,
+            "`has` of set-like object must be callable",
+        ),
+        TestAction::assert_native_error(
+            "set.union({ size: 1, has() {}, keys: () => 1 })",
+            JsNativeErrorKind::Type,
+            "`keys` of set-like object must return an object",
+        ),
+        TestAction::assert_native_error(
+            "Set.prototype.union.call({}, set)",
+            JsNativeErrorKind::Type,
+            "Method Set.prototype.union called on incompatible receiver",
+        ),
+    ]);
+}
+
+#[test]
+fn set_methods_mutation_during_iteration() {
+    run_test_actions([
+        TestAction::run_harness(),
+        TestAction::run(indoc! {r#"
+                const set = new Set([1, 2, 3]);
+                const seen = [];
+                const setLike = {
+                    size: 10,
+                    has(x) {
+                        seen.push(x);
+                        if (x === 1) {
+                            set.delete(2);
+                            set.add(4);
+                        }
+                        return true;
+                    },
+                    keys() {},
+                };
+            "#}),
+        TestAction::assert("arrayEquals([...set.intersection(setLike)], [1, 3, 4])"),
+        TestAction::assert("arrayEquals(seen, [1, 3, 4])"),
+    ]);
+}
@@ -0,0 +1,461 @@
+//! Boa's implementation of the `ShadowRealm` builtin object.
+//!
+//! A `ShadowRealm` is a distinct global environment, with its own global object containing its own
+//! intrinsics and built-ins. Code evaluated inside of a `ShadowRealm` can only exchange primitive
+//! values and callables with the realm that created it, which makes it useful to sandbox code.
+//!
+//! More information:
+//!  - [ShadowRealm proposal][spec]
+//!
+//! [spec]: https://tc39.es/proposal-shadowrealm/
+
+use boa_ast::operations::{contains, ContainsSymbol};
+use boa_gc::{Finalize, Trace};
+use boa_parser::{Parser, Source};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{
+        promise::PromiseCapability, BuiltInBuilder, BuiltInConstructor, BuiltInObject, Error, Eval,
+        IntrinsicObject, Promise,
+    },
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    js_string,
+    module::{ModuleRequest, Referrer},
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    vm::load_dynamic_import,
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, JsString, JsValue,
+};
+
+mod wrapped;
+
+#[cfg(test)]
+mod tests;
+
+pub(crate) use wrapped::WrappedFunction;
+
+/// Boa's implementation of the `ShadowRealm` builtin object.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm-objects
+#[derive(Debug, Trace, Finalize, JsData)]
+pub(crate) struct ShadowRealm {
+    /// `[[ShadowRealm]]`
+    realm: Realm,
+}
+
+impl IntrinsicObject for ShadowRealm {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .method(Self::evaluate, js_string!("evaluate"), 1)
+            .method(Self::import_value, js_string!("importValue"), 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+    }
+}
+
+impl BuiltInObject for ShadowRealm {
+    const NAME: JsString = StaticJsStrings::SHADOW_REALM;
+}
+
+impl BuiltInConstructor for ShadowRealm {
+    const LENGTH: usize = 0;
+    const P: usize = 3;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::shadow_realm;
+
+    /// Constructor [`ShadowRealm ( )`][spec].
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm
+    fn constructor(
+        new_target: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. Let O be ? OrdinaryCreateFromConstructor(NewTarget, "%ShadowRealm.prototype%", « [[ShadowRealm]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::shadow_realm,
+            context,
+        )?;
+
+        // 3. Let callerContext be the running execution context.
+        // 4. Perform ? InitializeHostDefinedRealm().
+        // 5. Let innerContext be the running execution context.
+        // 6. Remove innerContext from the execution context stack and restore callerContext as the running execution context.
+        // 7. Let realmRec be the Realm of innerContext.
+        let realm = context.create_realm()?;
+
+        // 8. Set O.[[ShadowRealm]] to realmRec.
+        // 9. Perform ? HostInitializeShadowRealm(realmRec, innerContext, O).
+        context
+            .host_hooks()
+            .initialize_shadow_realm(&realm, context)?;
+
+        // 10. Return O.
+        Ok(JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self { realm },
+        )
+        .into())
+    }
+}
+
+impl ShadowRealm {
+    /// Abstract operation [`ValidateShadowRealmObject ( O )`][spec], returning the
+    /// `[[ShadowRealm]]` of the `this` value.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-validateshadowrealmobject
+    fn this_realm(this: &JsValue, method: &str) -> JsResult<Realm> {
+        // 1. Perform ? RequireInternalSlot(O, [[ShadowRealm]]).
+        this.as_object()
+            .and_then(|o| o.downcast_ref::<Self>().map(|sr| sr.realm.clone()))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "ShadowRealm.prototype.{method}: `this` is not a ShadowRealm"
+                    ))
+                    .into()
+            })
+    }
+
+    /// [`ShadowRealm.prototype.evaluate ( sourceText )`][spec]
+    ///
+    /// Synchronously evaluates `sourceText` as a script inside the `ShadowRealm`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.evaluate
+    fn evaluate(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "evaluate")?;
+
+        // 3. If sourceText is not a String, throw a TypeError exception.
+        let Some(source_text) = args.get_or_undefined(0).as_string() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.evaluate: the source text must be a string")
+                .into());
+        };
+
+        // 4. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 5. Let evalRealm be O.[[ShadowRealm]].
+        // 6. Return ? PerformShadowRealmEval(sourceText, callerRealm, evalRealm).
+        Self::perform_shadow_realm_eval(source_text, &caller_realm, eval_realm, context)
+    }
+
+    /// [`ShadowRealm.prototype.importValue ( specifier, exportName )`][spec]
+    ///
+    /// Imports the module `specifier` inside the `ShadowRealm`, resolving the returned promise
+    /// with its export `exportName`.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealm.prototype.importvalue
+    fn import_value(this: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        // 1. Let O be this value.
+        // 2. Perform ? ValidateShadowRealmObject(O).
+        let eval_realm = Self::this_realm(this, "importValue")?;
+
+        // 3. Let specifierString be ? ToString(specifier).
+        let specifier = args.get_or_undefined(0).to_string(context)?;
+
+        // 4. If exportName is not a String, throw a TypeError exception.
+        let Some(export_name) = args.get_or_undefined(1).as_string().cloned() else {
+            return Err(JsNativeError::typ()
+                .with_message("ShadowRealm.prototype.importValue: the export name must be a string")
+                .into());
+        };
+
+        // 5. Let callerRealm be the current Realm Record.
+        let caller_realm = context.realm().clone();
+
+        // 6. Let evalRealm be O.[[ShadowRealm]].
+        // 7. Return ShadowRealmImportValue(specifierString, exportName, callerRealm, evalRealm).
+        Ok(Self::shadow_realm_import_value(
+            specifier,
+            export_name,
+            &caller_realm,
+            eval_realm,
+            context,
+        )
+        .into())
+    }
+
+    /// Abstract operation [`PerformShadowRealmEval ( sourceText, callerRealm, evalRealm )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-performshadowrealmeval
+    fn perform_shadow_realm_eval(
+        source_text: &JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Perform ? HostEnsureCanCompileStrings(evalRealm, « », sourceText, false).
+        context.host_hooks().ensure_can_compile_strings(
+            eval_realm.clone(),
+            &[],
+            source_text,
+            false,
+            context,
+        )?;
+
+        // 2. Perform the following substeps in an implementation-defined order, possibly interleaving parsing and error detection:
+        //     a. Let script be ParseText(sourceText, Script).
+        //     b. If script is a List of errors, throw a SyntaxError exception.
+        //     c. If script Contains ScriptBody is false, return undefined.
+        //     d. Let body be the ScriptBody of script.
+        let source = source_text.to_vec();
+        let mut parser = Parser::new(Source::from_utf16(&source));
+        parser.set_identifier(context.next_parser_identifier());
+        let body = parser.parse_eval(false, context.interner_mut())?;
+
+        //     e. If body Contains NewTarget is true, throw a SyntaxError exception.
+        //     f. If body Contains SuperProperty is true, throw a SyntaxError exception.
+        //     g. If body Contains SuperCall is true, throw a SyntaxError exception.
+        if contains(&body, ContainsSymbol::NewTarget)
+            || contains(&body, ContainsSymbol::SuperProperty)
+            || contains(&body, ContainsSymbol::SuperCall)
+        {
+            return Err(JsNativeError::syntax()
+                .with_message("invalid `new.target` or `super` inside a ShadowRealm evaluation")
+                .into());
+        }
+
+        // 3. Let strictEval be IsStrict of script.
+        // 4. Let runningContext be the running execution context.
+        // 5. If runningContext is not already suspended, suspend runningContext.
+        // 6. Let evalContext be GetShadowRealmContext(evalRealm, strictEval).
+        // 7. Let lexEnv be evalContext's LexicalEnvironment.
+        // 8. Let varEnv be evalContext's VariableEnvironment.
+        // 9. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let caller_realm_record = context.enter_realm(eval_realm);
+
+        // 10. Let result be Completion(EvalDeclarationInstantiation(body, varEnv, lexEnv, null, strictEval)).
+        // 11. If result is a normal completion, then
+        //     a. Set result to Completion(Evaluation of body).
+        // 12. If result is a normal completion and result.[[Value]] is empty, then
+        //     a. Set result to NormalCompletion(undefined).
+        let result = Eval::evaluate_body(body, false, None, false, context);
+
+        // 13. Suspend evalContext and remove it from the execution context stack.
+        // 14. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(caller_realm_record);
+
+        match result {
+            // 15. If result is an abrupt completion, then
+            //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+            //     b. Return ThrowCompletion(copiedError).
+            Err(err) => Err(create_type_error_copy(err)),
+            // 16. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+            Ok(value) => get_wrapped_value(caller_realm, value, context),
+        }
+    }
+
+    /// Abstract operation [`ShadowRealmImportValue ( specifierString, exportNameString, callerRealm, evalRealm )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-shadowrealmimportvalue
+    fn shadow_realm_import_value(
+        specifier: JsString,
+        export_name: JsString,
+        caller_realm: &Realm,
+        eval_realm: Realm,
+        context: &mut Context,
+    ) -> JsObject {
+        // 1. Let innerCapability be ! NewPromiseCapability(%Promise%).
+        let inner_capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 2. Let runningContext be the running execution context.
+        // 3. If runningContext is not already suspended, suspend runningContext.
+        // 4. Let evalContext be GetShadowRealmContext(evalRealm, true).
+        // 5. Push evalContext onto the execution context stack; evalContext is now the running execution context.
+        let caller_realm_record = context.enter_realm(eval_realm.clone());
+
+        // 6. Let referrer be evalContext's Realm component.
+        // 7. Perform HostLoadImportedModule(referrer, ModuleRequest { [[Specifier]]: specifierString,
+        //    [[Attributes]]: « » }, empty, innerCapability).
+        load_dynamic_import(
+            Referrer::Realm(eval_realm),
+            ModuleRequest::from_specifier(specifier),
+            inner_capability.clone(),
+            context,
+        );
+
+        // 8. Suspend evalContext and remove it from the execution context stack.
+        // 9. Resume the context that is now on the top of the execution context stack as the running execution context.
+        context.enter_realm(caller_realm_record);
+
+        // 10. Let steps be the steps of an ExportGetter function as described below.
+        // 11. Let onFulfilled be CreateBuiltinFunction(steps, 1, "", « [[ExportNameString]] », callerRealm).
+        // 12. Set onFulfilled.[[ExportNameString]] to exportNameString.
+        let on_fulfilled = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_copy_closure_with_captures(
+                |_, args, export_name, context| {
+                    // 1. Assert: exports is a module namespace exotic object.
+                    let exports = args
+                        .get_or_undefined(0)
+                        .as_object()
+                        .expect("the promise must be resolved with a module namespace");
+
+                    // 2. Let f be the active function object.
+                    // 3. Let string be f.[[ExportNameString]].
+                    // 4. Assert: string is a String.
+                    // 5. Let hasOwn be ? HasOwnProperty(exports, string).
+                    // 6. If hasOwn is false, throw a TypeError exception.
+                    if !exports.has_own_property(export_name.clone(), context)? {
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "ShadowRealm.prototype.importValue: the module has no export named `{}`",
+                                export_name.to_std_string_escaped()
+                            ))
+                            .into());
+                    }
+
+                    // 7. Let value be ? Get(exports, string).
+                    let value = exports.get(export_name.clone(), context)?;
+
+                    // 8. Let realm be f.[[Realm]].
+                    // 9. Return ? GetWrappedValue(realm, value).
+                    let realm = context.realm().clone();
+                    get_wrapped_value(&realm, value, context)
+                },
+                export_name,
+            ),
+        )
+        .length(1)
+        .build();
+
+        // 13. Let errorSteps be the steps of an ImportValueError function as described below.
+        // 14. Let onRejected be CreateBuiltinFunction(errorSteps, 1, "", « », callerRealm).
+        let on_rejected = FunctionObjectBuilder::new(
+            caller_realm,
+            NativeFunction::from_fn_ptr(|_, args, _| {
+                // 1. Let realmRecord be the current Realm Record.
+                // 2. Let copiedError be CreateTypeErrorCopy(realmRecord, error).
+                // 3. Return ThrowCompletion(copiedError).
+                Err(create_type_error_copy(JsError::from_opaque(
+                    args.get_or_undefined(0).clone(),
+                )))
+            }),
+        )
+        .length(1)
+        .build();
+
+        // 15. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let capability = PromiseCapability::new(
+            &context.intrinsics().constructors().promise().constructor(),
+            context,
+        )
+        .expect("operation cannot fail for the %Promise% intrinsic");
+
+        // 16. Return PerformPromiseThen(innerCapability.[[Promise]], onFulfilled, onRejected, promiseCapability).
+        Promise::perform_promise_then(
+            inner_capability.promise(),
+            Some(on_fulfilled),
+            Some(on_rejected),
+            Some(capability.clone()),
+            context,
+        );
+
+        capability.promise().clone()
+    }
+}
+
+/// Abstract operation [`GetWrappedValue ( callerRealm, value )`][spec]
+///
+/// Wraps `value` to be used in `caller_realm`, which only allows primitive values and callables to
+/// cross the boundary of a `ShadowRealm`.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-getwrappedvalue
+pub(crate) fn get_wrapped_value(
+    caller_realm: &Realm,
+    value: JsValue,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. If value is an Object, then
+    if let Some(object) = value.as_object() {
+        // a. If IsCallable(value) is false, throw a TypeError exception.
+        if !object.is_callable() {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "cannot pass a non-callable object across the boundary of a ShadowRealm",
+                )
+                .into());
+        }
+
+        // b. Return ? WrappedFunctionCreate(callerRealm, value).
+        return WrappedFunction::create(caller_realm.clone(), object, context).map(Into::into);
+    }
+
+    // 2. Return value.
+    Ok(value)
+}
+
+/// Abstract operation [`CreateTypeErrorCopy ( realmRecord, originalError )`][spec]
+///
+/// Creates the `TypeError` that replaces an error thrown across the boundary of a `ShadowRealm`.
+/// The message of the original error is kept if it can be read without running any user code.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-createtypeerrorcopy
+pub(crate) fn create_type_error_copy(error: JsError) -> JsError {
+    // Errors that cannot be caught by JavaScript code must keep propagating.
+    if !error.is_catchable() {
+        return error;
+    }
+
+    let message = if let Some(native) = error.as_native() {
+        Some(native.message().to_owned())
+    } else {
+        error
+            .as_opaque()
+            .and_then(JsValue::as_object)
+            .filter(|object| object.is::<Error>())
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .properties()
+                    .get(&js_string!("message").into())
+            })
+            .and_then(|message| message.value().and_then(JsValue::as_string).cloned())
+            .map(|message| message.to_std_string_escaped())
+    };
+
+    JsNativeError::typ()
+        .with_message(message.map_or_else(
+            || "an error was thrown across the boundary of a ShadowRealm".to_owned(),
+            |message| {
+                format!("an error was thrown across the boundary of a ShadowRealm: {message}")
+            },
+        ))
+        .into()
+}
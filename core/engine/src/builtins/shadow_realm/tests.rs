@@ -0,0 +1,108 @@
+use crate::{run_test_actions, JsNativeErrorKind, JsValue, TestAction};
+use boa_macros::js_str;
+use indoc::indoc;
+
+#[test]
+fn evaluate() {
+    run_test_actions([
+        TestAction::run("var realm = new ShadowRealm();"),
+        TestAction::assert_eq("realm.evaluate('1 + 2')", 3),
+        TestAction::assert_eq("realm.evaluate('')", JsValue::undefined()),
+        TestAction::run("realm.evaluate('var x = 10; globalThis.y = 20;')"),
+        TestAction::assert_eq("realm.evaluate('x + y')", 30),
+        TestAction::assert_eq("typeof x", js_str!("undefined")),
+        TestAction::run("realm.evaluate('let z = 1;')"),
+        TestAction::assert_eq("realm.evaluate('typeof z')", js_str!("undefined")),
+        TestAction::assert("realm.evaluate('Array') !== Array"),
+        TestAction::assert_eq(
+            "Object.prototype.toString.call(realm)",
+            js_str!("[object ShadowRealm]"),
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('({})')",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across the boundary of a ShadowRealm",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('throw new Error(\"oops\")')",
+            JsNativeErrorKind::Type,
+            "an error was thrown across the boundary of a ShadowRealm: oops",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('(')",
+            JsNativeErrorKind::Syntax,
+            "abrupt end",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate('new.target')",
+            JsNativeErrorKind::Syntax,
+            "invalid new.target usage at line 1, col 1",
+        ),
+        TestAction::assert_native_error(
+            "realm.evaluate(1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: the source text must be a string",
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm.prototype.evaluate.call({}, '1')",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.evaluate: `this` is not a ShadowRealm",
+        ),
+        TestAction::assert_native_error(
+            "ShadowRealm()",
+            JsNativeErrorKind::Type,
+            "ShadowRealm: cannot call constructor without `new`",
+        ),
+    ]);
+}
+
+#[test]
+fn wrapped_functions() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var add = realm.evaluate("(function add(a, b) { return a + b; })");
+            var callback = realm.evaluate("(callback) => callback(21)");
+        "#}),
+        TestAction::assert_eq("add(1, 2)", 3),
+        TestAction::assert_eq("add.name", js_str!("add")),
+        TestAction::assert_eq("add.length", 2),
+        TestAction::assert("Object.getPrototypeOf(add) === Function.prototype"),
+        TestAction::assert("!('prototype' in add)"),
+        TestAction::assert_eq("callback((x) => x * 2)", 42),
+        TestAction::assert_native_error(
+            "add({}, 1)",
+            JsNativeErrorKind::Type,
+            "cannot pass a non-callable object across the boundary of a ShadowRealm",
+        ),
+        TestAction::assert_native_error(
+            "callback(() => { throw new RangeError('inner'); })",
+            JsNativeErrorKind::Type,
+            "an error was thrown across the boundary of a ShadowRealm: \
+             an error was thrown across the boundary of a ShadowRealm: inner",
+        ),
+        TestAction::assert_native_error(
+            "new add(1, 2)",
+            JsNativeErrorKind::Type,
+            "not a constructor",
+        ),
+    ]);
+}
+
+#[test]
+fn import_value() {
+    run_test_actions([
+        TestAction::run(indoc! {r#"
+            var realm = new ShadowRealm();
+            var error;
+            realm.importValue("./module.js", "value").catch((e) => { error = e; });
+        "#}),
+        TestAction::inspect_context(|ctx| ctx.run_jobs()),
+        TestAction::assert("error instanceof TypeError"),
+        TestAction::assert_native_error(
+            "realm.importValue('./module.js', 1)",
+            JsNativeErrorKind::Type,
+            "ShadowRealm.prototype.importValue: the export name must be a string",
+        ),
+    ]);
+}
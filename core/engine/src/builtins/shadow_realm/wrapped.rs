@@ -0,0 +1,168 @@
+use boa_gc::{Finalize, Trace};
+
+use crate::{
+    builtins::function::copy_name_and_length,
+    object::{
+        internal_methods::{CallValue, InternalObjectMethods, ORDINARY_INTERNAL_METHODS},
+        JsData,
+    },
+    realm::Realm,
+    Context, JsObject, JsResult, JsValue,
+};
+
+use super::{create_type_error_copy, get_wrapped_value};
+
+/// A wrapped function exotic object.
+///
+/// Wrapped functions are the only objects that can cross the boundary of a `ShadowRealm`. They
+/// forward their calls to a callable of another realm, wrapping the arguments and the return
+/// value in the process.
+///
+/// More information:
+///  - [ShadowRealm proposal][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct WrappedFunction {
+    /// `[[WrappedTargetFunction]]`
+    target: JsObject,
+
+    /// `[[Realm]]`
+    realm: Realm,
+}
+
+impl JsData for WrappedFunction {
+    fn internal_methods(&self) -> &'static InternalObjectMethods {
+        static METHODS: InternalObjectMethods = InternalObjectMethods {
+            __call__: wrapped_function_exotic_call,
+            ..ORDINARY_INTERNAL_METHODS
+        };
+
+        &METHODS
+    }
+}
+
+impl WrappedFunction {
+    /// Abstract operation [`WrappedFunctionCreate ( callerRealm, Target )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrappedfunctioncreate
+    pub(crate) fn create(
+        caller_realm: Realm,
+        target: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        // 1. Let internalSlotsList be the internal slots listed in Table 2, plus [[Prototype]] and [[Extensible]].
+        // 2. Let wrapped be MakeBasicObject(internalSlotsList).
+        // 3. Set wrapped.[[Prototype]] to callerRealm.[[Intrinsics]].[[%Function.prototype%]].
+        // 4. Set wrapped.[[Call]] as described in 2.1.
+        // 5. Set wrapped.[[WrappedTargetFunction]] to Target.
+        // 6. Set wrapped.[[Realm]] to callerRealm.
+        let prototype = caller_realm
+            .intrinsics()
+            .constructors()
+            .function()
+            .prototype();
+        let wrapped = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                target: target.clone(),
+                realm: caller_realm,
+            },
+        );
+
+        // 7. Let result be Completion(CopyNameAndLength(wrapped, Target)).
+        // 8. If result is an abrupt completion, throw a TypeError exception.
+        copy_name_and_length(&wrapped, target, None, 0, context).map_err(create_type_error_copy)?;
+
+        // 9. Return wrapped.
+        Ok(wrapped)
+    }
+
+    /// Gets the realm in which this wrapped function was created.
+    pub(crate) const fn realm(&self) -> &Realm {
+        &self.realm
+    }
+}
+
+/// Internal method [`[[Call]]`][spec] for wrapped function exotic objects.
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-wrapped-function-exotic-objects-call-thisargument-argumentslist
+fn wrapped_function_exotic_call(
+    obj: &JsObject,
+    argument_count: usize,
+    context: &mut Context,
+) -> JsResult<CallValue> {
+    let args = context.vm.pop_n_values(argument_count);
+    let _func = context.vm.pop();
+    let this = context.vm.pop();
+
+    // The target can be another wrapped function, so this can recurse indefinitely.
+    context.check_runtime_limits()?;
+
+    let (target, mut realm) = {
+        let wrapped = obj.downcast_ref::<WrappedFunction>().expect(
+            "wrapped function exotic method should only be callable from wrapped functions",
+        );
+        (wrapped.target.clone(), wrapped.realm.clone())
+    };
+
+    // 1. Let callerContext be the running execution context.
+    // 2. Let calleeContext be PrepareForWrappedFunctionCall(F).
+    // 3. Assert: calleeContext is now the running execution context.
+    context.swap_realm(&mut realm);
+
+    // 4. Let result be Completion(OrdinaryWrappedFunctionCall(F, thisArgument, argumentsList)).
+    let result = ordinary_wrapped_function_call(&target, &this, &args, context)
+        .map_err(|err| err.inject_realm(context.realm().clone()));
+
+    // 5. Remove calleeContext from the execution context stack and restore callerContext as the running execution context.
+    context.swap_realm(&mut realm);
+
+    // 6. Return ? result.
+    context.vm.push(result?);
+
+    Ok(CallValue::Complete)
+}
+
+/// Abstract operation [`OrdinaryWrappedFunctionCall ( F, thisArgument, argumentsList )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-shadowrealm/#sec-ordinary-wrapped-function-call
+fn ordinary_wrapped_function_call(
+    target: &JsObject,
+    this: &JsValue,
+    args: &[JsValue],
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    // 1. Let target be F.[[WrappedTargetFunction]].
+    // 2. Assert: IsCallable(target) is true.
+    // 3. Let callerRealm be F.[[Realm]].
+    // 4. NOTE: Any exception objects produced after this point are associated with callerRealm.
+    let caller_realm = context.realm().clone();
+
+    // 5. Let targetRealm be ? GetFunctionRealm(target).
+    let target_realm = target.get_function_realm(context)?;
+
+    // 6. Let wrappedArgs be a new empty List.
+    // 7. For each element arg of argumentsList, do
+    //     a. Let wrappedValue be ? GetWrappedValue(targetRealm, arg).
+    //     b. Append wrappedValue to wrappedArgs.
+    let wrapped_args = args
+        .iter()
+        .map(|arg| get_wrapped_value(&target_realm, arg.clone(), context))
+        .collect::<JsResult<Vec<_>>>()?;
+
+    // 8. Let wrappedThisArgument be ? GetWrappedValue(targetRealm, thisArgument).
+    let wrapped_this = get_wrapped_value(&target_realm, this.clone(), context)?;
+
+    // 9. Let result be Completion(Call(target, wrappedThisArgument, wrappedArgs)).
+    match target.call(&wrapped_this, &wrapped_args, context) {
+        // 10. If result is a normal completion, then
+        //     a. Return ? GetWrappedValue(callerRealm, result.[[Value]]).
+        Ok(value) => get_wrapped_value(&caller_realm, value, context),
+        // 11. Else,
+        //     a. Let copiedError be CreateTypeErrorCopy(callerRealm, result.[[Value]]).
+        //     b. Return ThrowCompletion(copiedError).
+        Err(err) => Err(create_type_error_copy(err)),
+    }
+}
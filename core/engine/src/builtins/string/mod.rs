@@ -48,6 +48,78 @@ pub(crate) struct StringNormalizers {
 #[cfg(test)]
 mod tests;
 
+/// Gets the first locale of a list of locales, for the locale-sensitive string methods.
+///
+/// This is a reduced version of [`CanonicalizeLocaleList`][spec] used when the `Intl` object is
+/// not available. It validates every tag of the list, but doesn't canonicalize them, since that
+/// requires the locale data of `Intl`.
+///
+/// With the `intl` feature, the locale-sensitive string methods use
+/// `builtins::intl::locale::canonicalize_locale_list` instead, which implements the full
+/// operation.
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizelocalelist
+#[cfg(all(feature = "locale_strings", not(feature = "intl")))]
+fn first_requested_locale(
+    locales: &JsValue,
+    context: &mut Context,
+) -> JsResult<Option<icu_locid::Locale>> {
+    // 1. If locales is undefined, then
+    if locales.is_undefined() {
+        // a. Return a new empty List.
+        return Ok(None);
+    }
+
+    // 3. If Type(locales) is String, then
+    let o = if locales.is_string() {
+        // a. Let O be CreateArrayFromList(« locales »).
+        Array::create_array_from_list([locales.clone()], context)
+    } else {
+        // 4. Else,
+        // a. Let O be ? ToObject(locales).
+        locales.to_object(context)?
+    };
+
+    // 5. Let len be ? ToLength(? Get(O, "length")).
+    let len = o.length_of_array_like(context)?;
+
+    let mut first = None;
+
+    // 6 Let k be 0.
+    // 7. Repeat, while k < len,
+    for k in 0..len {
+        // a. Let Pk be ToString(k).
+        // b. Let kPresent be ? HasProperty(O, Pk).
+        // c. If kPresent is true, then
+        // c.i. Let kValue be ? Get(O, Pk).
+        if let Some(k_value) = o.try_get(k, context)? {
+            // ii. If Type(kValue) is not String or Object, throw a TypeError exception.
+            if !(k_value.is_object() || k_value.is_string()) {
+                return Err(JsNativeError::typ()
+                    .with_message("locale should be a String or Object")
+                    .into());
+            }
+
+            // iv. Let tag be ? ToString(kValue).
+            let tag = k_value.to_string(context)?.to_std_string_escaped();
+
+            // v. If IsStructurallyValidLanguageTag(tag) is false, throw a RangeError exception.
+            let tag = Some(tag)
+                .filter(|tag| !tag.contains('_'))
+                .and_then(|tag| tag.parse::<icu_locid::Locale>().ok())
+                .ok_or_else(|| {
+                    JsNativeError::range()
+                        .with_message("locale is not a structurally valid language tag")
+                })?;
+
+            first.get_or_insert(tag);
+        }
+        // d. Increase k by 1.
+    }
+
+    Ok(first)
+}
+
 #[derive(Clone, Copy, Eq, PartialEq)]
 pub(crate) enum Placement {
     Start,
@@ -1455,8 +1527,28 @@ impl String {
                 collator.compare_utf16(&s, &that_value) as i8
             }
 
+            // Use the root collation if only the locale-sensitive string methods are enabled.
+            #[cfg(all(feature = "locale_strings", not(feature = "intl")))]
+            {
+                use icu_collator::{Collator, CollatorOptions};
+                use icu_locid::Locale;
+
+                // Loading the collation data is expensive, so the collator is shared by all the
+                // calls of the thread.
+                thread_local! {
+                    static ROOT_COLLATOR: Collator =
+                        Collator::try_new(&Locale::UND.into(), CollatorOptions::new())
+                            .expect("the compiled data must contain the root collation");
+                }
+
+                let s = s.iter().collect::<Vec<_>>();
+                let that_value = that_value.iter().collect::<Vec<_>>();
+
+                ROOT_COLLATOR.with(|collator| collator.compare_utf16(&s, &that_value)) as i8
+            }
+
             // Default to common comparison if the user doesn't have `Intl` enabled.
-            #[cfg(not(feature = "intl"))]
+            #[cfg(not(any(feature = "intl", feature = "locale_strings")))]
             {
                 s.cmp(&that_value) as i8
             }
@@ -1817,7 +1909,54 @@ impl String {
             Ok(result.into())
         }
 
-        #[cfg(not(feature = "intl"))]
+        #[cfg(all(feature = "locale_strings", not(feature = "intl")))]
+        {
+            use icu_casemap::CaseMapper;
+
+            // 1. Let O be ? RequireObjectCoercible(this value).
+            let this = this.require_object_coercible()?;
+
+            // 2. Let S be ? ToString(O).
+            let string = this.to_string(context)?;
+
+            // 3. Return ? TransformCase(S, locales, lower).
+
+            //  TransformCase ( S, locales, targetCase )
+            // https://tc39.es/ecma402/#sec-transform-case
+
+            // 1. Let requestedLocales be ? CanonicalizeLocaleList(locales).
+            // 2. If requestedLocales is not an empty List, then
+            //     a. Let requestedLocale be requestedLocales[0].
+            // 3. Else,
+            //     a. Let requestedLocale be ! DefaultLocale().
+            // 4. Let noExtensionsLocale be the String value that is requestedLocale with any Unicode locale extension sequences (6.2.1) removed.
+            // 5-7. The case mapper only looks at the language subtag to choose the
+            //      language sensitive mappings, so it can be used directly with the requested locale.
+            let locale = first_requested_locale(args.get_or_undefined(0), context)?
+                .map(|locale| locale.id)
+                .unwrap_or_default();
+
+            let casemapper = CaseMapper::new();
+
+            // 8. Let codePoints be StringToCodePoints(S).
+            let result = string.map_valid_segments(|segment| {
+                if UPPER {
+                    // 10. Else,
+                    //     a. Assert: targetCase is upper.
+                    //     b. Let newCodePoints be a List whose elements are the result of an uppercase transformation of codePoints according to an implementation-derived algorithm using locale or the Unicode Default Case Conversion algorithm.
+                    casemapper.uppercase_to_string(&segment, &locale)
+                } else {
+                    // 9. If targetCase is lower, then
+                    //     a. Let newCodePoints be a List whose elements are the result of a lowercase transformation of codePoints according to an implementation-derived algorithm using locale or the Unicode Default Case Conversion algorithm.
+                    casemapper.lowercase_to_string(&segment, &locale)
+                }
+            });
+
+            // 11. Return CodePointsToString(newCodePoints).
+            Ok(result.into())
+        }
+
+        #[cfg(not(any(feature = "intl", feature = "locale_strings")))]
         {
             Self::to_case::<UPPER>(this, args, context)
         }
@@ -916,3 +916,28 @@ fn from_code_point() {
         ),
     ]);
 }
+
+#[cfg(all(feature = "locale_strings", not(feature = "intl")))]
+#[test]
+fn locale_strings() {
+    run_test_actions([
+        TestAction::assert_eq("'istanbul'.toLocaleUpperCase('tr')", js_str!("İSTANBUL")),
+        TestAction::assert_eq("'I'.toLocaleLowerCase(['tr', 'en'])", js_str!("ı")),
+        TestAction::assert_eq("'I'.toLocaleLowerCase()", js_str!("i")),
+        TestAction::assert_eq("'i\\u0307'.toLocaleUpperCase('lt')", js_str!("I")),
+        TestAction::assert_native_error(
+            "'a'.toLocaleUpperCase('en_US')",
+            JsNativeErrorKind::Range,
+            "locale is not a structurally valid language tag",
+        ),
+        TestAction::assert_native_error(
+            "'a'.toLocaleUpperCase([1])",
+            JsNativeErrorKind::Type,
+            "locale should be a String or Object",
+        ),
+        TestAction::assert_eq("'a'.localeCompare('B')", -1),
+        TestAction::assert_eq("'a'.localeCompare('A')", -1),
+        TestAction::assert_eq("'résumé'.localeCompare('resume')", 1),
+        TestAction::assert_eq("'b'.localeCompare('b')", 0),
+    ]);
+}
@@ -122,7 +122,7 @@ impl IntrinsicObject for Symbol {
             .name(js_string!("get description"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .static_method(Self::for_, js_string!("for"), 1)
             .static_method(Self::key_for, js_string!("keyFor"), 1)
             .static_property(
@@ -171,8 +171,19 @@ impl IntrinsicObject for Symbol {
                 symbol_to_primitive,
                 to_primitive,
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+
+        // TODO: remove `cfg` when the explicit resource management proposal gets to stage 4.
+        #[cfg(feature = "experimental")]
+        let builder = builder
+            .static_property(
+                js_string!("asyncDispose"),
+                JsSymbol::async_dispose(),
+                attribute,
             )
-            .build();
+            .static_property(js_string!("dispose"), JsSymbol::dispose(), attribute);
+
+        builder.build();
     }
 
     fn get(intrinsics: &Intrinsics) -> JsObject {
@@ -187,7 +198,7 @@ impl BuiltInObject for Symbol {
 impl BuiltInConstructor for Symbol {
     const LENGTH: usize = 0;
     const P: usize = 5;
-    const SP: usize = 15;
+    const SP: usize = 17;
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
         StandardConstructors::symbol;
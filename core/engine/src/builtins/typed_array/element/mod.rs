@@ -10,6 +10,7 @@ use std::sync::atomic::Ordering;
 use std::{convert::identity, ops::BitAnd};
 
 use bytemuck::{AnyBitPattern, NoUninit};
+use half::f16;
 use num_traits::{WrappingAdd, WrappingSub};
 use portable_atomic::{
     AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicU16, AtomicU32, AtomicU64, AtomicU8,
@@ -186,6 +187,12 @@ impl From<ClampedU8> for Numeric {
     }
 }
 
+impl From<f16> for Numeric {
+    fn from(value: f16) -> Self {
+        Numeric::Number(value.to_f64())
+    }
+}
+
 /// A native element that can be inside a `TypedArray`.
 pub(crate) trait Element:
     Sized + Into<TypedArrayElement> + NoUninit + AnyBitPattern
@@ -331,6 +338,16 @@ element!(
     to_le: |this: ClampedU8| this.to_le(),
 );
 
+element!(
+    f16,
+    AtomicU16,
+    from_js: JsValue::to_f16,
+    from_plain: f16::from_bits,
+    to_plain: |f: f16| f.to_bits(),
+    to_be: |this: f16| f16::from_bits(this.to_bits().to_be()),
+    to_le: |this: f16| f16::from_bits(this.to_bits().to_le()),
+);
+
 element!(
     f32,
     AtomicU32,
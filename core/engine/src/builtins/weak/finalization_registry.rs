@@ -0,0 +1,564 @@
+use std::{cell::Cell, rc::Rc};
+
+use boa_gc::{Ephemeron, Finalize, Gc, Trace, WeakGc};
+use boa_profiler::Profiler;
+
+use crate::{
+    builtins::{BuiltInBuilder, BuiltInConstructor, BuiltInObject, IntrinsicObject},
+    context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
+    job::{JobCallback, NativeJob},
+    js_string,
+    object::{internal_methods::get_prototype_from_constructor, ErasedVTableObject, JsObject},
+    property::Attribute,
+    realm::Realm,
+    string::StaticJsStrings,
+    symbol::JsSymbol,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsValue,
+};
+
+/// Boa's implementation of ECMAScript's `FinalizationRegistry` builtin object.
+///
+/// A `FinalizationRegistry` lets the user register a cleanup callback that gets called some time
+/// after any of the objects registered on the registry are collected by the garbage collector.
+///
+/// More Information:
+///  - [ECMAScript Reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-finalization-registry-objects
+#[derive(Trace, Finalize, JsData)]
+pub(crate) struct FinalizationRegistry {
+    /// `[[Realm]]`
+    realm: Realm,
+
+    /// `[[CleanupCallback]]`
+    cleanup_callback: Gc<JobCallback>,
+
+    /// `[[Cells]]`
+    cells: Vec<RegistryCell>,
+
+    /// Flag set by the garbage collector when any of the targets of the registry gets collected.
+    #[unsafe_ignore_trace]
+    needs_cleanup: Rc<Cell<bool>>,
+}
+
+/// A single record of the `[[Cells]]` list of a `FinalizationRegistry`.
+#[derive(Trace, Finalize)]
+struct RegistryCell {
+    /// `[[WeakRefTarget]]`
+    target: Ephemeron<ErasedVTableObject, CleanupSignaler>,
+
+    /// `[[HeldValue]]`
+    held_value: JsValue,
+
+    /// `[[UnregisterToken]]`
+    unregister_token: Option<WeakGc<ErasedVTableObject>>,
+}
+
+/// Value stored alongside the target of a [`RegistryCell`].
+///
+/// The garbage collector runs its finalizer after the target is collected, which notifies the
+/// owning registry that it needs to be cleaned up.
+#[derive(Debug, Clone, Trace)]
+struct CleanupSignaler(#[unsafe_ignore_trace] Rc<Cell<bool>>);
+
+impl Finalize for CleanupSignaler {
+    fn finalize(&self) {
+        self.0.set(true);
+    }
+}
+
+/// Weak handle to a `FinalizationRegistry`, used by the [`Context`] to find the registries
+/// that need to be cleaned up.
+pub(crate) struct RegistryHandle {
+    registry: WeakGc<ErasedVTableObject>,
+    needs_cleanup: Rc<Cell<bool>>,
+}
+
+impl IntrinsicObject for FinalizationRegistry {
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        Self::STANDARD_CONSTRUCTOR(intrinsics.constructors()).constructor()
+    }
+
+    fn init(realm: &Realm) {
+        let _timer = Profiler::global().start_event(std::any::type_name::<Self>(), "init");
+        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::CONFIGURABLE,
+            )
+            .method(Self::register, js_string!("register"), 2)
+            .method(Self::unregister, js_string!("unregister"), 1)
+            .build();
+    }
+}
+
+impl BuiltInObject for FinalizationRegistry {
+    const NAME: JsString = StaticJsStrings::FINALIZATION_REGISTRY;
+
+    const ATTRIBUTE: Attribute = Attribute::WRITABLE.union(Attribute::CONFIGURABLE);
+}
+
+impl BuiltInConstructor for FinalizationRegistry {
+    /// The amount of arguments the `FinalizationRegistry` constructor takes.
+    const LENGTH: usize = 1;
+    const P: usize = 3;
+    const SP: usize = 0;
+
+    const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
+        StandardConstructors::finalization_registry;
+
+    /// Constructor [`FinalizationRegistry ( cleanupCallback )`][cons]
+    ///
+    /// [cons]: https://tc39.es/ecma262/#sec-finalization-registry-cleanup-callback
+    fn constructor(
+        new_target: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return Err(JsNativeError::typ()
+                .with_message("FinalizationRegistry: cannot call constructor without `new`")
+                .into());
+        }
+
+        // 2. If IsCallable(cleanupCallback) is false, throw a TypeError exception.
+        let cleanup_callback = args.get_or_undefined(0);
+        let Some(cleanup_callback) = cleanup_callback.as_function() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry: expected callable cleanup callback, got value of type `{}`",
+                    cleanup_callback.type_of()
+                ))
+                .into());
+        };
+
+        // 3. Let finalizationRegistry be ? OrdinaryCreateFromConstructor(NewTarget, "%FinalizationRegistry.prototype%", « [[Realm]], [[CleanupCallback]], [[Cells]] »).
+        let prototype = get_prototype_from_constructor(
+            new_target,
+            StandardConstructors::finalization_registry,
+            context,
+        )?;
+
+        // 4. Let fn be the active function object.
+        // 5. Set finalizationRegistry.[[Realm]] to fn.[[Realm]].
+        let realm = context.realm().clone();
+
+        // 6. Set finalizationRegistry.[[CleanupCallback]] to HostMakeJobCallback(cleanupCallback).
+        let cleanup_callback = context
+            .host_hooks()
+            .make_job_callback(cleanup_callback, context);
+
+        // 7. Set finalizationRegistry.[[Cells]] to a new empty List.
+        let needs_cleanup = Rc::new(Cell::new(false));
+        let registry = JsObject::from_proto_and_data_with_shared_shape(
+            context.root_shape(),
+            prototype,
+            Self {
+                realm,
+                cleanup_callback: Gc::new(cleanup_callback),
+                cells: Vec::new(),
+                needs_cleanup: needs_cleanup.clone(),
+            },
+        );
+
+        context.finalization_registries.push(RegistryHandle {
+            registry: WeakGc::new(registry.inner()),
+            needs_cleanup,
+        });
+
+        // 8. Return finalizationRegistry.
+        Ok(registry.into())
+    }
+}
+
+impl FinalizationRegistry {
+    /// Method [`FinalizationRegistry.prototype.register ( target, heldValue [ , unregisterToken ] )`][spec].
+    ///
+    /// Registers `target` on the registry, which will call the cleanup callback with `heldValue`
+    /// some time after `target` is collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.register
+    pub(crate) fn register(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "register")?;
+
+        let target = args.get_or_undefined(0);
+        let held_value = args.get_or_undefined(1);
+        let unregister_token = args.get_or_undefined(2);
+
+        // 3. If CanBeHeldWeakly(target) is false, throw a TypeError exception.
+        let Some(target_obj) = target.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.register: expected target argument of type `object`, got target of type `{}`",
+                    target.type_of()
+                ))
+                .into());
+        };
+
+        // 4. If SameValue(target, heldValue) is true, throw a TypeError exception.
+        if JsValue::same_value(target, held_value) {
+            return Err(JsNativeError::typ()
+                .with_message(
+                    "FinalizationRegistry.prototype.register: target and held value cannot be the same",
+                )
+                .into());
+        }
+
+        // 5. If CanBeHeldWeakly(unregisterToken) is false, then
+        let unregister_token = match unregister_token.as_object() {
+            Some(token) => Some(WeakGc::new(token.inner())),
+            //     a. If unregisterToken is not undefined, throw a TypeError exception.
+            None if !unregister_token.is_undefined() => {
+                return Err(JsNativeError::typ()
+                    .with_message(format!(
+                        "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `undefined`, got token of type `{}`",
+                        unregister_token.type_of()
+                    ))
+                    .into());
+            }
+            //     b. Set unregisterToken to empty.
+            None => None,
+        };
+
+        // 6. Let cell be the Record { [[WeakRefTarget]]: target, [[HeldValue]]: heldValue, [[UnregisterToken]]: unregisterToken }.
+        let needs_cleanup = registry
+            .downcast_ref::<Self>()
+            .expect("`this_registry` must return a `FinalizationRegistry`")
+            .needs_cleanup
+            .clone();
+        let cell = RegistryCell {
+            target: Ephemeron::new(target_obj.inner(), CleanupSignaler(needs_cleanup)),
+            held_value: held_value.clone(),
+            unregister_token,
+        };
+
+        // 7. Append cell to finalizationRegistry.[[Cells]].
+        registry
+            .downcast_mut::<Self>()
+            .expect("`this_registry` must return a `FinalizationRegistry`")
+            .cells
+            .push(cell);
+
+        // 8. Return undefined.
+        Ok(JsValue::undefined())
+    }
+
+    /// Method [`FinalizationRegistry.prototype.unregister ( unregisterToken )`][spec].
+    ///
+    /// Removes all the cells registered with `unregisterToken`, returning `true` if any cell
+    /// was removed.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-finalization-registry.prototype.unregister
+    pub(crate) fn unregister(
+        this: &JsValue,
+        args: &[JsValue],
+        _context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let finalizationRegistry be the this value.
+        // 2. Perform ? RequireInternalSlot(finalizationRegistry, [[Cells]]).
+        let registry = Self::this_registry(this, "unregister")?;
+
+        // 3. If CanBeHeldWeakly(unregisterToken) is false, throw a TypeError exception.
+        let unregister_token = args.get_or_undefined(0);
+        let Some(unregister_token) = unregister_token.as_object() else {
+            return Err(JsNativeError::typ()
+                .with_message(format!(
+                    "FinalizationRegistry.prototype.unregister: expected unregister token of type `object`, got token of type `{}`",
+                    unregister_token.type_of()
+                ))
+                .into());
+        };
+
+        let mut registry = registry
+            .downcast_mut::<Self>()
+            .expect("`this_registry` must return a `FinalizationRegistry`");
+
+        // 4. Let removed be false.
+        let len = registry.cells.len();
+
+        // 5. For each Record { [[WeakRefTarget]], [[HeldValue]], [[UnregisterToken]] } cell of finalizationRegistry.[[Cells]], do
+        registry.cells.retain(|cell| {
+            //     a. If cell.[[UnregisterToken]] is not empty and SameValue(cell.[[UnregisterToken]], unregisterToken) is true, then
+            //         i. Remove cell from finalizationRegistry.[[Cells]].
+            //         ii. Set removed to true.
+            !cell
+                .unregister_token
+                .as_ref()
+                .and_then(WeakGc::upgrade)
+                .is_some_and(|token| JsObject::equals(&JsObject::from(token), unregister_token))
+        });
+
+        // 6. Return removed.
+        Ok((registry.cells.len() != len).into())
+    }
+
+    /// Abstract operation [`CleanupFinalizationRegistry ( finalizationRegistry )`][spec].
+    ///
+    /// Calls the cleanup callback of `registry` once for each cell whose target was collected.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-cleanup-finalization-registry
+    pub(crate) fn cleanup(registry: &JsObject, context: &mut Context) -> JsResult<()> {
+        // 1. Assert: finalizationRegistry has [[Cells]] and [[CleanupCallback]] internal slots.
+        // 2. Let callback be finalizationRegistry.[[CleanupCallback]].
+        let callback = registry
+            .downcast_ref::<Self>()
+            .expect("cleanup jobs must only be created for `FinalizationRegistry` objects")
+            .cleanup_callback
+            .clone();
+
+        // 3. While finalizationRegistry.[[Cells]] contains a Record cell such that cell.[[WeakRefTarget]] is empty, an implementation may perform the following steps:
+        loop {
+            let held_value = {
+                let mut registry = registry
+                    .downcast_mut::<Self>()
+                    .expect("cleanup jobs must only be created for `FinalizationRegistry` objects");
+
+                // a. Choose any such cell.
+                let Some(index) = registry
+                    .cells
+                    .iter()
+                    .position(|cell| !cell.target.has_value())
+                else {
+                    break;
+                };
+
+                // b. Remove cell from finalizationRegistry.[[Cells]].
+                registry.cells.remove(index).held_value.clone()
+            };
+
+            // c. Perform ? HostCallJobCallback(callback, undefined, « cell.[[HeldValue]] »).
+            // Hosts that need their host defined data in the cleanup callbacks must create the
+            // record with `JobCallback::new_cloneable`; otherwise it is called without it.
+            let job_callback = callback
+                .try_clone()
+                .unwrap_or_else(|| JobCallback::new(callback.callback().clone(), ()));
+            context.host_hooks().call_job_callback(
+                job_callback,
+                &JsValue::undefined(),
+                &[held_value],
+                context,
+            )?;
+        }
+
+        // 4. Return unused.
+        Ok(())
+    }
+
+    /// Enqueues a cleanup job for every live registry that had any of its targets collected,
+    /// using [`HostHooks::enqueue_finalization_registry_cleanup_job`].
+    ///
+    /// Returns `true` if any cleanup job was enqueued.
+    ///
+    /// [`HostHooks::enqueue_finalization_registry_cleanup_job`]: crate::context::HostHooks::enqueue_finalization_registry_cleanup_job
+    pub(crate) fn enqueue_cleanup_jobs(context: &mut Context) -> bool {
+        let mut pending = Vec::new();
+        context.finalization_registries.retain(|handle| {
+            let Some(registry) = handle.registry.upgrade() else {
+                return false;
+            };
+            if handle.needs_cleanup.replace(false) {
+                pending.push(JsObject::from(registry));
+            }
+            true
+        });
+
+        let mut enqueued = false;
+        for registry in pending {
+            let realm = {
+                let data = registry
+                    .downcast_ref::<Self>()
+                    .expect("handles must only point to `FinalizationRegistry` objects");
+
+                // The finalizer of a cell can also run when the cell itself is collected after
+                // being unregistered, so check that there is something to clean up.
+                if data.cells.iter().all(|cell| cell.target.has_value()) {
+                    continue;
+                }
+                data.realm.clone()
+            };
+
+            // `HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`
+            // 1. Let cleanupJob be a new Job Abstract Closure with no parameters that captures finalizationRegistry and performs the following steps when called:
+            //     a. Let cleanupResult be Completion(CleanupFinalizationRegistry(finalizationRegistry)).
+            //     b. If cleanupResult is an abrupt completion, perform any host-defined steps for reporting the error.
+            //     c. Return unused.
+            let job = NativeJob::with_realm(
+                move |context| {
+                    Self::cleanup(&registry, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+                context,
+            );
+            context
+                .host_hooks()
+                .enqueue_finalization_registry_cleanup_job(job, context);
+            enqueued = true;
+        }
+
+        enqueued
+    }
+
+    /// Gets the `this` value as a `FinalizationRegistry` object, throwing a `TypeError` otherwise.
+    fn this_registry(this: &JsValue, method: &str) -> JsResult<JsObject> {
+        this.as_object()
+            .filter(|obj| obj.is::<Self>())
+            .cloned()
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message(format!(
+                        "FinalizationRegistry.prototype.{method}: expected `this` to be a `FinalizationRegistry` object"
+                    ))
+                    .into()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use indoc::indoc;
+
+    use crate::{
+        context::{ContextBuilder, HostHooks},
+        job::NativeJob,
+        run_test_actions, run_test_actions_with, Context, JsNativeErrorKind, TestAction,
+    };
+
+    const COLLECT_TARGETS: &str = indoc! {r#"
+        var cleaned = [];
+        var registry = new FinalizationRegistry((held) => cleaned.push(held));
+        var token = {};
+        {
+            let a = {};
+            let b = {};
+            let c = {};
+            registry.register(a, "a");
+            registry.register(b, "b", token);
+            registry.register(c, "c");
+        }
+    "#};
+
+    fn collect_and_run_jobs(context: &mut Context) {
+        context.clear_kept_objects();
+        boa_gc::force_collect();
+        context.run_jobs();
+    }
+
+    #[test]
+    fn finalization_registry_errors() {
+        run_test_actions([
+            TestAction::run("var registry = new FinalizationRegistry(() => {});"),
+            TestAction::assert_native_error(
+                "FinalizationRegistry(() => {})",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry: cannot call constructor without `new`",
+            ),
+            TestAction::assert_native_error(
+                "new FinalizationRegistry(1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry: expected callable cleanup callback, got value of type `number`",
+            ),
+            TestAction::assert_native_error(
+                "registry.register(1, 2)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: expected target argument of type `object`, got target of type `number`",
+            ),
+            TestAction::assert_native_error(
+                "var obj = {}; registry.register(obj, obj)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: target and held value cannot be the same",
+            ),
+            TestAction::assert_native_error(
+                "registry.register({}, 1, 'token')",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: expected unregister token of type `object` or `undefined`, got token of type `string`",
+            ),
+            TestAction::assert_native_error(
+                "registry.unregister(1)",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.unregister: expected unregister token of type `object`, got token of type `number`",
+            ),
+            TestAction::assert_native_error(
+                "FinalizationRegistry.prototype.register.call({}, {})",
+                JsNativeErrorKind::Type,
+                "FinalizationRegistry.prototype.register: expected `this` to be a `FinalizationRegistry` object",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_unregister() {
+        run_test_actions([
+            TestAction::run(indoc! {r#"
+                var registry = new FinalizationRegistry(() => {});
+                var token = {};
+                registry.register({}, 1, token);
+                registry.register({}, 2, token);
+            "#}),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::assert("!registry.unregister(token)"),
+            TestAction::assert("!registry.unregister({})"),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_cleanup() {
+        run_test_actions([
+            TestAction::run_harness(),
+            TestAction::run(COLLECT_TARGETS),
+            TestAction::assert("registry.unregister(token)"),
+            TestAction::inspect_context(collect_and_run_jobs),
+            TestAction::assert("arrayEquals(cleaned.sort(), ['a', 'c'])"),
+            TestAction::inspect_context(collect_and_run_jobs),
+            TestAction::assert_eq("cleaned.length", 2),
+        ]);
+    }
+
+    #[test]
+    fn finalization_registry_host_cleanup_hook() {
+        thread_local! {
+            static DEFERRED_JOBS: RefCell<Vec<NativeJob>> = const { RefCell::new(Vec::new()) };
+        }
+
+        struct DeferCleanupHooks;
+
+        impl HostHooks for DeferCleanupHooks {
+            fn enqueue_finalization_registry_cleanup_job(&self, job: NativeJob, _: &mut Context) {
+                DEFERRED_JOBS.with_borrow_mut(|jobs| jobs.push(job));
+            }
+        }
+
+        let context = &mut ContextBuilder::new()
+            .host_hooks(&DeferCleanupHooks)
+            .build()
+            .unwrap();
+
+        run_test_actions_with(
+            [
+                TestAction::run_harness(),
+                TestAction::run(COLLECT_TARGETS),
+                TestAction::inspect_context(collect_and_run_jobs),
+                TestAction::assert_eq("cleaned.length", 0),
+                TestAction::inspect_context(|context| {
+                    let jobs = DEFERRED_JOBS.take();
+                    assert_eq!(jobs.len(), 1);
+                    for job in jobs {
+                        job.call(context).unwrap();
+                    }
+                }),
+                TestAction::assert("arrayEquals(cleaned.sort(), ['a', 'b', 'c'])"),
+            ],
+            context,
+        );
+    }
+}
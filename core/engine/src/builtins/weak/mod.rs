@@ -1,5 +1,7 @@
-//! Boa's implementation of ECMAScript's `WeakRef` object.
+//! Boa's implementation of ECMAScript's `WeakRef` and `FinalizationRegistry` objects.
 
+mod finalization_registry;
 mod weak_ref;
 
+pub(crate) use finalization_registry::{FinalizationRegistry, RegistryHandle};
 pub(crate) use weak_ref::WeakRef;
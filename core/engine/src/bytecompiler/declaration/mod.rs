@@ -1 +1,4 @@
 mod declaration_pattern;
+mod using;
+
+pub(crate) use using::using_declarations_kind;
@@ -0,0 +1,134 @@
+use crate::{
+    bytecompiler::{jump_control::JumpControlInfoFlags, ByteCompiler, Operand},
+    vm::Opcode,
+};
+use boa_ast::{declaration::LexicalDeclaration, Declaration, StatementList, StatementListItem};
+
+/// Returns `Some(true)` if the statement list contains `await using` declarations, `Some(false)`
+/// if it only contains `using` declarations, and `None` if it contains neither.
+pub(crate) fn using_declarations_kind(list: &StatementList) -> Option<bool> {
+    list.statements()
+        .iter()
+        .filter_map(|item| match item {
+            StatementListItem::Declaration(Declaration::Lexical(decl)) => Some(decl),
+            _ => None,
+        })
+        .fold(None, |kind, decl| match decl {
+            LexicalDeclaration::AwaitUsing(_) => Some(true),
+            LexicalDeclaration::Using(_) => kind.or(Some(false)),
+            _ => kind,
+        })
+}
+
+impl ByteCompiler<'_> {
+    /// Compiles a scope containing `using` declarations.
+    ///
+    /// The code emitted by `body` runs with a new dispose capability, and the resources added to it
+    /// are disposed when the scope is exited, be it by a normal or an abrupt completion. This works
+    /// like a `try` statement with a `finally` block that disposes the resources.
+    ///
+    /// More information:
+    ///  - [Explicit Resource Management proposal][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources
+    pub(crate) fn compile_using_scope<F>(&mut self, r#async: bool, use_expr: bool, body: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.emit_opcode(Opcode::PushDisposeCapability);
+        self.push_try_with_finally_control_info(use_expr);
+
+        let handler = self.push_handler();
+        body(self);
+
+        self.emit_opcode(Opcode::PushZero);
+        self.emit_opcode(Opcode::PushFalse);
+
+        // stack: 0, false
+        let finally = self.jump();
+        self.patch_handler(handler);
+
+        if self.is_generator() {
+            // If the exception is empty this is a generator `return()` call, in which case the
+            // resources are disposed before continuing with the return.
+            self.emit_opcode(Opcode::MaybeException);
+            let has_exception = self.jump_if_true();
+
+            self.emit_opcode(Opcode::PushUndefined);
+            self.emit_opcode(Opcode::PushFalse);
+
+            // stack: undefined, false
+            self.current_stack_value_count += 2;
+            self.compile_dispose_resources(r#async);
+            self.current_stack_value_count -= 2;
+            self.emit_opcode(Opcode::PopDisposeCapability);
+
+            let is_return = self.jump_if_false();
+            self.emit_opcode(Opcode::Throw);
+            self.patch_jump(is_return);
+            self.emit_opcode(Opcode::Pop);
+            self.emit_opcode(Opcode::ReThrow);
+
+            self.patch_jump(has_exception);
+        } else {
+            self.emit_opcode(Opcode::Exception);
+        }
+
+        // stack: exception, true
+        self.emit_opcode(Opcode::PushTrue);
+
+        self.patch_jump(finally);
+
+        let finally_start = self.next_opcode_location();
+        self.jump_info
+            .last_mut()
+            .expect("there should be a try block")
+            .flags |= JumpControlInfoFlags::IN_FINALLY;
+
+        self.current_stack_value_count += 2;
+        self.compile_dispose_resources(r#async);
+        self.current_stack_value_count -= 2;
+        self.emit_opcode(Opcode::PopDisposeCapability);
+
+        // Rethrow the exception, if any.
+        let do_not_throw_exit = self.jump_if_false();
+        self.emit_opcode(Opcode::Throw);
+        self.patch_jump(do_not_throw_exit);
+
+        self.pop_try_with_finally_control_info(finally_start);
+    }
+
+    /// Disposes the resources of the current dispose capability, updating the completion on the
+    /// top of the stack with any errors thrown while disposing them.
+    ///
+    /// stack: value, is_throw **=>** value, is_throw
+    fn compile_dispose_resources(&mut self, r#async: bool) {
+        let start = self.next_opcode_location();
+        let handler = self.push_handler();
+
+        // stack: value, is_throw, result, await, done
+        self.emit_opcode(Opcode::DisposeResources);
+        let done = self.jump_if_true();
+        let skip_await = self.jump_if_false();
+        if r#async {
+            self.emit_opcode(Opcode::Await);
+            self.emit_opcode(Opcode::GeneratorNext);
+        }
+        self.patch_jump(skip_await);
+        self.emit_opcode(Opcode::Pop);
+        self.emit(Opcode::Jump, &[Operand::U32(start)]);
+
+        self.patch_handler(handler);
+
+        // stack: value, is_throw, error
+        self.emit_opcode(Opcode::Exception);
+        self.emit_opcode(Opcode::SuppressDisposeError);
+        self.emit(Opcode::Jump, &[Operand::U32(start)]);
+
+        self.patch_jump(done);
+
+        // stack: value, is_throw, result, await
+        self.emit_opcode(Opcode::Pop);
+        self.emit_opcode(Opcode::Pop);
+    }
+}
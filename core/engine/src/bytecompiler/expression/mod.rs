@@ -317,6 +317,7 @@ impl ByteCompiler<'_> {
                     }
                 }
 
+                self.set_source_position(super_call.position());
                 if contains_spread {
                     self.emit_opcode(Opcode::SuperCallSpread);
                 } else {
@@ -325,6 +326,7 @@ impl ByteCompiler<'_> {
                         super_call.arguments().len() as u32,
                     );
                 }
+                self.set_source_position(None);
                 self.emit_opcode(Opcode::BindThisValue);
 
                 if !use_expr {
@@ -333,6 +335,11 @@ impl ByteCompiler<'_> {
             }
             Expression::ImportCall(import) => {
                 self.compile_expr(import.argument(), true);
+                if let Some(options) = import.options() {
+                    self.compile_expr(options, true);
+                } else {
+                    self.emit_opcode(Opcode::PushUndefined);
+                }
                 self.emit_opcode(Opcode::ImportCall);
                 if !use_expr {
                     self.emit_opcode(Opcode::Pop);
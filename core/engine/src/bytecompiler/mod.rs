@@ -19,7 +19,7 @@ use crate::{
     js_string,
     vm::{
         BindingOpcode, CallFrame, CodeBlock, CodeBlockFlags, Constant, GeneratorResumeKind,
-        Handler, InlineCache, Opcode, VaryingOperandKind,
+        Handler, InlineCache, Opcode, SourceMapEntry, VaryingOperandKind,
     },
     JsBigInt, JsStr, JsString,
 };
@@ -41,12 +41,13 @@ use boa_ast::{
     pattern::Pattern,
     property::MethodDefinitionKind,
     scope::{BindingLocator, BindingLocatorError, FunctionScopes, IdentifierReference, Scope},
-    Declaration, Expression, Statement, StatementList, StatementListItem,
+    Declaration, Expression, Position, Statement, StatementList, StatementListItem,
 };
 use boa_gc::Gc;
 use boa_interner::{Interner, Sym};
 use boa_macros::js_str;
 use class::ClassSpec;
+use declaration::using_declarations_kind;
 use rustc_hash::FxHashMap;
 use thin_vec::ThinVec;
 
@@ -424,6 +425,7 @@ pub struct ByteCompiler<'ctx> {
     current_stack_value_count: u32,
     code_block_flags: CodeBlockFlags,
     handlers: ThinVec<Handler>,
+    source_map: ThinVec<SourceMapEntry>,
     pub(crate) ic: Vec<InlineCache>,
     literals_map: FxHashMap<Literal, u32>,
     names_map: FxHashMap<Identifier, u32>,
@@ -524,6 +526,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             current_stack_value_count: 0,
             code_block_flags,
             handlers: ThinVec::default(),
+            source_map: ThinVec::default(),
             ic: Vec::default(),
 
             literals_map: FxHashMap::default(),
@@ -688,6 +691,17 @@ impl<'ctx> ByteCompiler<'ctx> {
         self.bytecode.len() as u32
     }
 
+    /// Maps the bytecode emitted from now on to `position` in the source code.
+    pub(crate) fn set_source_position(&mut self, position: Option<Position>) {
+        let pc = self.next_opcode_location();
+        match self.source_map.last_mut() {
+            Some(entry) if entry.pc == pc => entry.position = position,
+            Some(entry) if entry.position == position => {}
+            None if position.is_none() => {}
+            _ => self.source_map.push(SourceMapEntry { pc, position }),
+        }
+    }
+
     pub(crate) fn emit(&mut self, opcode: Opcode, operands: &[Operand]) {
         let mut varying_kind = VaryingOperandKind::U8;
         for operand in operands {
@@ -1015,13 +1029,17 @@ impl<'ctx> ByteCompiler<'ctx> {
                     PropertyAccessField::Const(name) => {
                         self.compile_expr(access.target(), true);
                         self.emit_opcode(Opcode::Dup);
+                        self.set_source_position(access.position());
                         self.emit_get_property_by_name(*name);
+                        self.set_source_position(None);
                     }
                     PropertyAccessField::Expr(expr) => {
                         self.compile_expr(access.target(), true);
                         self.emit_opcode(Opcode::Dup);
                         self.compile_expr(expr, true);
+                        self.set_source_position(access.position());
                         self.emit_opcode(Opcode::GetPropertyByValue);
+                        self.set_source_position(None);
                     }
                 },
                 PropertyAccess::Private(access) => {
@@ -1195,6 +1213,17 @@ impl<'ctx> ByteCompiler<'ctx> {
 
     /// Compile a [`StatementList`].
     pub fn compile_statement_list(&mut self, list: &StatementList, use_expr: bool, block: bool) {
+        if let Some(r#async) = using_declarations_kind(list) {
+            self.compile_using_scope(r#async, use_expr, |compiler| {
+                compiler.compile_statement_list_items(list, use_expr, block);
+            });
+        } else {
+            self.compile_statement_list_items(list, use_expr, block);
+        }
+    }
+
+    /// Compile the items of a [`StatementList`].
+    fn compile_statement_list_items(&mut self, list: &StatementList, use_expr: bool, block: bool) {
         if use_expr || self.jump_control_info_has_use_expr() {
             let mut use_expr_index = 0;
             for (i, statement) in list.statements().iter().enumerate() {
@@ -1246,13 +1275,16 @@ impl<'ctx> ByteCompiler<'ctx> {
                 self.emit_opcode(Opcode::Dup);
                 match access.field() {
                     PropertyAccessField::Const(field) => {
+                        self.set_source_position(access.position());
                         self.emit_get_property_by_name(*field);
                     }
                     PropertyAccessField::Expr(field) => {
                         self.compile_expr(field, true);
+                        self.set_source_position(access.position());
                         self.emit_opcode(Opcode::GetPropertyByValue);
                     }
                 }
+                self.set_source_position(None);
             }
             PropertyAccess::Private(access) => {
                 self.compile_expr(access.target(), true);
@@ -1476,6 +1508,22 @@ impl<'ctx> ByteCompiler<'ctx> {
                     }
                 }
             }
+            LexicalDeclaration::Using(decls) | LexicalDeclaration::AwaitUsing(decls) => {
+                let r#async = decl.is_await_using();
+                for variable in decls.as_ref() {
+                    let Binding::Identifier(ident) = variable.binding() else {
+                        unreachable!("using declarations cannot contain binding patterns");
+                    };
+                    let ident = ident.to_js_string(self.interner());
+                    let init = variable
+                        .init()
+                        .expect("using declaration must have initializer");
+                    self.compile_expr(init, true);
+                    self.emit_opcode(Opcode::Dup);
+                    self.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+                    self.emit_binding(BindingOpcode::InitLexical, ident);
+                }
+            }
         };
     }
 
@@ -1758,6 +1806,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             }
         }
 
+        self.set_source_position(call.position());
         match kind {
             CallKind::CallEval => {
                 let scope_index = self.constants.len() as u32;
@@ -1782,6 +1831,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             CallKind::New if contains_spread => self.emit_opcode(Opcode::NewSpread),
             CallKind::New => self.emit_with_varying_operand(Opcode::New, call.args().len() as u32),
         }
+        self.set_source_position(None);
 
         if !use_expr {
             self.emit_opcode(Opcode::Pop);
@@ -1831,6 +1881,7 @@ impl<'ctx> ByteCompiler<'ctx> {
             bindings: self.bindings.into_boxed_slice(),
             local_bindings_initialized,
             handlers: self.handlers,
+            source_map: self.source_map,
             flags: Cell::new(self.code_block_flags),
             ic: self.ic.into_boxed_slice(),
         }
@@ -1,15 +1,36 @@
 use crate::vm::{BindingOpcode, Opcode};
 
 use super::{ByteCompiler, Literal, Operand, ToJsString};
-use boa_ast::{declaration::ExportDeclaration, ModuleItem, ModuleItemList};
+use boa_ast::{
+    declaration::ExportDeclaration, Declaration, ModuleItem, ModuleItemList, StatementListItem,
+};
 use boa_interner::Sym;
 
 impl ByteCompiler<'_> {
     /// Compiles a [`ModuleItemList`].
     #[inline]
     pub fn compile_module_item_list(&mut self, list: &ModuleItemList) {
-        for node in list.items() {
-            self.compile_module_item(node);
+        let using = list
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                ModuleItem::StatementListItem(StatementListItem::Declaration(
+                    Declaration::Lexical(decl),
+                )) if decl.is_using() || decl.is_await_using() => Some(decl.is_await_using()),
+                _ => None,
+            })
+            .reduce(|a, b| a || b);
+
+        if let Some(r#async) = using {
+            self.compile_using_scope(r#async, false, |compiler| {
+                for node in list.items() {
+                    compiler.compile_module_item(node);
+                }
+            });
+        } else {
+            for node in list.items() {
+                self.compile_module_item(node);
+            }
         }
     }
 
@@ -21,6 +21,21 @@ impl ByteCompiler<'_> {
         label: Option<Sym>,
         use_expr: bool,
     ) {
+        // The resources of `using` declarations in the head of the loop are disposed after the loop completes.
+        if let Some(ForLoopInitializer::Lexical(decl)) = for_loop.init() {
+            let decl = decl.declaration();
+            if decl.is_using() || decl.is_await_using() {
+                self.compile_using_scope(decl.is_await_using(), use_expr, |compiler| {
+                    compiler.compile_for_loop_impl(for_loop, label, use_expr);
+                });
+                return;
+            }
+        }
+
+        self.compile_for_loop_impl(for_loop, label, use_expr);
+    }
+
+    fn compile_for_loop_impl(&mut self, for_loop: &ForLoop, label: Option<Sym>, use_expr: bool) {
         let mut let_binding_indices = None;
         let mut outer_scope_local = None;
         let mut outer_scope = None;
@@ -202,6 +217,9 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_) => {
+                unreachable!("using declarations are not allowed in for-in loops")
+            }
         }
 
         self.compile_stmt(for_in_loop.body(), use_expr, true);
@@ -308,9 +326,33 @@ impl ByteCompiler<'_> {
             IterableLoopInitializer::Pattern(pattern) => {
                 self.compile_declaration_pattern(pattern, BindingOpcode::SetName);
             }
+            IterableLoopInitializer::Using(declaration)
+            | IterableLoopInitializer::AwaitUsing(declaration) => {
+                let Binding::Identifier(ident) = declaration else {
+                    unreachable!("using declarations cannot contain binding patterns");
+                };
+                let ident = ident.to_js_string(self.interner());
+                let r#async = matches!(
+                    for_of_loop.initializer(),
+                    IterableLoopInitializer::AwaitUsing(_)
+                );
+
+                // The resources of each iteration are disposed at the end of the iteration.
+                self.compile_using_scope(r#async, use_expr, |compiler| {
+                    compiler.emit_opcode(Opcode::Dup);
+                    compiler.emit(Opcode::AddDisposableResource, &[Operand::Bool(r#async)]);
+                    compiler.emit_binding(BindingOpcode::InitLexical, ident);
+                    compiler.compile_stmt(for_of_loop.body(), use_expr, true);
+                });
+            }
         }
 
-        self.compile_stmt(for_of_loop.body(), use_expr, true);
+        if !matches!(
+            for_of_loop.initializer(),
+            IterableLoopInitializer::Using(_) | IterableLoopInitializer::AwaitUsing(_)
+        ) {
+            self.compile_stmt(for_of_loop.body(), use_expr, true);
+        }
 
         {
             let exit = self.jump();
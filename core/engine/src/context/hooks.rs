@@ -1,7 +1,7 @@
 use crate::{
     builtins::promise::OperationType,
     context::intrinsics::Intrinsics,
-    job::JobCallback,
+    job::{JobCallback, NativeJob},
     object::{JsFunction, JsObject},
     realm::Realm,
     Context, JsResult, JsString, JsValue,
@@ -58,12 +58,15 @@ pub trait HostHooks {
     ///
     /// - It must return a `JobCallback` Record whose `[[Callback]]` field is `callback`.
     ///
+    /// The cleanup callback of a `FinalizationRegistry` can be called many times, so its host
+    /// defined field is only kept if the record is created with [`JobCallback::new_cloneable`].
+    ///
     /// [spec]: https://tc39.es/ecma262/#sec-hostmakejobcallback
     fn make_job_callback(&self, callback: JsFunction, _context: &mut Context) -> JobCallback {
         // The default implementation of HostMakeJobCallback performs the following steps when called:
 
         // 1. Return the JobCallback Record { [[Callback]]: callback, [[HostDefined]]: empty }.
-        JobCallback::new(callback, ())
+        JobCallback::new_cloneable(callback, ())
     }
 
     /// [`HostCallJobCallback ( jobCallback, V, argumentsList )`][spec]
@@ -105,6 +108,39 @@ pub trait HostHooks {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// [`HostEnqueueFinalizationRegistryCleanupJob ( finalizationRegistry )`][spec]
+    ///
+    /// Called when some of the targets registered on a `FinalizationRegistry` have been
+    /// collected. `job` performs the cleanup of the registry when called, which allows hosts
+    /// to control when (and if) the cleanup callbacks of the registry are executed.
+    ///
+    /// # Requirements
+    ///
+    /// - It must schedule `job` to be performed at some future time, if possible.
+    /// - It must not interrupt the synchronous evaluation of ECMAScript code to perform `job`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-cleanup-finalization-registry
+    fn enqueue_finalization_registry_cleanup_job(&self, job: NativeJob, context: &mut Context) {
+        // The default implementation enqueues the job on the job queue of the context, which
+        // performs it along with the rest of the pending jobs.
+        context.enqueue_job(job);
+    }
+
+    /// [`HostInitializeShadowRealm ( realm )`][spec]
+    ///
+    /// Called when a new `ShadowRealm` is created, after its global object has been populated with
+    /// the default global bindings. This allows hosts to add their own global bindings to `realm`,
+    /// or to throw an error to abort the creation of the `ShadowRealm`.
+    ///
+    /// This is only called if the `experimental` feature is enabled, since `ShadowRealm` is
+    /// still a proposal.
+    ///
+    /// [spec]: https://tc39.es/proposal-shadowrealm/#sec-hostinitializeshadowrealm
+    fn initialize_shadow_realm(&self, _realm: &Realm, _context: &mut Context) -> JsResult<()> {
+        // The default implementation of HostInitializeShadowRealm is to return NormalCompletion(unused).
+        Ok(())
+    }
+
     /// [`HostEnsureCanCompileStrings ( calleeRealm, parameterStrings, bodyString, direct )`][spec]
     ///
     /// # Requirements
@@ -194,6 +230,27 @@ pub trait HostHooks {
             .map_or(0, UtcOffset::whole_seconds)
     }
 
+    /// Returns the offset of the IANA time zone `time_zone` to the `utc` timezone in seconds, or
+    /// `None` if the host doesn't have the data of the time zone.
+    ///
+    /// `time_zone` is always the canonical identifier of the time zone (e.g. `"Asia/Kolkata"`).
+    /// This is used by `Intl.DateTimeFormat` to format dates in named time zones.
+    ///
+    /// With the `intl_bundled` feature, defaults to using the IANA time zone database bundled
+    /// with Boa. Otherwise, defaults to `None` for all time zones.
+    #[allow(unused_variables)]
+    fn time_zone_offset_seconds(&self, time_zone: &str, unix_time_seconds: i64) -> Option<i32> {
+        #[cfg(feature = "intl_bundled")]
+        {
+            let time_zone = jiff::tz::db().get(time_zone).ok()?;
+            let timestamp = jiff::Timestamp::from_second(unix_time_seconds).ok()?;
+            Some(time_zone.to_offset(timestamp).seconds())
+        }
+
+        #[cfg(not(feature = "intl_bundled"))]
+        None
+    }
+
     /// Gets the maximum size in bits that can be allocated for an `ArrayBuffer` or a
     /// `SharedArrayBuffer`.
     ///
@@ -2,12 +2,22 @@ use std::{cell::OnceCell, fmt::Debug};
 
 use boa_profiler::Profiler;
 use icu_casemap::CaseMapper;
-use icu_locid_transform::{LocaleCanonicalizer, LocaleExpander, LocaleTransformError};
+use icu_locid_transform::{
+    provider::{
+        AliasesV2Marker, LikelySubtagsForLanguageV1Marker, LikelySubtagsForScriptRegionV1Marker,
+        LocaleFallbackLikelySubtagsV1Marker, LocaleFallbackParentsV1Marker,
+    },
+    LocaleCanonicalizer, LocaleExpander, LocaleTransformError,
+};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer, NormalizerError};
 use icu_provider::{
     AnyProvider, AsDeserializingBufferProvider, AsDowncastingAnyProvider, BufferProvider,
-    DataError, DataProvider, DataRequest, DataResponse, KeyedDataMarker, MaybeSendSync,
+    DataError, DataErrorKind, DataKey, DataLocale, DataProvider, DataRequest, DataRequestMetadata,
+    DataResponse, KeyedDataMarker, MaybeSendSync,
 };
+use icu_provider_adapters::fallback::LocaleFallbackProvider;
+use icu_provider_blob::BlobDataProvider;
+use icu_timezone::{MetazoneCalculator, TimeZoneError, TimeZoneIdMapper};
 use serde::Deserialize;
 use thiserror::Error;
 use yoke::{trait_hack::YokeTraitHack, Yokeable};
@@ -33,8 +43,40 @@ pub enum IcuError {
     /// Failed to create the case mapping tools.
     #[error("could not construct the case mapping tools")]
     CaseMap(#[from] DataError),
+    /// Failed to create the time zone tools.
+    #[error("could not construct the time zone tools: {0}")]
+    TimeZone(TimeZoneError),
+    /// Failed to read a data blob.
+    #[error("could not read the data blob")]
+    Io(#[from] std::io::Error),
+    /// The provided data is not a valid ICU4X data blob.
+    #[error("invalid data blob: {0}")]
+    InvalidBlob(DataError),
+    /// The provided data blob doesn't contain some of the keys required by the engine.
+    #[error("the data blob is missing the required keys: {}", display_keys(.0))]
+    MissingKeys(Vec<DataKey>),
+}
+
+/// Formats a list of data keys as a comma separated list of their paths.
+fn display_keys(keys: &[DataKey]) -> String {
+    keys.iter()
+        .map(|key| key.path().get())
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
+/// The data keys that must be present in a data blob loaded at runtime.
+///
+/// These are required to construct the locale canonicalization tools and the locale fallback
+/// algorithm, which are used by all services of `Intl`.
+const REQUIRED_BLOB_KEYS: [DataKey; 5] = [
+    AliasesV2Marker::KEY,
+    LikelySubtagsForLanguageV1Marker::KEY,
+    LikelySubtagsForScriptRegionV1Marker::KEY,
+    LocaleFallbackLikelySubtagsV1Marker::KEY,
+    LocaleFallbackParentsV1Marker::KEY,
+];
+
 impl From<IcuError> for JsNativeError {
     fn from(value: IcuError) -> Self {
         JsNativeError::typ().with_message(value.to_string())
@@ -54,6 +96,8 @@ pub(crate) struct IntlProvider {
     locale_expander: OnceCell<LocaleExpander>,
     string_normalizers: OnceCell<StringNormalizers>,
     case_mapper: OnceCell<CaseMapper>,
+    time_zone_id_mapper: OnceCell<TimeZoneIdMapper>,
+    metazone_calculator: OnceCell<MetazoneCalculator>,
 }
 
 impl<M> DataProvider<M> for IntlProvider
@@ -77,6 +121,8 @@ impl Debug for IntlProvider {
             .field("locale_expander", &self.locale_expander)
             .field("string_normalizers", &self.string_normalizers)
             .field("string_normalizercase_mapper", &self.case_mapper)
+            .field("time_zone_id_mapper", &self.time_zone_id_mapper)
+            .field("metazone_calculator", &self.metazone_calculator)
             .finish_non_exhaustive()
     }
 }
@@ -96,6 +142,8 @@ impl IntlProvider {
             locale_expander: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
+            time_zone_id_mapper: OnceCell::new(),
+            metazone_calculator: OnceCell::new(),
             inner_provider: ErasedProvider::Buffer(Box::new(provider)),
         }
     }
@@ -114,10 +162,51 @@ impl IntlProvider {
             locale_expander: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
+            time_zone_id_mapper: OnceCell::new(),
+            metazone_calculator: OnceCell::new(),
             inner_provider: ErasedProvider::Any(Box::new(provider)),
         }
     }
 
+    /// Creates a new [`IntlProvider`] from an ICU4X data blob loaded at runtime.
+    ///
+    /// The blob is wrapped with a [`LocaleFallbackProvider`], the same as the bundled data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob is invalid or if it doesn't contain the keys in
+    /// [`REQUIRED_BLOB_KEYS`].
+    pub(crate) fn try_new_with_blob(blob: Box<[u8]>) -> Result<IntlProvider, IcuError> {
+        let _timer = Profiler::global().start_event("ICU::try_new_with_blob", "ICU");
+        let provider = BlobDataProvider::try_new_from_blob(blob).map_err(IcuError::InvalidBlob)?;
+
+        // A key is only missing if the blob has no data for it at all; missing `und` data is fine.
+        let missing = REQUIRED_BLOB_KEYS
+            .into_iter()
+            .filter(|key| {
+                let request = DataRequest {
+                    locale: &DataLocale::default(),
+                    metadata: DataRequestMetadata::default(),
+                };
+                matches!(
+                    provider.load_buffer(*key, request),
+                    Err(DataError {
+                        kind: DataErrorKind::MissingDataKey,
+                        ..
+                    })
+                )
+            })
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(IcuError::MissingKeys(missing));
+        }
+
+        let provider = LocaleFallbackProvider::try_new_with_buffer_provider(provider)
+            .map_err(IcuError::InvalidBlob)?;
+
+        Ok(Self::try_new_with_buffer_provider(provider))
+    }
+
     /// Gets the [`LocaleCanonicalizer`] tool.
     pub(crate) fn locale_canonicalizer(&self) -> Result<&LocaleCanonicalizer, IcuError> {
         if let Some(lc) = self.locale_canonicalizer.get() {
@@ -176,8 +265,92 @@ impl IntlProvider {
         Ok(self.case_mapper.get_or_init(|| cm))
     }
 
+    /// Gets the [`TimeZoneIdMapper`] tool.
+    pub(crate) fn time_zone_id_mapper(&self) -> Result<&TimeZoneIdMapper, IcuError> {
+        if let Some(tzm) = self.time_zone_id_mapper.get() {
+            return Ok(tzm);
+        }
+        let tzm = match &self.inner_provider {
+            ErasedProvider::Any(a) => TimeZoneIdMapper::try_new_with_any_provider(a),
+            ErasedProvider::Buffer(b) => TimeZoneIdMapper::try_new_with_buffer_provider(b),
+        }
+        .map_err(|e| IcuError::TimeZone(e.into()))?;
+        Ok(self.time_zone_id_mapper.get_or_init(|| tzm))
+    }
+
+    /// Gets the [`MetazoneCalculator`] tool.
+    pub(crate) fn metazone_calculator(&self) -> Result<&MetazoneCalculator, IcuError> {
+        if let Some(mc) = self.metazone_calculator.get() {
+            return Ok(mc);
+        }
+        let mc = match &self.inner_provider {
+            ErasedProvider::Any(a) => MetazoneCalculator::try_new_with_any_provider(a),
+            ErasedProvider::Buffer(b) => MetazoneCalculator::try_new_with_buffer_provider(b),
+        }
+        .map_err(IcuError::TimeZone)?;
+        Ok(self.metazone_calculator.get_or_init(|| mc))
+    }
+
     /// Gets the inner erased provider.
     pub(crate) fn erased_provider(&self) -> &ErasedProvider {
         &self.inner_provider
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use icu_locid::langid;
+
+    use super::{IcuError, IntlProvider, REQUIRED_BLOB_KEYS};
+    use crate::{js_str, run_test_actions_with, Context, TestAction};
+
+    /// A blob with the data of `icu_locid_transform`, which contains all the required keys.
+    const LOCID_TRANSFORM_BLOB: &[u8] =
+        include_bytes!("../../../icu_provider/data/icu_locid_transform.postcard");
+
+    /// A valid blob without any of the required keys.
+    const LIST_BLOB: &[u8] = include_bytes!("../../../icu_provider/data/icu_list.postcard");
+
+    #[test]
+    fn invalid_blob() {
+        assert!(matches!(
+            IntlProvider::try_new_with_blob(Box::new(*b"not a data blob")),
+            Err(IcuError::InvalidBlob(_))
+        ));
+    }
+
+    #[test]
+    fn blob_missing_keys() {
+        let Err(IcuError::MissingKeys(keys)) = IntlProvider::try_new_with_blob(LIST_BLOB.into())
+        else {
+            panic!("a blob without the locale data must be rejected");
+        };
+        assert_eq!(keys, REQUIRED_BLOB_KEYS);
+    }
+
+    #[test]
+    fn blob_load() {
+        let provider = IntlProvider::try_new_with_blob(LOCID_TRANSFORM_BLOB.into())
+            .expect("the blob must contain the required keys");
+
+        let mut id = langid!("zh-TW");
+        provider
+            .locale_expander()
+            .expect("the blob must contain the likely subtags")
+            .maximize(&mut id);
+        assert_eq!(id, langid!("zh-Hant-TW"));
+
+        let context = &mut Context::builder()
+            .icu_blob(LOCID_TRANSFORM_BLOB)
+            .expect("the blob must contain the required keys")
+            .build()
+            .expect("the context must be built with the blob");
+        run_test_actions_with(
+            [TestAction::assert_eq(
+                "Intl.getCanonicalLocales('IW-il').join()",
+                js_str!("he-IL"),
+            )],
+            context,
+        );
+    }
+}
@@ -15,13 +15,17 @@ use temporal_rs::tzdb::FsTzdbProvider;
 
 use crate::vm::RuntimeLimits;
 use crate::{
-    builtins,
+    builtins::{
+        self,
+        weak::{FinalizationRegistry, RegistryHandle},
+    },
     class::{Class, ClassBuilder},
+    error::StackFrame,
     job::{JobQueue, NativeJob, SimpleJobQueue},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
-    object::{shape::RootShape, FunctionObjectBuilder, JsObject},
+    object::{shape::RootShape, ErasedVTableObject, FunctionObjectBuilder, JsObject},
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
@@ -100,6 +104,12 @@ pub struct Context {
 
     pub(crate) kept_alive: Vec<JsObject>,
 
+    /// Handles to all the `FinalizationRegistry` objects created by this context.
+    pub(crate) finalization_registries: Vec<RegistryHandle>,
+
+    /// Stack traces captured when the `Error` objects of this context were created.
+    pub(crate) error_stack_traces: boa_gc::WeakMap<ErasedVTableObject, Box<[StackFrame]>>,
+
     can_block: bool,
 
     #[cfg(feature = "temporal")]
@@ -474,10 +484,18 @@ impl Context {
     }
 
     /// Runs all the jobs in the job queue.
+    ///
+    /// This also enqueues the cleanup jobs of any `FinalizationRegistry` that had some of its
+    /// targets collected, and runs the job queue again until no more cleanup jobs are enqueued.
     #[inline]
     pub fn run_jobs(&mut self) {
-        self.job_queue().run_jobs(self);
-        self.clear_kept_objects();
+        loop {
+            self.job_queue().run_jobs(self);
+            self.clear_kept_objects();
+            if !FinalizationRegistry::enqueue_cleanup_jobs(self) {
+                break;
+            }
+        }
     }
 
     /// Asynchronously runs all the jobs in the job queue.
@@ -489,8 +507,13 @@ impl Context {
     /// provide a custom implementor of `JobQueue` to the context.
     #[allow(clippy::future_not_send)]
     pub async fn run_jobs_async(&mut self) {
-        self.job_queue().run_jobs_async(self).await;
-        self.clear_kept_objects();
+        loop {
+            self.job_queue().run_jobs_async(self).await;
+            self.clear_kept_objects();
+            if !FinalizationRegistry::enqueue_cleanup_jobs(self) {
+                break;
+            }
+        }
     }
 
     /// Abstract operation [`ClearKeptObjects`][clear].
@@ -973,6 +996,46 @@ impl ContextBuilder {
         Ok(self)
     }
 
+    /// Provides an ICU4X data blob to the [`Context`], to be used as its [`BufferProvider`].
+    ///
+    /// This function is only available if the `intl` feature is enabled.
+    ///
+    /// This allows shipping the locale data separately from the engine. The blob must be
+    /// exported using the `BlobExporter` of `icu_datagen`; `tools/gen-icu4x-data` can generate
+    /// blobs for a custom subset of locales. Locale fallback is handled by the engine, so the
+    /// data should be generated with runtime fallback.
+    ///
+    /// # Errors
+    ///
+    /// This returns `Err` if `blob` is not a valid ICU4X data blob, or if it doesn't contain the
+    /// data keys required to construct a [`LocaleCanonicalizer`], a [`LocaleExpander`] and the
+    /// locale fallback algorithm. The error lists the missing keys.
+    ///
+    /// [`LocaleCanonicalizer`]: icu_locid_transform::LocaleCanonicalizer
+    /// [`LocaleExpander`]: icu_locid_transform::LocaleExpander
+    /// [`BufferProvider`]: icu_provider::BufferProvider
+    #[cfg(feature = "intl")]
+    pub fn icu_blob(mut self, blob: impl Into<Box<[u8]>>) -> Result<Self, IcuError> {
+        self.icu = Some(icu::IntlProvider::try_new_with_blob(blob.into())?);
+        Ok(self)
+    }
+
+    /// Reads an ICU4X data blob from the file at `path`, and provides it to the [`Context`].
+    ///
+    /// This function is only available if the `intl` feature is enabled.
+    ///
+    /// See [`ContextBuilder::icu_blob`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// This returns `Err` if the file cannot be read, or in the same cases as
+    /// [`ContextBuilder::icu_blob`].
+    #[cfg(feature = "intl")]
+    pub fn icu_blob_file<P: AsRef<Path>>(self, path: P) -> Result<Self, IcuError> {
+        let blob = std::fs::read(path)?;
+        self.icu_blob(blob)
+    }
+
     /// Provides an [`AnyProvider`] data provider to the [`Context`].
     ///
     /// This function is only available if the `intl` feature is enabled.
@@ -1118,6 +1181,8 @@ impl ContextBuilder {
             #[cfg(feature = "fuzz")]
             instructions_remaining: self.instructions_remaining,
             kept_alive: Vec::new(),
+            finalization_registries: Vec::new(),
+            error_stack_traces: boa_gc::WeakMap::new(),
             host_hooks,
             job_queue,
             module_loader,
@@ -6,10 +6,16 @@ use crate::{
     object::JsObject,
     property::PropertyDescriptor,
     realm::Realm,
+    vm::Vm,
     Context, JsString, JsValue,
 };
+use boa_ast::Position;
 use boa_gc::{custom_trace, Finalize, Trace};
-use std::{borrow::Cow, error, fmt};
+use std::{
+    borrow::Cow,
+    error, fmt,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 
 /// Create an error object from a value or string literal. Optionally the
@@ -21,9 +27,9 @@ use thiserror::Error;
 ///
 /// # Native Errors
 ///
-/// The only native error that is not buildable using this macro is
-/// `AggregateError`, which requires multiple error objects available at
-/// construction.
+/// The only native errors that are not buildable using this macro are
+/// `AggregateError` and `SuppressedError`, which require other error objects
+/// available at construction.
 ///
 /// [`InternalError`][mdn] is non-standard and unsupported in Boa.
 ///
@@ -358,13 +364,15 @@ impl JsError {
     /// In other words, for this conversion to succeed:
     /// - `message` **MUST** be a `JsString` value.
     /// - `errors` (in the case of `AggregateError`s) **MUST** be an `Array` object.
+    /// - `error` and `suppressed` (in the case of `SuppressedError`s) **MUST** be present.
     ///
     /// # Note 2
     ///
     /// This operation should be considered a lossy conversion, since it
     /// won't store any additional properties of the opaque
-    /// error, other than `message`, `cause` and `errors` (in the case of
-    /// `AggregateError`s). If you cannot affort a lossy conversion, clone
+    /// error, other than `message`, `cause`, `errors` (in the case of
+    /// `AggregateError`s) and `error` and `suppressed` (in the case of
+    /// `SuppressedError`s). If you cannot affort a lossy conversion, clone
     /// the object before calling [`from_opaque`][JsError::from_opaque]
     /// to preserve its original properties.
     ///
@@ -394,6 +402,7 @@ impl JsError {
                 let error = *obj
                     .downcast_ref::<Error>()
                     .ok_or_else(|| TryNativeError::NotAnErrorObject(val.clone()))?;
+                let stack_trace = Error::stack_trace(obj, context);
 
                 let try_get_property = |key: JsString, name, context: &mut Context| {
                     obj.try_get(key, context)
@@ -459,6 +468,18 @@ impl JsError {
 
                         JsNativeErrorKind::Aggregate(error_list)
                     }
+                    Error::Suppressed => {
+                        let error = try_get_property(js_string!("error"), "error", context)?
+                            .ok_or(TryNativeError::InvalidPropertyType("error"))?;
+                        let suppressed =
+                            try_get_property(js_string!("suppressed"), "suppressed", context)?
+                                .ok_or(TryNativeError::InvalidPropertyType("suppressed"))?;
+
+                        JsNativeErrorKind::Suppressed {
+                            error: Box::new(Self::from_opaque(error)),
+                            suppressed: Box::new(Self::from_opaque(suppressed)),
+                        }
+                    }
                 };
 
                 let realm = try_get_property(js_string!("constructor"), "constructor", context)?
@@ -473,6 +494,7 @@ impl JsError {
                     message,
                     cause: cause.map(|v| Box::new(Self::from_opaque(v))),
                     realm: Some(realm),
+                    stack_trace: Some(stack_trace),
                 })
             }
         }
@@ -579,6 +601,12 @@ impl JsError {
                     .map(|err| err.into_erased(context))
                     .collect(),
             ),
+            JsNativeErrorKind::Suppressed { error, suppressed } => {
+                JsErasedNativeErrorKind::Suppressed {
+                    error: Box::new(error.into_erased(context)),
+                    suppressed: Box::new(suppressed.into_erased(context)),
+                }
+            }
             JsNativeErrorKind::Error => JsErasedNativeErrorKind::Error,
             JsNativeErrorKind::Eval => JsErasedNativeErrorKind::Eval,
             JsNativeErrorKind::Range => JsErasedNativeErrorKind::Range,
@@ -616,6 +644,54 @@ impl JsError {
         self
     }
 
+    /// Injects the stack trace of the current execution on the `stack_trace` field of a native
+    /// error.
+    ///
+    /// This is a no-op if the error is not native or if the stack trace of the error was already
+    /// captured.
+    pub(crate) fn inject_stack_trace(mut self, vm: &Vm) -> Self {
+        match &mut self.inner {
+            Repr::Native(err) if err.stack_trace.is_none() => {
+                err.stack_trace = Some(vm.stack_trace().into());
+            }
+            _ => {}
+        }
+        self
+    }
+
+    /// Gets the stack trace captured when this error was created or thrown, starting from the
+    /// innermost frame.
+    ///
+    /// For opaque errors, this is the stack trace captured when the `Error` object was created,
+    /// and is empty if the error is not an `Error` object.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{Context, Source};
+    /// let context = &mut Context::default();
+    ///
+    /// let error = context
+    ///     .eval(Source::from_bytes("function f() { throw new Error('oops'); }\nf();"))
+    ///     .unwrap_err();
+    /// let stack_trace = error.stack_trace(context);
+    ///
+    /// assert_eq!(stack_trace[0].function_name(), "f");
+    /// assert_eq!(stack_trace[0].position().unwrap().line_number(), 1);
+    /// assert_eq!(stack_trace[1].position().unwrap().line_number(), 2);
+    /// ```
+    #[must_use]
+    pub fn stack_trace(&self, context: &Context) -> Vec<StackFrame> {
+        match &self.inner {
+            Repr::Native(err) => err.stack_trace().to_vec(),
+            Repr::Opaque(val) => val
+                .as_object()
+                .filter(|obj| obj.is::<Error>())
+                .map(|obj| Error::stack_trace(obj, context).into_vec())
+                .unwrap_or_default(),
+        }
+    }
+
     /// Is the [`JsError`] catchable in JavaScript.
     #[inline]
     pub(crate) fn is_catchable(&self) -> bool {
@@ -667,7 +743,7 @@ impl fmt::Display for JsError {
 ///
 /// assert_eq!(native_error.message(), "cannot decode uri");
 /// ```
-#[derive(Clone, Finalize, Error, PartialEq, Eq)]
+#[derive(Clone, Finalize, Error)]
 pub struct JsNativeError {
     /// The kind of native error (e.g. `TypeError`, `SyntaxError`, etc.)
     pub kind: JsNativeErrorKind,
@@ -675,8 +751,21 @@ pub struct JsNativeError {
     #[source]
     cause: Option<Box<JsError>>,
     realm: Option<Realm>,
+    stack_trace: Option<Box<[StackFrame]>>,
 }
 
+// The stack trace is only used for diagnostics, so it doesn't take part in equality.
+impl PartialEq for JsNativeError {
+    fn eq(&self, other: &Self) -> bool {
+        self.kind == other.kind
+            && self.message == other.message
+            && self.cause == other.cause
+            && self.realm == other.realm
+    }
+}
+
+impl Eq for JsNativeError {}
+
 impl fmt::Display for JsNativeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.kind)?;
@@ -743,6 +832,7 @@ impl JsNativeError {
             message,
             cause,
             realm: None,
+            stack_trace: None,
         }
     }
 
@@ -781,6 +871,40 @@ impl JsNativeError {
         matches!(self.kind, JsNativeErrorKind::Aggregate(_))
     }
 
+    /// Creates a new `JsNativeError` of kind `SuppressedError` from an `error` and the
+    /// `suppressed` error it hid, with empty `message` and undefined `cause`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::{JsNativeError, JsNativeErrorKind};
+    /// let error = JsNativeError::suppressed(
+    ///     JsNativeError::typ().into(),
+    ///     JsNativeError::range().into(),
+    /// );
+    ///
+    /// assert!(matches!(error.kind, JsNativeErrorKind::Suppressed { .. }));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn suppressed(error: JsError, suppressed: JsError) -> Self {
+        Self::new(
+            JsNativeErrorKind::Suppressed {
+                error: Box::new(error),
+                suppressed: Box::new(suppressed),
+            },
+            Cow::Borrowed(""),
+            None,
+        )
+    }
+
+    /// Check if it's a [`JsNativeErrorKind::Suppressed`].
+    #[must_use]
+    #[inline]
+    pub const fn is_suppressed(&self) -> bool {
+        matches!(self.kind, JsNativeErrorKind::Suppressed { .. })
+    }
+
     /// Creates a new `JsNativeError` of kind `Error`, with empty `message` and undefined `cause`.
     ///
     /// # Examples
@@ -1060,6 +1184,25 @@ impl JsNativeError {
         self.cause.as_deref()
     }
 
+    /// Gets the stack trace captured when this error was thrown, starting from the innermost
+    /// frame.
+    ///
+    /// This is empty if the error hasn't been thrown from JavaScript code yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use boa_engine::JsNativeError;
+    /// let error = JsNativeError::range().with_message("number too large");
+    ///
+    /// assert!(error.stack_trace().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn stack_trace(&self) -> &[StackFrame] {
+        self.stack_trace.as_deref().unwrap_or_default()
+    }
+
     /// Converts this native error to its opaque representation as a [`JsObject`].
     ///
     /// # Examples
@@ -1089,6 +1232,7 @@ impl JsNativeError {
             message,
             cause,
             realm,
+            stack_trace,
         } = self;
         let constructors = realm.as_ref().map_or_else(
             || context.intrinsics().constructors(),
@@ -1098,6 +1242,10 @@ impl JsNativeError {
             JsNativeErrorKind::Aggregate(_) => {
                 (constructors.aggregate_error().prototype(), Error::Aggregate)
             }
+            JsNativeErrorKind::Suppressed { .. } => (
+                constructors.suppressed_error().prototype(),
+                Error::Suppressed,
+            ),
             JsNativeErrorKind::Error => (constructors.error().prototype(), Error::Error),
             JsNativeErrorKind::Eval => (constructors.eval_error().prototype(), Error::Eval),
             JsNativeErrorKind::Range => (constructors.range_error().prototype(), Error::Range),
@@ -1121,6 +1269,13 @@ impl JsNativeError {
         let o =
             JsObject::from_proto_and_data_with_shared_shape(context.root_shape(), prototype, tag);
 
+        // Errors that were never thrown from JavaScript code capture the stack trace of their
+        // creation instead.
+        match stack_trace {
+            Some(stack_trace) => Error::set_stack_trace(&o, stack_trace.clone(), context),
+            None => Error::capture_stack_trace(&o, context),
+        }
+
         o.create_non_enumerable_data_property_or_throw(
             js_string!("message"),
             js_string!(message.as_ref()),
@@ -1152,6 +1307,17 @@ impl JsNativeError {
             )
             .expect("The spec guarantees this succeeds for a newly created object ");
         }
+
+        if let JsNativeErrorKind::Suppressed { error, suppressed } = kind {
+            let error = error.to_opaque(context);
+            let suppressed = suppressed.to_opaque(context);
+            o.create_non_enumerable_data_property_or_throw(js_string!("error"), error, context);
+            o.create_non_enumerable_data_property_or_throw(
+                js_string!("suppressed"),
+                suppressed,
+                context,
+            );
+        }
         o
     }
 
@@ -1194,6 +1360,21 @@ pub enum JsNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsError>),
+    /// An error that was thrown while another error was being handled, hiding the original
+    /// error.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsError>,
+        /// The error that was hidden by `error`.
+        suppressed: Box<JsError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1278,6 +1459,10 @@ unsafe impl Trace for JsNativeErrorKind {
         mark,
         match &this {
             Self::Aggregate(errors) => mark(errors),
+            Self::Suppressed { error, suppressed } => {
+                mark(error);
+                mark(suppressed);
+            }
             Self::Error
             | Self::Eval
             | Self::Range
@@ -1298,6 +1483,7 @@ impl JsNativeErrorKind {
     pub(crate) fn is_catchable(&self) -> bool {
         match self {
             Self::Aggregate(_)
+            | Self::Suppressed { .. }
             | Self::Error
             | Self::Eval
             | Self::Range
@@ -1317,6 +1503,7 @@ impl PartialEq<Error> for JsNativeErrorKind {
         matches!(
             (self, other),
             (Self::Aggregate(_), Error::Aggregate)
+                | (Self::Suppressed { .. }, Error::Suppressed)
                 | (Self::Error, Error::Error)
                 | (Self::Eval, Error::Eval)
                 | (Self::Range, Error::Range)
@@ -1332,6 +1519,7 @@ impl fmt::Display for JsNativeErrorKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Aggregate(_) => "AggregateError",
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1443,6 +1631,21 @@ pub enum JsErasedNativeErrorKind {
     /// [spec]: https://tc39.es/ecma262/#sec-aggregate-error-objects
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AggregateError
     Aggregate(Vec<JsErasedError>),
+    /// An error that was thrown while another error was being handled, hiding the original
+    /// error.
+    ///
+    /// More information:
+    /// - [Explicit Resource Management proposal][spec]
+    /// - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-objects
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/SuppressedError
+    Suppressed {
+        /// The error that was thrown last.
+        error: Box<JsErasedError>,
+        /// The error that was hidden by `error`.
+        suppressed: Box<JsErasedError>,
+    },
     /// A generic error. Commonly used as the base for custom exceptions.
     ///
     /// More information:
@@ -1521,6 +1724,7 @@ impl fmt::Display for JsErasedNativeErrorKind {
             Self::Aggregate(errors) => {
                 return write!(f, "AggregateError(error count: {})", errors.len());
             }
+            Self::Suppressed { .. } => "SuppressedError",
             Self::Error => "Error",
             Self::Eval => "EvalError",
             Self::Range => "RangeError",
@@ -1533,3 +1737,93 @@ impl fmt::Display for JsErasedNativeErrorKind {
         .fmt(f)
     }
 }
+
+/// A frame of the stack trace captured when an error is created or thrown.
+///
+/// Its [`Display`][fmt::Display] implementation follows the format used by V8 for the lines
+/// of the `stack` property of `Error` objects, e.g. `f (script.js:2:5)`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use boa_engine::{Context, Source};
+/// let context = &mut Context::default();
+///
+/// let error = context
+///     .eval(Source::from_bytes("function f() { null.x; }\nf();"))
+///     .unwrap_err();
+/// let frames = error.stack_trace(context);
+///
+/// assert_eq!(frames.len(), 2);
+/// assert_eq!(frames[0].function_name(), "f");
+/// assert_eq!(frames[1].to_string(), "<anonymous>:2:2");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize)]
+// Safety: `StackFrame` doesn't contain any traceable types.
+#[boa_gc(unsafe_empty_trace)]
+pub struct StackFrame {
+    function_name: JsString,
+    path: Option<PathBuf>,
+    position: Option<Position>,
+}
+
+impl StackFrame {
+    /// Creates a new `StackFrame`.
+    pub(crate) const fn new(
+        function_name: JsString,
+        path: Option<PathBuf>,
+        position: Option<Position>,
+    ) -> Self {
+        Self {
+            function_name,
+            path,
+            position,
+        }
+    }
+
+    /// Gets the name of the function of this frame.
+    ///
+    /// This is empty for anonymous functions and for the top-level code of scripts and modules.
+    #[must_use]
+    pub const fn function_name(&self) -> &JsString {
+        &self.function_name
+    }
+
+    /// Gets the path of the script or module of this frame, if it was created from a file or
+    /// assigned.
+    #[must_use]
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Gets the position in the source code of the instruction that was executing on this
+    /// frame, if it is known.
+    #[must_use]
+    pub const fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let has_name = !self.function_name.is_empty();
+        if has_name {
+            write!(f, "{} (", self.function_name.to_std_string_escaped())?;
+        }
+
+        match &self.path {
+            Some(path) => write!(f, "{}", path.display())?,
+            None => f.write_str("<anonymous>")?,
+        }
+
+        if let Some(position) = self.position {
+            write!(f, ":{position}")?;
+        }
+
+        if has_name {
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
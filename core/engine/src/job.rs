@@ -12,16 +12,31 @@
 //! - [`IdleJobQueue`], which is a queue that does nothing, and the default queue if no queue is
 //!   provided. Useful for hosts that want to disable promises.
 //! - [`SimpleJobQueue`], which is a simple FIFO queue that runs all jobs to completion, bailing
-//!   on the first error encountered.
+//!   on the first error encountered. Futures and timeout jobs are also driven to completion by
+//!   blocking the current thread until they're ready.
+//!
+//! [`PendingJobs`] keeps the futures and timeout jobs of a queue until they're ready to run, and
+//! can be used to implement custom job queues.
 //!
 //! [Job]: https://tc39.es/ecma262/#sec-jobs
 //! [JobCallback]: https://tc39.es/ecma262/#sec-jobcallback-records
 
-use std::{cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
+use std::{
+    cell::{Cell, RefCell},
+    cmp::{Ordering, Reverse},
+    collections::{BinaryHeap, VecDeque},
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{self, Poll, Wake, Waker},
+    thread::Thread,
+};
 
 use crate::{
     object::{JsFunction, NativeObject},
     realm::Realm,
+    sys::time::{Duration, Instant},
     Context, JsResult, JsValue,
 };
 use boa_gc::{Finalize, Trace};
@@ -135,19 +150,29 @@ impl NativeJob {
 
 /// [`JobCallback`][spec] records.
 ///
+/// Records created with [`JobCallback::new_cloneable`] can be cloned with
+/// [`JobCallback::try_clone`], which allows calling the same record more than once with
+/// [`HostHooks::call_job_callback`], as done by `FinalizationRegistry`.
+///
 /// [spec]: https://tc39.es/ecma262/#sec-jobcallback-records
+/// [`HostHooks::call_job_callback`]: crate::context::HostHooks::call_job_callback
 #[derive(Trace, Finalize)]
 pub struct JobCallback {
     callback: JsFunction,
     host_defined: Box<dyn NativeObject>,
+    #[unsafe_ignore_trace]
+    clone_host_defined: Option<CloneHostDefined>,
 }
 
+/// Clones the host defined field of a [`JobCallback`].
+type CloneHostDefined = fn(&dyn NativeObject) -> Box<dyn NativeObject>;
+
 impl Debug for JobCallback {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("JobCallback")
             .field("callback", &self.callback)
             .field("host_defined", &"dyn NativeObject")
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -158,9 +183,37 @@ impl JobCallback {
         Self {
             callback,
             host_defined: Box::new(host_defined),
+            clone_host_defined: None,
+        }
+    }
+
+    /// Creates a new `JobCallback` that can be cloned with [`JobCallback::try_clone`].
+    #[inline]
+    pub fn new_cloneable<T: NativeObject + Clone>(callback: JsFunction, host_defined: T) -> Self {
+        Self {
+            callback,
+            host_defined: Box::new(host_defined),
+            clone_host_defined: Some(|host_defined| {
+                let host_defined = host_defined
+                    .downcast_ref::<T>()
+                    .expect("the host defined field must keep the type it was created with");
+                Box::new(host_defined.clone())
+            }),
         }
     }
 
+    /// Clones the `JobCallback`, or returns `None` if it was not created with
+    /// [`JobCallback::new_cloneable`].
+    #[must_use]
+    pub fn try_clone(&self) -> Option<Self> {
+        let clone_host_defined = self.clone_host_defined?;
+        Some(Self {
+            callback: self.callback.clone(),
+            host_defined: clone_host_defined(&*self.host_defined),
+            clone_host_defined: Some(clone_host_defined),
+        })
+    }
+
     /// Gets the inner callback of the job.
     #[inline]
     #[must_use]
@@ -226,6 +279,34 @@ pub trait JobQueue {
     /// or `catch` handlers, even if `future` was already completed.
     fn enqueue_future_job(&self, future: FutureJob, context: &mut Context);
 
+    /// [`HostEnqueueTimeoutJob ( timeoutJob, realm, milliseconds )`][spec].
+    ///
+    /// Enqueues a [`NativeJob`] that must be run after at least `timeout` has elapsed.
+    ///
+    /// # Requirements
+    ///
+    /// Per the [spec]:
+    /// > An implementation of `HostEnqueueTimeoutJob` must conform to the requirements in [9.5][Jobs].
+    ///
+    /// By default, enqueues a future with [`JobQueue::enqueue_future_job`] that returns `job` once
+    /// `timeout` has elapsed. The future wakes its waker from a separate thread that sleeps until
+    /// the deadline, so queues that can wait for a deadline themselves should override this
+    /// method to avoid spawning a thread per timeout job.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-hostenqueuetimeoutjob
+    /// [Jobs]: https://tc39.es/ecma262/#sec-jobs
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, context: &mut Context) {
+        // A deadline that cannot be represented will never be reached.
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            let delay = Delay {
+                deadline,
+                job: Some(job),
+                waker: None,
+            };
+            self.enqueue_future_job(Box::pin(delay), context);
+        }
+    }
+
     /// Asynchronously runs all jobs in the queue.
     ///
     /// Running a job could enqueue more jobs in the queue. The implementor of the trait
@@ -272,6 +353,146 @@ impl JobQueue for IdleJobQueue {
     fn run_jobs(&self, _: &mut Context) {}
 
     fn enqueue_future_job(&self, _: FutureJob, _: &mut Context) {}
+
+    fn enqueue_timeout_job(&self, _: NativeJob, _: Duration, _: &mut Context) {}
+}
+
+/// The futures and timeout jobs of a job queue that aren't ready to run yet.
+///
+/// This is a building block for implementing [`JobQueue`]s: it keeps the pending futures and
+/// the timeout jobs sorted by their deadline, and hands out the jobs that are ready to run.
+/// Deadlines can be any ordered type, like an [`Instant`] or the time elapsed since the start
+/// of a custom clock.
+///
+/// Timeout jobs with the same deadline are returned in the order they were enqueued.
+pub struct PendingJobs<T = Instant> {
+    futures: RefCell<Vec<FutureJob>>,
+    timeouts: RefCell<BinaryHeap<Reverse<Timeout<T>>>>,
+    next_seq: Cell<u64>,
+}
+
+/// A timeout job waiting in a [`PendingJobs`].
+struct Timeout<T> {
+    deadline: T,
+    /// Sequence number used to order timeouts with the same deadline.
+    seq: u64,
+    job: NativeJob,
+}
+
+impl<T: Ord> PartialEq for Timeout<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl<T: Ord> Eq for Timeout<T> {}
+
+impl<T: Ord> PartialOrd for Timeout<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Timeout<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.deadline
+            .cmp(&other.deadline)
+            .then(self.seq.cmp(&other.seq))
+    }
+}
+
+impl<T> Default for PendingJobs<T> {
+    fn default() -> Self {
+        Self {
+            futures: RefCell::default(),
+            timeouts: RefCell::default(),
+            next_seq: Cell::new(0),
+        }
+    }
+}
+
+impl<T> Debug for PendingJobs<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PendingJobs")
+            .field("futures", &self.futures.borrow().len())
+            .field("timeouts", &self.timeouts.borrow().len())
+            .field("next_seq", &self.next_seq.get())
+            .finish()
+    }
+}
+
+impl<T: Ord + Copy> PendingJobs<T> {
+    /// Creates an empty `PendingJobs`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a future that must be polled until it returns its job.
+    pub fn enqueue_future(&self, future: FutureJob) {
+        self.futures.borrow_mut().push(future);
+    }
+
+    /// Adds a job that must run once `deadline` has passed.
+    pub fn enqueue_timeout(&self, job: NativeJob, deadline: T) {
+        let seq = self.next_seq.get();
+        self.next_seq.set(seq + 1);
+        self.timeouts
+            .borrow_mut()
+            .push(Reverse(Timeout { deadline, seq, job }));
+    }
+
+    /// Polls all pending futures once, returning the jobs of the completed ones in the order
+    /// their futures were enqueued.
+    pub fn poll_futures(&self, cx: &mut task::Context<'_>) -> Vec<NativeJob> {
+        // Polling a future could enqueue more futures, so the list cannot be borrowed while
+        // polling.
+        let futures = std::mem::take(&mut *self.futures.borrow_mut());
+        let mut ready = Vec::new();
+        for mut future in futures {
+            match future.as_mut().poll(cx) {
+                Poll::Ready(job) => ready.push(job),
+                Poll::Pending => self.futures.borrow_mut().push(future),
+            }
+        }
+        ready
+    }
+
+    /// Removes the timeout job with the earliest deadline if that deadline is not after `now`.
+    pub fn pop_expired(&self, now: T) -> Option<NativeJob> {
+        let mut timeouts = self.timeouts.borrow_mut();
+        if timeouts.peek()?.0.deadline > now {
+            return None;
+        }
+        timeouts.pop().map(|Reverse(timeout)| timeout.job)
+    }
+
+    /// Returns the earliest deadline of the pending timeout jobs.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<T> {
+        self.timeouts
+            .borrow()
+            .peek()
+            .map(|Reverse(timeout)| timeout.deadline)
+    }
+
+    /// Returns `true` if there are futures that haven't completed yet.
+    #[must_use]
+    pub fn has_futures(&self) -> bool {
+        !self.futures.borrow().is_empty()
+    }
+
+    /// Returns the number of timeout jobs that haven't expired yet.
+    #[must_use]
+    pub fn pending_timeouts(&self) -> usize {
+        self.timeouts.borrow().len()
+    }
+
+    /// Drops all pending futures and timeout jobs.
+    pub fn clear(&self) {
+        self.futures.borrow_mut().clear();
+        self.timeouts.borrow_mut().clear();
+    }
 }
 
 /// A simple FIFO job queue that bails on the first error.
@@ -279,9 +500,15 @@ impl JobQueue for IdleJobQueue {
 /// This is the default job queue for the [`Context`], but it is mostly pretty limited for
 /// custom event queues.
 ///
+/// Futures and timeout jobs are driven by [`JobQueue::run_jobs`], which blocks the current thread
+/// until all futures have completed and all timeout jobs have run.
+///
 /// To disable running promise jobs on the engine, see [`IdleJobQueue`].
 #[derive(Default)]
-pub struct SimpleJobQueue(RefCell<VecDeque<NativeJob>>);
+pub struct SimpleJobQueue {
+    jobs: RefCell<VecDeque<NativeJob>>,
+    pending: PendingJobs,
+}
 
 impl Debug for SimpleJobQueue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -295,28 +522,125 @@ impl SimpleJobQueue {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the earliest deadline of the timeout jobs that haven't run yet.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.next_deadline()
+    }
+
+    /// Returns `true` if there are futures or timeout jobs that haven't run yet.
+    #[must_use]
+    pub fn has_pending_jobs(&self) -> bool {
+        self.pending.has_futures() || self.pending.pending_timeouts() > 0
+    }
 }
 
 impl JobQueue for SimpleJobQueue {
     fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
-        self.0.borrow_mut().push_back(job);
+        self.jobs.borrow_mut().push_back(job);
     }
 
     fn run_jobs(&self, context: &mut Context) {
-        // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
-        // dropped after calling `pop_front`.
-        let mut next_job = self.0.borrow_mut().pop_front();
-        while let Some(job) = next_job {
-            if job.call(context).is_err() {
-                self.0.borrow_mut().clear();
-                return;
-            };
-            next_job = self.0.borrow_mut().pop_front();
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = task::Context::from_waker(&waker);
+
+        loop {
+            // Yeah, I have no idea why Rust extends the lifetime of a `RefCell` that should be immediately
+            // dropped after calling `pop_front`.
+            let mut next_job = self.jobs.borrow_mut().pop_front();
+            while let Some(job) = next_job {
+                if job.call(context).is_err() {
+                    self.jobs.borrow_mut().clear();
+                    self.pending.clear();
+                    return;
+                }
+                next_job = self.jobs.borrow_mut().pop_front();
+            }
+
+            let mut ready = self.pending.poll_futures(&mut cx);
+            let now = Instant::now();
+            while let Some(job) = self.pending.pop_expired(now) {
+                ready.push(job);
+            }
+
+            if !ready.is_empty() {
+                self.jobs.borrow_mut().extend(ready);
+                continue;
+            }
+
+            // Nothing is ready to run, so block until either a future wakes us up or the next
+            // timeout job expires.
+            match self.pending.next_deadline() {
+                Some(deadline) => std::thread::park_timeout(deadline.duration_since(now)),
+                None if self.pending.has_futures() => std::thread::park(),
+                None => return,
+            }
+        }
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.pending.enqueue_future(future);
+    }
+
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, _: &mut Context) {
+        // A deadline that cannot be represented will never be reached.
+        if let Some(deadline) = Instant::now().checked_add(timeout) {
+            self.pending.enqueue_timeout(job, deadline);
         }
     }
+}
+
+/// A [`Waker`] that unparks the thread running the jobs of a [`SimpleJobQueue`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
 
-    fn enqueue_future_job(&self, future: FutureJob, context: &mut Context) {
-        let job = pollster::block_on(future);
-        self.enqueue_promise_job(job, context);
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A future that returns its job once its deadline has passed, used by the default
+/// implementation of [`JobQueue::enqueue_timeout_job`].
+struct Delay {
+    deadline: Instant,
+    job: Option<NativeJob>,
+    /// The waker of the last poll, shared with the timer thread once it has been spawned.
+    waker: Option<Arc<Mutex<Waker>>>,
+}
+
+impl Future for Delay {
+    type Output = NativeJob;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut task::Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let now = Instant::now();
+        if now < this.deadline {
+            if let Some(waker) = &this.waker {
+                // The timer thread is already running, so only update the waker it will wake.
+                waker
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone_from(cx.waker());
+            } else {
+                let waker = Arc::new(Mutex::new(cx.waker().clone()));
+                let timer_waker = waker.clone();
+                let timeout = this.deadline.duration_since(now);
+                std::thread::spawn(move || {
+                    std::thread::sleep(timeout);
+                    timer_waker
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .wake_by_ref();
+                });
+                this.waker = Some(waker);
+            }
+            return Poll::Pending;
+        }
+        Poll::Ready(this.job.take().expect("`Delay` polled after completion"))
     }
 }
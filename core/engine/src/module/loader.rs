@@ -3,9 +3,11 @@ use std::path::{Component, Path, PathBuf};
 use boa_profiler::Profiler;
 use rustc_hash::FxHashMap;
 
-use boa_gc::GcRefCell;
+use boa_gc::{Finalize, GcRefCell, Trace};
+use boa_interner::Interner;
 use boa_parser::Source;
 
+use crate::bytecompiler::ToJsString;
 use crate::script::Script;
 use crate::{
     js_string, object::JsObject, realm::Realm, vm::ActiveRunnable, Context, JsError, JsNativeError,
@@ -110,6 +112,163 @@ pub fn resolve_module_specifier(
     }
 }
 
+/// An import attribute of a [`ModuleRequest`].
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#importattribute-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ImportAttribute {
+    key: JsString,
+    value: JsString,
+}
+
+impl ImportAttribute {
+    /// Creates a new `ImportAttribute` from its key and value.
+    #[must_use]
+    pub const fn new(key: JsString, value: JsString) -> Self {
+        Self { key, value }
+    }
+
+    /// Gets the key of the import attribute.
+    #[must_use]
+    pub const fn key(&self) -> &JsString {
+        &self.key
+    }
+
+    /// Gets the value of the import attribute.
+    #[must_use]
+    pub const fn value(&self) -> &JsString {
+        &self.value
+    }
+}
+
+/// The type of a module, as requested by the `type` import attribute of a [`ModuleRequest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleType {
+    /// An ECMAScript source text module. This is the default when no `type` is requested.
+    JavaScript,
+    /// A [JSON module][spec] (`with { type: "json" }`).
+    ///
+    /// [spec]: https://tc39.es/proposal-json-modules/
+    Json,
+}
+
+/// A request to load a module, as defined by the [`ModuleRequest`][spec] record.
+///
+/// This contains the specifier of the requested module, together with the import attributes
+/// declared on its `with` clause, sorted by their keys.
+///
+/// [spec]: https://tc39.es/ecma262/#modulerequest-record
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Trace, Finalize)]
+pub struct ModuleRequest {
+    specifier: JsString,
+    attributes: Box<[ImportAttribute]>,
+}
+
+impl ModuleRequest {
+    /// Creates a new `ModuleRequest` from a specifier and its import attributes.
+    #[must_use]
+    pub fn new(specifier: JsString, attributes: impl Into<Box<[ImportAttribute]>>) -> Self {
+        let mut attributes = attributes.into();
+
+        // Sort attributes according to the lexicographic order of their [[Key]] field, treating
+        // the value of each such field as a sequence of UTF-16 code unit values.
+        attributes.sort_unstable_by(|a, b| a.key.cmp(&b.key));
+
+        Self {
+            specifier,
+            attributes,
+        }
+    }
+
+    /// Creates a new `ModuleRequest` from a specifier, without any import attributes.
+    #[must_use]
+    pub fn from_specifier(specifier: JsString) -> Self {
+        Self {
+            specifier,
+            attributes: Box::default(),
+        }
+    }
+
+    /// Creates a `ModuleRequest` from its AST representation.
+    pub(crate) fn from_ast(
+        request: &boa_ast::declaration::ModuleRequest,
+        interner: &Interner,
+    ) -> Self {
+        Self::new(
+            request.specifier().to_js_string(interner),
+            request
+                .attributes()
+                .iter()
+                .map(|attribute| {
+                    ImportAttribute::new(
+                        attribute.key().to_js_string(interner),
+                        attribute.value().to_js_string(interner),
+                    )
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Gets the specifier of the requested module.
+    #[must_use]
+    pub const fn specifier(&self) -> &JsString {
+        &self.specifier
+    }
+
+    /// Gets the import attributes of the request, sorted by their keys.
+    #[must_use]
+    pub fn attributes(&self) -> &[ImportAttribute] {
+        &self.attributes
+    }
+
+    /// Gets the value of the import attribute with the provided key, if it exists.
+    #[must_use]
+    pub fn get_attribute(&self, key: &str) -> Option<&JsString> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.key == key)
+            .map(ImportAttribute::value)
+    }
+
+    /// Gets the [`ModuleType`] requested by the `type` import attribute.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TypeError` if the requested `type` is not a supported module type.
+    pub fn module_type(&self) -> JsResult<ModuleType> {
+        match self.get_attribute("type") {
+            None => Ok(ModuleType::JavaScript),
+            Some(ty) if ty == "json" => Ok(ModuleType::Json),
+            Some(ty) => Err(JsNativeError::typ()
+                .with_message(format!(
+                    "unsupported module type `{}` for module `{}`",
+                    ty.to_std_string_escaped(),
+                    self.specifier.to_std_string_escaped()
+                ))
+                .into()),
+        }
+    }
+
+    /// Abstract operation [`AllImportAttributesSupported ( attributes )`][spec].
+    ///
+    /// The only import attribute supported by Boa is `type`.
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-AllImportAttributesSupported
+    pub(crate) fn unsupported_attribute(&self) -> Option<&JsString> {
+        // 1. Let supported be HostGetSupportedImportAttributes().
+        // 2. For each ImportAttribute Record attribute of attributes, do
+        //     a. If supported does not contain attribute.[[Key]], return false.
+        // 3. Return true.
+        self.attributes
+            .iter()
+            .map(ImportAttribute::key)
+            .find(|key| *key != "type")
+    }
+}
+
 /// The referrer from which a load request of a module originates.
 #[derive(Debug, Clone)]
 pub enum Referrer {
@@ -147,7 +306,7 @@ impl From<ActiveRunnable> for Referrer {
 /// This trait allows to customize the behaviour of the engine on module load requests and
 /// `import.meta` requests.
 pub trait ModuleLoader {
-    /// Host hook [`HostLoadImportedModule ( referrer, specifier, hostDefined, payload )`][spec].
+    /// Host hook [`HostLoadImportedModule ( referrer, moduleRequest, hostDefined, payload )`][spec].
     ///
     /// This hook allows to customize the module loading functionality of the engine. Technically,
     /// this should call the [`FinishLoadingImportedModule`][finish] operation, but this simpler API just provides
@@ -155,15 +314,17 @@ pub trait ModuleLoader {
     ///
     /// # Requirements
     ///
-    /// - The host environment must perform `FinishLoadingImportedModule(referrer, specifier, payload, result)`,
+    /// - The host environment must perform `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)`,
     ///   where result is either a normal completion containing the loaded Module Record or a throw
     ///   completion, either synchronously or asynchronously. This is equivalent to calling the `finish_load`
     ///   callback.
-    /// - If this operation is called multiple times with the same `(referrer, specifier)` pair and
-    ///   it performs FinishLoadingImportedModule(referrer, specifier, payload, result) where result
+    /// - If this operation is called multiple times with the same `(referrer, moduleRequest)` pair and
+    ///   it performs FinishLoadingImportedModule(referrer, moduleRequest, payload, result) where result
     ///   is a normal completion, then it must perform
-    ///   `FinishLoadingImportedModule(referrer, specifier, payload, result)` with the same result each
+    ///   `FinishLoadingImportedModule(referrer, moduleRequest, payload, result)` with the same result each
     ///   time.
+    /// - If `moduleRequest` has a `type` attribute, the loaded module must be of the requested
+    ///   type (see [`ModuleRequest::module_type`]), or the load must fail.
     /// - The operation must treat payload as an opaque value to be passed through to
     ///   `FinishLoadingImportedModule`. (can be ignored)
     ///
@@ -173,7 +334,7 @@ pub trait ModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     );
@@ -223,7 +384,7 @@ impl ModuleLoader for IdleModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        _specifier: JsString,
+        _request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
@@ -238,6 +399,8 @@ impl ModuleLoader for IdleModuleLoader {
 
 /// A simple module loader that loads modules relative to a root path.
 ///
+/// JSON modules are loaded if the `type: "json"` import attribute is provided.
+///
 /// # Note
 ///
 /// This loader only works by using the type methods [`SimpleModuleLoader::insert`] and
@@ -247,6 +410,7 @@ impl ModuleLoader for IdleModuleLoader {
 pub struct SimpleModuleLoader {
     root: PathBuf,
     module_map: GcRefCell<FxHashMap<PathBuf, Module>>,
+    json_map: GcRefCell<FxHashMap<PathBuf, Module>>,
 }
 
 impl SimpleModuleLoader {
@@ -267,6 +431,7 @@ impl SimpleModuleLoader {
         Ok(Self {
             root: absolute,
             module_map: GcRefCell::default(),
+            json_map: GcRefCell::default(),
         })
     }
 
@@ -287,30 +452,54 @@ impl ModuleLoader for SimpleModuleLoader {
     fn load_imported_module(
         &self,
         referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
         let result = (|| {
+            let specifier = request.specifier();
             let short_path = specifier.to_std_string_escaped();
             let path =
-                resolve_module_specifier(Some(&self.root), &specifier, referrer.path(), context)?;
-            if let Some(module) = self.get(&path) {
-                return Ok(module);
+                resolve_module_specifier(Some(&self.root), specifier, referrer.path(), context)?;
+
+            match request.module_type()? {
+                ModuleType::JavaScript => {
+                    if let Some(module) = self.get(&path) {
+                        return Ok(module);
+                    }
+
+                    let source = Source::from_filepath(&path).map_err(|err| {
+                        JsNativeError::typ()
+                            .with_message(format!("could not open file `{short_path}`"))
+                            .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                    })?;
+                    let module = Module::parse(source, None, context).map_err(|err| {
+                        JsNativeError::syntax()
+                            .with_message(format!("could not parse module `{short_path}`"))
+                            .with_cause(err)
+                    })?;
+                    self.insert(path, module.clone());
+                    Ok(module)
+                }
+                ModuleType::Json => {
+                    if let Some(module) = self.json_map.borrow().get(&path) {
+                        return Ok(module.clone());
+                    }
+
+                    let json = std::fs::read_to_string(&path).map_err(|err| {
+                        JsNativeError::typ()
+                            .with_message(format!("could not open file `{short_path}`"))
+                            .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
+                    })?;
+                    let module = Module::parse_json(js_string!(json), context).map_err(|err| {
+                        JsNativeError::syntax()
+                            .with_message(format!("could not parse JSON module `{short_path}`"))
+                            .with_cause(err)
+                    })?;
+                    self.json_map.borrow_mut().insert(path, module.clone());
+                    Ok(module)
+                }
             }
-
-            let source = Source::from_filepath(&path).map_err(|err| {
-                JsNativeError::typ()
-                    .with_message(format!("could not open file `{short_path}`"))
-                    .with_cause(JsError::from_opaque(js_string!(err.to_string()).into()))
-            })?;
-            let module = Module::parse(source, None, context).map_err(|err| {
-                JsNativeError::syntax()
-                    .with_message(format!("could not parse module `{short_path}`"))
-                    .with_cause(err)
-            })?;
-            self.insert(path, module.clone());
-            Ok(module)
         })();
 
         finish_load(result, context);
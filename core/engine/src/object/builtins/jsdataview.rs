@@ -1,12 +1,13 @@
 //! A Rust API wrapper for Boa's `DataView` Builtin ECMAScript Object
 use crate::{
-    builtins::{array_buffer::BufferObject, DataView},
+    builtins::{array_buffer::BufferObject, number::f64_to_f16, DataView},
     object::{JsArrayBuffer, JsObject},
     value::TryFromJs,
     Context, JsNativeError, JsResult, JsValue,
 };
 
 use boa_gc::{Finalize, Trace};
+use half::f16;
 use std::ops::Deref;
 
 /// `JsDataView` provides a wrapper for Boa's implementation of the ECMAScript `DataView` object
@@ -214,6 +215,22 @@ impl JsDataView {
         .map(|v| v.as_number().expect("value must be a number") as u64)
     }
 
+    /// Returns a 16-bit float at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn get_float16(
+        &self,
+        byte_offset: usize,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<f16> {
+        DataView::get_float16(
+            &self.inner.clone().upcast().into(),
+            &[byte_offset.into(), is_little_endian.into()],
+            context,
+        )
+        .map(|v| f64_to_f16(v.as_number().expect("value must be a number")))
+    }
+
     /// Returns a signed 32-bit float integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn get_float32(
@@ -374,6 +391,26 @@ impl JsDataView {
         )
     }
 
+    /// Sets a 16-bit float at the specified offset from the start of the [`JsDataView`]
+    #[inline]
+    pub fn set_float16(
+        &self,
+        byte_offset: usize,
+        value: f16,
+        is_little_endian: bool,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        DataView::set_float16(
+            &self.inner.clone().upcast().into(),
+            &[
+                byte_offset.into(),
+                value.to_f64().into(),
+                is_little_endian.into(),
+            ],
+            context,
+        )
+    }
+
     /// Sets a signed 32-bit integer at the specified offset from the start of the [`JsDataView`]
     #[inline]
     pub fn set_float32(
@@ -141,6 +141,118 @@ impl JsSet {
         )
     }
 
+    /// Returns a new set containing the elements of this set and the elements of `other`.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.union(other)`.
+    #[inline]
+    pub fn union(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let result = Set::union(&self.inner.clone().into(), &[other.clone().into()], context)?;
+
+        Ok(Self {
+            inner: result
+                .as_object()
+                .cloned()
+                .expect("`union` must always return a Set"),
+        })
+    }
+
+    /// Returns a new set containing the elements of this set that are also in `other`.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.intersection(other)`.
+    #[inline]
+    pub fn intersection(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let result =
+            Set::intersection(&self.inner.clone().into(), &[other.clone().into()], context)?;
+
+        Ok(Self {
+            inner: result
+                .as_object()
+                .cloned()
+                .expect("`intersection` must always return a Set"),
+        })
+    }
+
+    /// Returns a new set containing the elements of this set that are not in `other`.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.difference(other)`.
+    #[inline]
+    pub fn difference(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let result = Set::difference(&self.inner.clone().into(), &[other.clone().into()], context)?;
+
+        Ok(Self {
+            inner: result
+                .as_object()
+                .cloned()
+                .expect("`difference` must always return a Set"),
+        })
+    }
+
+    /// Returns a new set containing the elements that are either in this set or in `other`, but not in both.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.symmetricDifference(other)`.
+    #[inline]
+    pub fn symmetric_difference(&self, other: &JsObject, context: &mut Context) -> JsResult<Self> {
+        let result = Set::symmetric_difference(
+            &self.inner.clone().into(),
+            &[other.clone().into()],
+            context,
+        )?;
+
+        Ok(Self {
+            inner: result
+                .as_object()
+                .cloned()
+                .expect("`symmetricDifference` must always return a Set"),
+        })
+    }
+
+    /// Returns a boolean asserting whether all the elements of this set are in `other`.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.isSubsetOf(other)`.
+    #[inline]
+    pub fn is_subset_of(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Ok(
+            Set::is_subset_of(&self.inner.clone().into(), &[other.clone().into()], context)?
+                .to_boolean(),
+        )
+    }
+
+    /// Returns a boolean asserting whether all the elements of `other` are in this set.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.isSupersetOf(other)`.
+    #[inline]
+    pub fn is_superset_of(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Ok(
+            Set::is_superset_of(&self.inner.clone().into(), &[other.clone().into()], context)?
+                .to_boolean(),
+        )
+    }
+
+    /// Returns a boolean asserting whether this set has no elements in common with `other`.
+    ///
+    /// `other` can be any set-like object, such as another `JsSet` or a `JsMap`.
+    ///
+    /// Same as JavaScript's `set.isDisjointFrom(other)`.
+    #[inline]
+    pub fn is_disjoint_from(&self, other: &JsObject, context: &mut Context) -> JsResult<bool> {
+        Ok(
+            Set::is_disjoint_from(&self.inner.clone().into(), &[other.clone().into()], context)?
+                .to_boolean(),
+        )
+    }
+
     /// Utility: Creates `JsSet` from `JsObject`, if not a Set throw `TypeError`.
     #[inline]
     pub fn from_object(object: JsObject) -> JsResult<Self> {
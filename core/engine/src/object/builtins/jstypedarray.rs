@@ -8,6 +8,7 @@ use crate::{
     Context, JsResult, JsString, JsValue,
 };
 use boa_gc::{Finalize, Trace};
+use half::f16;
 use std::ops::Deref;
 
 /// `JsTypedArray` provides a wrapper for Boa's implementation of the ECMAScript `TypedArray`
@@ -1127,6 +1128,14 @@ JsTypedArrayType!(
     to_i32,
     i32
 );
+JsTypedArrayType!(
+    JsFloat16Array,
+    Float16Array,
+    is_typed_float16_array,
+    typed_float16_array,
+    to_f16,
+    f16
+);
 JsTypedArrayType!(
     JsFloat32Array,
     Float32Array,
@@ -1164,6 +1173,23 @@ fn typed_iterators_uint32() {
     assert_eq!(vec, vec2);
 }
 
+#[test]
+fn typed_iterators_f16() {
+    let context = &mut Context::default();
+    let vec = vec![
+        f16::from_f32(0.1),
+        f16::from_f32(0.2),
+        f16::from_f32(0.3),
+        f16::MAX,
+        f16::MIN_POSITIVE_SUBNORMAL,
+        f16::NEG_INFINITY,
+    ];
+
+    let array = JsFloat16Array::from_iter(vec.clone(), context).unwrap();
+    let vec2 = array.iter(context).collect::<Vec<_>>();
+    assert_eq!(vec, vec2);
+}
+
 #[test]
 fn typed_iterators_f32() {
     let context = &mut Context::default();
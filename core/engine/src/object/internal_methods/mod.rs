@@ -25,7 +25,7 @@ pub(crate) mod string;
 
 /// A lightweight wrapper around [`Context`] used in [`InternalObjectMethods`].
 #[derive(Debug)]
-pub(crate) struct InternalMethodContext<'ctx> {
+pub struct InternalMethodContext<'ctx> {
     context: &'ctx mut Context,
     slot: Slot,
 }
@@ -135,7 +135,11 @@ impl JsObject {
     ///  - [ECMAScript reference][spec]
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-getownproperty-p
-    pub(crate) fn __get_own_property__(
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the internal method of the object throws, e.g. a `Proxy` trap.
+    pub fn __get_own_property__(
         &self,
         key: &PropertyKey,
         context: &mut InternalMethodContext<'_>,
@@ -47,6 +47,7 @@ pub mod shape;
 pub(crate) use builtins::*;
 
 pub use datatypes::JsData;
+pub use internal_methods::InternalMethodContext;
 pub use jsobject::*;
 
 /// Const `constructor`, usually set on prototypes as a key to point to their respective constructor object.
@@ -425,6 +426,17 @@ impl Object<dyn NativeObject> {
         }
     }
 
+    /// Checks if it a `Float16Array` object.
+    #[inline]
+    #[must_use]
+    pub fn is_typed_float16_array(&self) -> bool {
+        if let Some(int) = self.downcast_ref::<TypedArray>() {
+            matches!(int.kind(), TypedArrayKind::Float16)
+        } else {
+            false
+        }
+    }
+
     /// Checks if it a `Float32Array` object.
     #[inline]
     #[must_use]
@@ -820,6 +820,13 @@ impl JsObject {
             return Ok(f.realm.clone().unwrap_or_else(|| context.realm().clone()));
         }
 
+        #[cfg(feature = "experimental")]
+        if let Some(wrapped) =
+            constructor.downcast_ref::<crate::builtins::shadow_realm::WrappedFunction>()
+        {
+            return Ok(wrapped.realm().clone());
+        }
+
         if let Some(bound) = constructor.downcast_ref::<BoundFunction>() {
             let fun = bound.target_function().clone();
             drop(constructor);
@@ -20,6 +20,7 @@ pub use boa_macros::TryIntoJs;
 use boa_profiler::Profiler;
 #[doc(inline)]
 pub use conversions::convert::Convert;
+use half::f16;
 
 pub(crate) use self::conversions::IntoOrUndefined;
 #[doc(inline)]
@@ -32,7 +33,7 @@ use crate::builtins::RegExp;
 use crate::object::{JsFunction, JsPromise, JsRegExp};
 use crate::{
     builtins::{
-        number::{f64_to_int32, f64_to_uint32},
+        number::{f64_to_f16, f64_to_int32, f64_to_uint32},
         Number, Promise,
     },
     error::JsNativeError,
@@ -994,6 +995,11 @@ impl JsValue {
         }
     }
 
+    /// Converts a value to a 16 bit floating point.
+    pub fn to_f16(&self, context: &mut Context) -> JsResult<f16> {
+        self.to_number(context).map(f64_to_f16)
+    }
+
     /// Converts a value to a 32 bit floating point.
     pub fn to_f32(&self, context: &mut Context) -> JsResult<f32> {
         self.to_number(context).map(|n| n as f32)
@@ -4,6 +4,7 @@
 
 use crate::{
     builtins::{
+        disposable::DisposeCapability,
         iterable::IteratorRecord,
         promise::{PromiseCapability, ResolvingFunctions},
     },
@@ -55,6 +56,10 @@ pub struct CallFrame {
     // Iterators and their `[[Done]]` flags that must be closed when an abrupt completion is thrown.
     pub(crate) iterators: ThinVec<IteratorRecord>,
 
+    // The dispose capabilities of the scopes containing `using` declarations, whose resources must be
+    // disposed when the scope is exited.
+    pub(crate) dispose_capabilities: ThinVec<DisposeCapability>,
+
     // The stack of bindings being updated.
     // SAFETY: Nothing in `BindingLocator` requires tracing, so this is safe.
     #[unsafe_ignore_trace]
@@ -162,6 +167,7 @@ impl CallFrame {
             env_fp: 0,
             argument_count: 0,
             iterators: ThinVec::new(),
+            dispose_capabilities: ThinVec::new(),
             binding_stack: Vec::new(),
             local_binings_initialized,
             loop_iteration_count: 0,
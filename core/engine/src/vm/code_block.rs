@@ -11,7 +11,10 @@ use crate::{
     Context, JsBigInt, JsString, JsValue,
 };
 use bitflags::bitflags;
-use boa_ast::scope::{BindingLocator, Scope};
+use boa_ast::{
+    scope::{BindingLocator, Scope},
+    Position,
+};
 use boa_gc::{empty_trace, Finalize, Gc, Trace};
 use boa_profiler::Profiler;
 use std::{cell::Cell, fmt::Display, mem::size_of};
@@ -108,6 +111,16 @@ impl Handler {
     }
 }
 
+/// Maps the bytecode starting at `pc` to a `position` in the source code.
+///
+/// The mapping applies until the `pc` of the next entry of the source map. A [`None`] position
+/// indicates that the bytecode doesn't correspond to a known position.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SourceMapEntry {
+    pub(crate) pc: u32,
+    pub(crate) position: Option<Position>,
+}
+
 #[derive(Clone, Debug, Trace, Finalize)]
 pub(crate) enum Constant {
     /// Property field names and private names `[[description]]`s.
@@ -164,6 +177,10 @@ pub struct CodeBlock {
     #[unsafe_ignore_trace]
     pub(crate) handlers: ThinVec<Handler>,
 
+    /// Source positions of the bytecode, sorted by their `pc`.
+    #[unsafe_ignore_trace]
+    pub(crate) source_map: ThinVec<SourceMapEntry>,
+
     /// inline caching
     pub(crate) ic: Box<[InlineCache]>,
 }
@@ -188,6 +205,7 @@ impl CodeBlock {
             mapped_arguments_binding_indices: ThinVec::new(),
             parameter_length: 0,
             handlers: ThinVec::default(),
+            source_map: ThinVec::default(),
             ic: Box::default(),
         }
     }
@@ -291,6 +309,13 @@ impl CodeBlock {
             .find(|(_, handler)| handler.contains(pc))
     }
 
+    /// Find the position in the source code of the instruction at the given program counter
+    /// (`pc`), if it is known.
+    pub(crate) fn position_at(&self, pc: u32) -> Option<Position> {
+        let index = self.source_map.partition_point(|entry| entry.pc <= pc);
+        self.source_map[..index].last()?.position
+    }
+
     /// Get the [`JsString`] constant from the [`CodeBlock`].
     ///
     /// # Panics
@@ -386,7 +411,7 @@ impl CodeBlock {
             }
             .to_owned(),
             Instruction::RotateLeft { n } | Instruction::RotateRight { n } => n.to_string(),
-            Instruction::Generator { r#async } => {
+            Instruction::Generator { r#async } | Instruction::AddDisposableResource { r#async } => {
                 format!("async: {async}")
             }
             Instruction::PushInt8 { value } => value.to_string(),
@@ -635,6 +660,10 @@ impl CodeBlock {
             | Instruction::ReThrow
             | Instruction::Exception
             | Instruction::MaybeException
+            | Instruction::PushDisposeCapability
+            | Instruction::PopDisposeCapability
+            | Instruction::DisposeResources
+            | Instruction::SuppressDisposeError
             | Instruction::This
             | Instruction::ThisForObjectEnvironmentName { .. }
             | Instruction::Super
@@ -733,12 +762,7 @@ impl CodeBlock {
             | Instruction::Reserved40
             | Instruction::Reserved41
             | Instruction::Reserved42
-            | Instruction::Reserved43
-            | Instruction::Reserved44
-            | Instruction::Reserved45
-            | Instruction::Reserved46
-            | Instruction::Reserved47
-            | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
+            | Instruction::Reserved43 => unreachable!("Reserved opcodes are unreachable"),
         }
     }
 }
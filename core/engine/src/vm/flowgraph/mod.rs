@@ -48,7 +48,7 @@ impl CodeBlock {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
-                Instruction::Generator { .. } => {
+                Instruction::Generator { .. } | Instruction::AddDisposableResource { .. } => {
                     graph.add_node(previous_pc, NodeShape::None, label.into(), Color::None);
                     graph.add_edge(previous_pc, pc, None, Color::None, EdgeStyle::Line);
                 }
@@ -450,6 +450,10 @@ impl CodeBlock {
                 | Instruction::SetReturnValue
                 | Instruction::Exception
                 | Instruction::MaybeException
+                | Instruction::PushDisposeCapability
+                | Instruction::PopDisposeCapability
+                | Instruction::DisposeResources
+                | Instruction::SuppressDisposeError
                 | Instruction::CheckReturn
                 | Instruction::BindThisValue
                 | Instruction::CreateMappedArgumentsObject
@@ -511,12 +515,7 @@ impl CodeBlock {
                 | Instruction::Reserved40
                 | Instruction::Reserved41
                 | Instruction::Reserved42
-                | Instruction::Reserved43
-                | Instruction::Reserved44
-                | Instruction::Reserved45
-                | Instruction::Reserved46
-                | Instruction::Reserved47
-                | Instruction::Reserved48 => unreachable!("Reserved opcodes are unreachable"),
+                | Instruction::Reserved43 => unreachable!("Reserved opcodes are unreachable"),
             }
         }
 
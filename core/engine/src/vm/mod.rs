@@ -5,11 +5,13 @@
 //! plus an interpreter to execute those instructions
 
 use crate::{
-    environments::EnvironmentStack, realm::Realm, script::Script, vm::code_block::Readable,
-    Context, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue, Module,
+    environments::EnvironmentStack, error::StackFrame, realm::Realm, script::Script,
+    vm::code_block::Readable, Context, JsError, JsNativeError, JsObject, JsResult, JsString,
+    JsValue, Module,
 };
 
 use boa_gc::{custom_trace, Finalize, Gc, Trace};
+use boa_macros::js_str;
 use boa_profiler::Profiler;
 use std::{future::Future, mem::size_of, ops::ControlFlow, pin::Pin, task};
 
@@ -30,7 +32,9 @@ pub(crate) use inline_cache::InlineCache;
 
 // TODO: see if this can be exposed on all features.
 #[allow(unused_imports)]
-pub(crate) use opcode::{Instruction, InstructionIterator, Opcode, VaryingOperandKind};
+pub(crate) use opcode::{
+    load_dynamic_import, Instruction, InstructionIterator, Opcode, VaryingOperandKind,
+};
 pub use runtime_limits::RuntimeLimits;
 pub use {
     call_frame::{CallFrame, GeneratorResumeKind},
@@ -41,6 +45,7 @@ pub(crate) use {
     call_frame::CallFrameFlags,
     code_block::{
         create_function_object, create_function_object_fast, CodeBlockFlags, Constant, Handler,
+        SourceMapEntry,
     },
     completion_record::CompletionRecord,
     opcode::BindingOpcode,
@@ -106,7 +111,21 @@ unsafe impl Trace for ActiveRunnable {
     });
 }
 
+impl ActiveRunnable {
+    /// Gets the path of the script or module, if it was created from a file or assigned.
+    pub(crate) fn path(&self) -> Option<&std::path::Path> {
+        match self {
+            Self::Script(script) => script.path(),
+            Self::Module(module) => module.path(),
+        }
+    }
+}
+
 impl Vm {
+    /// The maximum number of frames captured by [`Vm::stack_trace`], which is the same as the
+    /// default value of `Error.stackTraceLimit` in V8.
+    const STACK_TRACE_LIMIT: usize = 10;
+
     /// Creates a new virtual machine.
     pub(crate) fn new(realm: Realm) -> Self {
         let _timer = Profiler::global().start_event("VM::new", "VM");
@@ -220,6 +239,39 @@ impl Vm {
         }
     }
 
+    /// Captures the stack trace of the current execution, starting from the innermost frame.
+    pub(crate) fn stack_trace(&self) -> Vec<StackFrame> {
+        // The first frame is the dummy frame of the VM, which is not part of the stack trace.
+        let Some(frames) = self.frames.get(1..) else {
+            return Vec::new();
+        };
+
+        std::iter::once(&self.frame)
+            .chain(frames.iter().rev())
+            .take(Self::STACK_TRACE_LIMIT)
+            .map(|frame| {
+                let code_block = frame.code_block();
+                let function_name = if code_block.name() == &js_str!("<main>") {
+                    JsString::default()
+                } else {
+                    code_block.name().clone()
+                };
+
+                // NOTE: -1 because the `pc` points after the executing instruction or the
+                //       instruction that called the next frame.
+                StackFrame::new(
+                    function_name,
+                    frame
+                        .active_runnable
+                        .as_ref()
+                        .and_then(ActiveRunnable::path)
+                        .map(std::path::Path::to_path_buf),
+                    code_block.position_at(frame.pc.saturating_sub(1)),
+                )
+            })
+            .collect()
+    }
+
     /// Handles an exception thrown at position `pc`.
     ///
     /// Returns `true` if the exception was handled, `false` otherwise.
@@ -463,6 +515,8 @@ impl Context {
                     return ControlFlow::Break(CompletionRecord::Throw(err));
                 }
 
+                let err = err.inject_stack_trace(&self.vm);
+
                 // Note: -1 because we increment after fetching the opcode.
                 let pc = self.vm.frame().pc.saturating_sub(1);
                 if self.vm.handle_exception_at(pc) {
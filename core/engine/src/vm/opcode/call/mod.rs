@@ -1,10 +1,12 @@
 use crate::{
     builtins::{promise::PromiseCapability, Promise},
     error::JsNativeError,
-    module::{ModuleKind, Referrer},
+    js_string,
+    module::{ImportAttribute, ModuleKind, ModuleRequest, Referrer},
     object::FunctionObjectBuilder,
+    property::PropertyNameKind,
     vm::{opcode::Operation, CompletionType},
-    Context, JsObject, JsResult, JsValue, NativeFunction,
+    Context, JsObject, JsResult, JsString, JsValue, NativeFunction,
 };
 
 /// `CallEval` implements the Opcode Operation for `Opcode::CallEval`
@@ -286,6 +288,7 @@ impl Operation for ImportCall {
         // Import Calls
         // Runtime Semantics: Evaluation
         // https://tc39.es/ecma262/#sec-import-call-runtime-semantics-evaluation
+        // https://tc39.es/ecma262/#sec-evaluate-import-call
 
         // 1. Let referrer be GetActiveScriptOrModule().
         // 2. If referrer is null, set referrer to the current Realm Record.
@@ -293,11 +296,17 @@ impl Operation for ImportCall {
             .get_active_script_or_module()
             .map_or_else(|| Referrer::Realm(context.realm().clone()), Into::into);
 
-        // 3. Let argRef be ? Evaluation of AssignmentExpression.
-        // 4. Let specifier be ? GetValue(argRef).
-        let arg = context.vm.pop();
-
-        // 5. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 3. Let specifierRef be ? Evaluation of specifierExpression.
+        // 4. Let specifier be ? GetValue(specifierRef).
+        // 5. If optionsExpression is present, then
+        //     a. Let optionsRef be ? Evaluation of optionsExpression.
+        //     b. Let options be ? GetValue(optionsRef).
+        // 6. Else,
+        //     a. Let options be undefined.
+        let options = context.vm.pop();
+        let specifier = context.vm.pop();
+
+        // 7. Let promiseCapability be ! NewPromiseCapability(%Promise%).
         let cap = PromiseCapability::new(
             &context.intrinsics().constructors().promise().constructor(),
             context,
@@ -305,182 +314,292 @@ impl Operation for ImportCall {
         .expect("operation cannot fail for the %Promise% intrinsic");
         let promise = cap.promise().clone();
 
-        // 6. Let specifierString be Completion(ToString(specifier)).
-        match arg.to_string(context) {
-            // 7. IfAbruptRejectPromise(specifierString, promiseCapability).
+        // 8. Let specifierString be Completion(ToString(specifier)).
+        // 9. IfAbruptRejectPromise(specifierString, promiseCapability).
+        // 10. Let attributes be a new empty List.
+        // 11. If options is not undefined, then
+        //     ...
+        // 12. Let moduleRequest be a new ModuleRequest Record { [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+        match specifier
+            .to_string(context)
+            .and_then(|specifier| module_request_from_options(specifier, &options, context))
+        {
             Err(err) => {
                 let err = err.to_opaque(context);
                 cap.reject().call(&JsValue::undefined(), &[err], context)?;
             }
-            // 8. Perform HostLoadImportedModule(referrer, specifierString, empty, promiseCapability).
-            Ok(specifier) => context.module_loader().load_imported_module(
-                referrer.clone(),
-                specifier.clone(),
-                Box::new(move |completion, context| {
-                    // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
-                    // https://tc39.es/ecma262/#sec-ContinueDynamicImport
-
-                    // `FinishLoadingImportedModule ( referrer, specifier, payload, result )`
-                    // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
-                    let module = match completion {
-                        // 1. If result is a normal completion, then
-                        Ok(m) => {
-                            match referrer {
-                                Referrer::Module(module) => {
-                                    let ModuleKind::SourceText(src) = module.kind() else {
-                                        panic!("referrer cannot be a synthetic module");
-                                    };
-
-                                    let mut loaded_modules = src.loaded_modules().borrow_mut();
-
-                                    //     a. If referrer.[[LoadedModules]] contains a Record whose [[Specifier]] is specifier, then
-                                    //     b. Else,
-                                    //         i. Append the Record { [[Specifier]]: specifier, [[Module]]: result.[[Value]] } to referrer.[[LoadedModules]].
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-
-                                    //         i. Assert: That Record's [[Module]] is result.[[Value]].
-                                    debug_assert_eq!(&m, entry);
-
-                                    // Same steps apply to referrers below
-                                }
-                                Referrer::Realm(realm) => {
-                                    let mut loaded_modules = realm.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-                                    debug_assert_eq!(&m, entry);
-                                }
-                                Referrer::Script(script) => {
-                                    let mut loaded_modules = script.loaded_modules().borrow_mut();
-                                    let entry = loaded_modules
-                                        .entry(specifier)
-                                        .or_insert_with(|| m.clone());
-                                    debug_assert_eq!(&m, entry);
-                                }
-                            }
-
-                            m
+            // 13. Perform HostLoadImportedModule(referrer, moduleRequest, empty, promiseCapability).
+            Ok(request) => load_dynamic_import(referrer, request, cap, context),
+        };
+
+        // 14. Return promiseCapability.[[Promise]].
+        context.vm.push(promise);
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// Creates the `ModuleRequest` of an import call from its specifier and its `options` argument.
+///
+/// Contains steps 10 to 12 of [`EvaluateImportCall`][spec].
+///
+/// [spec]: https://tc39.es/ecma262/#sec-evaluate-import-call
+fn module_request_from_options(
+    specifier: JsString,
+    options: &JsValue,
+    context: &mut Context,
+) -> JsResult<ModuleRequest> {
+    // 10. Let attributes be a new empty List.
+    let mut attributes = Vec::new();
+
+    // 11. If options is not undefined, then
+    if !options.is_undefined() {
+        // a. If options is not an Object, then
+        let Some(options) = options.as_object() else {
+            // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+            // ii. Return promiseCapability.[[Promise]].
+            return Err(JsNativeError::typ()
+                .with_message("the options of an import call must be an object")
+                .into());
+        };
+
+        // b. Let attributesObj be Completion(Get(options, "with")).
+        // c. IfAbruptRejectPromise(attributesObj, promiseCapability).
+        let attributes_obj = options.get(js_string!("with"), context)?;
+
+        // d. If attributesObj is not undefined, then
+        if !attributes_obj.is_undefined() {
+            // i. If attributesObj is not an Object, then
+            let Some(attributes_obj) = attributes_obj.as_object() else {
+                // 1. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                // 2. Return promiseCapability.[[Promise]].
+                return Err(JsNativeError::typ()
+                    .with_message("the `with` option of an import call must be an object")
+                    .into());
+            };
+
+            // ii. Let entries be Completion(EnumerableOwnProperties(attributesObj, key+value)).
+            // iii. IfAbruptRejectPromise(entries, promiseCapability).
+            let entries = attributes_obj
+                .enumerable_own_property_names(PropertyNameKind::KeyAndValue, context)?;
+
+            // iv. For each element entry of entries, do
+            for entry in entries {
+                let entry = entry
+                    .as_object()
+                    .expect("entries of EnumerableOwnProperties must be objects");
+
+                // 1. Let key be ! Get(entry, "0").
+                // 2. Let value be ! Get(entry, "1").
+                let key = entry.get(0, context).expect("entry must have a key");
+                let value = entry.get(1, context).expect("entry must have a value");
+
+                // 3. If key is a String, then
+                if let Some(key) = key.as_string() {
+                    // a. If value is not a String, then
+                    let Some(value) = value.as_string() else {
+                        // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+                        // ii. Return promiseCapability.[[Promise]].
+                        return Err(JsNativeError::typ()
+                            .with_message(format!(
+                                "the value of the import attribute `{}` must be a string",
+                                key.to_std_string_escaped()
+                            ))
+                            .into());
+                    };
+
+                    // b. Append the ImportAttribute Record { [[Key]]: key, [[Value]]: value } to attributes.
+                    attributes.push(ImportAttribute::new(key.clone(), value.clone()));
+                }
+            }
+        }
+    }
+
+    //     e. If AllImportAttributesSupported(attributes) is false, then
+    //         i. Perform ! Call(promiseCapability.[[Reject]], undefined, « a newly created TypeError object »).
+    //         ii. Return promiseCapability.[[Promise]].
+    //     f. Sort attributes according to the lexicographic order of their [[Key]] field, treating
+    //        the value of each such field as a sequence of UTF-16 code unit values.
+    // 12. Let moduleRequest be a new ModuleRequest Record { [[Specifier]]: specifierString, [[Attributes]]: attributes }.
+    let request = ModuleRequest::new(specifier, attributes);
+    if let Some(key) = request.unsupported_attribute() {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "unsupported import attribute `{}`",
+                key.to_std_string_escaped()
+            ))
+            .into());
+    }
+
+    Ok(request)
+}
+
+/// Performs [`HostLoadImportedModule ( referrer, moduleRequest, empty, promiseCapability )`][host],
+/// continuing with [`ContinueDynamicImport ( promiseCapability, moduleCompletion )`][spec] once
+/// the module finishes loading.
+///
+/// [host]: https://tc39.es/ecma262/#sec-HostLoadImportedModule
+/// [spec]: https://tc39.es/ecma262/#sec-ContinueDynamicImport
+pub(crate) fn load_dynamic_import(
+    referrer: Referrer,
+    request: ModuleRequest,
+    cap: PromiseCapability,
+    context: &mut Context,
+) {
+    context.module_loader().load_imported_module(
+        referrer.clone(),
+        request.clone(),
+        Box::new(move |completion, context| {
+            // `ContinueDynamicImport ( promiseCapability, moduleCompletion )`
+            // https://tc39.es/ecma262/#sec-ContinueDynamicImport
+
+            // `FinishLoadingImportedModule ( referrer, specifier, payload, result )`
+            // https://tc39.es/ecma262/#sec-FinishLoadingImportedModule
+            let module = match completion {
+                // 1. If result is a normal completion, then
+                Ok(m) => {
+                    match referrer {
+                        Referrer::Module(module) => {
+                            let ModuleKind::SourceText(src) = module.kind() else {
+                                panic!("referrer cannot be a synthetic module");
+                            };
+
+                            let mut loaded_modules = src.loaded_modules().borrow_mut();
+
+                            //     a. If referrer.[[LoadedModules]] contains a LoadedModuleRequest Record record such that
+                            //        ModuleRequestsEqual(record, moduleRequest) is true, then
+                            //     b. Else,
+                            //         i. Append the LoadedModuleRequest Record { [[Specifier]]: moduleRequest.[[Specifier]],
+                            //            [[Attributes]]: moduleRequest.[[Attributes]], [[Module]]: result.[[Value]] }
+                            //            to referrer.[[LoadedModules]].
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+
+                            //         i. Assert: That Record's [[Module]] is result.[[Value]].
+                            debug_assert_eq!(&m, entry);
+
+                            // Same steps apply to referrers below
+                        }
+                        Referrer::Realm(realm) => {
+                            let mut loaded_modules = realm.loaded_modules().borrow_mut();
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
                         }
-                        // 1. If moduleCompletion is an abrupt completion, then
-                        Err(err) => {
-                            // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « moduleCompletion.[[Value]] »).
-                            let err = err.to_opaque(context);
+                        Referrer::Script(script) => {
+                            let mut loaded_modules = script.loaded_modules().borrow_mut();
+                            let entry = loaded_modules.entry(request).or_insert_with(|| m.clone());
+                            debug_assert_eq!(&m, entry);
+                        }
+                    }
+
+                    m
+                }
+                // 1. If moduleCompletion is an abrupt completion, then
+                Err(err) => {
+                    // a. Perform ! Call(promiseCapability.[[Reject]], undefined, « moduleCompletion.[[Value]] »).
+                    let err = err.to_opaque(context);
+                    cap.reject()
+                        .call(&JsValue::undefined(), &[err], context)
+                        .expect("default `reject` function cannot throw");
+
+                    // b. Return unused.
+                    return;
+                }
+            };
+
+            // 2. Let module be moduleCompletion.[[Value]].
+            // 3. Let loadPromise be module.LoadRequestedModules().
+            let load = module.load(context);
+
+            // 4. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures promiseCapability and performs the following steps when called:
+            // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
+            let on_rejected = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, cap, context| {
+                        //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
+                        cap.reject()
+                            .call(&JsValue::undefined(), args, context)
+                            .expect("default `reject` function cannot throw");
+
+                        //     b. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    cap.clone(),
+                ),
+            )
+            .build();
+
+            // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no parameters that captures module, promiseCapability, and onRejected and performs the following steps when called:
+            // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure, 0, "", « »).
+            let link_evaluate = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, _, (module, cap, on_rejected), context| {
+                        // a. Let link be Completion(module.Link()).
+                        // b. If link is an abrupt completion, then
+                        if let Err(e) = module.link(context) {
+                            // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « link.[[Value]] »).
+                            let e = e.to_opaque(context);
                             cap.reject()
-                                .call(&JsValue::undefined(), &[err], context)
+                                .call(&JsValue::undefined(), &[e], context)
                                 .expect("default `reject` function cannot throw");
-
-                            // b. Return unused.
-                            return;
+                            // ii. Return unused.
+                            return Ok(JsValue::undefined());
                         }
-                    };
 
-                    // 2. Let module be moduleCompletion.[[Value]].
-                    // 3. Let loadPromise be module.LoadRequestedModules().
-                    let load = module.load(context);
-
-                    // 4. Let rejectedClosure be a new Abstract Closure with parameters (reason) that captures promiseCapability and performs the following steps when called:
-                    // 5. Let onRejected be CreateBuiltinFunction(rejectedClosure, 1, "", « »).
-                    let on_rejected = FunctionObjectBuilder::new(
-                        context.realm(),
-                        NativeFunction::from_copy_closure_with_captures(
-                            |_, args, cap, context| {
-                                //     a. Perform ! Call(promiseCapability.[[Reject]], undefined, « reason »).
-                                cap.reject()
-                                    .call(&JsValue::undefined(), args, context)
-                                    .expect("default `reject` function cannot throw");
-
-                                //     b. Return unused.
-                                Ok(JsValue::undefined())
-                            },
-                            cap.clone(),
-                        ),
-                    )
-                    .build();
-
-                    // 6. Let linkAndEvaluateClosure be a new Abstract Closure with no parameters that captures module, promiseCapability, and onRejected and performs the following steps when called:
-                    // 7. Let linkAndEvaluate be CreateBuiltinFunction(linkAndEvaluateClosure, 0, "", « »).
-                    let link_evaluate = FunctionObjectBuilder::new(
-                        context.realm(),
-                        NativeFunction::from_copy_closure_with_captures(
-                            |_, _, (module, cap, on_rejected), context| {
-                                // a. Let link be Completion(module.Link()).
-                                // b. If link is an abrupt completion, then
-                                if let Err(e) = module.link(context) {
-                                    // i. Perform ! Call(promiseCapability.[[Reject]], undefined, « link.[[Value]] »).
-                                    let e = e.to_opaque(context);
-                                    cap.reject()
-                                        .call(&JsValue::undefined(), &[e], context)
-                                        .expect("default `reject` function cannot throw");
-                                    // ii. Return unused.
-                                    return Ok(JsValue::undefined());
-                                }
-
-                                // c. Let evaluatePromise be module.Evaluate().
-                                let evaluate = module.evaluate(context);
-
-                                // d. Let fulfilledClosure be a new Abstract Closure with no parameters that captures module and promiseCapability and performs the following steps when called:
-                                // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
-                                let fulfill = FunctionObjectBuilder::new(
-                                    context.realm(),
-                                    NativeFunction::from_copy_closure_with_captures(
-                                        |_, _, (module, cap), context| {
-                                            // i. Let namespace be GetModuleNamespace(module).
-                                            let namespace = module.namespace(context);
-
-                                            // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
-                                            cap.resolve()
-                                                .call(
-                                                    &JsValue::undefined(),
-                                                    &[namespace.into()],
-                                                    context,
-                                                )
-                                                .expect("default `resolve` function cannot throw");
-
-                                            // iii. Return unused.
-                                            Ok(JsValue::undefined())
-                                        },
-                                        (module.clone(), cap.clone()),
-                                    ),
-                                )
-                                .build();
-
-                                // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
-                                Promise::perform_promise_then(
-                                    &evaluate,
-                                    Some(fulfill),
-                                    Some(on_rejected.clone()),
-                                    None,
-                                    context,
-                                );
-
-                                // g. Return unused.
-                                Ok(JsValue::undefined())
-                            },
-                            (module.clone(), cap.clone(), on_rejected.clone()),
-                        ),
-                    )
-                    .build();
-
-                    // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
-                    Promise::perform_promise_then(
-                        &load,
-                        Some(link_evaluate),
-                        Some(on_rejected),
-                        None,
-                        context,
-                    );
-
-                    // 9. Return unused.
-                }),
+                        // c. Let evaluatePromise be module.Evaluate().
+                        let evaluate = module.evaluate(context);
+
+                        // d. Let fulfilledClosure be a new Abstract Closure with no parameters that captures module and promiseCapability and performs the following steps when called:
+                        // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
+                        let fulfill = FunctionObjectBuilder::new(
+                            context.realm(),
+                            NativeFunction::from_copy_closure_with_captures(
+                                |_, _, (module, cap), context| {
+                                    // i. Let namespace be GetModuleNamespace(module).
+                                    let namespace = module.namespace(context);
+
+                                    // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
+                                    cap.resolve()
+                                        .call(&JsValue::undefined(), &[namespace.into()], context)
+                                        .expect("default `resolve` function cannot throw");
+
+                                    // iii. Return unused.
+                                    Ok(JsValue::undefined())
+                                },
+                                (module.clone(), cap.clone()),
+                            ),
+                        )
+                        .build();
+
+                        // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+                        Promise::perform_promise_then(
+                            &evaluate,
+                            Some(fulfill),
+                            Some(on_rejected.clone()),
+                            None,
+                            context,
+                        );
+
+                        // g. Return unused.
+                        Ok(JsValue::undefined())
+                    },
+                    (module.clone(), cap.clone(), on_rejected.clone()),
+                ),
+            )
+            .build();
+
+            // 8. Perform PerformPromiseThen(loadPromise, linkAndEvaluate, onRejected).
+            Promise::perform_promise_then(
+                &load,
+                Some(link_evaluate),
+                Some(on_rejected),
+                None,
                 context,
-            ),
-        };
+            );
 
-        // 9. Return promiseCapability.[[Promise]].
-        context.vm.push(promise);
-
-        Ok(CompletionType::Normal)
-    }
+            // 9. Return unused.
+        }),
+        context,
+    );
 }
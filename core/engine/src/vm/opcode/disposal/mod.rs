@@ -0,0 +1,151 @@
+use crate::{
+    builtins::disposable::{
+        add_disposable_resource, suppress_error, DisposeCapability, DisposeHint, DisposeStep,
+    },
+    vm::{opcode::Operation, CompletionType},
+    Context, JsError, JsResult, JsValue,
+};
+
+/// `PushDisposeCapability` implements the Opcode Operation for `Opcode::PushDisposeCapability`
+///
+/// Operation:
+///  - Push a new dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PushDisposeCapability;
+
+impl Operation for PushDisposeCapability {
+    const NAME: &'static str = "PushDisposeCapability";
+    const INSTRUCTION: &'static str = "INST - PushDisposeCapability";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .push(DisposeCapability::new());
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `PopDisposeCapability` implements the Opcode Operation for `Opcode::PopDisposeCapability`
+///
+/// Operation:
+///  - Pop the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PopDisposeCapability;
+
+impl Operation for PopDisposeCapability {
+    const NAME: &'static str = "PopDisposeCapability";
+    const INSTRUCTION: &'static str = "INST - PopDisposeCapability";
+    const COST: u8 = 1;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        context.vm.frame_mut().dispose_capabilities.pop();
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `AddDisposableResource` implements the Opcode Operation for `Opcode::AddDisposableResource`
+///
+/// Operation:
+///  - Add a disposable resource to the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AddDisposableResource;
+
+impl Operation for AddDisposableResource {
+    const NAME: &'static str = "AddDisposableResource";
+    const INSTRUCTION: &'static str = "INST - AddDisposableResource";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let r#async = context.vm.read::<u8>() != 0;
+        let value = context.vm.pop();
+
+        let hint = if r#async {
+            DisposeHint::Async
+        } else {
+            DisposeHint::Sync
+        };
+
+        if let Some(resource) = add_disposable_resource(&value, hint, None, context)? {
+            context
+                .vm
+                .frame_mut()
+                .dispose_capabilities
+                .last_mut()
+                .expect("there should be a dispose capability")
+                .push(resource);
+        }
+
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `DisposeResources` implements the Opcode Operation for `Opcode::DisposeResources`
+///
+/// Operation:
+///  - Execute the next step of disposing the resources of the current dispose capability.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposeResources;
+
+impl Operation for DisposeResources {
+    const NAME: &'static str = "DisposeResources";
+    const INSTRUCTION: &'static str = "INST - DisposeResources";
+    const COST: u8 = 5;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let step = context
+            .vm
+            .frame_mut()
+            .dispose_capabilities
+            .last_mut()
+            .expect("there should be a dispose capability")
+            .next_step();
+
+        let (result, r#await, done) = match step {
+            DisposeStep::Await => (JsValue::undefined(), true, false),
+            DisposeStep::Dispose(resource) => {
+                let result = resource.dispose(context)?;
+                let r#await = resource.hint() == DisposeHint::Async;
+                (result, r#await, false)
+            }
+            DisposeStep::Done => (JsValue::undefined(), false, true),
+        };
+
+        context.vm.push(result);
+        context.vm.push(r#await);
+        context.vm.push(done);
+        Ok(CompletionType::Normal)
+    }
+}
+
+/// `SuppressDisposeError` implements the Opcode Operation for `Opcode::SuppressDisposeError`
+///
+/// Operation:
+///  - Combine an error thrown while disposing a resource with the current completion.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SuppressDisposeError;
+
+impl Operation for SuppressDisposeError {
+    const NAME: &'static str = "SuppressDisposeError";
+    const INSTRUCTION: &'static str = "INST - SuppressDisposeError";
+    const COST: u8 = 3;
+
+    fn execute(context: &mut Context) -> JsResult<CompletionType> {
+        let error = JsError::from_opaque(context.vm.pop());
+        let is_throw = context.vm.pop().to_boolean();
+        let value = context.vm.pop();
+
+        let completion = if is_throw {
+            Err(JsError::from_opaque(value))
+        } else {
+            Ok(())
+        };
+        let error = suppress_error(error, completion).to_opaque(context);
+
+        context.vm.push(error);
+        context.vm.push(true);
+        Ok(CompletionType::Normal)
+    }
+}
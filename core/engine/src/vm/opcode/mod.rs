@@ -13,6 +13,7 @@ mod control_flow;
 mod copy;
 mod define;
 mod delete;
+mod disposal;
 mod dup;
 mod environment;
 mod generator;
@@ -54,6 +55,8 @@ pub(crate) use define::*;
 #[doc(inline)]
 pub(crate) use delete::*;
 #[doc(inline)]
+pub(crate) use disposal::*;
+#[doc(inline)]
 pub(crate) use dup::*;
 #[doc(inline)]
 pub(crate) use environment::*;
@@ -1596,6 +1599,49 @@ generate_opcodes! {
     /// Stack: **=>** (`true`, exception) or `false`
     MaybeException,
 
+    /// Push a new dispose capability, used to track the resources of a scope containing `using`
+    /// declarations.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PushDisposeCapability,
+
+    /// Pop the current dispose capability.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>**
+    PopDisposeCapability,
+
+    /// Add a disposable resource to the current dispose capability.
+    ///
+    /// Operands:
+    /// - async: bool (codified as u8 with `0` -> `false` and `!0` -> `true`)
+    ///
+    /// Stack: value **=>**
+    AddDisposableResource { r#async: bool },
+
+    /// Execute the next step of disposing the resources of the current dispose capability.
+    ///
+    /// If `done` is `true`, then all the resources have been disposed. Otherwise, if `await` is
+    /// `true`, `result` must be awaited before continuing with the next step.
+    ///
+    /// Operands:
+    ///
+    /// Stack: **=>** result, await, done
+    DisposeResources,
+
+    /// Combine an error thrown while disposing a resource with the current completion.
+    ///
+    /// If the completion is a throw completion, the error is wrapped in a `SuppressedError` that
+    /// suppresses the completion value.
+    ///
+    /// Operands:
+    ///
+    /// Stack: value, is_throw, error **=>** new_value, `true`
+    SuppressDisposeError,
+
     /// Throw a new `TypeError` exception
     ///
     /// Operands: message: u32
@@ -1681,7 +1727,7 @@ generate_opcodes! {
     ///
     /// Operands:
     ///
-    /// Stack: specifier **=>** promise
+    /// Stack: specifier, options **=>** promise
     ImportCall,
 
     /// Pop the two values of the stack, strict equal compares the two values,
@@ -2277,16 +2323,6 @@ generate_opcodes! {
     Reserved42 => Reserved,
     /// Reserved [`Opcode`].
     Reserved43 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved44 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved45 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved46 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved47 => Reserved,
-    /// Reserved [`Opcode`].
-    Reserved48 => Reserved,
 }
 
 /// Specific opcodes for bindings.
@@ -1,9 +1,9 @@
 #![allow(unused_crate_dependencies, missing_docs)]
 
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc};
 
 use boa_engine::builtins::promise::PromiseState;
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, Referrer};
 use boa_engine::{js_string, Context, JsResult, JsString, Module, Source};
 
 #[test]
@@ -13,11 +13,11 @@ fn test_json_module_from_str() {
         fn load_imported_module(
             &self,
             _referrer: Referrer,
-            specifier: JsString,
+            request: ModuleRequest,
             finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
             context: &mut Context,
         ) {
-            assert_eq!(specifier.to_std_string_escaped(), "basic");
+            assert_eq!(request.specifier().to_std_string_escaped(), "basic");
 
             finish_load(
                 Ok(Module::parse_json(self.0.clone(), context).unwrap()),
@@ -63,3 +63,110 @@ fn test_json_module_from_str() {
         json_string
     );
 }
+
+#[test]
+fn test_import_attributes() {
+    use boa_engine::module::ModuleType;
+
+    // Loaders must return the same module for the same request, so cache one module per type.
+    #[derive(Default)]
+    struct TestModuleLoader {
+        json: RefCell<Option<Module>>,
+        js: RefCell<Option<Module>>,
+    }
+    impl ModuleLoader for TestModuleLoader {
+        fn load_imported_module(
+            &self,
+            _referrer: Referrer,
+            request: ModuleRequest,
+            finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
+            context: &mut Context,
+        ) {
+            let (cache, module) = match request.module_type() {
+                Ok(ModuleType::Json) => (
+                    &self.json,
+                    Module::parse_json(js_string!(r#"{"kind":"json"}"#), context),
+                ),
+                Ok(ModuleType::JavaScript) => (
+                    &self.js,
+                    Module::parse(
+                        Source::from_bytes(b"export default { kind: 'js' };"),
+                        None,
+                        context,
+                    ),
+                ),
+                Err(err) => return finish_load(Err(err), context),
+            };
+            let cached = cache.borrow().clone();
+            let module = match cached {
+                Some(module) => Ok(module),
+                None => module.inspect(|module| *cache.borrow_mut() = Some(module.clone())),
+            };
+            finish_load(module, context);
+        }
+    }
+
+    let mut context = Context::builder()
+        .module_loader(Rc::new(TestModuleLoader::default()))
+        .build()
+        .unwrap();
+
+    let source = Source::from_bytes(
+        b"
+        import json from 'data' with { type: 'json' };
+        import js from 'data';
+        const dynamic = await import('data', { with: { type: 'json' } });
+        export const kinds = [json.kind, js.kind, dynamic.default.kind].join();
+        export const invalid = await import('data', { with: { type: 'css' } })
+            .then(() => 'loaded', (e) => e.name);
+        export const unsupported = await import('data', { with: { other: 'x' } })
+            .then(() => 'loaded', (e) => e.name);
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    if let PromiseState::Rejected(e) = promise.state() {
+        panic!("Unexpected error: {:?}", e.to_string(&mut context).unwrap());
+    }
+
+    let namespace = module.namespace(&mut context);
+    let mut get = |name| {
+        namespace
+            .get(JsString::from(name), &mut context)
+            .unwrap()
+            .as_string()
+            .unwrap()
+            .to_std_string_escaped()
+    };
+    assert_eq!(get("kinds"), "json,js,json");
+    assert_eq!(get("invalid"), "TypeError");
+    assert_eq!(get("unsupported"), "TypeError");
+}
+
+#[test]
+fn test_unsupported_import_attribute() {
+    let mut context = Context::default();
+
+    let source = Source::from_bytes(
+        b"
+        import json from 'data' with { other: 'json' };
+    ",
+    );
+
+    let module = Module::parse(source, None, &mut context).unwrap();
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    let PromiseState::Rejected(e) = promise.state() else {
+        panic!("module should fail to load");
+    };
+    let name = e
+        .as_object()
+        .unwrap()
+        .get(js_string!("name"), &mut context)
+        .unwrap();
+    assert_eq!(name, js_string!("SyntaxError").into());
+}
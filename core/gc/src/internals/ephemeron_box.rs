@@ -1,4 +1,4 @@
-use crate::{trace::Trace, Gc, GcBox, Tracer};
+use crate::{trace::Trace, DropGuard, Gc, GcBox, Tracer};
 use std::{cell::UnsafeCell, ptr::NonNull};
 
 use super::GcHeader;
@@ -176,6 +176,17 @@ impl<K: Trace + ?Sized, V: Trace> ErasedEphemeronBox for EphemeronBox<K, V> {
     fn finalize_and_clear(&self) {
         // SAFETY: the invariants of the garbage collector ensures this is only executed when
         // there are no remaining references to the inner data.
-        unsafe { (*self.data.get()).take() };
+        let Some(data) = (unsafe { (*self.data.get()).take() }) else {
+            return;
+        };
+
+        // Run the finalizers of the held value, which allows users to attach callbacks that
+        // execute when the key of an ephemeron gets collected.
+        data.value.run_finalizer();
+
+        // `run_finalizer` already released the references of the value, so drop it the same
+        // way the sweep phase does to avoid releasing them twice.
+        let _guard = DropGuard::new();
+        drop(data);
     }
 }
@@ -89,6 +89,60 @@ fn eph_allocation_chains() {
     });
 }
 
+#[test]
+fn eph_finalize_value_on_key_collection() {
+    #[derive(Clone)]
+    struct Signaler {
+        finalized: Rc<Cell<bool>>,
+        inner: Gc<String>,
+    }
+
+    impl Finalize for Signaler {
+        fn finalize(&self) {
+            self.finalized.set(true);
+        }
+    }
+
+    // SAFETY: `inner` is the only traceable field of `Signaler`.
+    unsafe impl Trace for Signaler {
+        crate::custom_trace!(this, mark, {
+            mark(&this.inner);
+        });
+    }
+
+    run_test(|| {
+        let finalized = Rc::new(Cell::new(false));
+        let key = Gc::new(5);
+        let value = Gc::new(String::from("held"));
+
+        {
+            let eph = Ephemeron::new(
+                &key,
+                Signaler {
+                    finalized: finalized.clone(),
+                    inner: value.clone(),
+                },
+            );
+
+            force_collect();
+            assert!(!finalized.get());
+
+            drop(key);
+            force_collect();
+
+            assert!(finalized.get());
+            assert!(!eph.has_value());
+        }
+
+        // The references held by the finalized value must have been released exactly once.
+        force_collect();
+        assert_eq!(*value, "held");
+        drop(value);
+        force_collect();
+        Harness::assert_empty_gc();
+    });
+}
+
 #[test]
 fn eph_basic_alloc_dump_test() {
     run_test(|| {
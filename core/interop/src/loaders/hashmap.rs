@@ -1,19 +1,28 @@
 //! A `ModuleLoader` that loads modules from a `HashMap` based on the name.
 use rustc_hash::FxHashMap;
 
-use boa_engine::module::{ModuleLoader, Referrer};
+use boa_engine::module::{ModuleLoader, ModuleRequest, ModuleType, Referrer};
 use boa_engine::{Context, JsNativeError, JsResult, JsString, Module};
 use boa_gc::GcRefCell;
 
 /// A `ModuleLoader` that loads modules from a `HashMap` based on the name.
 /// After registering modules, this loader will look for the exact name
 /// in its internal map to resolve.
+///
+/// JavaScript modules and JSON modules (imported `with { type: "json" }`) are
+/// registered separately, so the same name can be used for both.
 #[derive(Debug, Clone)]
-pub struct HashMapModuleLoader(GcRefCell<FxHashMap<JsString, Module>>);
+pub struct HashMapModuleLoader {
+    modules: GcRefCell<FxHashMap<JsString, Module>>,
+    json: GcRefCell<FxHashMap<JsString, Module>>,
+}
 
 impl Default for HashMapModuleLoader {
     fn default() -> Self {
-        Self(GcRefCell::new(FxHashMap::default()))
+        Self {
+            modules: GcRefCell::new(FxHashMap::default()),
+            json: GcRefCell::new(FxHashMap::default()),
+        }
     }
 }
 
@@ -26,14 +35,31 @@ impl HashMapModuleLoader {
 
     /// Registers a module with a given name.
     pub fn register(&self, key: impl Into<JsString>, value: Module) {
-        self.0.borrow_mut().insert(key.into(), value);
+        self.modules.borrow_mut().insert(key.into(), value);
+    }
+
+    /// Registers a JSON module with a given name, parsing it from its source text.
+    ///
+    /// # Errors
+    /// Returns an error if `json` is not valid JSON.
+    pub fn register_json(
+        &self,
+        key: impl Into<JsString>,
+        json: JsString,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        let module = Module::parse_json(json, context)?;
+        self.json.borrow_mut().insert(key.into(), module);
+        Ok(())
     }
 }
 
 impl FromIterator<(JsString, Module)> for HashMapModuleLoader {
     fn from_iter<T: IntoIterator<Item = (JsString, Module)>>(iter: T) -> Self {
-        let map = iter.into_iter().collect();
-        Self(GcRefCell::new(map))
+        Self {
+            modules: GcRefCell::new(iter.into_iter().collect()),
+            json: GcRefCell::new(FxHashMap::default()),
+        }
     }
 }
 
@@ -41,13 +67,24 @@ impl ModuleLoader for HashMapModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
+        let specifier = request.specifier();
+        let modules = match request.module_type() {
+            Ok(ModuleType::JavaScript) => &self.modules,
+            Ok(ModuleType::Json) => &self.json,
+            Err(err) => {
+                finish_load(Err(err), context);
+                return;
+            }
+        };
+
         // First, try to resolve from our internal cached.
-        if let Some(module) = self.0.borrow().get(&specifier) {
-            finish_load(Ok(module.clone()), context);
+        let module = modules.borrow().get(specifier).cloned();
+        if let Some(module) = module {
+            finish_load(Ok(module), context);
         } else {
             let err = JsNativeError::typ().with_message(format!(
                 "could not find module `{}`",
@@ -58,6 +95,6 @@ impl ModuleLoader for HashMapModuleLoader {
     }
 
     fn get_module(&self, specifier: JsString) -> Option<Module> {
-        self.0.borrow().get(&specifier).cloned()
+        self.modules.borrow().get(&specifier).cloned()
     }
 }
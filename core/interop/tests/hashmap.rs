@@ -0,0 +1,101 @@
+//! Tests for the hashmap module loader.
+
+#![allow(unused_crate_dependencies)]
+
+use std::rc::Rc;
+
+use boa_engine::builtins::promise::PromiseState;
+use boa_engine::{js_string, Context, Module, Source};
+use boa_interop::loaders::HashMapModuleLoader;
+
+#[test]
+fn json() {
+    let module_loader = Rc::new(HashMapModuleLoader::new());
+    let mut context = Context::builder()
+        .module_loader(module_loader.clone())
+        .build()
+        .unwrap();
+
+    let js = Module::parse(
+        Source::from_bytes(b"export default 'javascript';"),
+        None,
+        &mut context,
+    )
+    .unwrap();
+    module_loader.register(js_string!("data"), js);
+    module_loader
+        .register_json(
+            js_string!("data"),
+            js_string!(r#"{ "answer": 42 }"#),
+            &mut context,
+        )
+        .unwrap();
+
+    let module = Module::parse(
+        Source::from_bytes(
+            b"import data from 'data' with { type: 'json' };
+              import js from 'data';
+              export const answer = data.answer;
+              export const kind = js;",
+        ),
+        None,
+        &mut context,
+    )
+    .expect("failed to parse module");
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    match promise.state() {
+        PromiseState::Fulfilled(_) => {
+            let namespace = module.namespace(&mut context);
+            let answer = namespace.get(js_string!("answer"), &mut context).unwrap();
+            assert_eq!(answer.as_number(), Some(42.));
+            let kind = namespace.get(js_string!("kind"), &mut context).unwrap();
+            assert_eq!(kind.as_string(), Some(&js_string!("javascript")));
+        }
+        PromiseState::Rejected(err) => panic!(
+            "promise was not fulfilled: {:?}",
+            err.to_string(&mut context)
+        ),
+        PromiseState::Pending => panic!("Promise was not settled"),
+    }
+}
+
+#[test]
+fn unsupported_type() {
+    let module_loader = Rc::new(HashMapModuleLoader::new());
+    let mut context = Context::builder()
+        .module_loader(module_loader.clone())
+        .build()
+        .unwrap();
+
+    let css = Module::parse(Source::from_bytes(b"export default 1;"), None, &mut context).unwrap();
+    module_loader.register(js_string!("style"), css);
+
+    let module = Module::parse(
+        Source::from_bytes(b"import style from 'style' with { type: 'css' };"),
+        None,
+        &mut context,
+    )
+    .expect("failed to parse module");
+    let promise = module.load_link_evaluate(&mut context);
+    context.run_jobs();
+
+    match promise.state() {
+        PromiseState::Rejected(err) => {
+            let message = err
+                .as_object()
+                .unwrap()
+                .get(js_string!("message"), &mut context)
+                .unwrap();
+            assert_eq!(
+                message.as_string(),
+                Some(&js_string!(
+                    "unsupported module type `css` for module `style`"
+                ))
+            );
+        }
+        PromiseState::Fulfilled(_) => panic!("the module should not be loaded"),
+        PromiseState::Pending => panic!("Promise was not settled"),
+    }
+}
@@ -73,9 +73,10 @@ where
         let token = cursor.peek(0, interner).or_abrupt()?;
 
         let lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
+            let position = token.span().start();
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            Call::new(self.first_member_expr, args).into()
+            Call::with_position(self.first_member_expr, args, position).into()
         } else {
             let next_token = cursor.next(interner)?.expect("token vanished");
             return Err(Error::expected(
@@ -128,26 +129,28 @@ where
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
-                    lhs = ast::Expression::from(Call::new(lhs, args));
+                    lhs =
+                        ast::Expression::from(Call::with_position(lhs, args, token.span().start()));
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
+                    let position = token.span().start();
                     cursor.advance(interner);
 
                     let access = match cursor.next(interner).or_abrupt()?.kind() {
                         TokenKind::IdentifierName((name, _)) => {
-                            SimplePropertyAccess::new(lhs, *name).into()
+                            SimplePropertyAccess::with_position(lhs, *name, position).into()
                         }
                         TokenKind::Keyword((kw, _)) => {
-                            SimplePropertyAccess::new(lhs, kw.to_sym()).into()
+                            SimplePropertyAccess::with_position(lhs, kw.to_sym(), position).into()
                         }
                         TokenKind::BooleanLiteral((true, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::TRUE).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::TRUE, position).into()
                         }
                         TokenKind::BooleanLiteral((false, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::FALSE).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::FALSE, position).into()
                         }
                         TokenKind::NullLiteral(_) => {
-                            SimplePropertyAccess::new(lhs, Sym::NULL).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::NULL, position).into()
                         }
                         TokenKind::PrivateIdentifier(name) => {
                             PrivatePropertyAccess::new(lhs, PrivateName::new(*name)).into()
@@ -165,12 +168,14 @@ where
                     lhs = ast::Expression::PropertyAccess(access);
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let position = token.span().start();
                     cursor.advance(interner);
                     let idx = Expression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    lhs = ast::Expression::PropertyAccess(
+                        SimplePropertyAccess::with_position(lhs, idx, position).into(),
+                    );
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
@@ -149,7 +149,7 @@ where
                         }
                         _ => Box::new([]),
                     };
-                    let call_node = Call::new(lhs_inner, args);
+                    let call_node = Call::with_position(lhs_inner, args, position);
 
                     ast::Expression::from(New::from(call_node))
                 };
@@ -217,6 +217,7 @@ where
         while let Some(tok) = cursor.peek(0, interner)? {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Dot) => {
+                    let position = tok.span().start();
                     cursor
                         .next(interner)?
                         .expect("dot punctuator token disappeared"); // We move the parser forward.
@@ -225,19 +226,19 @@ where
 
                     let access = match token.kind() {
                         TokenKind::IdentifierName((name, _)) => {
-                            SimplePropertyAccess::new(lhs, *name).into()
+                            SimplePropertyAccess::with_position(lhs, *name, position).into()
                         }
                         TokenKind::Keyword((kw, _)) => {
-                            SimplePropertyAccess::new(lhs, kw.to_sym()).into()
+                            SimplePropertyAccess::with_position(lhs, kw.to_sym(), position).into()
                         }
                         TokenKind::BooleanLiteral((true, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::TRUE).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::TRUE, position).into()
                         }
                         TokenKind::BooleanLiteral((false, _)) => {
-                            SimplePropertyAccess::new(lhs, Sym::FALSE).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::FALSE, position).into()
                         }
                         TokenKind::NullLiteral(_) => {
-                            SimplePropertyAccess::new(lhs, Sym::NULL).into()
+                            SimplePropertyAccess::with_position(lhs, Sym::NULL, position).into()
                         }
                         TokenKind::PrivateIdentifier(name) => {
                             PrivatePropertyAccess::new(lhs, PrivateName::new(*name)).into()
@@ -255,14 +256,16 @@ where
                     lhs = ast::Expression::PropertyAccess(access);
                 }
                 TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let position = tok.span().start();
                     cursor
                         .next(interner)?
                         .expect("open bracket punctuator token disappeared"); // We move the parser forward.
                     let idx = Expression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor, interner)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression", interner)?;
-                    lhs =
-                        ast::Expression::PropertyAccess(SimplePropertyAccess::new(lhs, idx).into());
+                    lhs = ast::Expression::PropertyAccess(
+                        SimplePropertyAccess::with_position(lhs, idx, position).into(),
+                    );
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
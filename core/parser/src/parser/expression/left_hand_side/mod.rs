@@ -28,7 +28,7 @@ use crate::{
             },
             AssignmentExpression,
         },
-        AllowAwait, AllowYield, Cursor, ParseResult, TokenParser,
+        AllowAwait, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -117,10 +117,10 @@ where
         cursor.set_goal(InputElement::TemplateTail);
 
         let mut lhs = if is_keyword_call(Keyword::Super, cursor, interner)? {
-            cursor.advance(interner);
+            let position = cursor.next(interner).or_abrupt()?.span().start();
             let args =
                 Arguments::new(self.allow_yield, self.allow_await).parse(cursor, interner)?;
-            SuperCall::new(args).into()
+            SuperCall::with_position(args, position).into()
         } else if is_keyword_call(Keyword::Import, cursor, interner)? {
             // `import`
             cursor.advance(interner);
@@ -130,18 +130,31 @@ where
             let arg = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
+            let mut options = None;
+            if cursor.next_if(Punctuator::Comma, interner)?.is_some()
+                && cursor.peek(0, interner).or_abrupt()?.kind()
+                    != &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                options = Some(
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                        .parse(cursor, interner)?,
+                );
+                cursor.next_if(Punctuator::Comma, interner)?;
+            }
+
             cursor.expect(
                 TokenKind::Punctuator(Punctuator::CloseParen),
                 "import call",
                 interner,
             )?;
 
-            CallExpressionTail::new(
-                self.allow_yield,
-                self.allow_await,
-                ImportCall::new(arg).into(),
-            )
-            .parse(cursor, interner)?
+            let call = match options {
+                Some(options) => ImportCall::with_options(arg, options),
+                None => ImportCall::new(arg),
+            };
+
+            CallExpressionTail::new(self.allow_yield, self.allow_await, call.into())
+                .parse(cursor, interner)?
         } else {
             let mut member = MemberExpression::new(self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
@@ -399,6 +399,17 @@ where
             )));
         }
 
+        // It is a Syntax Error if the goal symbol is Script and UsingDeclaration is not contained,
+        // either directly or indirectly, within a Block, ForStatement, ForInOfStatement,
+        // FunctionBody, GeneratorBody, AsyncGeneratorBody, AsyncFunctionBody, ClassStaticBlockBody,
+        // or ClassBody.
+        if statement::has_using_declaration(&body) {
+            return Err(Error::general(
+                "using declarations are not allowed at the top level of scripts",
+                Position::new(1, 1),
+            ));
+        }
+
         Ok(body)
     }
 }
@@ -1,14 +1,16 @@
 //! Lexical declaration parsing.
 //!
-//! This parses `let` and `const` declarations.
+//! This parses `let`, `const`, `using` and `await using` declarations.
 //!
 //! More information:
 //!  - [ECMAScript specification][spec]
+//!  - [Explicit Resource Management proposal][proposal]
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-let-and-const-declarations
+//! [proposal]: https://tc39.es/proposal-explicit-resource-management/#sec-let-const-and-using-declarations
 
 use crate::{
-    lexer::{Error as LexError, Token, TokenKind},
+    lexer::{token::ContainsEscapeSequence, Error as LexError, Token, TokenKind},
     parser::{
         cursor::{Cursor, SemicolonResult},
         expression::Initializer,
@@ -70,32 +72,52 @@ where
         let _timer = Profiler::global().start_event("LexicalDeclaration", "Parsing");
         let tok = cursor.next(interner).or_abrupt()?;
 
-        let lexical_declaration = match tok.kind() {
-            TokenKind::Keyword((Keyword::Const | Keyword::Let, true)) => {
+        let kind = match tok.kind() {
+            TokenKind::Keyword((Keyword::Const | Keyword::Let | Keyword::Await, true))
+            | TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(true))) => {
                 return Err(Error::general(
                     "Keyword must not contain escaped characters",
                     tok.span().start(),
                 ))
             }
-            TokenKind::Keyword((Keyword::Const, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                true,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
-            TokenKind::Keyword((Keyword::Let, false)) => BindingList::new(
-                self.allow_in,
-                self.allow_yield,
-                self.allow_await,
-                false,
-                self.loop_init,
-            )
-            .parse(cursor, interner)?,
+            TokenKind::Keyword((Keyword::Const, false)) => DeclarationKind::Const,
+            TokenKind::Keyword((Keyword::Let, false)) => DeclarationKind::Let,
+            TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => {
+                DeclarationKind::Using
+            }
+            TokenKind::Keyword((Keyword::Await, false)) => {
+                let using = cursor.next(interner).or_abrupt()?;
+                match using.kind() {
+                    TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(false))) => {}
+                    TokenKind::IdentifierName((Sym::USING, ContainsEscapeSequence(true))) => {
+                        return Err(Error::general(
+                            "Keyword must not contain escaped characters",
+                            using.span().start(),
+                        ))
+                    }
+                    _ => {
+                        return Err(Error::expected(
+                            ["using".to_owned()],
+                            using.to_string(interner),
+                            using.span(),
+                            "await using declaration",
+                        ))
+                    }
+                }
+                DeclarationKind::AwaitUsing
+            }
             _ => unreachable!("unknown token found: {:?}", tok),
         };
 
+        let lexical_declaration = BindingList::new(
+            self.allow_in,
+            self.allow_yield,
+            self.allow_await,
+            kind,
+            self.loop_init,
+        )
+        .parse(cursor, interner)?;
+
         if !self.loop_init {
             cursor.expect_semicolon("lexical declaration", interner)?;
         }
@@ -123,6 +145,56 @@ where
     }
 }
 
+/// Check if the cursor is at the start of a `using` or `await using` declaration.
+///
+/// A line terminator is not allowed after the `await` and `using` keywords of these
+/// declarations, otherwise they are parsed as expressions.
+pub(crate) fn is_using_declaration<R>(
+    cursor: &mut Cursor<R>,
+    allow_await: AllowAwait,
+    interner: &mut Interner,
+) -> ParseResult<bool>
+where
+    R: ReadChar,
+{
+    // `using` declarations are still a proposal, so `using` is parsed as an identifier unless the
+    // `experimental` feature is enabled.
+    if cfg!(not(feature = "experimental")) {
+        return Ok(false);
+    }
+
+    let start = usize::from(
+        cursor
+            .peek_is_line_terminator(0, interner)?
+            .unwrap_or_default(),
+    );
+    let skip_n = match cursor.peek(0, interner)?.map(Token::kind) {
+        Some(TokenKind::IdentifierName((Sym::USING, _))) => start + 1,
+        Some(TokenKind::Keyword((Keyword::Await, _))) if allow_await.0 => {
+            if !matches!(
+                cursor
+                    .peek_no_skip_line_term(start + 1, interner)?
+                    .map(Token::kind),
+                Some(TokenKind::IdentifierName((Sym::USING, _)))
+            ) {
+                return Ok(false);
+            }
+            start + 2
+        }
+        _ => return Ok(false),
+    };
+
+    Ok(matches!(
+        cursor
+            .peek_no_skip_line_term(skip_n, interner)?
+            .map(Token::kind),
+        Some(
+            TokenKind::IdentifierName(_)
+                | TokenKind::Keyword((Keyword::Await | Keyword::Yield | Keyword::Async, _))
+        )
+    ))
+}
+
 /// Check if the given token is valid after the `let` keyword of a lexical declaration.
 pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     matches!(
@@ -138,10 +210,20 @@ pub(crate) fn allowed_token_after_let(token: Option<&Token>) -> bool {
     )
 }
 
+/// The kind of lexical declaration being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeclarationKind {
+    Let,
+    Const,
+    Using,
+    AwaitUsing,
+}
+
 /// Parses a binding list.
 ///
-/// It will return an error if a `const` declaration is being parsed and there is no
-/// initializer.
+/// It will return an error if a `const`, `using` or `await using` declaration is being parsed
+/// and there is no initializer, or if a `using` or `await using` declaration contains a binding
+/// pattern.
 ///
 /// More information:
 ///  - [ECMAScript specification][spec]
@@ -152,7 +234,7 @@ struct BindingList {
     allow_in: AllowIn,
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    is_const: bool,
+    kind: DeclarationKind,
     loop_init: bool,
 }
 
@@ -162,7 +244,7 @@ impl BindingList {
         allow_in: I,
         allow_yield: Y,
         allow_await: A,
-        is_const: bool,
+        kind: DeclarationKind,
         loop_init: bool,
     ) -> Self
     where
@@ -174,7 +256,7 @@ impl BindingList {
             allow_in: allow_in.into(),
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            is_const,
+            kind,
             loop_init,
         }
     }
@@ -194,25 +276,43 @@ where
         let mut decls = Vec::new();
 
         loop {
+            let position = cursor.peek(0, interner).or_abrupt()?.span().start();
             let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor, interner)?;
 
-            if self.is_const {
-                let init_is_some = decl.init().is_some();
-
-                if init_is_some || self.loop_init {
-                    decls.push(decl);
-                } else {
-                    let next = cursor.next(interner).or_abrupt()?;
-                    return Err(Error::general(
-                        "Expected initializer for const declaration",
-                        next.span().start(),
-                    ));
+            match self.kind {
+                DeclarationKind::Let => {}
+                DeclarationKind::Const => {
+                    if decl.init().is_none() && !self.loop_init {
+                        let next = cursor.next(interner).or_abrupt()?;
+                        return Err(Error::general(
+                            "Expected initializer for const declaration",
+                            next.span().start(),
+                        ));
+                    }
+                }
+                DeclarationKind::Using | DeclarationKind::AwaitUsing => {
+                    // It is a Syntax Error if the BoundNames of BindingList contains a binding
+                    // pattern.
+                    if matches!(decl.binding(), ast::declaration::Binding::Pattern(_)) {
+                        return Err(Error::general(
+                            "binding patterns are not allowed in using declarations",
+                            position,
+                        ));
+                    }
+
+                    if decl.init().is_none() && !self.loop_init {
+                        let next = cursor.next(interner).or_abrupt()?;
+                        return Err(Error::general(
+                            "Expected initializer for using declaration",
+                            next.span().start(),
+                        ));
+                    }
                 }
-            } else {
-                decls.push(decl);
             }
 
+            decls.push(decl);
+
             match cursor.peek_semicolon(interner)? {
                 SemicolonResult::Found(_) => break,
                 SemicolonResult::NotFound(tk)
@@ -253,11 +353,12 @@ where
             .try_into()
             .expect("`LexicalBinding` must return at least one variable");
 
-        if self.is_const {
-            Ok(ast::declaration::LexicalDeclaration::Const(decls))
-        } else {
-            Ok(ast::declaration::LexicalDeclaration::Let(decls))
-        }
+        Ok(match self.kind {
+            DeclarationKind::Let => ast::declaration::LexicalDeclaration::Let(decls),
+            DeclarationKind::Const => ast::declaration::LexicalDeclaration::Const(decls),
+            DeclarationKind::Using => ast::declaration::LexicalDeclaration::Using(decls),
+            DeclarationKind::AwaitUsing => ast::declaration::LexicalDeclaration::AwaitUsing(decls),
+        })
     }
 }
 
@@ -20,7 +20,7 @@ pub(in crate::parser) use self::{
         class_decl::ClassTail, ClassDeclaration, FunctionDeclaration, HoistableDeclaration,
     },
     import::ImportDeclaration,
-    lexical::{allowed_token_after_let, LexicalDeclaration},
+    lexical::{allowed_token_after_let, is_using_declaration, LexicalDeclaration},
 };
 use crate::{
     lexer::TokenKind,
@@ -28,7 +28,7 @@ use crate::{
     source::ReadChar,
     Error,
 };
-use boa_ast::{self as ast, Keyword};
+use boa_ast::{self as ast, Keyword, Punctuator};
 use boa_interner::{Interner, Sym};
 use boa_profiler::Profiler;
 
@@ -139,3 +139,115 @@ where
         Ok((*from).into())
     }
 }
+
+/// Parses an optional `with` clause.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithClause
+#[derive(Debug, Clone, Copy)]
+struct WithClause {
+    context: &'static str,
+}
+
+impl WithClause {
+    /// Creates a new `with` clause parser
+    #[inline]
+    const fn new(context: &'static str) -> Self {
+        Self { context }
+    }
+}
+
+impl<R> TokenParser<R> for WithClause
+where
+    R: ReadChar,
+{
+    type Output = Box<[ast::declaration::ImportAttribute]>;
+
+    fn parse(self, cursor: &mut Cursor<R>, interner: &mut Interner) -> ParseResult<Self::Output> {
+        let _timer = Profiler::global().start_event("WithClause", "Parsing");
+
+        match cursor.peek(0, interner)? {
+            Some(tok) if tok.kind() == &TokenKind::Keyword((Keyword::With, false)) => {
+                cursor.advance(interner);
+            }
+            _ => return Ok(Box::default()),
+        }
+
+        cursor.expect(Punctuator::OpenBlock, self.context, interner)?;
+
+        let mut attributes: Vec<ast::declaration::ImportAttribute> = Vec::new();
+
+        loop {
+            let tok = cursor.next(interner).or_abrupt()?;
+            let key = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::StringLiteral((key, _)) | TokenKind::IdentifierName((key, _)) => *key,
+                TokenKind::Keyword((kw, _)) => kw.to_sym(),
+                TokenKind::BooleanLiteral((b, _)) => {
+                    if *b {
+                        Sym::TRUE
+                    } else {
+                        Sym::FALSE
+                    }
+                }
+                TokenKind::NullLiteral(_) => Sym::NULL,
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::CloseBlock.to_string(),
+                            "identifier name".to_owned(),
+                            "string literal".to_owned(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            };
+
+            // It is a Syntax Error if WithClauseToAttributes of WithClause has two different entries
+            // a and b such that a.[[Key]] is b.[[Key]].
+            if attributes.iter().any(|attribute| attribute.key() == key) {
+                return Err(Error::general(
+                    "duplicate key in import attributes",
+                    tok.span().start(),
+                ));
+            }
+
+            cursor.expect(Punctuator::Colon, self.context, interner)?;
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            let TokenKind::StringLiteral((value, _)) = tok.kind() else {
+                return Err(Error::expected(
+                    ["string literal".to_owned()],
+                    tok.to_string(interner),
+                    tok.span(),
+                    self.context,
+                ));
+            };
+
+            attributes.push(ast::declaration::ImportAttribute::new(key, *value));
+
+            let tok = cursor.next(interner).or_abrupt()?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::CloseBlock) => break,
+                TokenKind::Punctuator(Punctuator::Comma) => {}
+                _ => {
+                    return Err(Error::expected(
+                        [
+                            Punctuator::CloseBlock.to_string(),
+                            Punctuator::Comma.to_string(),
+                        ],
+                        tok.to_string(interner),
+                        tok.span(),
+                        self.context,
+                    ))
+                }
+            }
+        }
+
+        Ok(attributes.into_boxed_slice())
+    }
+}
@@ -1,9 +1,12 @@
-use crate::parser::tests::{check_invalid_script, check_module_parser, check_script_parser};
+use crate::parser::tests::{
+    check_invalid_module, check_invalid_script, check_module_parser, check_script_parser,
+};
 use boa_ast::{
     declaration::{
-        ExportDeclaration, ExportSpecifier, LexicalDeclaration, VarDeclaration, Variable,
+        ExportDeclaration, ExportSpecifier, ImportAttribute, ImportDeclaration, ImportKind,
+        LexicalDeclaration, ModuleSpecifier, ReExportKind, VarDeclaration, Variable,
     },
-    expression::literal::Literal,
+    expression::{literal::Literal, Identifier},
     Declaration, ModuleItem, Statement,
 };
 use boa_interner::{Interner, Sym};
@@ -397,3 +400,109 @@ fn module_export_reserved() {
         interner,
     );
 }
+
+/// Checks `using` and `await using` declaration parsing.
+#[cfg(feature = "experimental")]
+#[test]
+fn using_declaration() {
+    let interner = &mut Interner::default();
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    let b = interner.get_or_intern_static("b", utf16!("b"));
+    check_module_parser(
+        "using a = b; await using c = null;",
+        vec![
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::Using(
+                    vec![Variable::from_identifier(
+                        a.into(),
+                        Some(Identifier::new(b).into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+            ModuleItem::StatementListItem(
+                Declaration::Lexical(LexicalDeclaration::AwaitUsing(
+                    vec![Variable::from_identifier(
+                        interner.get_or_intern_static("c", utf16!("c")).into(),
+                        Some(Literal::Null.into()),
+                    )]
+                    .try_into()
+                    .unwrap(),
+                ))
+                .into(),
+            ),
+        ],
+        interner,
+    );
+}
+
+/// Checks `using` declaration early errors.
+#[cfg(feature = "experimental")]
+#[test]
+fn using_declaration_early_errors() {
+    check_invalid_script("using a = b;");
+    check_invalid_script("{ using a; }");
+    check_invalid_script("{ using a = b, [c] = d; }");
+    check_invalid_script("{ using a = b, a = c; }");
+    check_invalid_script("switch (x) { case 0: using a = b; }");
+    check_invalid_script("for (using a in b) {}");
+    check_invalid_script("function f() { await using a = b; }");
+}
+
+/// Checks import attributes parsing on imports and re-exports.
+#[test]
+fn import_attributes() {
+    let interner = &mut Interner::default();
+    let data = interner.get_or_intern_static("data", utf16!("data"));
+    let data_json = interner.get_or_intern_static("./data.json", utf16!("./data.json"));
+    let module_js = interner.get_or_intern_static("./module.js", utf16!("./module.js"));
+    let ty = interner.get_or_intern_static("type", utf16!("type"));
+    let json = interner.get_or_intern_static("json", utf16!("json"));
+    let other = interner.get_or_intern_static("other", utf16!("other"));
+    let a = interner.get_or_intern_static("a", utf16!("a"));
+    check_module_parser(
+        r#"
+            import data from "./data.json" with { type: "json" };
+            import "./module.js" with {};
+            export { a } from "./module.js" with { "type": "json", other: "a", };
+        "#,
+        vec![
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                Some(data.into()),
+                ImportKind::DefaultOrUnnamed,
+                ModuleSpecifier::new(data_json),
+                vec![ImportAttribute::new(ty, json)].into(),
+            )),
+            ModuleItem::ImportDeclaration(ImportDeclaration::new(
+                None,
+                ImportKind::DefaultOrUnnamed,
+                ModuleSpecifier::new(module_js),
+                Box::default(),
+            )),
+            ModuleItem::ExportDeclaration(ExportDeclaration::ReExport {
+                kind: ReExportKind::Named {
+                    names: vec![ExportSpecifier::new(a, a, false)].into(),
+                },
+                specifier: ModuleSpecifier::new(module_js),
+                attributes: vec![
+                    ImportAttribute::new(ty, json),
+                    ImportAttribute::new(other, a),
+                ]
+                .into(),
+            }),
+        ],
+        interner,
+    );
+}
+
+/// Checks import attributes early errors.
+#[test]
+fn import_attributes_early_errors() {
+    check_invalid_module(r#"import a from "a" with { type: "json", type: "json" };"#);
+    check_invalid_module(r#"import a from "a" with { type: "json", "type": "css" };"#);
+    check_invalid_module(r#"import a from "a" with { type: json };"#);
+    check_invalid_module(r#"import a from "a" with { type: "json" "#);
+    check_invalid_module(r#"export * from "a" with { , };"#);
+}
@@ -12,7 +12,7 @@ use crate::{
     parser::{
         expression::{AssignmentExpression, Expression},
         statement::{
-            declaration::{allowed_token_after_let, LexicalDeclaration},
+            declaration::{allowed_token_after_let, is_using_declaration, LexicalDeclaration},
             variable::VariableDeclarationList,
             Statement,
         },
@@ -130,6 +130,16 @@ where
                         .into(),
                 )
             }
+            TokenKind::IdentifierName((Sym::USING, _))
+            | TokenKind::Keyword((Keyword::Await, _))
+                if is_using_declaration(cursor, self.allow_await, interner)? =>
+            {
+                Some(
+                    LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
+                        .parse(cursor, interner)?
+                        .into(),
+                )
+            }
             TokenKind::Keyword((Keyword::Const, _)) => Some(
                 LexicalDeclaration::new(false, self.allow_yield, self.allow_await, true)
                     .parse(cursor, interner)?
@@ -225,7 +235,10 @@ where
                 // Checks are only applicable to lexical bindings.
                 if matches!(
                     &init,
-                    IterableLoopInitializer::Const(_) | IterableLoopInitializer::Let(_)
+                    IterableLoopInitializer::Const(_)
+                        | IterableLoopInitializer::Let(_)
+                        | IterableLoopInitializer::Using(_)
+                        | IterableLoopInitializer::AwaitUsing(_)
                 ) {
                     // It is a Syntax Error if the BoundNames of ForDeclaration contains "let".
                     // It is a Syntax Error if any element of the BoundNames of ForDeclaration also occurs in the VarDeclaredNames of Statement.
@@ -263,11 +276,16 @@ where
         };
 
         if let Some(ForLoopInitializer::Lexical(initializer)) = &init {
-            if let ast::declaration::LexicalDeclaration::Const(list) = initializer.declaration() {
-                for decl in list.as_ref() {
+            let declaration = initializer.declaration();
+            if declaration.is_const() {
+                for decl in declaration.variable_list().as_ref() {
                     if decl.init().is_none() {
                         return Err(Error::general(
-                            "Expected initializer for const declaration",
+                            if declaration.is_using() {
+                                "Expected initializer for using declaration"
+                            } else {
+                                "Expected initializer for const declaration"
+                            },
                             position,
                         ));
                     }
@@ -390,6 +408,13 @@ fn initializer_to_iterable_loop_initializer(
                         position,
                     )));
                     }
+                    if in_loop && initializer.declaration().is_using() {
+                        return Err(Error::lex(LexError::Syntax(
+                            "using declarations are not allowed in the head of a for-in loop"
+                                .into(),
+                            position,
+                        )));
+                    }
                     Ok(match initializer.declaration() {
                         ast::declaration::LexicalDeclaration::Const(_) => {
                             IterableLoopInitializer::Const(decl.binding().clone())
@@ -397,6 +422,12 @@ fn initializer_to_iterable_loop_initializer(
                         ast::declaration::LexicalDeclaration::Let(_) => {
                             IterableLoopInitializer::Let(decl.binding().clone())
                         }
+                        ast::declaration::LexicalDeclaration::Using(_) => {
+                            IterableLoopInitializer::Using(decl.binding().clone())
+                        }
+                        ast::declaration::LexicalDeclaration::AwaitUsing(_) => {
+                            IterableLoopInitializer::AwaitUsing(decl.binding().clone())
+                        }
                     })
                 }
                 _ => Err(Error::lex(LexError::Syntax(
@@ -26,7 +26,10 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::{allowed_token_after_let, Declaration, ExportDeclaration, ImportDeclaration},
+    declaration::{
+        allowed_token_after_let, is_using_declaration, Declaration, ExportDeclaration,
+        ImportDeclaration, LexicalDeclaration,
+    },
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -56,7 +59,7 @@ use boa_ast::{
     pattern::{ArrayPattern, ArrayPatternElement, ObjectPatternElement},
     Keyword, Punctuator,
 };
-use boa_interner::Interner;
+use boa_interner::{Interner, Sym};
 use boa_macros::utf16;
 use boa_profiler::Profiler;
 
@@ -369,6 +372,16 @@ where
     }
 }
 
+/// Returns `true` if the statement list directly contains a `using` or `await using` declaration.
+pub(in crate::parser) fn has_using_declaration(list: &ast::StatementList) -> bool {
+    list.statements().iter().any(|item| {
+        matches!(
+            item,
+            ast::StatementListItem::Declaration(ast::Declaration::Lexical(decl)) if decl.is_using()
+        )
+    })
+}
+
 /// Statement list item parsing
 ///
 /// A statement list item can either be an statement or a declaration.
@@ -425,6 +438,14 @@ where
                     .parse(cursor, interner)
                     .map(ast::StatementListItem::from)
             }
+            TokenKind::IdentifierName((Sym::USING, _))
+            | TokenKind::Keyword((Keyword::Await, _))
+                if is_using_declaration(cursor, self.allow_await, interner)? =>
+            {
+                LexicalDeclaration::new(true, self.allow_yield, self.allow_await, false)
+                    .parse(cursor, interner)
+                    .map(|decl| ast::StatementListItem::Declaration(decl.into()))
+            }
             TokenKind::Keyword((Keyword::Async, false)) => {
                 let skip_n = if cursor.peek_is_line_terminator(0, interner).or_abrupt()? {
                     2
@@ -4,8 +4,9 @@ mod tests;
 use crate::{
     lexer::TokenKind,
     parser::{
-        expression::Expression, statement::StatementList, AllowAwait, AllowReturn, AllowYield,
-        Cursor, OrAbrupt, ParseResult, TokenParser,
+        expression::Expression,
+        statement::{has_using_declaration, StatementList},
+        AllowAwait, AllowReturn, AllowYield, Cursor, OrAbrupt, ParseResult, TokenParser,
     },
     source::ReadChar,
     Error,
@@ -177,6 +178,13 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    if has_using_declaration(&statement_list) {
+                        return Err(Error::general(
+                            "using declarations are not allowed directly in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::new(cond, statement_list));
                 }
                 TokenKind::Keyword((Keyword::Default, false)) => {
@@ -201,6 +209,13 @@ where
                     )
                     .parse(cursor, interner)?;
 
+                    if has_using_declaration(&statement_list) {
+                        return Err(Error::general(
+                            "using declarations are not allowed directly in switch cases",
+                            token.span().start(),
+                        ));
+                    }
+
                     cases.push(statement::Case::default(statement_list));
 
                     has_default_case = true;
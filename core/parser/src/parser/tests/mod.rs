@@ -72,6 +72,14 @@ pub(super) fn check_invalid_script(js: &str) {
         .is_err());
 }
 
+/// Checks that the given javascript string creates a parse error when parsed as a module.
+#[track_caller]
+pub(super) fn check_invalid_module(js: &str) {
+    assert!(Parser::new(Source::from_bytes(js))
+        .parse_module(&Scope::new_global(), &mut Interner::default())
+        .is_err());
+}
+
 /// Should be parsed as `new Class().method()` instead of `new (Class().method())`
 #[test]
 fn check_construct_call_precedence() {
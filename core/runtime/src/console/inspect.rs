@@ -0,0 +1,496 @@
+//! Rendering of JavaScript values for the `console` object.
+//!
+//! The output follows the format used by other JavaScript runtimes: nested objects are rendered
+//! up to a depth, cyclic references are marked as `[Circular]`, and values are highlighted with
+//! ANSI colours if the logger output supports them.
+
+use boa_engine::{
+    builtins::error::Error,
+    js_string,
+    object::builtins::{JsArrayBuffer, JsDate, JsMap, JsRegExp, JsSet, JsTypedArray},
+    property::{PropertyDescriptor, PropertyKey},
+    Context, JsBigInt, JsObject, JsResult, JsString, JsSymbol, JsValue,
+};
+
+/// The maximum number of elements of arrays, maps and sets that are rendered.
+const MAX_ENTRIES: usize = 100;
+
+/// The maximum width of a single line rendering of an object.
+const BREAK_LENGTH: usize = 72;
+
+/// Options of the value inspector.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct InspectOptions {
+    /// The number of nested objects that are rendered, or `None` to render all of them.
+    pub(crate) depth: Option<usize>,
+
+    /// Whether values are highlighted with ANSI colours.
+    pub(crate) colors: bool,
+
+    /// Whether objects are always rendered in a single line.
+    pub(crate) single_line: bool,
+}
+
+impl Default for InspectOptions {
+    fn default() -> Self {
+        Self {
+            depth: Some(2),
+            colors: false,
+            single_line: false,
+        }
+    }
+}
+
+/// The styles used to highlight the different kinds of values.
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Number,
+    String,
+    Symbol,
+    Null,
+    Undefined,
+    Special,
+    Date,
+    RegExp,
+}
+
+impl Style {
+    /// Returns the ANSI codes that enable and disable the style.
+    const fn codes(self) -> (u8, u8) {
+        match self {
+            Self::Number => (33, 39),
+            Self::String | Self::Symbol => (32, 39),
+            Self::Null => (1, 22),
+            Self::Undefined => (90, 39),
+            Self::Special => (36, 39),
+            Self::Date => (35, 39),
+            Self::RegExp => (31, 39),
+        }
+    }
+}
+
+/// Renders `value` in a human readable format.
+pub(crate) fn inspect(
+    value: &JsValue,
+    options: InspectOptions,
+    context: &mut Context,
+) -> JsResult<String> {
+    Inspector {
+        options,
+        seen: Vec::new(),
+    }
+    .value(value, 0, 0, context)
+}
+
+/// Returns the length of `text` as displayed in a terminal, ignoring ANSI escape sequences.
+pub(crate) fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip the rest of the escape sequence, which ends with `m`.
+            for c in chars.by_ref() {
+                if c == 'm' {
+                    break;
+                }
+            }
+        } else {
+            len += 1;
+        }
+    }
+    len
+}
+
+/// Quotes a string with single quotes, escaping the characters that need it.
+fn quote(string: &JsString) -> String {
+    let mut quoted = String::from("'");
+    for c in string.to_std_string_escaped().chars() {
+        match c {
+            '\'' => quoted.push_str("\\'"),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
+
+/// Renders a property key, quoting it if it's not a valid identifier.
+fn format_key(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::Symbol(symbol) => {
+            format!("[{}]", symbol.descriptive_string().to_std_string_escaped())
+        }
+        PropertyKey::Index(index) => index.get().to_string(),
+        PropertyKey::String(string) => {
+            let name = string.to_std_string_escaped();
+            let mut chars = name.chars();
+            let is_identifier = chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+            if is_identifier {
+                name
+            } else {
+                quote(string)
+            }
+        }
+    }
+}
+
+struct Inspector {
+    options: InspectOptions,
+
+    /// The objects currently being rendered, used to detect cycles.
+    seen: Vec<JsObject>,
+}
+
+impl Inspector {
+    fn stylize(&self, text: String, style: Style) -> String {
+        if !self.options.colors {
+            return text;
+        }
+        let (start, end) = style.codes();
+        format!("\x1b[{start}m{text}\x1b[{end}m")
+    }
+
+    fn value(
+        &mut self,
+        value: &JsValue,
+        depth: usize,
+        indent: usize,
+        context: &mut Context,
+    ) -> JsResult<String> {
+        if let Some(object) = value.as_object() {
+            return self.object(object, depth, indent, context);
+        }
+
+        Ok(if let Some(string) = value.as_string() {
+            self.stylize(quote(string), Style::String)
+        } else if let Some(symbol) = value.as_symbol() {
+            self.stylize(
+                symbol.descriptive_string().to_std_string_escaped(),
+                Style::Symbol,
+            )
+        } else if value.is_undefined() {
+            self.stylize(String::from("undefined"), Style::Undefined)
+        } else if value.is_null() {
+            self.stylize(String::from("null"), Style::Null)
+        } else {
+            self.stylize(value.display().to_string(), Style::Number)
+        })
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn object(
+        &mut self,
+        object: &JsObject,
+        depth: usize,
+        indent: usize,
+        context: &mut Context,
+    ) -> JsResult<String> {
+        if self.seen.iter().any(|seen| JsObject::equals(seen, object)) {
+            return Ok(self.stylize(String::from("[Circular]"), Style::Special));
+        }
+
+        let name = constructor_name(object, context)?;
+        let tag = object
+            .get(JsSymbol::to_string_tag(), context)?
+            .as_string()
+            .map(JsString::to_std_string_escaped)
+            .filter(|tag| !tag.is_empty() && Some(tag) != name.as_ref());
+        let mut prefix = match (&name, &tag) {
+            (Some(name), Some(tag)) => format!("{name} [{tag}] "),
+            (None, Some(tag)) => format!("[{tag}: null prototype] "),
+            (Some(name), None) if name != "Object" => format!("{name} "),
+            (Some(_), None) => String::new(),
+            (None, None) => String::from("[Object: null prototype] "),
+        };
+
+        // Objects rendered as a single value, followed by their own properties.
+        let base = if object.is_callable() {
+            let function_name = object
+                .get(js_string!("name"), context)?
+                .as_string()
+                .map(JsString::to_std_string_escaped)
+                .filter(|name| !name.is_empty());
+            Some(self.stylize(
+                match function_name {
+                    Some(name) => format!("[Function: {name}]"),
+                    None => String::from("[Function (anonymous)]"),
+                },
+                Style::Special,
+            ))
+        } else if let Ok(date) = JsDate::from_object(object.clone()) {
+            let time = date.get_time(context)?;
+            let text = if time.as_number().is_some_and(f64::is_finite) {
+                date.to_iso_string(context)?
+                    .to_string(context)?
+                    .to_std_string_escaped()
+            } else {
+                String::from("Invalid Date")
+            };
+            Some(self.stylize(text, Style::Date))
+        } else if let Ok(regexp) = JsRegExp::from_object(object.clone()) {
+            Some(self.stylize(regexp.to_string(context)?, Style::RegExp))
+        } else if object.is::<Error>() {
+            let error_name = object.get(js_string!("name"), context)?;
+            let message = object.get(js_string!("message"), context)?;
+            let error_name = error_name.to_string(context)?.to_std_string_escaped();
+            let message = message.to_string(context)?.to_std_string_escaped();
+            Some(if message.is_empty() {
+                error_name
+            } else {
+                format!("{error_name}: {message}")
+            })
+        } else if let Some((kind, primitive)) = boxed_primitive(object) {
+            let value = self.value(&primitive, 0, 0, context)?;
+            Some(format!("[{kind}: {value}]"))
+        } else {
+            None
+        };
+
+        let is_array = object.is_array();
+        let typed_array = JsTypedArray::from_object(object.clone()).ok();
+        let map = JsMap::from_object(object.clone()).ok();
+        let set = JsSet::from_object(object.clone()).ok();
+        let skip_indices = is_array || typed_array.is_some() || object.is::<JsString>();
+
+        if base.is_none() && self.options.depth.is_some_and(|max| depth > max) {
+            let name = if is_array {
+                String::from("Array")
+            } else {
+                name.unwrap_or_else(|| String::from("Object: null prototype"))
+            };
+            return Ok(self.stylize(format!("[{name}]"), Style::Special));
+        }
+
+        self.seen.push(object.clone());
+        let mut entries = Vec::new();
+        let mut brackets = ("{", "}");
+
+        if let Some(array) = &typed_array {
+            let length = array.length(context)?;
+            let tag = array
+                .to_string_tag(context)?
+                .to_string(context)?
+                .to_std_string_escaped();
+            prefix = format!("{tag}({length}) ");
+            brackets = ("[", "]");
+            for index in 0..length.min(MAX_ENTRIES) {
+                let value = object.get(index, context)?;
+                entries.push(self.value(&value, depth + 1, indent + 2, context)?);
+            }
+            if length > MAX_ENTRIES {
+                entries.push(format!("... {} more items", length - MAX_ENTRIES));
+            }
+        } else if is_array {
+            let length = object
+                .get(js_string!("length"), context)?
+                .to_length(context)?;
+            let length = usize::try_from(length).unwrap_or(usize::MAX);
+            // Plain arrays have no prefix, unlike subclasses of `Array`.
+            prefix = if name.as_deref() == Some("Array") && tag.is_none() {
+                String::new()
+            } else {
+                format!("{}({length}) ", prefix.trim_end())
+            };
+            brackets = ("[", "]");
+            let mut holes = 0;
+            for index in 0..length.min(MAX_ENTRIES) {
+                if !object.has_own_property(index, context)? {
+                    holes += 1;
+                    continue;
+                }
+                if holes > 0 {
+                    entries.push(empty_items(holes));
+                    holes = 0;
+                }
+                let value = object.get(index, context)?;
+                entries.push(self.value(&value, depth + 1, indent + 2, context)?);
+            }
+            if holes > 0 {
+                entries.push(empty_items(holes));
+            }
+            if length > MAX_ENTRIES {
+                entries.push(format!("... {} more items", length - MAX_ENTRIES));
+            }
+        } else if let Some(map) = &map {
+            let mut pairs = Vec::new();
+            map.for_each_native(|key, value| {
+                pairs.push((key, value));
+                Ok(())
+            })?;
+            prefix = format!("{}({}) ", prefix.trim_end(), pairs.len());
+            for (key, value) in pairs.iter().take(MAX_ENTRIES) {
+                let key = self.value(key, depth + 1, indent + 2, context)?;
+                let value = self.value(value, depth + 1, indent + 2, context)?;
+                entries.push(format!("{key} => {value}"));
+            }
+            if pairs.len() > MAX_ENTRIES {
+                entries.push(format!("... {} more items", pairs.len() - MAX_ENTRIES));
+            }
+        } else if let Some(set) = &set {
+            let values = set_values(set, context)?;
+            prefix = format!("{}({}) ", prefix.trim_end(), values.len());
+            for value in values.iter().take(MAX_ENTRIES) {
+                entries.push(self.value(value, depth + 1, indent + 2, context)?);
+            }
+            if values.len() > MAX_ENTRIES {
+                entries.push(format!("... {} more items", values.len() - MAX_ENTRIES));
+            }
+        } else if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+            let length = self.stylize(buffer.byte_length().to_string(), Style::Number);
+            entries.push(format!("byteLength: {length}"));
+        }
+
+        for (key, descriptor) in own_enumerable_properties(object, context)? {
+            if skip_indices && matches!(key, PropertyKey::Index(_)) {
+                continue;
+            }
+
+            let value = if descriptor.is_accessor_descriptor() {
+                let getter = descriptor.get().is_some_and(|get| !get.is_undefined());
+                let setter = descriptor.set().is_some_and(|set| !set.is_undefined());
+                let text = match (getter, setter) {
+                    (true, true) => "[Getter/Setter]",
+                    (true, false) => "[Getter]",
+                    _ => "[Setter]",
+                };
+                self.stylize(String::from(text), Style::Special)
+            } else {
+                let value = descriptor.value().cloned().unwrap_or_default();
+                self.value(&value, depth + 1, indent + 2, context)?
+            };
+            entries.push(format!("{}: {value}", format_key(&key)));
+        }
+
+        self.seen.pop();
+
+        if let Some(base) = base {
+            if entries.is_empty() {
+                return Ok(base);
+            }
+            prefix = format!("{base} ");
+        }
+
+        Ok(self.reduce(&prefix, &entries, brackets, indent))
+    }
+
+    /// Joins the rendered entries of an object, breaking them in multiple lines if they don't
+    /// fit in a single one.
+    fn reduce(
+        &self,
+        prefix: &str,
+        entries: &[String],
+        (open, close): (&str, &str),
+        indent: usize,
+    ) -> String {
+        if entries.is_empty() {
+            return format!("{prefix}{open}{close}");
+        }
+
+        let single_line = format!("{prefix}{open} {} {close}", entries.join(", "));
+        if self.options.single_line
+            || (!single_line.contains('\n') && indent + visible_len(&single_line) <= BREAK_LENGTH)
+        {
+            return single_line;
+        }
+
+        let padding = " ".repeat(indent + 2);
+        format!(
+            "{prefix}{open}\n{padding}{}\n{}{close}",
+            entries.join(&format!(",\n{padding}")),
+            " ".repeat(indent)
+        )
+    }
+}
+
+/// Gets the own enumerable properties of an object, with their descriptors.
+pub(crate) fn own_enumerable_properties(
+    object: &JsObject,
+    context: &mut Context,
+) -> JsResult<Vec<(PropertyKey, PropertyDescriptor)>> {
+    let mut properties = Vec::new();
+    for key in object.own_property_keys(context)? {
+        let Some(descriptor) = object.__get_own_property__(&key, &mut context.into())? else {
+            continue;
+        };
+        if descriptor.expect_enumerable() {
+            properties.push((key, descriptor));
+        }
+    }
+    Ok(properties)
+}
+
+/// Gets the name of the constructor of an object, or `None` if it has a null prototype.
+fn constructor_name(object: &JsObject, context: &mut Context) -> JsResult<Option<String>> {
+    let mut prototype = object.prototype();
+    if prototype.is_none() {
+        return Ok(None);
+    }
+
+    while let Some(current) = prototype {
+        if current.has_own_property(js_string!("constructor"), context)? {
+            let constructor = current.get(js_string!("constructor"), context)?;
+            if let Some(constructor) = constructor.as_object() {
+                let name = constructor.get(js_string!("name"), context)?;
+                if let Some(name) = name.as_string().filter(|name| !name.is_empty()) {
+                    return Ok(Some(name.to_std_string_escaped()));
+                }
+            }
+        }
+        prototype = current.prototype();
+    }
+
+    Ok(Some(String::from("Object")))
+}
+
+/// Gets the kind and primitive value of a `Boolean`, `Number`, `String`, `Symbol` or `BigInt`
+/// object.
+fn boxed_primitive(object: &JsObject) -> Option<(&'static str, JsValue)> {
+    if let Some(boolean) = object.downcast_ref::<bool>() {
+        return Some(("Boolean", JsValue::new(*boolean)));
+    }
+    if let Some(number) = object.downcast_ref::<f64>() {
+        return Some(("Number", JsValue::new(*number)));
+    }
+    if let Some(string) = object.downcast_ref::<JsString>() {
+        return Some(("String", JsValue::new(string.clone())));
+    }
+    if let Some(symbol) = object.downcast_ref::<JsSymbol>() {
+        return Some(("Symbol", JsValue::new(symbol.clone())));
+    }
+    if let Some(bigint) = object.downcast_ref::<JsBigInt>() {
+        return Some(("BigInt", JsValue::new(bigint.clone())));
+    }
+    None
+}
+
+/// Collects the values of a set.
+fn set_values(set: &JsSet, context: &mut Context) -> JsResult<Vec<JsValue>> {
+    let iterator = set.values(context)?;
+    let mut values = Vec::new();
+    loop {
+        let result = iterator.next(context)?;
+        let Some(result) = result.as_object() else {
+            break;
+        };
+        if result.get(js_string!("done"), context)?.to_boolean() {
+            break;
+        }
+        values.push(result.get(js_string!("value"), context)?);
+    }
+    Ok(values)
+}
+
+fn empty_items(count: usize) -> String {
+    if count == 1 {
+        String::from("<1 empty item>")
+    } else {
+        format!("<{count} empty items>")
+    }
+}
@@ -11,9 +11,13 @@
 //! [spec]: https://console.spec.whatwg.org/
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Console
 
+mod inspect;
+mod table;
 #[cfg(test)]
 mod tests;
 
+use inspect::{inspect, InspectOptions};
+
 use boa_engine::property::Attribute;
 use boa_engine::{
     js_str, js_string,
@@ -24,7 +28,13 @@ use boa_engine::{
 };
 use boa_gc::{Finalize, Trace};
 use rustc_hash::FxHashMap;
-use std::{cell::RefCell, collections::hash_map::Entry, io::Write, rc::Rc, time::SystemTime};
+use std::{
+    cell::RefCell,
+    collections::hash_map::Entry,
+    io::{IsTerminal, Write},
+    rc::Rc,
+    time::SystemTime,
+};
 
 /// A trait that can be used to forward console logs to an implementation.
 pub trait Logger: Trace + Sized {
@@ -59,6 +69,28 @@ pub trait Logger: Trace + Sized {
     /// # Errors
     /// Returning an error will throw an exception in JavaScript.
     fn error(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()>;
+
+    /// Returns `true` if the output of the logger for messages of the given `level` is a
+    /// terminal, in which case logged values are highlighted with ANSI colours. By default,
+    /// returns `false`.
+    fn is_terminal(&self, _level: LogLevel) -> bool {
+        false
+    }
+}
+
+/// The level of a message passed to a [`Logger`], which matches the method used to log it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// A message logged with [`Logger::debug`].
+    Debug,
+    /// A message logged with [`Logger::log`].
+    Log,
+    /// A message logged with [`Logger::info`].
+    Info,
+    /// A message logged with [`Logger::warn`].
+    Warn,
+    /// A message logged with [`Logger::error`].
+    Error,
 }
 
 /// The default implementation for logging from the console.
@@ -91,23 +123,52 @@ impl Logger for DefaultLogger {
         let indent = state.indent();
         writeln!(std::io::stderr(), "{msg:>indent$}").map_err(JsError::from_rust)
     }
+
+    #[inline]
+    fn is_terminal(&self, level: LogLevel) -> bool {
+        // Errors are the only messages written to stderr.
+        match level {
+            LogLevel::Error => std::io::stderr().is_terminal(),
+            LogLevel::Debug | LogLevel::Log | LogLevel::Info | LogLevel::Warn => {
+                std::io::stdout().is_terminal()
+            }
+        }
+    }
 }
 
 /// This represents the `console` formatter.
-fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
-    fn to_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
-        // There is a slight difference between the standard [`JsValue::to_string`] and
-        // the way Console actually logs, w.r.t Symbols.
-        if let Some(s) = value.as_symbol() {
-            Ok(s.to_string())
+///
+/// If `colors` is `true`, the values that are not strings are highlighted with ANSI colours.
+#[allow(clippy::too_many_lines)]
+fn formatter(data: &[JsValue], colors: bool, context: &mut Context) -> JsResult<String> {
+    fn to_string(
+        value: &JsValue,
+        options: InspectOptions,
+        context: &mut Context,
+    ) -> JsResult<String> {
+        // Strings are printed as they are, while the rest of values are inspected.
+        if let Some(s) = value.as_string() {
+            Ok(s.to_std_string_escaped())
         } else {
-            Ok(value.to_string(context)?.to_std_string_escaped())
+            inspect(value, options, context)
         }
     }
 
+    let options = InspectOptions {
+        colors,
+        ..InspectOptions::default()
+    };
+
     match data {
         [] => Ok(String::new()),
-        [val] => to_string(val, context),
+        [val] => to_string(val, options, context),
+        [first, ..] if !first.is_string() => {
+            let data = data
+                .iter()
+                .map(|value| to_string(value, options, context))
+                .collect::<JsResult<Vec<_>>>()?;
+            Ok(data.join(" "))
+        }
         data => {
             let mut formatted = String::new();
             let mut arg_index = 1;
@@ -135,10 +196,18 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
                             formatted.push_str(&format!("{arg:.6}"));
                             arg_index += 1;
                         }
-                        /* object, FIXME: how to render this properly? */
+                        /* object, with a deeper inspection for `%o` */
                         'o' | 'O' => {
                             let arg = data.get_or_undefined(arg_index);
-                            formatted.push_str(&arg.display().to_string());
+                            let options = if fmt == 'o' {
+                                InspectOptions {
+                                    depth: Some(4),
+                                    ..options
+                                }
+                            } else {
+                                options
+                            };
+                            formatted.push_str(&inspect(arg, options, context)?);
                             arg_index += 1;
                         }
                         /* string */
@@ -166,8 +235,9 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
 
                             arg_index += 1;
                         }
+                        /* CSS styles, which are ignored */
+                        'c' => arg_index += 1,
                         '%' => formatted.push('%'),
-                        /* TODO: %c is not implemented */
                         c => {
                             formatted.push('%');
                             formatted.push(c);
@@ -181,7 +251,7 @@ fn formatter(data: &[JsValue], context: &mut Context) -> JsResult<String> {
             /* unformatted data */
             for rest in data.iter().skip(arg_index) {
                 formatted.push(' ');
-                formatted.push_str(&to_string(rest, context)?);
+                formatted.push_str(&to_string(rest, options, context)?);
             }
 
             Ok(formatted)
@@ -390,6 +460,11 @@ impl Console {
             js_string!("dir"),
             0,
         )
+        .function(
+            console_method(Self::table, state.clone(), logger.clone()),
+            js_string!("table"),
+            0,
+        )
         .function(
             console_method(Self::dir, state, logger.clone()),
             js_string!("dirxml"),
@@ -436,7 +511,11 @@ impl Console {
                 args[0] = JsValue::new(concat);
             }
 
-            logger.error(formatter(&args, context)?, &console.state, context)?;
+            logger.error(
+                formatter(&args, logger.is_terminal(LogLevel::Error), context)?,
+                &console.state,
+                context,
+            )?;
         }
 
         Ok(JsValue::undefined())
@@ -481,7 +560,11 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger.debug(formatter(args, context)?, &console.state, context)?;
+        logger.debug(
+            formatter(args, logger.is_terminal(LogLevel::Debug), context)?,
+            &console.state,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -502,7 +585,11 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger.error(formatter(args, context)?, &console.state, context)?;
+        logger.error(
+            formatter(args, logger.is_terminal(LogLevel::Error), context)?,
+            &console.state,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -523,7 +610,11 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger.info(formatter(args, context)?, &console.state, context)?;
+        logger.info(
+            formatter(args, logger.is_terminal(LogLevel::Info), context)?,
+            &console.state,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -544,7 +635,11 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger.log(formatter(args, context)?, &console.state, context)?;
+        logger.log(
+            formatter(args, logger.is_terminal(LogLevel::Log), context)?,
+            &console.state,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -566,7 +661,11 @@ impl Console {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         if !args.is_empty() {
-            logger.log(formatter(args, context)?, &console.state, context)?;
+            logger.log(
+                formatter(args, logger.is_terminal(LogLevel::Log), context)?,
+                &console.state,
+                context,
+            )?;
         }
 
         let stack_trace_dump = context
@@ -599,7 +698,11 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        logger.warn(formatter(args, context)?, &console.state, context)?;
+        logger.warn(
+            formatter(args, logger.is_terminal(LogLevel::Warn), context)?,
+            &console.state,
+            context,
+        )?;
         Ok(JsValue::undefined())
     }
 
@@ -735,8 +838,12 @@ impl Console {
         if let Some(t) = console.state.timer_map.get(&label) {
             let time = Self::system_time_in_ms();
             let mut concat = format!("{}: {} ms", label.to_std_string_escaped(), time - t);
+            let options = InspectOptions {
+                colors: logger.is_terminal(LogLevel::Log),
+                ..InspectOptions::default()
+            };
             for msg in args.iter().skip(1) {
-                concat = concat + " " + &msg.display().to_string();
+                concat = concat + " " + &inspect(msg, options, context)?;
             }
             logger.log(concat, &console.state, context)?;
         } else {
@@ -811,7 +918,7 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let group_label = formatter(args, context)?;
+        let group_label = formatter(args, false, context)?;
 
         logger.info(format!("group: {group_label}"), &console.state, context)?;
         console.state.groups.push(group_label);
@@ -864,7 +971,9 @@ impl Console {
 
     /// `console.dir(item, options)`
     ///
-    /// Prints info about item
+    /// Prints info about item. The `depth` option sets how many nested objects are printed,
+    /// with `null` or `Infinity` printing all of them, and the `colors` option overrides
+    /// the highlighting of the logger.
     ///
     /// More information:
     ///  - [MDN documentation][mdn]
@@ -872,7 +981,7 @@ impl Console {
     ///
     /// [spec]: https://console.spec.whatwg.org/#dir
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/dir
-    #[allow(clippy::unnecessary_wraps)]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     fn dir(
         _: &JsValue,
         args: &[JsValue],
@@ -880,8 +989,77 @@ impl Console {
         logger: &impl Logger,
         context: &mut Context,
     ) -> JsResult<JsValue> {
+        let mut options = InspectOptions {
+            colors: logger.is_terminal(LogLevel::Info),
+            ..InspectOptions::default()
+        };
+
+        if let Some(dir_options) = args.get_or_undefined(1).as_object() {
+            let depth = dir_options.get(js_string!("depth"), context)?;
+            if depth.is_null() {
+                options.depth = None;
+            } else if !depth.is_undefined() {
+                let depth = depth.to_number(context)?;
+                if depth == f64::INFINITY {
+                    options.depth = None;
+                } else if depth.is_finite() {
+                    options.depth = Some(depth.max(0.0) as usize);
+                }
+            }
+
+            let colors = dir_options.get(js_string!("colors"), context)?;
+            if !colors.is_undefined() {
+                options.colors = colors.to_boolean();
+            }
+        }
+
         logger.info(
-            args.get_or_undefined(0).display_obj(true),
+            inspect(args.get_or_undefined(0), options, context)?,
+            &console.state,
+            context,
+        )?;
+        Ok(JsValue::undefined())
+    }
+
+    /// `console.table(tabularData, properties)`
+    ///
+    /// Prints the properties of `tabularData` as a table, only including the columns listed
+    /// in `properties` if it is provided. Values that are not objects are logged as they are.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [WHATWG `console` specification][spec]
+    ///
+    /// [spec]: https://console.spec.whatwg.org/#table
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/table
+    fn table(
+        this: &JsValue,
+        args: &[JsValue],
+        console: &Self,
+        logger: &impl Logger,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let Some(data) = args.get_or_undefined(0).as_object() else {
+            return Self::log(this, args, console, logger, context);
+        };
+
+        let properties = match args.get_or_undefined(1).as_object() {
+            Some(properties) if properties.is_array() => {
+                let length = properties
+                    .get(js_string!("length"), context)?
+                    .to_length(context)?;
+                let mut names = Vec::new();
+                for index in 0..length {
+                    let name = properties.get(index, context)?;
+                    names.push(name.to_string(context)?.to_std_string_escaped());
+                }
+                Some(names)
+            }
+            _ => None,
+        };
+
+        logger.log(
+            table::table(data, properties.as_deref(), context)?,
             &console.state,
             context,
         )?;
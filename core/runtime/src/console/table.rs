@@ -0,0 +1,171 @@
+//! Rendering of tabular data for `console.table`.
+
+use super::inspect::{inspect, own_enumerable_properties, visible_len, InspectOptions};
+use boa_engine::{
+    js_string,
+    object::builtins::{JsMap, JsSet},
+    property::PropertyKey,
+    Context, JsObject, JsResult,
+};
+
+/// Options used to render the cells of the table.
+const CELL_OPTIONS: InspectOptions = InspectOptions {
+    depth: Some(0),
+    colors: false,
+    single_line: true,
+};
+
+/// Renders a property key as the label of a row or a column.
+fn key_label(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::String(string) => string.to_std_string_escaped(),
+        PropertyKey::Symbol(symbol) => symbol.descriptive_string().to_std_string_escaped(),
+        PropertyKey::Index(index) => index.get().to_string(),
+    }
+}
+
+/// Renders `data` as a table.
+///
+/// Each property of `data` (or each entry if it's a `Map` or a `Set`) is a row of the table.
+/// The properties of the rows that are objects are the columns of the table, while primitive
+/// rows are rendered in a `Values` column. If `properties` is provided, only the columns
+/// with those names are rendered.
+pub(crate) fn table(
+    data: &JsObject,
+    properties: Option<&[String]>,
+    context: &mut Context,
+) -> JsResult<String> {
+    let mut index_header = "(index)";
+    let mut keys = None;
+    let mut rows = Vec::new();
+
+    if let Ok(map) = JsMap::from_object(data.clone()) {
+        index_header = "(iteration index)";
+        let mut pairs = Vec::new();
+        map.for_each_native(|key, value| {
+            pairs.push((key, value));
+            Ok(())
+        })?;
+        let mut rendered_keys = Vec::with_capacity(pairs.len());
+        for (index, (key, value)) in pairs.into_iter().enumerate() {
+            rendered_keys.push(inspect(&key, CELL_OPTIONS, context)?);
+            rows.push((index.to_string(), value));
+        }
+        keys = Some(rendered_keys);
+    } else if let Ok(set) = JsSet::from_object(data.clone()) {
+        index_header = "(iteration index)";
+        let iterator = set.values(context)?;
+        loop {
+            let result = iterator.next(context)?;
+            let Some(result) = result.as_object() else {
+                break;
+            };
+            if result.get(js_string!("done"), context)?.to_boolean() {
+                break;
+            }
+            let value = result.get(js_string!("value"), context)?;
+            rows.push((rows.len().to_string(), value));
+        }
+    } else {
+        for (key, _) in own_enumerable_properties(data, context)? {
+            let value = data.get(key.clone(), context)?;
+            rows.push((key_label(&key), value));
+        }
+    }
+
+    let mut columns: Vec<String> = properties.map(<[String]>::to_vec).unwrap_or_default();
+    let mut cells = Vec::with_capacity(rows.len());
+    let mut values = Vec::with_capacity(rows.len());
+
+    for (_, value) in &rows {
+        let mut row = Vec::new();
+        let mut primitive = None;
+
+        match value.as_object() {
+            Some(object) if keys.is_none() && !object.is_callable() => {
+                for (key, _) in own_enumerable_properties(object, context)? {
+                    let column = key_label(&key);
+                    if !columns.contains(&column) {
+                        if properties.is_some() {
+                            continue;
+                        }
+                        columns.push(column.clone());
+                    }
+                    let value = object.get(key, context)?;
+                    row.push((column, inspect(&value, CELL_OPTIONS, context)?));
+                }
+            }
+            _ => primitive = Some(inspect(value, CELL_OPTIONS, context)?),
+        }
+
+        cells.push(row);
+        values.push(primitive);
+    }
+
+    let mut header = vec![String::from(index_header)];
+    if keys.is_some() {
+        header.push(String::from("Key"));
+    }
+    header.extend(columns.iter().cloned());
+    let has_values = values.iter().any(Option::is_some);
+    if has_values {
+        header.push(String::from("Values"));
+    }
+
+    let body = rows
+        .into_iter()
+        .zip(cells)
+        .zip(values)
+        .enumerate()
+        .map(|(index, (((label, _), row), value))| {
+            let mut line = vec![label];
+            if let Some(keys) = &keys {
+                line.push(keys[index].clone());
+            }
+            for column in &columns {
+                let cell = row
+                    .iter()
+                    .find(|(name, _)| name == column)
+                    .map(|(_, cell)| cell.clone());
+                line.push(cell.unwrap_or_default());
+            }
+            if has_values {
+                line.push(value.unwrap_or_default());
+            }
+            line
+        })
+        .collect::<Vec<_>>();
+
+    Ok(render(&header, &body))
+}
+
+/// Draws the borders of a table around its cells.
+fn render(header: &[String], body: &[Vec<String>]) -> String {
+    let widths = (0..header.len())
+        .map(|column| {
+            body.iter()
+                .map(|row| visible_len(&row[column]))
+                .chain([visible_len(&header[column])])
+                .max()
+                .unwrap_or_default()
+                + 2
+        })
+        .collect::<Vec<_>>();
+
+    let border = |left: &str, middle: &str, right: &str| {
+        let lines = widths.iter().map(|width| "─".repeat(*width));
+        format!("{left}{}{right}", lines.collect::<Vec<_>>().join(middle))
+    };
+    let line = |row: &[String]| {
+        let cells = row.iter().zip(&widths).map(|(cell, width)| {
+            let padding = width - 1 - visible_len(cell);
+            format!(" {cell}{}", " ".repeat(padding))
+        });
+        format!("│{}│", cells.collect::<Vec<_>>().join("│"))
+    };
+
+    let mut table = vec![border("┌", "┬", "┐"), line(header), border("├", "┼", "┤")];
+    table.extend(body.iter().map(|row| line(row)));
+    table.push(border("└", "┴", "┘"));
+    table.join("\n")
+}
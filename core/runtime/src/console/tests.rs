@@ -1,4 +1,4 @@
-use super::{formatter, Console, ConsoleState};
+use super::{formatter, Console, ConsoleState, LogLevel};
 use crate::test::{run_test_actions, run_test_actions_with, TestAction};
 use crate::Logger;
 use boa_engine::{js_string, property::Attribute, Context, JsError, JsResult, JsValue};
@@ -8,14 +8,17 @@ use indoc::indoc;
 #[test]
 fn formatter_no_args_is_empty_string() {
     run_test_actions([TestAction::inspect_context(|ctx| {
-        assert_eq!(formatter(&[], ctx).unwrap(), "");
+        assert_eq!(formatter(&[], false, ctx).unwrap(), "");
     })]);
 }
 
 #[test]
 fn formatter_empty_format_string_is_empty_string() {
     run_test_actions([TestAction::inspect_context(|ctx| {
-        assert_eq!(formatter(&[JsValue::new(js_string!())], ctx).unwrap(), "");
+        assert_eq!(
+            formatter(&[JsValue::new(js_string!())], false, ctx).unwrap(),
+            ""
+        );
     })]);
 }
 
@@ -23,7 +26,7 @@ fn formatter_empty_format_string_is_empty_string() {
 fn formatter_format_without_args_renders_verbatim() {
     run_test_actions([TestAction::inspect_context(|ctx| {
         assert_eq!(
-            formatter(&[JsValue::new(js_string!("%d %s %% %f"))], ctx).unwrap(),
+            formatter(&[JsValue::new(js_string!("%d %s %% %f"))], false, ctx).unwrap(),
             "%d %s %% %f"
         );
     })]);
@@ -39,6 +42,7 @@ fn formatter_empty_format_string_concatenates_rest_of_args() {
                     JsValue::new(js_string!("to powinno zostać")),
                     JsValue::new(js_string!("połączone")),
                 ],
+                false,
                 ctx
             )
             .unwrap(),
@@ -58,6 +62,7 @@ fn formatter_utf_8_checks() {
                     JsValue::new(1.23),
                     JsValue::new(js_string!("ł")),
                 ],
+                false,
                 ctx
             )
             .unwrap(),
@@ -75,6 +80,7 @@ fn formatter_trailing_format_leader_renders() {
                     JsValue::new(js_string!("%%%%%")),
                     JsValue::new(js_string!("|"))
                 ],
+                false,
                 ctx
             )
             .unwrap(),
@@ -88,7 +94,12 @@ fn formatter_trailing_format_leader_renders() {
 fn formatter_float_format_works() {
     run_test_actions([TestAction::inspect_context(|ctx| {
         assert_eq!(
-            formatter(&[JsValue::new(js_string!("%f")), JsValue::new(3.1415)], ctx).unwrap(),
+            formatter(
+                &[JsValue::new(js_string!("%f")), JsValue::new(3.1415)],
+                false,
+                ctx
+            )
+            .unwrap(),
             "3.141500"
         );
     })]);
@@ -117,6 +128,7 @@ fn console_log_cyclic() {
 #[derive(Clone, Debug, Default, boa_engine::Trace, boa_engine::Finalize)]
 struct RecordingLogger {
     log: Gc<GcRefCell<String>>,
+    terminal: bool,
 }
 
 impl Logger for RecordingLogger {
@@ -137,6 +149,10 @@ impl Logger for RecordingLogger {
     fn error(&self, msg: String, state: &ConsoleState, context: &mut Context) -> JsResult<()> {
         self.log(msg, state, context)
     }
+
+    fn is_terminal(&self, _: LogLevel) -> bool {
+        self.terminal
+    }
 }
 
 /// Harness methods to be used in JS tests.
@@ -337,3 +353,171 @@ fn console_namespace_object_class_string() {
         &mut context,
     );
 }
+
+/// Runs `source` with a console that records its output, returning the logs.
+fn record_logs(source: &str, terminal: bool) -> String {
+    let mut context = Context::default();
+    let logger = RecordingLogger {
+        log: Gc::default(),
+        terminal,
+    };
+    Console::register_with_logger(&mut context, logger.clone()).unwrap();
+
+    run_test_actions_with([TestAction::run(source.to_owned())], &mut context);
+
+    let logs = logger.log.borrow().clone();
+    logs
+}
+
+#[test]
+fn console_log_inspects_values() {
+    let logs = record_logs(
+        indoc! {r#"
+            class Point {
+                constructor() {
+                    this.x = 1;
+                    this.y = 2;
+                }
+            }
+            const cyclic = { name: "o" };
+            cyclic.self = cyclic;
+
+            console.log({ a: 1, b: "two", c: [1, 2, 3], "d-e": null, [Symbol("s")]: undefined });
+            console.log(new Map([["k", { x: 1 }]]), new Set([1, "a"]));
+            console.log(new Point());
+            console.log(Uint8Array.of(1, 2), [1, , 3], -0, 10n);
+            console.log(Object.create(null), function foo() {}, () => {});
+            console.log(cyclic);
+            console.log([new Date(0), /a+/g, new Number(3), new String("s")]);
+            console.log({ get a() { return 1; }, set b(v) {} });
+            console.log({
+                alpha: "aaaaaaaaaaaaaaaaaaaa",
+                beta: "bbbbbbbbbbbbbbbbbbbb",
+                gamma: ["cccccccccccccccccccc", "dddddddddddddddddddd", "eeeeeeeeeeeeeeeeeeee"],
+            });
+        "#},
+        false,
+    );
+
+    assert_eq!(
+        logs,
+        indoc! {r#"
+            { a: 1, b: 'two', c: [ 1, 2, 3 ], 'd-e': null, [Symbol(s)]: undefined }
+            Map(1) { 'k' => { x: 1 } } Set(2) { 1, 'a' }
+            Point { x: 1, y: 2 }
+            Uint8Array(2) [ 1, 2 ] [ 1, <1 empty item>, 3 ] -0 10n
+            [Object: null prototype] {} [Function: foo] [Function (anonymous)]
+            { name: 'o', self: [Circular] }
+            [ 1970-01-01T00:00:00.000Z, /a+/g, [Number: 3], [String: 's'] ]
+            { a: [Getter], b: [Setter] }
+            {
+              alpha: 'aaaaaaaaaaaaaaaaaaaa',
+              beta: 'bbbbbbbbbbbbbbbbbbbb',
+              gamma: [
+                'cccccccccccccccccccc',
+                'dddddddddddddddddddd',
+                'eeeeeeeeeeeeeeeeeeee'
+              ]
+            }
+        "#}
+    );
+}
+
+#[test]
+fn console_format_specifiers() {
+    let logs = record_logs(
+        indoc! {r#"
+            const deep = { a: { b: { c: { d: {} } } } };
+            console.log(deep);
+            console.log("%o", deep);
+            console.log("%O!", deep);
+            console.log("%cred%c text", "color: red", "font-weight: bold");
+            console.log("%s:", "rest", { x: [1] }, "end");
+        "#},
+        false,
+    );
+
+    assert_eq!(
+        logs,
+        indoc! {r#"
+            { a: { b: { c: [Object] } } }
+            { a: { b: { c: { d: {} } } } }
+            { a: { b: { c: [Object] } } }!
+            red text
+            rest: { x: [ 1 ] } end
+        "#}
+    );
+}
+
+#[test]
+fn console_dir_options() {
+    let logs = record_logs(
+        indoc! {r#"
+            const deep = { a: { b: { c: { d: [1] } } } };
+            console.dir(deep);
+            console.dir(deep, { depth: 0 });
+            console.dir(deep, { depth: null });
+            console.dir(deep, { depth: Infinity, colors: true });
+            console.dir("text");
+        "#},
+        false,
+    );
+
+    assert_eq!(
+        logs,
+        indoc! {"
+            { a: { b: { c: [Object] } } }
+            { a: [Object] }
+            { a: { b: { c: { d: [ 1 ] } } } }
+            { a: { b: { c: { d: [ \x1b[33m1\x1b[39m ] } } } }
+            'text'
+        "}
+    );
+}
+
+#[test]
+fn console_colors_on_terminal() {
+    let logs = record_logs(r#"console.log(1, "a", null, [undefined, "b"]);"#, true);
+
+    assert_eq!(
+        logs,
+        "\x1b[33m1\x1b[39m a \x1b[1mnull\x1b[22m [ \x1b[90mundefined\x1b[39m, \x1b[32m'b'\x1b[39m ]\n"
+    );
+}
+
+#[test]
+fn console_table() {
+    let logs = record_logs(
+        indoc! {r#"
+            console.table([{ a: 1, b: "Y" }, { a: "Z", c: true }, 3]);
+            console.table([{ a: 1, b: 2 }], ["b"]);
+            console.table(new Map([["x", { y: 1 }]]));
+            console.table(5);
+        "#},
+        false,
+    );
+
+    assert_eq!(
+        logs,
+        indoc! {r#"
+            ┌─────────┬─────┬─────┬──────┬────────┐
+            │ (index) │ a   │ b   │ c    │ Values │
+            ├─────────┼─────┼─────┼──────┼────────┤
+            │ 0       │ 1   │ 'Y' │      │        │
+            │ 1       │ 'Z' │     │ true │        │
+            │ 2       │     │     │      │ 3      │
+            └─────────┴─────┴─────┴──────┴────────┘
+            ┌─────────┬───┐
+            │ (index) │ b │
+            ├─────────┼───┤
+            │ 0       │ 2 │
+            └─────────┴───┘
+            ┌───────────────────┬─────┬──────────┐
+            │ (iteration index) │ Key │ Values   │
+            ├───────────────────┼─────┼──────────┤
+            │ 0                 │ 'x' │ { y: 1 } │
+            └───────────────────┴─────┴──────────┘
+            5
+        "#}
+    );
+}
@@ -54,25 +54,34 @@
 mod console;
 
 #[doc(inline)]
-pub use console::{Console, ConsoleState, Logger};
+pub use console::{Console, ConsoleState, LogLevel, Logger};
 
 mod text;
 
 #[doc(inline)]
 pub use text::{TextDecoder, TextEncoder};
 
+mod timers;
+
+#[doc(inline)]
+pub use timers::{Clock, ManualClock, SystemClock, TimerQueue, Timers};
+
+pub mod structured_clone;
+
 pub mod url;
 
 /// Options used when registering all built-in objects and functions of the `WebAPI` runtime.
 #[derive(Debug)]
 pub struct RegisterOptions<L: Logger> {
     console_logger: L,
+    timers: bool,
 }
 
 impl Default for RegisterOptions<console::DefaultLogger> {
     fn default() -> Self {
         Self {
             console_logger: console::DefaultLogger,
+            timers: true,
         }
     }
 }
@@ -90,6 +99,26 @@ impl<L: Logger> RegisterOptions<L> {
     pub fn with_console_logger<L2: Logger>(self, logger: L2) -> RegisterOptions<L2> {
         RegisterOptions::<L2> {
             console_logger: logger,
+            timers: self.timers,
+        }
+    }
+
+    /// Set whether the timer functions (`setTimeout`, `setInterval`, `clearTimeout`,
+    /// `clearInterval` and `queueMicrotask`) are registered. Enabled by default.
+    ///
+    /// Timers only fire if the job queue of the context runs timeout jobs. The default
+    /// [`SimpleJobQueue`] blocks in [`Context::run_jobs`] until all of them have fired, while
+    /// [`TimerQueue`] runs each of them as its own task, as the event loop of the Web API does.
+    /// With an [`IdleJobQueue`], the timer functions are available but never fire.
+    ///
+    /// [`SimpleJobQueue`]: boa_engine::job::SimpleJobQueue
+    /// [`IdleJobQueue`]: boa_engine::job::IdleJobQueue
+    /// [`Context::run_jobs`]: boa_engine::Context::run_jobs
+    #[must_use]
+    pub fn with_timers(self, enabled: bool) -> Self {
+        Self {
+            timers: enabled,
+            ..self
         }
     }
 }
@@ -106,8 +135,17 @@ pub fn register(
     TextDecoder::register(ctx)?;
     TextEncoder::register(ctx)?;
 
+    structured_clone::StructuredClone::register(ctx)?;
+
+    if options.timers {
+        Timers::register(ctx)?;
+    }
+
     #[cfg(feature = "url")]
-    url::Url::register(ctx)?;
+    {
+        url::Url::register(ctx)?;
+        url::UrlSearchParams::register(ctx)?;
+    }
 
     Ok(())
 }
@@ -172,6 +210,17 @@ pub(crate) mod test {
         pub(crate) fn inspect_context(op: fn(&mut Context)) -> Self {
             Self(Inner::InspectContext { op })
         }
+
+        /// Asserts that the script returns `expected` when evaluating `source`.
+        pub(crate) fn assert_eq(
+            source: impl Into<Cow<'static, str>>,
+            expected: impl Into<JsValue>,
+        ) -> Self {
+            Self(Inner::AssertEq {
+                source: source.into(),
+                expected: expected.into(),
+            })
+        }
     }
 
     /// Executes a list of test actions on a new, default context.
@@ -0,0 +1,647 @@
+//! Boa's implementation of the structured clone algorithm of the Web API.
+//!
+//! This module contains the `structuredClone` global function, and a Rust API to serialize
+//! JavaScript values into a [`SerializedValue`], which can be moved to another thread and
+//! deserialized into a different [`Context`].
+//!
+//! Supported values are primitives (except symbols), primitive wrapper objects, plain objects,
+//! arrays, `Date`, `RegExp`, `Map`, `Set`, `ArrayBuffer`, `SharedArrayBuffer`, typed arrays,
+//! `DataView` and errors. Cycles and shared references are preserved.
+//!
+//! Since Boa doesn't implement `DOMException`, the `DataCloneError` exceptions of the
+//! specification are thrown as `TypeError`s.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG structured clone specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#safe-passing-of-structured-data
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    builtins::{array_buffer::SharedArrayBuffer, error::Error},
+    context::intrinsics::{StandardConstructor, StandardConstructors},
+    js_error, js_string,
+    native_function::NativeFunction,
+    object::builtins::{
+        JsArray, JsArrayBuffer, JsDataView, JsDate, JsMap, JsRegExp, JsSet, JsSharedArrayBuffer,
+        JsTypedArray,
+    },
+    property::PropertyDescriptor,
+    Context, JsArgs, JsBigInt, JsError, JsNativeError, JsObject, JsResult, JsString, JsValue,
+};
+use rustc_hash::FxHashMap;
+
+/// Creates the error thrown when a value cannot be serialized.
+fn data_clone_error(what: &str) -> JsError {
+    js_error!(TypeError: "{} could not be cloned", what)
+}
+
+/// A JavaScript value serialized with the [`serialize`] or [`serialize_with_transfer`]
+/// functions.
+///
+/// A `SerializedValue` doesn't reference the [`Context`] it was created from, so it can be sent
+/// to another thread and deserialized into another [`Context`] with [`deserialize`].
+#[derive(Debug, Clone)]
+pub struct SerializedValue {
+    root: Entry,
+    records: Vec<Record>,
+}
+
+/// A serialized value, which is either a primitive or a reference to a serialized object.
+#[derive(Debug, Clone)]
+enum Entry {
+    Undefined,
+    Null,
+    Boolean(bool),
+    Number(f64),
+    BigInt(String),
+    String(Vec<u16>),
+    Object(usize),
+}
+
+/// The kind of a serialized error, following its `name` property.
+#[derive(Debug, Clone, Copy)]
+enum ErrorName {
+    Error,
+    EvalError,
+    RangeError,
+    ReferenceError,
+    SyntaxError,
+    TypeError,
+    UriError,
+}
+
+impl ErrorName {
+    fn from_name(name: &str) -> Self {
+        match name {
+            "EvalError" => Self::EvalError,
+            "RangeError" => Self::RangeError,
+            "ReferenceError" => Self::ReferenceError,
+            "SyntaxError" => Self::SyntaxError,
+            "TypeError" => Self::TypeError,
+            "URIError" => Self::UriError,
+            _ => Self::Error,
+        }
+    }
+
+    const fn native(self) -> JsNativeError {
+        match self {
+            Self::Error => JsNativeError::error(),
+            Self::EvalError => JsNativeError::eval(),
+            Self::RangeError => JsNativeError::range(),
+            Self::ReferenceError => JsNativeError::reference(),
+            Self::SyntaxError => JsNativeError::syntax(),
+            Self::TypeError => JsNativeError::typ(),
+            Self::UriError => JsNativeError::uri(),
+        }
+    }
+}
+
+/// Gets the standard constructor of a typed array.
+type TypedArrayConstructor = fn(&StandardConstructors) -> &StandardConstructor;
+
+/// The constructors of the typed arrays, indexed by their names.
+const TYPED_ARRAYS: [(&str, TypedArrayConstructor); 12] = [
+    ("Int8Array", StandardConstructors::typed_int8_array),
+    ("Uint8Array", StandardConstructors::typed_uint8_array),
+    (
+        "Uint8ClampedArray",
+        StandardConstructors::typed_uint8clamped_array,
+    ),
+    ("Int16Array", StandardConstructors::typed_int16_array),
+    ("Uint16Array", StandardConstructors::typed_uint16_array),
+    ("Int32Array", StandardConstructors::typed_int32_array),
+    ("Uint32Array", StandardConstructors::typed_uint32_array),
+    ("BigInt64Array", StandardConstructors::typed_bigint64_array),
+    (
+        "BigUint64Array",
+        StandardConstructors::typed_biguint64_array,
+    ),
+    ("Float16Array", StandardConstructors::typed_float16_array),
+    ("Float32Array", StandardConstructors::typed_float32_array),
+    ("Float64Array", StandardConstructors::typed_float64_array),
+];
+
+/// A serialized object.
+#[derive(Debug, Clone)]
+enum Record {
+    /// An object whose serialization hasn't finished, or that was already deserialized.
+    Pending,
+    Boolean(bool),
+    Number(f64),
+    BigInt(String),
+    String(Vec<u16>),
+    Date(f64),
+    RegExp {
+        source: String,
+        flags: String,
+    },
+    ArrayBuffer(Vec<u8>),
+    SharedArrayBuffer(SharedArrayBuffer),
+    TypedArray {
+        /// Index of the constructor in [`TYPED_ARRAYS`].
+        kind: usize,
+        buffer: usize,
+        byte_offset: usize,
+        length: usize,
+    },
+    DataView {
+        buffer: usize,
+        byte_offset: u64,
+        byte_length: u64,
+    },
+    Map(Vec<(Entry, Entry)>),
+    Set(Vec<Entry>),
+    Error {
+        name: ErrorName,
+        message: Option<Vec<u16>>,
+    },
+    Array {
+        length: u32,
+        properties: Vec<(Vec<u16>, Entry)>,
+    },
+    Object(Vec<(Vec<u16>, Entry)>),
+}
+
+/// Serializes `value` using the [`StructuredSerialize`][spec] algorithm.
+///
+/// # Errors
+/// This will error if `value` contains a value that cannot be serialized, like functions or
+/// symbols, or if a getter throws while reading the properties of an object.
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserialize
+pub fn serialize(value: &JsValue, context: &mut Context) -> JsResult<SerializedValue> {
+    serialize_with_transfer(value, &[], context)
+}
+
+/// Serializes `value` using the [`StructuredSerializeWithTransfer`][spec] algorithm,
+/// transferring the `ArrayBuffer`s in `transfer`.
+///
+/// Transferred buffers are detached, and their data is moved into the serialized value
+/// instead of being copied.
+///
+/// # Errors
+/// This will error if `value` cannot be serialized, or if `transfer` contains an object that
+/// is not a transferable `ArrayBuffer`.
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structuredserializewithtransfer
+pub fn serialize_with_transfer(
+    value: &JsValue,
+    transfer: &[JsObject],
+    context: &mut Context,
+) -> JsResult<SerializedValue> {
+    let mut serializer = Serializer::default();
+
+    let mut buffers = Vec::with_capacity(transfer.len());
+    for object in transfer {
+        let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) else {
+            return Err(js_error!(TypeError: "only ArrayBuffers can be transferred"));
+        };
+        if serializer.memory.contains_key(object) {
+            return Err(
+                js_error!(TypeError: "an ArrayBuffer cannot be transferred more than once"),
+            );
+        }
+        serializer
+            .memory
+            .insert(object.clone(), serializer.records.len());
+        serializer.records.push(Record::Pending);
+        buffers.push(buffer);
+    }
+
+    let root = serializer.entry(value, context)?;
+
+    for (index, buffer) in buffers.into_iter().enumerate() {
+        if buffer.data().is_none() {
+            return Err(data_clone_error("detached ArrayBuffer"));
+        }
+        let data = buffer.detach(&JsValue::undefined())?;
+        serializer.records[index] = Record::ArrayBuffer(data);
+    }
+
+    Ok(SerializedValue {
+        root,
+        records: serializer.records,
+    })
+}
+
+/// Deserializes `value` into `context` using the [`StructuredDeserialize`][spec] algorithm.
+///
+/// # Errors
+/// This will error if any of the objects cannot be created in `context`.
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#structureddeserialize
+pub fn deserialize(value: SerializedValue, context: &mut Context) -> JsResult<JsValue> {
+    let mut deserializer = Deserializer {
+        objects: vec![None; value.records.len()],
+        records: value.records,
+    };
+    deserializer.entry(value.root, context)
+}
+
+/// The state of a serialization.
+#[derive(Default)]
+struct Serializer {
+    /// Maps the objects already serialized to the index of their record.
+    memory: FxHashMap<JsObject, usize>,
+    records: Vec<Record>,
+}
+
+impl Serializer {
+    fn entry(&mut self, value: &JsValue, context: &mut Context) -> JsResult<Entry> {
+        if value.is_undefined() {
+            Ok(Entry::Undefined)
+        } else if value.is_null() {
+            Ok(Entry::Null)
+        } else if let Some(boolean) = value.as_boolean() {
+            Ok(Entry::Boolean(boolean))
+        } else if let Some(number) = value.as_number() {
+            Ok(Entry::Number(number))
+        } else if let Some(bigint) = value.as_bigint() {
+            Ok(Entry::BigInt(bigint.to_string_radix(10)))
+        } else if let Some(string) = value.as_string() {
+            Ok(Entry::String(string.iter().collect()))
+        } else if let Some(object) = value.as_object() {
+            self.object(object, context).map(Entry::Object)
+        } else {
+            Err(data_clone_error("Symbol"))
+        }
+    }
+
+    /// Serializes `object`, returning the index of its record.
+    fn object(&mut self, object: &JsObject, context: &mut Context) -> JsResult<usize> {
+        if let Some(&index) = self.memory.get(object) {
+            return Ok(index);
+        }
+
+        // The object must be in the memory before serializing its contents, to handle cycles.
+        let index = self.records.len();
+        self.memory.insert(object.clone(), index);
+        self.records.push(Record::Pending);
+
+        let record = self.record(object, context)?;
+        self.records[index] = record;
+
+        Ok(index)
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn record(&mut self, object: &JsObject, context: &mut Context) -> JsResult<Record> {
+        if let Some(boolean) = object.downcast_ref::<bool>() {
+            return Ok(Record::Boolean(*boolean));
+        }
+        if let Some(number) = object.downcast_ref::<f64>() {
+            return Ok(Record::Number(*number));
+        }
+        if let Some(bigint) = object.downcast_ref::<JsBigInt>() {
+            return Ok(Record::BigInt(bigint.to_string_radix(10)));
+        }
+        if let Some(string) = object.downcast_ref::<JsString>() {
+            return Ok(Record::String(string.iter().collect()));
+        }
+        if let Ok(date) = JsDate::from_object(object.clone()) {
+            let time = date.get_time(context)?.to_number(context)?;
+            return Ok(Record::Date(time));
+        }
+        if let Ok(regexp) = JsRegExp::from_object(object.clone()) {
+            return Ok(Record::RegExp {
+                source: regexp.source(context)?,
+                flags: regexp.flags(context)?,
+            });
+        }
+        if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+            let Some(data) = buffer.data() else {
+                return Err(data_clone_error("detached ArrayBuffer"));
+            };
+            return Ok(Record::ArrayBuffer(data.to_vec()));
+        }
+        if let Ok(buffer) = JsSharedArrayBuffer::from_object(object.clone()) {
+            return Ok(Record::SharedArrayBuffer(buffer.inner()));
+        }
+        if let Ok(array) = JsTypedArray::from_object(object.clone()) {
+            return self.typed_array(&array, context);
+        }
+        if let Ok(view) = JsDataView::from_object(object.clone()) {
+            let buffer = view.buffer(context)?;
+            return Ok(Record::DataView {
+                buffer: self.buffer(&buffer, context)?,
+                byte_offset: view.byte_offset(context)?,
+                byte_length: view.byte_length(context)?,
+            });
+        }
+        if let Ok(map) = JsMap::from_object(object.clone()) {
+            // Copy the entries first, since serializing them could modify the map.
+            let mut copied = Vec::new();
+            map.for_each_native(|key, value| {
+                copied.push((key, value));
+                Ok(())
+            })?;
+            let entries = copied
+                .iter()
+                .map(|(key, value)| Ok((self.entry(key, context)?, self.entry(value, context)?)))
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Map(entries));
+        }
+        if let Ok(set) = JsSet::from_object(object.clone()) {
+            let iterator = set.values(context)?;
+            let mut copied = Vec::new();
+            loop {
+                let result = iterator.next(context)?;
+                let Some(result) = result.as_object() else {
+                    break;
+                };
+                if result.get(js_string!("done"), context)?.to_boolean() {
+                    break;
+                }
+                copied.push(result.get(js_string!("value"), context)?);
+            }
+            let values = copied
+                .iter()
+                .map(|value| self.entry(value, context))
+                .collect::<JsResult<_>>()?;
+            return Ok(Record::Set(values));
+        }
+        if object.is::<Error>() {
+            let name = object
+                .get(js_string!("name"), context)?
+                .to_string(context)?
+                .to_std_string_escaped();
+            let message = if object.has_own_property(js_string!("message"), context)? {
+                let message = object.get(js_string!("message"), context)?;
+                Some(message.to_string(context)?.iter().collect())
+            } else {
+                None
+            };
+            return Ok(Record::Error {
+                name: ErrorName::from_name(&name),
+                message,
+            });
+        }
+        if object.is_array() {
+            let length = object
+                .get(js_string!("length"), context)?
+                .to_length(context)?;
+            let length = u32::try_from(length).map_err(|_| data_clone_error("Array"))?;
+            return Ok(Record::Array {
+                length,
+                properties: self.properties(object, context)?,
+            });
+        }
+        if object.is_ordinary() {
+            return Ok(Record::Object(self.properties(object, context)?));
+        }
+
+        if object.is_callable() {
+            Err(data_clone_error("function"))
+        } else {
+            Err(data_clone_error("object"))
+        }
+    }
+
+    /// Serializes the buffer of a typed array or a `DataView`.
+    fn buffer(&mut self, buffer: &JsValue, context: &mut Context) -> JsResult<usize> {
+        let Some(buffer) = buffer.as_object() else {
+            return Err(data_clone_error("view without a buffer"));
+        };
+        self.object(buffer, context)
+    }
+
+    fn typed_array(&mut self, array: &JsTypedArray, context: &mut Context) -> JsResult<Record> {
+        let name = array.to_string_tag(context)?.to_string(context)?;
+        let kind = TYPED_ARRAYS
+            .iter()
+            .position(|(n, _)| name == *n)
+            .ok_or_else(|| data_clone_error("typed array"))?;
+        let buffer = array.buffer(context)?;
+
+        Ok(Record::TypedArray {
+            kind,
+            buffer: self.buffer(&buffer, context)?,
+            byte_offset: array.byte_offset(context)?,
+            length: array.length(context)?,
+        })
+    }
+
+    /// Serializes the own enumerable string-keyed properties of `object`.
+    fn properties(
+        &mut self,
+        object: &JsObject,
+        context: &mut Context,
+    ) -> JsResult<Vec<(Vec<u16>, Entry)>> {
+        // Copying the properties reads each own enumerable property once, in order.
+        let copy = JsObject::with_null_proto();
+        copy.copy_data_properties::<JsString>(&object.clone().into(), Vec::new(), context)?;
+
+        let mut properties = Vec::new();
+        for key in copy.own_property_keys(context)? {
+            let name = JsValue::from(key.clone());
+            if name.is_symbol() {
+                continue;
+            }
+            let value = copy.get(key, context)?;
+            properties.push((
+                name.to_string(context)?.iter().collect(),
+                self.entry(&value, context)?,
+            ));
+        }
+
+        Ok(properties)
+    }
+}
+
+/// The state of a deserialization.
+struct Deserializer {
+    records: Vec<Record>,
+    /// The objects already deserialized, indexed like their records.
+    objects: Vec<Option<JsObject>>,
+}
+
+impl Deserializer {
+    fn entry(&mut self, entry: Entry, context: &mut Context) -> JsResult<JsValue> {
+        Ok(match entry {
+            Entry::Undefined => JsValue::undefined(),
+            Entry::Null => JsValue::null(),
+            Entry::Boolean(boolean) => boolean.into(),
+            Entry::Number(number) => number.into(),
+            Entry::BigInt(bigint) => bigint_from_string(&bigint)?.into(),
+            Entry::String(string) => JsString::from(&string[..]).into(),
+            Entry::Object(index) => self.object(index, context)?.into(),
+        })
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn object(&mut self, index: usize, context: &mut Context) -> JsResult<JsObject> {
+        if let Some(object) = &self.objects[index] {
+            return Ok(object.clone());
+        }
+
+        let record = std::mem::replace(&mut self.records[index], Record::Pending);
+        let object: JsObject = match record {
+            Record::Pending => {
+                return Err(js_error!(TypeError: "invalid serialized value"));
+            }
+            Record::Boolean(boolean) => JsValue::from(boolean).to_object(context)?,
+            Record::Number(number) => JsValue::from(number).to_object(context)?,
+            Record::BigInt(bigint) => {
+                JsValue::from(bigint_from_string(&bigint)?).to_object(context)?
+            }
+            Record::String(string) => {
+                JsValue::from(JsString::from(&string[..])).to_object(context)?
+            }
+            Record::Date(time) => {
+                let date = JsDate::new(context);
+                date.set_time(time, context)?;
+                date.into()
+            }
+            Record::RegExp { source, flags } => {
+                JsRegExp::new(js_string!(source), js_string!(flags), context)?.into()
+            }
+            Record::ArrayBuffer(data) => JsArrayBuffer::from_byte_block(data, context)?.into(),
+            Record::SharedArrayBuffer(buffer) => {
+                JsSharedArrayBuffer::from_buffer(buffer, context).into()
+            }
+            Record::TypedArray {
+                kind,
+                buffer,
+                byte_offset,
+                length,
+            } => {
+                let buffer = self.object(buffer, context)?;
+                let constructor =
+                    TYPED_ARRAYS[kind].1(context.intrinsics().constructors()).constructor();
+                constructor.construct(
+                    &[buffer.into(), byte_offset.into(), length.into()],
+                    None,
+                    context,
+                )?
+            }
+            Record::DataView {
+                buffer,
+                byte_offset,
+                byte_length,
+            } => {
+                let buffer = self.object(buffer, context)?;
+                let constructor = context
+                    .intrinsics()
+                    .constructors()
+                    .data_view()
+                    .constructor();
+                constructor.construct(
+                    &[buffer.into(), byte_offset.into(), byte_length.into()],
+                    None,
+                    context,
+                )?
+            }
+            Record::Map(entries) => {
+                let map = JsMap::new(context);
+                self.objects[index] = Some(map.clone().into());
+                for (key, value) in entries {
+                    let key = self.entry(key, context)?;
+                    let value = self.entry(value, context)?;
+                    map.set(key, value, context)?;
+                }
+                return Ok(map.into());
+            }
+            Record::Set(values) => {
+                let set = JsSet::new(context);
+                self.objects[index] = Some(set.clone().into());
+                for value in values {
+                    let value = self.entry(value, context)?;
+                    set.add(value, context)?;
+                }
+                return Ok(set.into());
+            }
+            Record::Error { name, message } => {
+                let error = name.native().to_opaque(context);
+                if let Some(message) = message {
+                    error.define_property_or_throw(
+                        js_string!("message"),
+                        PropertyDescriptor::builder()
+                            .value(JsString::from(&message[..]))
+                            .writable(true)
+                            .enumerable(false)
+                            .configurable(true),
+                        context,
+                    )?;
+                }
+                error
+            }
+            Record::Array { length, properties } => {
+                let array: JsObject = JsArray::new(context).into();
+                self.objects[index] = Some(array.clone());
+                array.set(js_string!("length"), length, true, context)?;
+                self.define_properties(&array, properties, context)?;
+                return Ok(array);
+            }
+            Record::Object(properties) => {
+                let object = JsObject::with_object_proto(context.intrinsics());
+                self.objects[index] = Some(object.clone());
+                self.define_properties(&object, properties, context)?;
+                return Ok(object);
+            }
+        };
+
+        self.objects[index] = Some(object.clone());
+        Ok(object)
+    }
+
+    fn define_properties(
+        &mut self,
+        object: &JsObject,
+        properties: Vec<(Vec<u16>, Entry)>,
+        context: &mut Context,
+    ) -> JsResult<()> {
+        for (key, value) in properties {
+            let value = self.entry(value, context)?;
+            object.create_data_property_or_throw(JsString::from(&key[..]), value, context)?;
+        }
+        Ok(())
+    }
+}
+
+fn bigint_from_string(bigint: &str) -> JsResult<JsBigInt> {
+    JsBigInt::from_string_radix(bigint, 10)
+        .ok_or_else(|| js_error!(TypeError: "invalid serialized BigInt"))
+}
+
+/// The `structuredClone` global function.
+#[derive(Debug, Clone, Copy)]
+pub struct StructuredClone;
+
+impl StructuredClone {
+    /// Register the `structuredClone` function in the global object.
+    ///
+    /// # Errors
+    /// This will error if the function cannot be registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_builtin_callable(
+            js_string!("structuredClone"),
+            1,
+            NativeFunction::from_fn_ptr(structured_clone),
+        )
+    }
+}
+
+/// Implementation of `structuredClone ( value [ , options ] )`.
+fn structured_clone(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let value = args.get_or_undefined(0);
+
+    let options = args.get_or_undefined(1);
+    let transfer = match options.as_object() {
+        Some(options) => options
+            .get(js_string!("transfer"), context)?
+            .try_js_into::<Option<Vec<JsObject>>>(context)?
+            .unwrap_or_default(),
+        None if options.is_null_or_undefined() => Vec::new(),
+        None => {
+            return Err(js_error!(TypeError: "options must be an object"));
+        }
+    };
+
+    let serialized = serialize_with_transfer(value, &transfer, context)?;
+    deserialize(serialized, context)
+}
@@ -0,0 +1,168 @@
+use crate::structured_clone::{deserialize, serialize, StructuredClone};
+use crate::test::{run_test_actions, TestAction};
+use boa_engine::{js_str, Context, Source};
+use indoc::indoc;
+
+const TEST_HARNESS: &str = r#"
+function assert(condition, message) {
+    if (!condition) {
+        throw new Error(message ?? "Assertion failed");
+    }
+}
+
+function assert_eq(a, b, message) {
+    if (a !== b) {
+        throw new Error(`${message} (${String(a)} !== ${String(b)})`);
+    }
+}
+"#;
+
+#[test]
+fn clone_values() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(indoc! {r#"
+            const original = {
+                number: 1,
+                string: "text",
+                big: 10n ** 30n,
+                nested: { list: [1, , 3] },
+                date: new Date(1000),
+                regexp: /a+b/gi,
+                map: new Map([[1, "one"]]),
+                set: new Set(["a"]),
+                wrapper: new Number(5),
+                error: new RangeError("out of range"),
+            };
+            const clone = structuredClone(original);
+
+            assert(clone !== original);
+            assert_eq(clone.number, 1);
+            assert_eq(clone.string, "text");
+            assert_eq(clone.big, 10n ** 30n);
+            assert(clone.nested !== original.nested);
+            assert_eq(clone.nested.list.length, 3);
+            assert(!(1 in clone.nested.list), "holes are preserved");
+            assert(clone.date instanceof Date);
+            assert_eq(clone.date.getTime(), 1000);
+            assert_eq(clone.regexp.source, "a+b");
+            assert_eq(clone.regexp.flags, "gi");
+            assert_eq(clone.map.get(1), "one");
+            assert(clone.set.has("a"));
+            assert(clone.wrapper instanceof Number);
+            assert_eq(clone.wrapper.valueOf(), 5);
+            assert(clone.error instanceof RangeError);
+            assert_eq(clone.error.message, "out of range");
+        "#}),
+    ]);
+}
+
+#[test]
+fn clone_cycles() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(indoc! {r#"
+            const shared = { value: 1 };
+            const original = { a: shared, b: shared, map: new Map() };
+            original.self = original;
+            original.map.set(original, original.map);
+
+            const clone = structuredClone(original);
+            assert(clone.self === clone);
+            assert(clone.a === clone.b);
+            assert(clone.a !== shared);
+            assert(clone.map.get(clone) === clone.map);
+        "#}),
+    ]);
+}
+
+#[test]
+fn clone_buffers() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(indoc! {r#"
+            const buffer = new ArrayBuffer(8);
+            const bytes = new Uint8Array(buffer, 2, 4);
+            bytes[0] = 42;
+            const view = new DataView(buffer, 4);
+
+            const clone = structuredClone({ bytes, view });
+            assert(clone.bytes instanceof Uint8Array);
+            assert_eq(clone.bytes.byteOffset, 2);
+            assert_eq(clone.bytes.length, 4);
+            assert_eq(clone.bytes[0], 42);
+            assert(clone.bytes.buffer === clone.view.buffer);
+            assert_eq(clone.view.byteOffset, 4);
+            assert_eq(buffer.byteLength, 8);
+
+            const moved = structuredClone(bytes, { transfer: [buffer] });
+            assert_eq(buffer.byteLength, 0, "transferred buffers are detached");
+            assert_eq(moved[0], 42);
+        "#}),
+    ]);
+}
+
+#[test]
+fn clone_errors() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(indoc! {r#"
+            function cloneError(value, options) {
+                try {
+                    structuredClone(value, options);
+                } catch (e) {
+                    assert(e instanceof TypeError);
+                    return e.message;
+                }
+                throw new Error("expected an error");
+            }
+
+            assert_eq(cloneError(() => {}), "function could not be cloned");
+            assert_eq(cloneError({ s: Symbol() }), "Symbol could not be cloned");
+            assert_eq(cloneError(new WeakMap()), "object could not be cloned");
+
+            const b = new ArrayBuffer(1);
+            assert_eq(
+                cloneError(b, { transfer: [b, b] }),
+                "an ArrayBuffer cannot be transferred more than once"
+            );
+        "#}),
+    ]);
+}
+
+#[test]
+fn move_between_threads() {
+    let context = &mut Context::default();
+    let value = context
+        .eval(Source::from_bytes(
+            "({ list: [1, 2n, 'three'], bytes: new Uint8Array([4, 5]) })",
+        ))
+        .unwrap();
+    let serialized = serialize(&value, context).unwrap();
+
+    let result = std::thread::spawn(move || {
+        let context = &mut Context::default();
+        StructuredClone::register(context).unwrap();
+        context
+            .global_object()
+            .set(
+                js_str!("value"),
+                deserialize(serialized, context).unwrap(),
+                true,
+                context,
+            )
+            .unwrap();
+        context
+            .eval(Source::from_bytes(
+                "`${value.list[0]},${value.list[1]},${value.list[2]},${value.bytes.join()}`",
+            ))
+            .unwrap()
+            .to_string(context)
+            .unwrap()
+            .to_std_string_escaped()
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(result, "1,2,three,4,5");
+}
@@ -2,22 +2,147 @@
 //!
 //! See <https://developer.mozilla.org/en-US/docs/Web/API/Encoding_API> for more information.
 
-use boa_engine::object::builtins::JsUint8Array;
+use boa_engine::object::builtins::{
+    JsArrayBuffer, JsDataView, JsSharedArrayBuffer, JsTypedArray, JsUint8Array,
+};
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
 use boa_engine::string::CodePoint;
+use boa_engine::value::{Convert, TryFromJs};
 use boa_engine::{
-    js_string, Context, Finalize, JsData, JsNativeError, JsObject, JsResult, JsString, Trace,
+    js_str, js_string, Context, Finalize, JsData, JsNativeError, JsObject, JsResult, JsString,
+    JsValue, Trace,
 };
-use boa_interop::js_class;
+use boa_interop::{js_class, JsClass};
+use encodings::{Decoder, Encoding};
 
+mod encodings;
 #[cfg(test)]
 mod tests;
 
-/// The `TextDecoder`[mdn] class represents an encoder for a specific method, that is
-/// a specific character encoding, like `utf-8`.
+/// The bytes of a `BufferSource`, that is an `ArrayBuffer`, a `SharedArrayBuffer`,
+/// a typed array or a `DataView`.
+#[derive(Debug, Clone)]
+struct BufferSource(Vec<u8>);
+
+impl BufferSource {
+    /// Copies `length` bytes of `buffer`, starting at `offset`.
+    fn view(
+        buffer: JsValue,
+        offset: usize,
+        length: usize,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        if let Some(buffer) = buffer
+            .as_object()
+            .and_then(|o| JsArrayBuffer::from_object(o.clone()).ok())
+        {
+            // A detached buffer has no bytes.
+            let bytes = buffer
+                .data()
+                .and_then(|data| {
+                    data.get(offset..offset.saturating_add(length))
+                        .map(<[u8]>::to_vec)
+                })
+                .unwrap_or_default();
+            return Ok(Self(bytes));
+        }
+
+        // The contents of shared buffers are only reachable through a view.
+        let view = context
+            .intrinsics()
+            .constructors()
+            .typed_uint8_array()
+            .constructor()
+            .construct(&[buffer, offset.into(), length.into()], None, context)?;
+        let view = JsUint8Array::from_object(view)?;
+        Ok(Self(view.iter(context).collect()))
+    }
+}
+
+impl TryFromJs for BufferSource {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let object = value.as_object().ok_or_else(|| {
+            JsNativeError::typ().with_message("the input must be an ArrayBuffer or a view")
+        })?;
+
+        if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+            let length = buffer.byte_length();
+            return Self::view(buffer.into(), 0, length, context);
+        }
+        if let Ok(buffer) = JsSharedArrayBuffer::from_object(object.clone()) {
+            let length = buffer.byte_length();
+            return Self::view(buffer.into(), 0, length, context);
+        }
+        if let Ok(array) = JsTypedArray::from_object(object.clone()) {
+            let buffer = array.buffer(context)?;
+            let offset = array.byte_offset(context)?;
+            let length = array.byte_length(context)?;
+            return Self::view(buffer, offset, length, context);
+        }
+        if let Ok(view) = JsDataView::from_object(object.clone()) {
+            let buffer = view.buffer(context)?;
+            let offset = usize::try_from(view.byte_offset(context)?).unwrap_or(usize::MAX);
+            let length = usize::try_from(view.byte_length(context)?).unwrap_or(usize::MAX);
+            return Self::view(buffer, offset, length, context);
+        }
+
+        Err(JsNativeError::typ()
+            .with_message("the input must be an ArrayBuffer or a view")
+            .into())
+    }
+}
+
+/// The options of the `TextDecoder` constructor.
+#[derive(Debug, Clone, Copy, Default)]
+struct TextDecoderOptions {
+    fatal: bool,
+    ignore_bom: bool,
+}
+
+impl TryFromJs for TextDecoderOptions {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = value.as_object() else {
+            return Ok(Self::default());
+        };
+        Ok(Self {
+            fatal: options.get(js_str!("fatal"), context)?.to_boolean(),
+            ignore_bom: options.get(js_str!("ignoreBOM"), context)?.to_boolean(),
+        })
+    }
+}
+
+/// The options of `TextDecoder.prototype.decode`.
+#[derive(Debug, Clone, Copy, Default)]
+struct TextDecodeOptions {
+    stream: bool,
+}
+
+impl TryFromJs for TextDecodeOptions {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(options) = value.as_object() else {
+            return Ok(Self::default());
+        };
+        Ok(Self {
+            stream: options.get(js_str!("stream"), context)?.to_boolean(),
+        })
+    }
+}
+
+/// The `TextDecoder`[mdn] class represents a decoder for a specific method, that is
+/// a specific character encoding, like `utf-8`, `utf-16le`, `utf-16be` or `windows-1252`.
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder
 #[derive(Debug, Clone, JsData, Trace, Finalize)]
-pub struct TextDecoder;
+pub struct TextDecoder {
+    #[unsafe_ignore_trace]
+    decoder: Decoder,
+    #[unsafe_ignore_trace]
+    encoding: Encoding,
+    fatal: bool,
+    ignore_bom: bool,
+    bom_seen: bool,
+}
 
 impl TextDecoder {
     /// Register the `TextDecoder` class into the realm.
@@ -29,6 +154,38 @@ impl TextDecoder {
         Ok(())
     }
 
+    /// Creates a new `TextDecoder` for the encoding of `label`, following the labels of the
+    /// [Encoding Standard][spec].
+    ///
+    /// If `fatal` is `true`, decoding invalid data throws an error instead of inserting
+    /// replacement characters. If `ignore_bom` is `true`, the byte order mark is kept in
+    /// the output.
+    ///
+    /// # Errors
+    /// This will error if the label is not one of a supported encoding.
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#names-and-labels
+    pub fn new(label: &str, fatal: bool, ignore_bom: bool) -> JsResult<Self> {
+        let encoding = Encoding::for_label(label).ok_or_else(|| {
+            JsNativeError::range()
+                .with_message(format!("the encoding label `{label}` is not supported"))
+        })?;
+
+        Ok(Self {
+            decoder: Decoder::new(encoding, fatal),
+            encoding,
+            fatal,
+            ignore_bom,
+            bom_seen: false,
+        })
+    }
+
+    /// Returns the name of the encoding of this decoder.
+    #[must_use]
+    pub fn encoding(&self) -> &'static str {
+        self.encoding.name()
+    }
+
     /// The `decode()` method of the `TextDecoder` interface returns a `JsString` containing
     /// the given `Uint8Array` decoded in the specific method. This will replace any
     /// invalid characters with the Unicode replacement character.
@@ -37,29 +194,79 @@ impl TextDecoder {
         let string = String::from_utf8_lossy(&buffer);
         JsString::from(string.as_ref())
     }
+
+    /// Returns a `JsString` containing the given bytes decoded with the encoding of
+    /// this decoder.
+    ///
+    /// If `stream` is `true`, an incomplete sequence at the end of `input` is kept and
+    /// completed by the next call to `decode_bytes`.
+    ///
+    /// # Errors
+    /// This will error if the decoder is fatal and the input is not valid.
+    pub fn decode_bytes(&mut self, input: &[u8], stream: bool) -> JsResult<JsString> {
+        let mut output = Vec::with_capacity(input.len());
+        if self.decoder.decode(input, !stream, &mut output).is_err() {
+            self.bom_seen = false;
+            return Err(JsNativeError::typ()
+                .with_message(format!("the input is not valid {}", self.encoding()))
+                .into());
+        }
+
+        if !self.ignore_bom
+            && !self.bom_seen
+            && self.encoding != Encoding::Windows1252
+            && !output.is_empty()
+        {
+            self.bom_seen = true;
+            if output[0] == 0xFEFF {
+                output.remove(0);
+            }
+        }
+
+        if !stream {
+            self.bom_seen = false;
+        }
+
+        Ok(JsString::from(output.as_slice()))
+    }
 }
 
 js_class! {
     class TextDecoder {
         property encoding {
-            fn get() -> JsString {
-                js_string!("utf-8")
+            fn get(this: JsClass<TextDecoder>) -> JsString {
+                JsString::from(this.borrow().encoding())
             }
         }
 
-        // Creates a new `TextEncoder` object. Encoding is optional but MUST BE
-        // "utf-8" if specified. Options is ignored.
-        constructor(encoding: Option<JsString>, _options: Option<JsObject>) {
-            if let Some(e) = encoding {
-                if e != js_string!("utf-8") {
-                    return Err(JsNativeError::typ().with_message("Only utf-8 encoding is supported").into());
-                }
+        property fatal {
+            fn get(this: JsClass<TextDecoder>) -> bool {
+                this.borrow().fatal
             }
-            Ok(TextDecoder)
         }
 
-        fn decode(array: JsUint8Array, context: &mut Context) -> JsString {
-            TextDecoder::decode(&array, context)
+        property ignore_bom as "ignoreBOM" {
+            fn get(this: JsClass<TextDecoder>) -> bool {
+                this.borrow().ignore_bom
+            }
+        }
+
+        // Creates a new `TextDecoder` object. The label defaults to "utf-8", and the
+        // options can set the `fatal` and `ignoreBOM` flags.
+        constructor(label: Option<Convert<JsString>>, options: Option<TextDecoderOptions>) {
+            let label = label.map_or_else(|| String::from("utf-8"), |l| l.0.to_std_string_escaped());
+            let options = options.unwrap_or_default();
+            TextDecoder::new(&label, options.fatal, options.ignore_bom)
+        }
+
+        fn decode(
+            this: JsClass<TextDecoder>,
+            input: Option<BufferSource>,
+            options: Option<TextDecodeOptions>,
+        ) -> JsResult<JsString> {
+            let input = input.map(|i| i.0).unwrap_or_default();
+            let options = options.unwrap_or_default();
+            this.borrow_mut().decode_bytes(&input, options.stream)
         }
     }
 }
@@ -96,6 +303,42 @@ impl TextEncoder {
             context,
         )
     }
+
+    /// The `encodeInto()` method of the `TextEncoder` interface encodes the given string
+    /// into `destination`, stopping before the first character that doesn't fit.
+    ///
+    /// Returns the number of UTF-16 code units read from `text` and the number of bytes
+    /// written to `destination`.
+    ///
+    /// # Errors
+    /// This will error if `destination` cannot be written.
+    pub fn encode_into(
+        text: &JsString,
+        destination: &JsUint8Array,
+        context: &mut Context,
+    ) -> JsResult<(usize, usize)> {
+        let capacity = destination.length(context)?;
+        let (mut read, mut written) = (0, 0);
+        let mut buffer = [0; 4];
+
+        for code_point in text.code_points() {
+            let c = match code_point {
+                CodePoint::Unicode(c) => c,
+                CodePoint::UnpairedSurrogate(_) => '\u{FFFD}',
+            };
+            let bytes = c.encode_utf8(&mut buffer).as_bytes();
+            if written + bytes.len() > capacity {
+                break;
+            }
+            for &byte in bytes {
+                destination.set(written, byte, true, context)?;
+                written += 1;
+            }
+            read += code_point.code_unit_count();
+        }
+
+        Ok((read, written))
+    }
 }
 
 js_class! {
@@ -120,5 +363,17 @@ js_class! {
         fn encode(text: JsString, context: &mut Context) -> JsResult<JsUint8Array> {
             TextEncoder::encode(&text, context)
         }
+
+        fn encode_into as "encodeInto"(
+            source: Convert<JsString>,
+            destination: JsUint8Array,
+            context: &mut Context,
+        ) -> JsResult<JsObject> {
+            let (read, written) = TextEncoder::encode_into(&source.0, &destination, context)?;
+            Ok(ObjectInitializer::new(context)
+                .property(js_str!("read"), read, Attribute::all())
+                .property(js_str!("written"), written, Attribute::all())
+                .build())
+        }
     }
 }
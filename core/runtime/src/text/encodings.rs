@@ -0,0 +1,295 @@
+//! Decoders of the encodings supported by `TextDecoder`.
+//!
+//! See <https://encoding.spec.whatwg.org/> for more information.
+
+/// The code points of the bytes `0x80..=0x9F` in `windows-1252`. The rest of the bytes map
+/// directly to the code point of the same value.
+const WINDOWS_1252: [u16; 32] = [
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039,
+    0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+];
+
+/// The encodings supported by `TextDecoder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Encoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+impl Encoding {
+    /// Gets the encoding of a label, as defined by the [spec].
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#concept-encoding-get
+    pub(crate) fn for_label(label: &str) -> Option<Self> {
+        let label = label
+            .trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '))
+            .to_ascii_lowercase();
+
+        match label.as_str() {
+            "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+            | "x-unicode20utf8" => Some(Self::Utf8),
+            "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff" | "utf-16"
+            | "utf-16le" => Some(Self::Utf16Le),
+            "unicodefffe" | "utf-16be" => Some(Self::Utf16Be),
+            "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+            | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+            | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252" | "x-cp1252" => {
+                Some(Self::Windows1252)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the canonical name of the encoding.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "utf-8",
+            Self::Utf16Le => "utf-16le",
+            Self::Utf16Be => "utf-16be",
+            Self::Windows1252 => "windows-1252",
+        }
+    }
+}
+
+/// Error returned by a [`Decoder`] in fatal mode when the input is malformed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DecodeError;
+
+/// A streaming decoder, which keeps the state of incomplete sequences between calls
+/// to [`Decoder::decode`].
+#[derive(Debug, Clone)]
+pub(crate) struct Decoder {
+    encoding: Encoding,
+    fatal: bool,
+    state: State,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Utf8 {
+        code_point: u32,
+        bytes_seen: u8,
+        bytes_needed: u8,
+        lower_boundary: u8,
+        upper_boundary: u8,
+    },
+    Utf16 {
+        lead_byte: Option<u8>,
+        lead_surrogate: Option<u16>,
+    },
+    Stateless,
+}
+
+impl State {
+    const fn new(encoding: Encoding) -> Self {
+        match encoding {
+            Encoding::Utf8 => Self::Utf8 {
+                code_point: 0,
+                bytes_seen: 0,
+                bytes_needed: 0,
+                lower_boundary: 0x80,
+                upper_boundary: 0xBF,
+            },
+            Encoding::Utf16Le | Encoding::Utf16Be => Self::Utf16 {
+                lead_byte: None,
+                lead_surrogate: None,
+            },
+            Encoding::Windows1252 => Self::Stateless,
+        }
+    }
+}
+
+impl Decoder {
+    /// Creates a new decoder for `encoding`.
+    pub(crate) const fn new(encoding: Encoding, fatal: bool) -> Self {
+        Self {
+            encoding,
+            fatal,
+            state: State::new(encoding),
+        }
+    }
+
+    /// Decodes `input` into UTF-16 code units, appending them to `output`.
+    ///
+    /// If `flush` is `false`, incomplete sequences at the end of `input` are kept for the
+    /// next call. Otherwise, they are treated as errors and the decoder is reset.
+    pub(crate) fn decode(
+        &mut self,
+        input: &[u8],
+        flush: bool,
+        output: &mut Vec<u16>,
+    ) -> Result<(), DecodeError> {
+        let result = self.decode_bytes(input, flush, output);
+        if flush || result.is_err() {
+            self.state = State::new(self.encoding);
+        }
+        result
+    }
+
+    fn decode_bytes(
+        &mut self,
+        input: &[u8],
+        flush: bool,
+        output: &mut Vec<u16>,
+    ) -> Result<(), DecodeError> {
+        for &byte in input {
+            match self.encoding {
+                Encoding::Utf8 => self.utf8_byte(byte, output)?,
+                Encoding::Utf16Le | Encoding::Utf16Be => self.utf16_byte(byte, output)?,
+                Encoding::Windows1252 => output.push(match byte {
+                    0x80..=0x9F => WINDOWS_1252[usize::from(byte - 0x80)],
+                    _ => u16::from(byte),
+                }),
+            }
+        }
+
+        if flush {
+            let incomplete = match self.state {
+                State::Utf8 { bytes_needed, .. } => bytes_needed != 0,
+                State::Utf16 {
+                    lead_byte,
+                    lead_surrogate,
+                } => lead_byte.is_some() || lead_surrogate.is_some(),
+                State::Stateless => false,
+            };
+            if incomplete {
+                self.state = State::new(self.encoding);
+                self.error(output)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles a decoding error, either failing or emitting a replacement character.
+    fn error(&self, output: &mut Vec<u16>) -> Result<(), DecodeError> {
+        if self.fatal {
+            return Err(DecodeError);
+        }
+        output.push(0xFFFD);
+        Ok(())
+    }
+
+    /// Runs the [UTF-8 decoder][spec] on a single byte.
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#utf-8-decoder
+    fn utf8_byte(&mut self, byte: u8, output: &mut Vec<u16>) -> Result<(), DecodeError> {
+        let State::Utf8 {
+            code_point,
+            bytes_seen,
+            bytes_needed,
+            lower_boundary,
+            upper_boundary,
+        } = &mut self.state
+        else {
+            unreachable!("the UTF-8 decoder must have a UTF-8 state");
+        };
+
+        if *bytes_needed == 0 {
+            match byte {
+                0x00..=0x7F => output.push(u16::from(byte)),
+                0xC2..=0xDF => {
+                    *bytes_needed = 1;
+                    *code_point = u32::from(byte & 0x1F);
+                }
+                0xE0..=0xEF => {
+                    if byte == 0xE0 {
+                        *lower_boundary = 0xA0;
+                    } else if byte == 0xED {
+                        *upper_boundary = 0x9F;
+                    }
+                    *bytes_needed = 2;
+                    *code_point = u32::from(byte & 0xF);
+                }
+                0xF0..=0xF4 => {
+                    if byte == 0xF0 {
+                        *lower_boundary = 0x90;
+                    } else if byte == 0xF4 {
+                        *upper_boundary = 0x8F;
+                    }
+                    *bytes_needed = 3;
+                    *code_point = u32::from(byte & 0x7);
+                }
+                _ => return self.error(output),
+            }
+            return Ok(());
+        }
+
+        if !(*lower_boundary..=*upper_boundary).contains(&byte) {
+            // The byte is not part of the sequence, so it must be processed again after
+            // reporting the error for the incomplete sequence.
+            self.state = State::new(Encoding::Utf8);
+            self.error(output)?;
+            return self.utf8_byte(byte, output);
+        }
+
+        *lower_boundary = 0x80;
+        *upper_boundary = 0xBF;
+        *code_point = (*code_point << 6) | u32::from(byte & 0x3F);
+        *bytes_seen += 1;
+
+        if bytes_seen != bytes_needed {
+            return Ok(());
+        }
+
+        let c = char::from_u32(*code_point).expect("the UTF-8 decoder must emit valid code points");
+        self.state = State::new(Encoding::Utf8);
+        output.extend_from_slice(c.encode_utf16(&mut [0; 2]));
+        Ok(())
+    }
+
+    /// Runs the [shared UTF-16 decoder][spec] on a single byte.
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#shared-utf-16-decoder
+    fn utf16_byte(&mut self, byte: u8, output: &mut Vec<u16>) -> Result<(), DecodeError> {
+        let State::Utf16 { lead_byte, .. } = &mut self.state else {
+            unreachable!("the UTF-16 decoder must have a UTF-16 state");
+        };
+
+        let Some(lead) = lead_byte.take() else {
+            *lead_byte = Some(byte);
+            return Ok(());
+        };
+
+        let code_unit = if self.encoding == Encoding::Utf16Be {
+            u16::from_be_bytes([lead, byte])
+        } else {
+            u16::from_le_bytes([lead, byte])
+        };
+
+        self.utf16_code_unit(code_unit, output)
+    }
+
+    fn utf16_code_unit(
+        &mut self,
+        code_unit: u16,
+        output: &mut Vec<u16>,
+    ) -> Result<(), DecodeError> {
+        let State::Utf16 { lead_surrogate, .. } = &mut self.state else {
+            unreachable!("the UTF-16 decoder must have a UTF-16 state");
+        };
+
+        if let Some(lead) = lead_surrogate.take() {
+            if (0xDC00..=0xDFFF).contains(&code_unit) {
+                output.extend_from_slice(&[lead, code_unit]);
+                return Ok(());
+            }
+
+            // The unpaired lead surrogate is an error, but the code unit must still be
+            // processed on its own.
+            self.error(output)?;
+            return self.utf16_code_unit(code_unit, output);
+        }
+
+        match code_unit {
+            0xD800..=0xDBFF => *lead_surrogate = Some(code_unit),
+            0xDC00..=0xDFFF => self.error(output)?,
+            _ => output.push(code_unit),
+        }
+
+        Ok(())
+    }
+}
@@ -150,3 +150,225 @@ fn roundtrip() {
         context,
     );
 }
+
+/// Creates a context with the text encoding classes.
+fn text_context() -> Context {
+    let mut context = Context::default();
+    TextEncoder::register(&mut context).unwrap();
+    TextDecoder::register(&mut context).unwrap();
+    context
+}
+
+#[test]
+fn decoder_labels() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("new TextDecoder().encoding", js_str!("utf-8")),
+            TestAction::assert_eq("new TextDecoder(' UTF8\\n').encoding", js_str!("utf-8")),
+            TestAction::assert_eq("new TextDecoder('utf-16').encoding", js_str!("utf-16le")),
+            TestAction::assert_eq("new TextDecoder('UTF-16BE').encoding", js_str!("utf-16be")),
+            TestAction::assert_eq(
+                "new TextDecoder('latin1').encoding",
+                js_str!("windows-1252"),
+            ),
+            TestAction::assert_eq("new TextDecoder('ascii').encoding", js_str!("windows-1252")),
+            TestAction::assert_eq(
+                "try { new TextDecoder('utf-7'); } catch (e) { e instanceof RangeError }",
+                true,
+            ),
+            TestAction::assert_eq("new TextDecoder().fatal", false),
+            TestAction::assert_eq("new TextDecoder('utf-8', { fatal: true }).fatal", true),
+            TestAction::assert_eq(
+                "new TextDecoder('utf-8', { ignoreBOM: true }).ignoreBOM",
+                true,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_encodings() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq(
+                "new TextDecoder('utf-16le').decode(Uint8Array.of(0xFF, 0xFE, 0x48, 0, 0x69, 0))",
+                js_str!("Hi"),
+            ),
+            TestAction::assert_eq(
+                "new TextDecoder('utf-16be').decode(Uint8Array.of(0, 0x48, 0xD8, 0x3D, 0xDE, 0))",
+                js_str!("H\u{1F600}"),
+            ),
+            TestAction::assert_eq(
+                "new TextDecoder('utf-16le').decode(Uint8Array.of(0x3D, 0xD8, 0x41, 0))",
+                js_str!("\u{FFFD}A"),
+            ),
+            TestAction::assert_eq(
+                "new TextDecoder('windows-1252').decode(Uint8Array.of(0x80, 0x41, 0xE9, 0x9F))",
+                js_str!("\u{20AC}A\u{E9}\u{178}"),
+            ),
+            TestAction::assert_eq(
+                "new TextDecoder().decode(Uint8Array.of(0xF0, 0x9F, 0x41, 0xED, 0xA0, 0x80))",
+                js_str!("\u{FFFD}A\u{FFFD}\u{FFFD}\u{FFFD}"),
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_stream() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const utf8 = new TextDecoder();
+                const utf16 = new TextDecoder("utf-16le");
+            "#}),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    utf8.decode(Uint8Array.of(0x61, 0xE2), { stream: true }) +
+                        utf8.decode(Uint8Array.of(0x82), { stream: true }) +
+                        utf8.decode(Uint8Array.of(0xAC, 0x62))
+                "#},
+                js_str!("a\u{20AC}b"),
+            ),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    utf16.decode(Uint8Array.of(0x3D, 0xD8, 0x00), { stream: true }) +
+                        utf16.decode(Uint8Array.of(0xDE))
+                "#},
+                js_str!("\u{1F600}"),
+            ),
+            // Incomplete sequences are flushed at the end of a non-streaming call.
+            TestAction::assert_eq(
+                "utf8.decode(Uint8Array.of(0xE2, 0x82), { stream: true }) + utf8.decode()",
+                js_str!("\u{FFFD}"),
+            ),
+            TestAction::assert_eq("utf8.decode(Uint8Array.of(0x62))", js_str!("b")),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_fatal_and_bom() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const fatal = new TextDecoder("utf-8", { fatal: true });
+                const bom = Uint8Array.of(0xEF, 0xBB, 0xBF, 0x61);
+            "#}),
+            TestAction::assert_eq(
+                "try { fatal.decode(Uint8Array.of(0x61, 0xFF)); } catch (e) { e instanceof TypeError }",
+                true,
+            ),
+            TestAction::assert_eq(
+                "try { fatal.decode(Uint8Array.of(0xE2), { stream: true }); fatal.decode(); } catch (e) { e.name }",
+                js_str!("TypeError"),
+            ),
+            TestAction::assert_eq("fatal.decode(Uint8Array.of(0x61))", js_str!("a")),
+            TestAction::assert_eq("new TextDecoder().decode(bom)", js_str!("a")),
+            TestAction::assert_eq(
+                "new TextDecoder('utf-8', { ignoreBOM: true }).decode(bom)",
+                js_str!("\u{FEFF}a"),
+            ),
+            // Only a leading BOM is removed.
+            TestAction::assert_eq(
+                indoc! {r#"
+                    const d = new TextDecoder();
+                    d.decode(bom, { stream: true }) + d.decode(bom)
+                "#},
+                js_str!("a\u{FEFF}a"),
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_buffer_sources() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const d = new TextDecoder();
+                const bytes = Uint8Array.of(0x61, 0x62, 0x63, 0x64);
+            "#}),
+            TestAction::assert_eq("d.decode(bytes.buffer)", js_str!("abcd")),
+            TestAction::assert_eq("d.decode(new DataView(bytes.buffer, 1, 2))", js_str!("bc")),
+            TestAction::assert_eq("d.decode(new Uint16Array(bytes.buffer, 2))", js_str!("cd")),
+            TestAction::assert_eq(
+                indoc! {r#"
+                    const shared = new SharedArrayBuffer(2);
+                    new Uint8Array(shared).set([0x68, 0x69]);
+                    d.decode(shared)
+                "#},
+                js_str!("hi"),
+            ),
+            TestAction::assert_eq("d.decode()", js_str!("")),
+            TestAction::assert_eq(
+                "try { d.decode('abc'); } catch (e) { e instanceof TypeError }",
+                true,
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn encoder_encode_into() {
+    let context = &mut text_context();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                const encoder = new TextEncoder();
+                const dest = new Uint8Array(4);
+                const result = encoder.encodeInto("a€b", dest);
+            "#}),
+            TestAction::assert_eq("result.read", 2),
+            TestAction::assert_eq("result.written", 4),
+            TestAction::assert_eq("dest.join()", js_str!("97,226,130,172")),
+            TestAction::assert_eq(
+                "JSON.stringify(encoder.encodeInto('\\u{1F600}', new Uint8Array(3)))",
+                js_str!(r#"{"read":0,"written":0}"#),
+            ),
+            TestAction::assert_eq(
+                "JSON.stringify(encoder.encodeInto('\\u{1F600}\\uD800', new Uint8Array(7)))",
+                js_str!(r#"{"read":3,"written":7}"#),
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn decoder_rust_api() {
+    let context = &mut Context::default();
+
+    let array = JsUint8Array::from_iter([0x61, 0xFF, 0x62], context).unwrap();
+    assert_eq!(
+        TextDecoder::decode(&array, context),
+        js_string!("a\u{FFFD}b")
+    );
+
+    let mut decoder = TextDecoder::new("utf-16le", false, false).unwrap();
+    assert_eq!(decoder.encoding(), "utf-16le");
+    assert_eq!(
+        decoder.decode_bytes(&[0x48, 0x00, 0x3D], true).unwrap(),
+        js_string!("H")
+    );
+    assert_eq!(
+        decoder.decode_bytes(&[0xD8, 0x00, 0xDE], false).unwrap(),
+        JsString::from("\u{1F600}")
+    );
+}
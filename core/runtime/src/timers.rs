@@ -0,0 +1,517 @@
+//! Boa's implementation of the timer functions of the Web API.
+//!
+//! This module contains the `setTimeout`, `setInterval`, `clearTimeout`, `clearInterval` and
+//! `queueMicrotask` global functions, and [`TimerQueue`], a [`JobQueue`] that runs timeout jobs
+//! in the order of their deadlines, as measured by a [`Clock`].
+//!
+//! The timer functions schedule their callbacks through [`JobQueue::enqueue_timeout_job`], which
+//! means they work with any job queue that implements timeout jobs. [`TimerQueue`] additionally
+//! allows injecting a [`ManualClock`] to advance time deterministically.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG timers specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#timers
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/setTimeout
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::{
+    job::{FutureJob, JobQueue, NativeJob, PendingJobs},
+    js_string,
+    native_function::NativeFunction,
+    object::builtins::JsFunction,
+    Context, JsArgs, JsError, JsNativeError, JsResult, JsValue,
+};
+use rustc_hash::FxHashSet;
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt::Debug,
+    rc::Rc,
+    sync::Arc,
+    task::{self, Wake, Waker},
+    thread::Thread,
+    time::{Duration, Instant},
+};
+
+/// A source of time for a [`TimerQueue`].
+pub trait Clock {
+    /// Returns the current time, measured from an arbitrary but fixed origin.
+    fn now(&self) -> Duration;
+
+    /// Blocks the current thread until either `deadline` is reached or the thread is unparked.
+    ///
+    /// Returns `false` if the clock cannot wait for time to pass, in which case the job queue
+    /// returns from [`JobQueue::run_jobs`] while keeping its pending timers.
+    fn wait_until(&self, deadline: Duration) -> bool;
+}
+
+/// A [`Clock`] that follows the monotonic time of the system.
+#[derive(Debug, Clone, Copy)]
+pub struct SystemClock {
+    origin: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl SystemClock {
+    /// Creates a new `SystemClock` whose origin is the current instant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn wait_until(&self, deadline: Duration) -> bool {
+        std::thread::park_timeout(deadline.saturating_sub(self.now()));
+        true
+    }
+}
+
+/// A [`Clock`] whose time only changes when calling [`ManualClock::advance`].
+///
+/// Clones of a `ManualClock` share the same time, so a clone can be kept around to drive
+/// the time of a [`TimerQueue`]:
+///
+/// ```
+/// use boa_engine::{context::ContextBuilder, Source};
+/// use boa_runtime::{ManualClock, RegisterOptions, TimerQueue};
+/// use std::{rc::Rc, time::Duration};
+///
+/// let clock = ManualClock::new();
+/// let queue = Rc::new(TimerQueue::with_clock(clock.clone()));
+/// let context = &mut ContextBuilder::new().job_queue(queue).build().unwrap();
+/// boa_runtime::register(context, RegisterOptions::new()).unwrap();
+///
+/// context
+///     .eval(Source::from_bytes("var fired = false; setTimeout(() => fired = true, 100);"))
+///     .unwrap();
+///
+/// context.run_jobs();
+/// assert!(!context.eval(Source::from_bytes("fired")).unwrap().to_boolean());
+///
+/// clock.advance(Duration::from_millis(100));
+/// context.run_jobs();
+/// assert!(context.eval(Source::from_bytes("fired")).unwrap().to_boolean());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ManualClock {
+    now: Rc<Cell<Duration>>,
+}
+
+impl ManualClock {
+    /// Creates a new `ManualClock` starting at time zero.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the time of the clock forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get().saturating_add(duration));
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    fn wait_until(&self, _: Duration) -> bool {
+        false
+    }
+}
+
+/// A job queue that runs timeout jobs in the order of their deadlines.
+///
+/// Following the event loop of the Web API, every timeout job runs as its own task: all promise
+/// jobs are run to completion after each timeout job, before the next timeout job runs.
+///
+/// Unlike [`SimpleJobQueue`], a job that throws doesn't discard the rest of the queue. Instead,
+/// the error is stored and can be retrieved with [`TimerQueue::take_errors`].
+///
+/// [`SimpleJobQueue`]: boa_engine::job::SimpleJobQueue
+pub struct TimerQueue<C: Clock = SystemClock> {
+    clock: C,
+    jobs: RefCell<VecDeque<NativeJob>>,
+    pending: PendingJobs<Duration>,
+    errors: RefCell<Vec<JsError>>,
+}
+
+impl<C: Clock> Debug for TimerQueue<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TimerQueue")
+            .field("jobs", &self.jobs.borrow().len())
+            .field("pending", &self.pending)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for TimerQueue<SystemClock> {
+    fn default() -> Self {
+        Self::with_clock(SystemClock::new())
+    }
+}
+
+impl TimerQueue<SystemClock> {
+    /// Creates an empty `TimerQueue` that follows the time of the system.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: Clock> TimerQueue<C> {
+    /// Creates an empty `TimerQueue` that measures time with `clock`.
+    #[must_use]
+    pub fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            jobs: RefCell::default(),
+            pending: PendingJobs::new(),
+            errors: RefCell::default(),
+        }
+    }
+
+    /// Gets the clock of this queue.
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Returns the number of timeout jobs that haven't run yet.
+    #[must_use]
+    pub fn pending_timers(&self) -> usize {
+        self.pending.pending_timeouts()
+    }
+
+    /// Returns the earliest deadline of the pending timeout jobs, as measured by the clock of
+    /// this queue.
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<Duration> {
+        self.pending.next_deadline()
+    }
+
+    /// Takes all the errors thrown by jobs since the last call to this method.
+    pub fn take_errors(&self) -> Vec<JsError> {
+        std::mem::take(&mut *self.errors.borrow_mut())
+    }
+
+    /// Runs all promise jobs, including the ones enqueued while running them.
+    fn run_promise_jobs(&self, context: &mut Context) {
+        // The queue cannot be borrowed while running a job, since the job could enqueue
+        // more jobs.
+        let mut next_job = self.jobs.borrow_mut().pop_front();
+        while let Some(job) = next_job {
+            self.call(job, context);
+            next_job = self.jobs.borrow_mut().pop_front();
+        }
+    }
+
+    fn call(&self, job: NativeJob, context: &mut Context) {
+        if let Err(e) = job.call(context) {
+            self.errors.borrow_mut().push(e);
+        }
+    }
+}
+
+impl<C: Clock> JobQueue for TimerQueue<C> {
+    fn enqueue_promise_job(&self, job: NativeJob, _: &mut Context) {
+        self.jobs.borrow_mut().push_back(job);
+    }
+
+    fn run_jobs(&self, context: &mut Context) {
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = task::Context::from_waker(&waker);
+
+        loop {
+            self.run_promise_jobs(context);
+
+            let ready = self.pending.poll_futures(&mut cx);
+            if !ready.is_empty() {
+                self.jobs.borrow_mut().extend(ready);
+                continue;
+            }
+
+            // Only the timers that are due when the turn starts run in it, so a timer scheduled
+            // by one of them waits for the next turn even if it is already due.
+            let now = self.clock.now();
+            let mut expired = Vec::new();
+            while let Some(job) = self.pending.pop_expired(now) {
+                expired.push(job);
+            }
+            if !expired.is_empty() {
+                for job in expired {
+                    self.call(job, context);
+                    self.run_promise_jobs(context);
+                }
+                continue;
+            }
+
+            // Nothing is ready to run, so wait until either a future wakes us up or the next
+            // timeout job expires.
+            match self.next_deadline() {
+                Some(deadline) => {
+                    if !self.clock.wait_until(deadline) {
+                        return;
+                    }
+                }
+                None if self.pending.has_futures() => std::thread::park(),
+                None => return,
+            }
+        }
+    }
+
+    fn enqueue_future_job(&self, future: FutureJob, _: &mut Context) {
+        self.pending.enqueue_future(future);
+    }
+
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, _: &mut Context) {
+        self.pending
+            .enqueue_timeout(job, self.clock.now().saturating_add(timeout));
+    }
+}
+
+/// Wakes up the thread running the jobs of a [`TimerQueue`] when a future can make progress.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// The minimum timeout of timers nested deeper than [`MAX_UNCLAMPED_NESTING_LEVEL`].
+const MIN_NESTED_TIMEOUT: Duration = Duration::from_millis(4);
+
+/// The nesting level after which timers are clamped to [`MIN_NESTED_TIMEOUT`].
+const MAX_UNCLAMPED_NESTING_LEVEL: u32 = 5;
+
+/// The map of active timers of a realm, shared by all the timer functions.
+#[derive(Debug, Default)]
+struct ActiveTimers {
+    last_id: i32,
+    active: FxHashSet<i32>,
+    /// The timer nesting level of the timer callback that is currently running, or 0 if no
+    /// timer callback is running.
+    nesting_level: u32,
+}
+
+/// The signature of the timer functions that need the active timers of the realm.
+type TimerFunction = fn(&[JsValue], &Rc<RefCell<ActiveTimers>>, &mut Context) -> JsResult<JsValue>;
+
+/// The timer functions of the Web API.
+///
+/// The callbacks of the timers are enqueued with [`JobQueue::enqueue_timeout_job`] on the job
+/// queue of the context, so they run whenever the job queue runs its timeout jobs. [`TimerQueue`]
+/// waits for their deadlines and runs each of them as its own task.
+#[derive(Debug, Clone, Copy)]
+pub struct Timers;
+
+impl Timers {
+    /// Register the `setTimeout`, `setInterval`, `clearTimeout`, `clearInterval` and
+    /// `queueMicrotask` functions in the global object.
+    ///
+    /// # Errors
+    /// This will error if any of the functions cannot be registered.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        fn timer_method(f: TimerFunction, timers: Rc<RefCell<ActiveTimers>>) -> NativeFunction {
+            // SAFETY: `ActiveTimers` doesn't contain types that need tracing.
+            unsafe {
+                NativeFunction::from_closure(move |_, args, context| f(args, &timers, context))
+            }
+        }
+
+        let timers = Rc::new(RefCell::new(ActiveTimers::default()));
+
+        context.register_global_builtin_callable(
+            js_string!("setTimeout"),
+            1,
+            timer_method(
+                |args, timers, context| set_timer(args, false, timers, context),
+                timers.clone(),
+            ),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("setInterval"),
+            1,
+            timer_method(
+                |args, timers, context| set_timer(args, true, timers, context),
+                timers.clone(),
+            ),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("clearTimeout"),
+            0,
+            timer_method(clear_timer, timers.clone()),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("clearInterval"),
+            0,
+            timer_method(clear_timer, timers),
+        )?;
+        context.register_global_builtin_callable(
+            js_string!("queueMicrotask"),
+            1,
+            NativeFunction::from_fn_ptr(queue_microtask),
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Returns the callback argument of the timer functions, or throws a `TypeError` if it is not
+/// callable.
+fn callback(value: &JsValue, function: &str) -> JsResult<JsFunction> {
+    value
+        .as_callable()
+        .cloned()
+        .and_then(JsFunction::from_object)
+        .ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message(format!("the callback of `{function}` must be a function"))
+                .into()
+        })
+}
+
+/// Implementation of `setTimeout ( handler [ , timeout [ , ...arguments ] ] )` and
+/// `setInterval ( handler [ , timeout [ , ...arguments ] ] )`.
+fn set_timer(
+    args: &[JsValue],
+    repeat: bool,
+    timers: &Rc<RefCell<ActiveTimers>>,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let handler = callback(
+        args.get_or_undefined(0),
+        if repeat { "setInterval" } else { "setTimeout" },
+    )?;
+
+    // If timeout is less than 0, then set timeout to 0.
+    let timeout = args.get_or_undefined(1).to_i32(context)?;
+    let timeout = Duration::from_millis(u64::try_from(timeout).unwrap_or(0));
+
+    let arguments = args.get(2..).unwrap_or_default().to_vec();
+
+    let (id, nesting_level) = {
+        let mut timers = timers.borrow_mut();
+        timers.last_id = timers.last_id.checked_add(1).unwrap_or(1);
+        let id = timers.last_id;
+        timers.active.insert(id);
+        (id, timers.nesting_level)
+    };
+
+    schedule_timer(
+        id,
+        handler,
+        arguments,
+        timeout,
+        repeat,
+        nesting_level,
+        timers.clone(),
+        context,
+    );
+
+    Ok(id.into())
+}
+
+/// Enqueues the job that runs the timer with the given `id`, unless it is cleared first.
+///
+/// `nesting_level` is the timer nesting level of the task scheduling the timer; the callback of
+/// the timer runs with a nesting level one higher.
+#[allow(clippy::too_many_arguments)]
+fn schedule_timer(
+    id: i32,
+    handler: JsFunction,
+    arguments: Vec<JsValue>,
+    timeout: Duration,
+    repeat: bool,
+    nesting_level: u32,
+    timers: Rc<RefCell<ActiveTimers>>,
+    context: &mut Context,
+) {
+    // If nesting level is greater than 5, and timeout is less than 4, then set timeout to 4.
+    let delay = if nesting_level > MAX_UNCLAMPED_NESTING_LEVEL {
+        timeout.max(MIN_NESTED_TIMEOUT)
+    } else {
+        timeout
+    };
+    let nesting_level = nesting_level.saturating_add(1);
+
+    let realm = context.realm().clone();
+    let job = NativeJob::with_realm(
+        move |context| {
+            if !timers.borrow().active.contains(&id) {
+                return Ok(JsValue::undefined());
+            }
+            if !repeat {
+                timers.borrow_mut().active.remove(&id);
+            }
+
+            let previous_level =
+                std::mem::replace(&mut timers.borrow_mut().nesting_level, nesting_level);
+            let this = context.global_object().into();
+            let result = handler.call(&this, &arguments, context);
+            timers.borrow_mut().nesting_level = previous_level;
+
+            // The callback could have cleared its own interval.
+            if repeat && timers.borrow().active.contains(&id) {
+                schedule_timer(
+                    id,
+                    handler,
+                    arguments,
+                    timeout,
+                    true,
+                    nesting_level,
+                    timers,
+                    context,
+                );
+            }
+
+            result
+        },
+        realm,
+        context,
+    );
+
+    context.job_queue().enqueue_timeout_job(job, delay, context);
+}
+
+/// Implementation of `clearTimeout ( id )` and `clearInterval ( id )`.
+fn clear_timer(
+    args: &[JsValue],
+    timers: &Rc<RefCell<ActiveTimers>>,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let id = args.get_or_undefined(0).to_i32(context)?;
+    timers.borrow_mut().active.remove(&id);
+    Ok(JsValue::undefined())
+}
+
+/// Implementation of `queueMicrotask ( callback )`.
+fn queue_microtask(_: &JsValue, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    let callback = callback(args.get_or_undefined(0), "queueMicrotask")?;
+
+    let realm = context.realm().clone();
+    let job = NativeJob::with_realm(
+        move |context| callback.call(&JsValue::undefined(), &[], context),
+        realm,
+        context,
+    );
+    context.job_queue().enqueue_promise_job(job, context);
+
+    Ok(JsValue::undefined())
+}
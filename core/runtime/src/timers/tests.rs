@@ -0,0 +1,169 @@
+use crate::test::{run_test_actions_with, TestAction};
+use crate::{ManualClock, TimerQueue, Timers};
+use boa_engine::{context::ContextBuilder, js_str, Context, JsValue};
+use indoc::indoc;
+use std::{rc::Rc, time::Duration};
+
+/// Creates a context with the timer functions, driven by a manual clock.
+fn context_with_clock() -> (Context, ManualClock, Rc<TimerQueue<ManualClock>>) {
+    let clock = ManualClock::new();
+    let queue = Rc::new(TimerQueue::with_clock(clock.clone()));
+    let mut context = ContextBuilder::new()
+        .job_queue(queue.clone())
+        .build()
+        .unwrap();
+    Timers::register(&mut context).unwrap();
+    (context, clock, queue)
+}
+
+#[test]
+fn timeout_order() {
+    let (context, clock, queue) = &mut context_with_clock();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            var log = [];
+            setTimeout(() => log.push("b"), 20);
+            setTimeout((x, y) => log.push(x + y), 10, "a", "!");
+            setTimeout(() => log.push("c"), 20);
+            setTimeout(() => log.push("zero"), -5);
+        "#})],
+        context,
+    );
+    assert_eq!(queue.pending_timers(), 4);
+    assert_eq!(queue.next_deadline(), Some(Duration::ZERO));
+
+    context.run_jobs();
+    run_test_actions_with(
+        [TestAction::assert_eq("log.join()", js_str!("zero"))],
+        context,
+    );
+
+    clock.advance(Duration::from_millis(15));
+    context.run_jobs();
+    run_test_actions_with(
+        [TestAction::assert_eq("log.join()", js_str!("zero,a!"))],
+        context,
+    );
+
+    clock.advance(Duration::from_millis(5));
+    context.run_jobs();
+    run_test_actions_with(
+        [TestAction::assert_eq("log.join()", js_str!("zero,a!,b,c"))],
+        context,
+    );
+    assert_eq!(queue.pending_timers(), 0);
+}
+
+#[test]
+fn interval_and_clear() {
+    let (context, clock, _) = &mut context_with_clock();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            var ticks = 0;
+            var id = setInterval(() => {
+                ticks++;
+                if (ticks === 3) {
+                    clearInterval(id);
+                }
+            }, 10);
+            var cleared = false;
+            clearTimeout(setTimeout(() => cleared = true, 5));
+        "#})],
+        context,
+    );
+
+    for _ in 0..5 {
+        clock.advance(Duration::from_millis(10));
+        context.run_jobs();
+    }
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq("ticks", 3),
+            TestAction::assert_eq("cleared", false),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn nested_timers_are_clamped() {
+    let (context, clock, queue) = &mut context_with_clock();
+
+    run_test_actions_with(
+        [TestAction::run(
+            "var ticks = 0; setInterval(() => ticks++, 0);",
+        )],
+        context,
+    );
+
+    // The first five repetitions aren't clamped, after that the interval waits at least 4ms.
+    context.run_jobs();
+    run_test_actions_with([TestAction::assert_eq("ticks", 6)], context);
+    assert_eq!(queue.next_deadline(), Some(Duration::from_millis(4)));
+
+    clock.advance(Duration::from_millis(4));
+    context.run_jobs();
+    run_test_actions_with([TestAction::assert_eq("ticks", 7)], context);
+}
+
+#[test]
+fn microtasks_run_between_timers() {
+    let (context, _, _) = &mut context_with_clock();
+
+    run_test_actions_with(
+        [TestAction::run(indoc! {r#"
+            var log = [];
+            setTimeout(() => {
+                log.push("timeout 1");
+                queueMicrotask(() => log.push("microtask"));
+                Promise.resolve().then(() => log.push("promise"));
+            });
+            setTimeout(() => log.push("timeout 2"));
+            queueMicrotask(() => log.push("first"));
+        "#})],
+        context,
+    );
+
+    context.run_jobs();
+
+    run_test_actions_with(
+        [TestAction::assert_eq(
+            "log.join()",
+            js_str!("first,timeout 1,microtask,promise,timeout 2"),
+        )],
+        context,
+    );
+}
+
+#[test]
+fn errors() {
+    let (context, _, queue) = &mut context_with_clock();
+
+    run_test_actions_with(
+        [
+            TestAction::run(indoc! {r#"
+                var ran = false;
+                setTimeout(() => { throw new Error("boom"); });
+                setTimeout(() => ran = true);
+            "#}),
+            TestAction::assert_eq(
+                "try { setTimeout('code'); } catch (e) { e.message }",
+                js_str!("the callback of `setTimeout` must be a function"),
+            ),
+            TestAction::assert_eq(
+                "try { queueMicrotask(1); } catch (e) { e.message }",
+                js_str!("the callback of `queueMicrotask` must be a function"),
+            ),
+            TestAction::assert_eq("clearTimeout(1234)", JsValue::undefined()),
+        ],
+        context,
+    );
+
+    context.run_jobs();
+
+    run_test_actions_with([TestAction::assert_eq("ran", true)], context);
+    assert_eq!(queue.take_errors().len(), 1);
+}
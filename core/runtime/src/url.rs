@@ -14,17 +14,25 @@
 #[cfg(test)]
 mod tests;
 
+mod search_params;
+
+#[doc(inline)]
+pub use search_params::UrlSearchParams;
+
 use boa_engine::value::Convert;
 use boa_engine::{
-    js_error, js_string, Context, Finalize, JsData, JsResult, JsString, JsValue, Trace,
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace,
 };
-use boa_interop::{js_class, IntoJsFunctionCopied, JsClass};
+use boa_interop::{js_class, IntoJsFunctionCopied, JsClass, JsThis};
 use std::fmt::Display;
 
 /// The `URL` class represents a (properly parsed) Uniform Resource Locator.
+///
+/// The second field holds the `URLSearchParams` object returned by `searchParams`, which is
+/// created the first time it is accessed.
 #[derive(Debug, Clone, JsData, Trace, Finalize)]
 #[boa_gc(unsafe_no_drop)]
-pub struct Url(#[unsafe_ignore_trace] url::Url);
+pub struct Url(#[unsafe_ignore_trace] url::Url, Option<JsObject>);
 
 impl Url {
     /// Register the `URL` class into the realm.
@@ -51,11 +59,11 @@ impl Url {
             let url = base_url
                 .join(url)
                 .map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
-            Ok(Self(url))
+            Ok(Self::from(url))
         } else {
             let url = url::Url::parse(url)
                 .map_err(|e| js_error!(TypeError: "Failed to parse URL: {}", e))?;
-            Ok(Self(url))
+            Ok(Self::from(url))
         }
     }
 }
@@ -68,7 +76,7 @@ impl Display for Url {
 
 impl From<url::Url> for Url {
     fn from(url: url::Url) -> Self {
-        Self(url)
+        Self(url, None)
     }
 }
 
@@ -178,8 +186,17 @@ js_class! {
         }
 
         property search_params as "searchParams" {
-            fn get() -> JsResult<()> {
-                Err(js_error!(Error: "URL.searchParams is not implemented"))
+            fn get(this: JsThis<JsObject>, context: &mut Context) -> JsResult<JsObject> {
+                let url = this.0.clone().downcast::<Url>().map_err(|_| {
+                    js_error!(TypeError: "`searchParams` called on a non URL object")
+                })?;
+                if let Some(params) = url.borrow().data().1.clone() {
+                    return Ok(params);
+                }
+
+                let params = UrlSearchParams::from_data(UrlSearchParams::linked(url.clone()), context)?;
+                url.borrow_mut().data_mut().1 = Some(params.clone());
+                Ok(params)
             }
         }
 
@@ -0,0 +1,440 @@
+//! Boa's implementation of JavaScript's `URLSearchParams` Web API class.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [WHATWG `URLSearchParams` specification][spec]
+//!
+//! [spec]: https://url.spec.whatwg.org/#interface-urlsearchparams
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/URLSearchParams
+
+use super::Url;
+use boa_engine::native_function::NativeFunction;
+use boa_engine::object::builtins::JsArray;
+use boa_engine::object::{FunctionObjectBuilder, ObjectInitializer};
+use boa_engine::property::Attribute;
+use boa_engine::value::{Convert, TryFromJs};
+use boa_engine::{
+    js_error, js_string, Context, Finalize, JsData, JsObject, JsResult, JsString, JsSymbol,
+    JsValue, Trace,
+};
+use boa_interop::{js_class, JsClass, JsThis};
+use url::form_urlencoded;
+
+/// Converts a value to a `USVString`, replacing unpaired surrogates with the replacement
+/// character.
+fn to_usv_string(value: &JsValue, context: &mut Context) -> JsResult<String> {
+    Ok(value.to_string(context)?.to_std_string_lossy())
+}
+
+/// Parses the query of a URL as a list of name-value pairs.
+fn parse(query: &str) -> Vec<(String, String)> {
+    form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect()
+}
+
+/// The argument of the `URLSearchParams` constructor.
+///
+/// This is the `(sequence<sequence<USVString>> or record<USVString, USVString> or USVString)`
+/// union of the specification, converted to a list of name-value pairs.
+#[derive(Debug, Clone)]
+pub(super) struct SearchParamsInit(Vec<(String, String)>);
+
+impl TryFromJs for SearchParamsInit {
+    fn try_from_js(value: &JsValue, context: &mut Context) -> JsResult<Self> {
+        let Some(object) = value.as_object() else {
+            let init = to_usv_string(value, context)?;
+            // If init is a string that starts with "?", remove the first code point from init.
+            let init = init.strip_prefix('?').unwrap_or(&init);
+            return Ok(Self(parse(init)));
+        };
+
+        let method = object.get(JsSymbol::iterator(), context)?;
+        if method.is_null_or_undefined() {
+            // The value is a record, so collect its own enumerable string-keyed properties.
+            let record = JsObject::with_null_proto();
+            record.copy_data_properties::<JsString>(value, Vec::new(), context)?;
+
+            let mut list = Vec::new();
+            for key in record.own_property_keys(context)? {
+                let name = JsValue::from(key.clone());
+                if name.is_symbol() {
+                    continue;
+                }
+                let value = record.get(key, context)?;
+                list.push((
+                    to_usv_string(&name, context)?,
+                    to_usv_string(&value, context)?,
+                ));
+            }
+            return Ok(Self(list));
+        }
+
+        let mut list = Vec::new();
+        for pair in iterate(value, &method, context)? {
+            let Some(pair_object) = pair.as_object() else {
+                return Err(js_error!(
+                    TypeError: "each pair of the URLSearchParams initializer must be iterable"
+                ));
+            };
+            let method = pair_object.get(JsSymbol::iterator(), context)?;
+            let pair = iterate(&pair, &method, context)?;
+            let [name, value] = pair.as_slice() else {
+                return Err(js_error!(
+                    TypeError: "each pair of the URLSearchParams initializer must contain exactly two items"
+                ));
+            };
+            list.push((
+                to_usv_string(name, context)?,
+                to_usv_string(value, context)?,
+            ));
+        }
+
+        Ok(Self(list))
+    }
+}
+
+/// Collects all the values produced by iterating `value` with the iterator `method`.
+fn iterate(value: &JsValue, method: &JsValue, context: &mut Context) -> JsResult<Vec<JsValue>> {
+    let Some(method) = method.as_callable() else {
+        return Err(js_error!(TypeError: "value is not iterable"));
+    };
+    let iterator = method.call(value, &[], context)?;
+    let Some(iterator) = iterator.as_object() else {
+        return Err(js_error!(TypeError: "returned iterator is not an object"));
+    };
+    let next = iterator.get(js_string!("next"), context)?;
+    let Some(next) = next.as_callable() else {
+        return Err(js_error!(TypeError: "the `next` method of the iterator is not a function"));
+    };
+
+    let mut values = Vec::new();
+    loop {
+        let result = next.call(&iterator.clone().into(), &[], context)?;
+        let Some(result) = result.as_object() else {
+            return Err(js_error!(TypeError: "iterator result is not an object"));
+        };
+        if result.get(js_string!("done"), context)?.to_boolean() {
+            return Ok(values);
+        }
+        values.push(result.get(js_string!("value"), context)?);
+    }
+}
+
+/// The `URLSearchParams` class represents the query string of a URL as a list of name-value
+/// pairs.
+///
+/// Objects returned by the `searchParams` property of a `URL` are linked to it: reading them
+/// reflects the current query of the URL, and mutating them updates it.
+#[derive(Debug, Clone, JsData, Trace, Finalize)]
+pub struct UrlSearchParams {
+    /// The list of pairs of a standalone `URLSearchParams`.
+    #[unsafe_ignore_trace]
+    list: Vec<(String, String)>,
+
+    /// The `URL` object owning this `URLSearchParams`, if any.
+    url: Option<JsObject<Url>>,
+}
+
+impl UrlSearchParams {
+    /// Register the `URLSearchParams` class into the realm.
+    ///
+    /// # Errors
+    /// This will error if the context or realm cannot register the class.
+    pub fn register(context: &mut Context) -> JsResult<()> {
+        context.register_global_class::<Self>()?;
+        Ok(())
+    }
+
+    /// Creates a `URLSearchParams` linked to the query of `url`.
+    pub(super) const fn linked(url: JsObject<Url>) -> Self {
+        Self {
+            list: Vec::new(),
+            url: Some(url),
+        }
+    }
+
+    /// Returns the current list of name-value pairs.
+    #[must_use]
+    pub fn list(&self) -> Vec<(String, String)> {
+        match &self.url {
+            Some(url) => url.borrow().data().0.query().map(parse).unwrap_or_default(),
+            None => self.list.clone(),
+        }
+    }
+
+    /// Applies `f` to the list of name-value pairs, then runs the [update steps][spec] to
+    /// propagate the changes to the owning `URL`.
+    ///
+    /// [spec]: https://url.spec.whatwg.org/#concept-urlsearchparams-update
+    fn update<R>(&mut self, f: impl FnOnce(&mut Vec<(String, String)>) -> R) -> R {
+        let Some(url) = &self.url else {
+            return f(&mut self.list);
+        };
+
+        let mut list = self.list();
+        let result = f(&mut list);
+
+        let mut url = url.borrow_mut();
+        let url = &mut url.data_mut().0;
+        if list.is_empty() {
+            url.set_query(None);
+        } else {
+            url.set_query(Some(&serialize(&list)));
+        }
+
+        result
+    }
+}
+
+/// Serializes a list of name-value pairs using the `application/x-www-form-urlencoded`
+/// serializer.
+fn serialize(list: &[(String, String)]) -> String {
+    form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(list)
+        .finish()
+}
+
+/// Returns `true` if `pair` matches `name` and, if present, `value`.
+fn matches(pair: &(String, String), name: &str, value: Option<&str>) -> bool {
+    pair.0 == name && value.is_none_or(|value| pair.1 == value)
+}
+
+/// The kind of values produced by a [`SearchParamsIterator`].
+#[derive(Debug, Clone, Copy)]
+enum IterationKind {
+    Keys,
+    Values,
+    Entries,
+}
+
+/// The data of the iterators returned by `entries`, `keys` and `values`.
+///
+/// The iterator reads the list of its `URLSearchParams` on every step, so it observes the
+/// changes made during the iteration.
+#[derive(Debug, JsData, Trace, Finalize)]
+struct SearchParamsIterator {
+    params: JsObject<UrlSearchParams>,
+    #[unsafe_ignore_trace]
+    kind: IterationKind,
+    index: usize,
+}
+
+impl SearchParamsIterator {
+    /// `%URLSearchParamsIteratorPrototype%.next ( )`
+    fn next(this: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let Some(mut iterator) = this.as_object().and_then(JsObject::downcast_mut::<Self>) else {
+            return Err(js_error!(
+                TypeError: "`next` called on a non URLSearchParams iterator object"
+            ));
+        };
+
+        let pair = iterator
+            .params
+            .borrow()
+            .data()
+            .list()
+            .into_iter()
+            .nth(iterator.index);
+        let kind = iterator.kind;
+        iterator.index += 1;
+        drop(iterator);
+
+        let (value, done) = match pair {
+            Some((name, value)) => {
+                let value = match kind {
+                    IterationKind::Keys => JsString::from(name).into(),
+                    IterationKind::Values => JsString::from(value).into(),
+                    IterationKind::Entries => JsArray::from_iter(
+                        [JsString::from(name).into(), JsString::from(value).into()],
+                        context,
+                    )
+                    .into(),
+                };
+                (value, false)
+            }
+            None => (JsValue::undefined(), true),
+        };
+
+        Ok(ObjectInitializer::new(context)
+            .property(js_string!("value"), value, Attribute::all())
+            .property(js_string!("done"), done, Attribute::all())
+            .build()
+            .into())
+    }
+}
+
+js_class! {
+    class UrlSearchParams as "URLSearchParams" {
+        property size {
+            fn get(this: JsClass<UrlSearchParams>) -> usize {
+                this.borrow().list().len()
+            }
+        }
+
+        constructor(init: Option<SearchParamsInit>) {
+            Ok(UrlSearchParams {
+                list: init.map(|init| init.0).unwrap_or_default(),
+                url: None,
+            })
+        }
+
+        init(class: &mut ClassBuilder) -> JsResult<()> {
+            let context = class.context();
+
+            let iterator_prototype = ObjectInitializer::new(context)
+            .function(NativeFunction::from_fn_ptr(SearchParamsIterator::next), js_string!("next"), 0)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("URLSearchParams Iterator"),
+                Attribute::CONFIGURABLE,
+            )
+            .build();
+            iterator_prototype.set_prototype(Some(
+                context.intrinsics().constructors().iterator().prototype(),
+            ));
+
+            let iterator_method = |kind: IterationKind, name: JsString, context: &mut Context| {
+                let function = NativeFunction::from_copy_closure_with_captures(
+                    move |this, _, prototype, _| {
+                        let params = this
+                            .as_object()
+                            .and_then(|o| o.clone().downcast::<UrlSearchParams>().ok())
+                            .ok_or_else(|| {
+                                js_error!(TypeError: "method called on a non URLSearchParams object")
+                            })?;
+                        let iterator = JsObject::from_proto_and_data(
+                            prototype.clone(),
+                            SearchParamsIterator {
+                                params,
+                                kind,
+                                index: 0,
+                            },
+                        );
+                        Ok(iterator.into())
+                    },
+                    iterator_prototype.clone(),
+                );
+                FunctionObjectBuilder::new(context.realm(), function)
+                    .name(name)
+                    .length(0)
+                    .build()
+            };
+
+            let entries = iterator_method(IterationKind::Entries, js_string!("entries"), context);
+            let keys = iterator_method(IterationKind::Keys, js_string!("keys"), context);
+            let values = iterator_method(IterationKind::Values, js_string!("values"), context);
+
+            let attribute = Attribute::WRITABLE | Attribute::CONFIGURABLE;
+            class
+                .property(js_string!("entries"), entries.clone(), attribute)
+                .property(js_string!("keys"), keys, attribute)
+                .property(js_string!("values"), values, attribute)
+                .property(JsSymbol::iterator(), entries, attribute)
+                .property(
+                    JsSymbol::to_string_tag(),
+                    js_string!("URLSearchParams"),
+                    Attribute::CONFIGURABLE,
+                );
+
+            Ok(())
+        }
+
+        fn append(this: JsClass<UrlSearchParams>, name: Convert<JsString>, value: Convert<JsString>) -> () {
+            let pair = (name.0.to_std_string_lossy(), value.0.to_std_string_lossy());
+            this.borrow_mut().update(|list| list.push(pair));
+        }
+
+        fn delete(this: JsClass<UrlSearchParams>, name: Convert<JsString>, value: Option<Convert<JsString>>) -> () {
+            let name = name.0.to_std_string_lossy();
+            let value = value.map(|value| value.0.to_std_string_lossy());
+            this.borrow_mut()
+                .update(|list| list.retain(|pair| !matches(pair, &name, value.as_deref())));
+        }
+
+        fn get(this: JsClass<UrlSearchParams>, name: Convert<JsString>) -> JsValue {
+            let name = name.0.to_std_string_lossy();
+            this.borrow()
+                .list()
+                .into_iter()
+                .find(|(n, _)| *n == name)
+                .map_or(JsValue::null(), |(_, value)| JsString::from(value).into())
+        }
+
+        fn get_all as "getAll"(this: JsClass<UrlSearchParams>, name: Convert<JsString>, context: &mut Context) -> JsValue {
+            let name = name.0.to_std_string_lossy();
+            let values = this
+                .borrow()
+                .list()
+                .into_iter()
+                .filter(|(n, _)| *n == name)
+                .map(|(_, value)| JsString::from(value).into())
+                .collect::<Vec<JsValue>>();
+            JsArray::from_iter(values, context).into()
+        }
+
+        fn has(this: JsClass<UrlSearchParams>, name: Convert<JsString>, value: Option<Convert<JsString>>) -> bool {
+            let name = name.0.to_std_string_lossy();
+            let value = value.map(|value| value.0.to_std_string_lossy());
+            this.borrow()
+                .list()
+                .iter()
+                .any(|pair| matches(pair, &name, value.as_deref()))
+        }
+
+        fn set(this: JsClass<UrlSearchParams>, name: Convert<JsString>, value: Convert<JsString>) -> () {
+            let name = name.0.to_std_string_lossy();
+            let value = value.0.to_std_string_lossy();
+            this.borrow_mut().update(|list| {
+                // Set the value of the first pair whose name is `name`, and remove the others.
+                let mut found = false;
+                list.retain_mut(|pair| {
+                    if pair.0 != name {
+                        return true;
+                    }
+                    if found {
+                        return false;
+                    }
+                    found = true;
+                    pair.1.clone_from(&value);
+                    true
+                });
+                if !found {
+                    list.push((name, value));
+                }
+            });
+        }
+
+        fn sort(this: JsClass<UrlSearchParams>) -> () {
+            // Names are compared by their UTF-16 code units, and the sort must be stable.
+            this.borrow_mut()
+                .update(|list| list.sort_by(|a, b| a.0.encode_utf16().cmp(b.0.encode_utf16())));
+        }
+
+        fn for_each as "forEach"(this: JsClass<UrlSearchParams>, this_value: JsThis<JsValue>, callback: JsValue, this_arg: Option<JsValue>, context: &mut Context) -> JsResult<()> {
+            let Some(callback) = callback.as_callable() else {
+                return Err(js_error!(TypeError: "the callback of `forEach` must be a function"));
+            };
+            let this_arg = this_arg.unwrap_or_default();
+
+            // The list is read again after each call, since the callback could modify it.
+            let mut index = 0;
+            loop {
+                let pair = this.borrow().list().into_iter().nth(index);
+                let Some((name, value)) = pair else {
+                    return Ok(());
+                };
+                callback.call(
+                    &this_arg,
+                    &[JsString::from(value).into(), JsString::from(name).into(), this_value.0.clone()],
+                    context,
+                )?;
+                index += 1;
+            }
+        }
+
+        fn to_string as "toString"(this: JsClass<UrlSearchParams>) -> JsString {
+            JsString::from(serialize(&this.borrow().list()))
+        }
+    }
+}
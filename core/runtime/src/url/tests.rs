@@ -111,3 +111,87 @@ fn url_static_methods() {
         ),
     ]);
 }
+
+#[test]
+fn search_params() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                params = new URLSearchParams("?a=1&b=2&a=3");
+                assert_eq(params.size, 3);
+                assert_eq(params.get("a"), "1");
+                assert_eq(params.get("c"), null);
+                assert_eq(params.getAll("a").join(), "1,3");
+                assert(params.has("b"));
+                assert(params.has("a", "3"));
+                assert(!params.has("a", "2"));
+
+                params.append("c", "x y");
+                params.set("a", "4");
+                assert_eq(params.toString(), "a=4&b=2&c=x+y");
+                params.delete("b");
+                params.sort();
+                assert_eq(params.toString(), "a=4&c=x+y");
+
+                assert_eq(new URLSearchParams({ x: "1", y: "2" }).toString(), "x=1&y=2");
+                assert_eq(new URLSearchParams([["x", "1"], ["x", "2"]]).toString(), "x=1&x=2");
+                assert_eq(new URLSearchParams(new Map([["k", "v"]])).toString(), "k=v");
+                assert_eq(new URLSearchParams().size, 0);
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn search_params_iteration() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                params = new URLSearchParams("b=2&a=1");
+                assert_eq([...params].map(([k, v]) => k + v).join(), "b2,a1");
+                assert_eq([...params.keys()].join(), "b,a");
+                assert_eq([...params.values()].join(), "2,1");
+                assert_eq(params[Symbol.iterator], params.entries);
+                assert_eq(Object.prototype.toString.call(params.keys()), "[object URLSearchParams Iterator]");
+
+                seen = [];
+                params.forEach((value, key, obj) => {
+                    assert_eq(obj, params);
+                    seen.push(key + "=" + value);
+                });
+                assert_eq(seen.join("&"), "b=2&a=1");
+            "##,
+        ),
+    ]);
+}
+
+#[test]
+fn url_search_params_link() {
+    run_test_actions([
+        TestAction::run(TEST_HARNESS),
+        TestAction::run(
+            r##"
+                url = new URL("https://example.com/?a=1#hash");
+                params = url.searchParams;
+                assert_eq(url.searchParams, params);
+                assert_eq(params.get("a"), "1");
+
+                params.append("b", "2 3");
+                assert_eq(url.href, "https://example.com/?a=1&b=2+3#hash");
+
+                url.search = "?c=4";
+                assert_eq(params.get("a"), null);
+                assert_eq(params.get("c"), "4");
+
+                url.href = "https://example.org/?d=5";
+                assert_eq([...params.keys()].join(), "d");
+
+                params.delete("d");
+                assert_eq(url.href, "https://example.org/");
+                assert_eq(url.search, "");
+            "##,
+        ),
+    ]);
+}
@@ -89,7 +89,9 @@ impl StaticJsStrings {
         (EMPTY_STRING, ""),
         (LENGTH, "length"),
         // Symbols
+        (SYMBOL_ASYNC_DISPOSE, "Symbol.asyncDispose"),
         (SYMBOL_ASYNC_ITERATOR, "Symbol.asyncIterator"),
+        (SYMBOL_DISPOSE, "Symbol.dispose"),
         (SYMBOL_HAS_INSTANCE, "Symbol.hasInstance"),
         (SYMBOL_IS_CONCAT_SPREADABLE, "Symbol.isConcatSpreadable"),
         (SYMBOL_ITERATOR, "Symbol.iterator"),
@@ -102,7 +104,9 @@ impl StaticJsStrings {
         (SYMBOL_TO_PRIMITIVE, "Symbol.toPrimitive"),
         (SYMBOL_TO_STRING_TAG, "Symbol.toStringTag"),
         (SYMBOL_UNSCOPABLES, "Symbol.unscopables"),
+        (FN_SYMBOL_ASYNC_DISPOSE, "[Symbol.asyncDispose]"),
         (FN_SYMBOL_ASYNC_ITERATOR, "[Symbol.asyncIterator]"),
+        (FN_SYMBOL_DISPOSE, "[Symbol.dispose]"),
         (FN_SYMBOL_HAS_INSTANCE, "[Symbol.hasInstance]"),
         (FN_SYMBOL_IS_CONCAT_SPREADABLE, "[Symbol.isConcatSpreadable]"),
         (FN_SYMBOL_ITERATOR, "[Symbol.iterator]"),
@@ -129,6 +133,7 @@ impl StaticJsStrings {
         (DATE, "Date"),
         (ERROR, "Error"),
         (AGGREGATE_ERROR, "AggregateError"),
+        (SUPPRESSED_ERROR, "SuppressedError"),
         (EVAL_ERROR, "EvalError"),
         (RANGE_ERROR, "RangeError"),
         (REFERENCE_ERROR, "ReferenceError"),
@@ -148,11 +153,15 @@ impl StaticJsStrings {
         (PLURAL_RULES, "PluralRules"),
         (SEGMENTER, "Segmenter"),
         (DATE_TIME_FORMAT, "DateTimeFormat"),
+        (DISPLAY_NAMES, "DisplayNames"),
+        (DURATION_FORMAT, "DurationFormat"),
+        (ITERATOR, "Iterator"),
         (JSON, "JSON"),
         (MAP, "Map"),
         (MATH, "Math"),
         (NUMBER, "Number"),
         (NUMBER_FORMAT, "NumberFormat"),
+        (RELATIVE_TIME_FORMAT, "RelativeTimeFormat"),
         (IS_FINITE, "isFinite"),
         (IS_NAN, "isNaN"),
         (PARSE_INT, "parseInt"),
@@ -175,6 +184,7 @@ impl StaticJsStrings {
         (UINT32_ARRAY, "Uint32Array"),
         (BIG_INT64_ARRAY, "BigInt64Array"),
         (BIG_UINT64_ARRAY, "BigUint64Array"),
+        (FLOAT16_ARRAY, "Float16Array"),
         (FLOAT32_ARRAY, "Float32Array"),
         (FLOAT64_ARRAY, "Float64Array"),
         (ENCODE_URI, "encodeURI"),
@@ -182,6 +192,10 @@ impl StaticJsStrings {
         (DECODE_URI, "decodeURI"),
         (DECODE_URI_COMPONENT, "decodeURIComponent"),
         (WEAK_REF, "WeakRef"),
+        (FINALIZATION_REGISTRY, "FinalizationRegistry"),
+        (DISPOSABLE_STACK, "DisposableStack"),
+        (ASYNC_DISPOSABLE_STACK, "AsyncDisposableStack"),
+        (SHADOW_REALM, "ShadowRealm"),
         (WEAK_MAP, "WeakMap"),
         (WEAK_SET, "WeakSet"),
         (TEMPORAL, "Temporal"),
@@ -233,8 +247,12 @@ static RAW_STATICS_CACHE: LazyLock<FxHashMap<JsStr<'static>, usize>> = LazyLock:
 const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("".as_bytes()),
     // Well known symbols
+    JsStr::latin1("Symbol.asyncDispose".as_bytes()),
+    JsStr::latin1("[Symbol.asyncDispose]".as_bytes()),
     JsStr::latin1("Symbol.asyncIterator".as_bytes()),
     JsStr::latin1("[Symbol.asyncIterator]".as_bytes()),
+    JsStr::latin1("Symbol.dispose".as_bytes()),
+    JsStr::latin1("[Symbol.dispose]".as_bytes()),
     JsStr::latin1("Symbol.hasInstance".as_bytes()),
     JsStr::latin1("[Symbol.hasInstance]".as_bytes()),
     JsStr::latin1("Symbol.isConcatSpreadable".as_bytes()),
@@ -275,6 +293,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Date".as_bytes()),
     JsStr::latin1("Error".as_bytes()),
     JsStr::latin1("AggregateError".as_bytes()),
+    JsStr::latin1("SuppressedError".as_bytes()),
     JsStr::latin1("EvalError".as_bytes()),
     JsStr::latin1("RangeError".as_bytes()),
     JsStr::latin1("ReferenceError".as_bytes()),
@@ -294,11 +313,15 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("PluralRules".as_bytes()),
     JsStr::latin1("Segmenter".as_bytes()),
     JsStr::latin1("DateTimeFormat".as_bytes()),
+    JsStr::latin1("DisplayNames".as_bytes()),
+    JsStr::latin1("DurationFormat".as_bytes()),
+    JsStr::latin1("Iterator".as_bytes()),
     JsStr::latin1("JSON".as_bytes()),
     JsStr::latin1("Map".as_bytes()),
     JsStr::latin1("Math".as_bytes()),
     JsStr::latin1("Number".as_bytes()),
     JsStr::latin1("NumberFormat".as_bytes()),
+    JsStr::latin1("RelativeTimeFormat".as_bytes()),
     JsStr::latin1("isFinite".as_bytes()),
     JsStr::latin1("isNaN".as_bytes()),
     JsStr::latin1("parseInt".as_bytes()),
@@ -321,6 +344,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Uint32Array".as_bytes()),
     JsStr::latin1("BigInt64Array".as_bytes()),
     JsStr::latin1("BigUint64Array".as_bytes()),
+    JsStr::latin1("Float16Array".as_bytes()),
     JsStr::latin1("Float32Array".as_bytes()),
     JsStr::latin1("Float64Array".as_bytes()),
     JsStr::latin1("encodeURI".as_bytes()),
@@ -328,6 +352,10 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("decodeURI".as_bytes()),
     JsStr::latin1("decodeURIComponent".as_bytes()),
     JsStr::latin1("WeakRef".as_bytes()),
+    JsStr::latin1("FinalizationRegistry".as_bytes()),
+    JsStr::latin1("DisposableStack".as_bytes()),
+    JsStr::latin1("AsyncDisposableStack".as_bytes()),
+    JsStr::latin1("ShadowRealm".as_bytes()),
     JsStr::latin1("WeakMap".as_bytes()),
     JsStr::latin1("WeakSet".as_bytes()),
     JsStr::latin1("Temporal".as_bytes()),
@@ -419,6 +447,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     // Array object
     JsStr::latin1("at".as_bytes()),
     JsStr::latin1("from".as_bytes()),
+    JsStr::latin1("fromAsync".as_bytes()),
     JsStr::latin1("isArray".as_bytes()),
     JsStr::latin1("of".as_bytes()),
     JsStr::latin1("copyWithin".as_bytes()),
@@ -547,7 +576,9 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("for".as_bytes()),
     JsStr::latin1("keyFor".as_bytes()),
     JsStr::latin1("description".as_bytes()),
+    JsStr::latin1("asyncDispose".as_bytes()),
     JsStr::latin1("asyncIterator".as_bytes()),
+    JsStr::latin1("dispose".as_bytes()),
     JsStr::latin1("hasInstance".as_bytes()),
     JsStr::latin1("species".as_bytes()),
     JsStr::latin1("unscopables".as_bytes()),
@@ -563,11 +594,19 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("size".as_bytes()),
     // Set object
     JsStr::latin1("add".as_bytes()),
+    JsStr::latin1("union".as_bytes()),
+    JsStr::latin1("intersection".as_bytes()),
+    JsStr::latin1("difference".as_bytes()),
+    JsStr::latin1("symmetricDifference".as_bytes()),
+    JsStr::latin1("isSubsetOf".as_bytes()),
+    JsStr::latin1("isSupersetOf".as_bytes()),
+    JsStr::latin1("isDisjointFrom".as_bytes()),
     // Reflect object
     // Proxy object
     JsStr::latin1("revocable".as_bytes()),
     // Error objects
     JsStr::latin1("message".as_bytes()),
+    JsStr::latin1("suppressed".as_bytes()),
     // Date object
     JsStr::latin1("toJSON".as_bytes()),
     JsStr::latin1("getDate".as_bytes()),
@@ -636,6 +675,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("Map Iterator".as_bytes()),
     JsStr::latin1("For In Iterator".as_bytes()),
     JsStr::latin1("RegExp String Iterator".as_bytes()),
+    JsStr::latin1("Iterator Helper".as_bytes()),
     // Iterator result object
     JsStr::latin1("done".as_bytes()),
     // Math object
@@ -662,6 +702,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("exp".as_bytes()),
     JsStr::latin1("expm1".as_bytes()),
     JsStr::latin1("floor".as_bytes()),
+    JsStr::latin1("f16round".as_bytes()),
     JsStr::latin1("fround".as_bytes()),
     JsStr::latin1("hypot".as_bytes()),
     JsStr::latin1("imul".as_bytes()),
@@ -696,6 +737,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     // DataView object
     JsStr::latin1("getBigInt64".as_bytes()),
     JsStr::latin1("getBigUint64".as_bytes()),
+    JsStr::latin1("getFloat16".as_bytes()),
     JsStr::latin1("getFloat32".as_bytes()),
     JsStr::latin1("getFloat64".as_bytes()),
     JsStr::latin1("getInt8".as_bytes()),
@@ -706,6 +748,7 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("getUint32".as_bytes()),
     JsStr::latin1("setBigInt64".as_bytes()),
     JsStr::latin1("setBigUint64".as_bytes()),
+    JsStr::latin1("setFloat16".as_bytes()),
     JsStr::latin1("setFloat32".as_bytes()),
     JsStr::latin1("setFloat64".as_bytes()),
     JsStr::latin1("setInt8".as_bytes()),
@@ -716,6 +759,20 @@ const RAW_STATICS: &[JsStr<'_>] = &[
     JsStr::latin1("setUint32".as_bytes()),
     // WeakRef object
     JsStr::latin1("deref".as_bytes()),
+    // FinalizationRegistry object
+    JsStr::latin1("register".as_bytes()),
+    JsStr::latin1("unregister".as_bytes()),
+    // DisposableStack object
+    JsStr::latin1("use".as_bytes()),
+    JsStr::latin1("adopt".as_bytes()),
+    JsStr::latin1("defer".as_bytes()),
+    JsStr::latin1("move".as_bytes()),
+    JsStr::latin1("disposed".as_bytes()),
+    JsStr::latin1("get disposed".as_bytes()),
+    JsStr::latin1("disposeAsync".as_bytes()),
+    // ShadowRealm object
+    JsStr::latin1("evaluate".as_bytes()),
+    JsStr::latin1("importValue".as_bytes()),
     // Atomic object
     JsStr::latin1("and".as_bytes()),
     JsStr::latin1("compareExchange".as_bytes()),
@@ -49,5 +49,19 @@ fn main() -> Result<(), JsError> {
 
     set.clear(context)?;
 
+    // Set algebra can be done directly on `JsSet`s.
+    let odds = JsSet::from_iter([JsValue::new(1), JsValue::new(3), JsValue::new(5)], context);
+    let small = JsSet::from_iter([JsValue::new(1), JsValue::new(2), JsValue::new(3)], context);
+
+    assert_eq!(odds.union(&small, context)?.size()?, 4);
+    assert_eq!(odds.intersection(&small, context)?.size()?, 2);
+    assert!(odds.difference(&small, context)?.has(5, context)?);
+    assert_eq!(odds.symmetric_difference(&small, context)?.size()?, 2);
+
+    let one = JsSet::from_iter([JsValue::new(1)], context);
+    assert!(one.is_subset_of(&odds, context)?);
+    assert!(small.is_superset_of(&one, context)?);
+    assert!(!odds.is_disjoint_from(&small, context)?);
+
     Ok(())
 }
@@ -1,11 +1,11 @@
-use std::{cell::RefCell, collections::VecDeque, future::Future, pin::Pin, rc::Rc};
+use std::{cell::RefCell, collections::VecDeque, future::Future, pin::Pin, rc::Rc, time::Duration};
 
 use boa_engine::{
     builtins::promise::PromiseState,
     job::{FutureJob, JobQueue, NativeJob},
     js_string,
-    module::ModuleLoader,
-    Context, JsNativeError, JsResult, JsString, JsValue, Module,
+    module::{ModuleLoader, ModuleRequest},
+    Context, JsNativeError, JsResult, JsValue, Module,
 };
 use boa_parser::Source;
 use futures_concurrency::future::FutureGroup;
@@ -22,11 +22,11 @@ impl ModuleLoader for HttpModuleLoader {
     fn load_imported_module(
         &self,
         _referrer: boa_engine::module::Referrer,
-        specifier: JsString,
+        request: ModuleRequest,
         finish_load: Box<dyn FnOnce(JsResult<Module>, &mut Context)>,
         context: &mut Context,
     ) {
-        let url = specifier.to_std_string_escaped();
+        let url = request.specifier().to_std_string_escaped();
 
         let fetch = async move {
             // Adding some prints to show the non-deterministic nature of the async fetches.
@@ -201,6 +201,17 @@ impl JobQueue for Queue {
         self.futures.borrow_mut().push(future);
     }
 
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, context: &mut Context) {
+        // Timeout jobs are just futures that wait on a timer before running the job.
+        self.enqueue_future_job(
+            Box::pin(async move {
+                smol::Timer::after(timeout).await;
+                job
+            }),
+            context,
+        );
+    }
+
     // While the sync flavor of `run_jobs` will block the current thread until all the jobs have finished...
     fn run_jobs(&self, context: &mut Context) {
         smol::block_on(smol::LocalExecutor::new().run(self.run_jobs_async(context)));
@@ -63,6 +63,17 @@ impl JobQueue for Queue {
         self.futures.borrow_mut().push(future);
     }
 
+    fn enqueue_timeout_job(&self, job: NativeJob, timeout: Duration, context: &mut Context) {
+        // Timeout jobs are just futures that wait on a timer before running the job.
+        self.enqueue_future_job(
+            Box::pin(async move {
+                time::sleep(timeout).await;
+                job
+            }),
+            context,
+        );
+    }
+
     // While the sync flavor of `run_jobs` will block the current thread until all the jobs have finished...
     fn run_jobs(&self, context: &mut Context) {
         let runtime = tokio::runtime::Builder::new_current_thread()
@@ -1,8 +1,9 @@
 #![allow(missing_docs, rustdoc::missing_crate_level_docs)]
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::{error::Error, fs::File};
 
+use clap::Parser;
 use icu_datagen::blob_exporter::BlobExporter;
 use icu_datagen::prelude::*;
 
@@ -13,17 +14,67 @@ const EXPORT_PATH: &str = "core/icu_provider/data";
 ///
 /// This must be kept in sync with the list of implemented services of `Intl`.
 const SERVICES: &[(&str, &[DataKey])] = &[
+    ("icu_calendar", icu_calendar::provider::KEYS),
     ("icu_casemap", icu_casemap::provider::KEYS),
     ("icu_collator", icu_collator::provider::KEYS),
     ("icu_datetime", icu_datetime::provider::KEYS),
     ("icu_decimal", icu_decimal::provider::KEYS),
+    ("icu_experimental", icu_experimental::provider::KEYS),
     ("icu_list", icu_list::provider::KEYS),
     ("icu_locid_transform", icu_locid_transform::provider::KEYS),
     ("icu_normalizer", icu_normalizer::provider::KEYS),
     ("icu_plurals", icu_plurals::provider::KEYS),
     ("icu_segmenter", icu_segmenter::provider::KEYS),
+    ("icu_timezone", icu_timezone::provider::KEYS),
 ];
 
+/// Generates the ICU4X data used by Boa.
+///
+/// By default, this regenerates the data bundled with `boa_icu_provider`, which contains the
+/// "modern" locales of CLDR. Use `--locales` and `--output` to export a single blob with the
+/// data of a custom set of locales instead, which can be loaded at runtime with
+/// `ContextBuilder::icu_blob_file` or the `--icu-data` flag of the CLI.
+#[derive(Debug, Parser)]
+#[command(author, version, about)]
+struct Opt {
+    /// Comma separated list of the locales to include in the exported blob.
+    ///
+    /// The descendants of each locale are also included.
+    #[arg(long, value_delimiter = ',', requires = "output")]
+    locales: Vec<LanguageIdentifier>,
+
+    /// Path of the exported blob.
+    #[arg(long, short, requires = "locales")]
+    output: Option<PathBuf>,
+}
+
+/// Creates the datagen driver that exports the data of `locales`.
+fn driver(locales: impl IntoIterator<Item = LanguageIdentifier>) -> DatagenDriver {
+    DatagenDriver::new()
+        .with_locales_and_fallback(locales.into_iter().map(LocaleFamily::with_descendants), {
+            let mut options = FallbackOptions::default();
+            options.deduplication_strategy = Some(DeduplicationStrategy::None);
+            options
+        })
+        .with_additional_collations([String::from("search*")])
+        .with_recommended_segmenter_models()
+}
+
+/// Exports the data of `keys` as a blob to the file at `path`.
+fn export_blob(
+    path: &Path,
+    keys: impl IntoIterator<Item = DataKey>,
+    provider: &DatagenProvider,
+    driver: DatagenDriver,
+) -> Result<(), Box<dyn Error>> {
+    driver.with_keys(keys).export(
+        provider,
+        BlobExporter::new_v2_with_sink(Box::new(File::create(path)?)),
+    )?;
+
+    Ok(())
+}
+
 fn export_for_service(
     service: &str,
     keys: &[DataKey],
@@ -38,12 +89,7 @@ fn export_for_service(
     let export_path = Path::new(EXPORT_PATH);
     let export_file = export_path.join(format!("{service}.postcard"));
 
-    driver.with_keys(keys.iter().copied()).export(
-        provider,
-        BlobExporter::new_v2_with_sink(Box::new(File::create(export_file)?)),
-    )?;
-
-    Ok(())
+    export_blob(&export_file, keys.iter().copied(), provider, driver)
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
@@ -52,25 +98,32 @@ fn main() -> Result<(), Box<dyn Error>> {
         .with_level(log::LevelFilter::Info)
         .init()?;
 
+    let opt = Opt::parse();
+
+    let provider = &DatagenProvider::new_latest_tested();
+
+    if let Some(output) = opt.output {
+        log::info!(
+            "Generating ICU4X data blob `{}` for locales: {:?}",
+            output.display(),
+            opt.locales
+        );
+
+        let keys = SERVICES.iter().flat_map(|(_, keys)| keys.iter().copied());
+        return export_blob(&output, keys, provider, driver(opt.locales));
+    }
+
     // Removal will throw an error if the directory doesn't exist, hence
     // why we can ignore the error.
     let _unused = std::fs::remove_dir_all(EXPORT_PATH);
     std::fs::create_dir_all(EXPORT_PATH)?;
 
-    let provider = &DatagenProvider::new_latest_tested();
     let locales = provider
         .locales_for_coverage_levels([CoverageLevel::Modern])?
         .into_iter()
         .chain([langid!("en-US")]);
 
-    let driver = DatagenDriver::new()
-        .with_locales_and_fallback(locales.map(LocaleFamily::with_descendants), {
-            let mut options = FallbackOptions::default();
-            options.deduplication_strategy = Some(DeduplicationStrategy::None);
-            options
-        })
-        .with_additional_collations([String::from("search*")])
-        .with_recommended_segmenter_models();
+    let driver = driver(locales);
 
     for (service, keys) in SERVICES {
         export_for_service(service, keys, provider, driver.clone())?;